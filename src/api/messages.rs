@@ -3,6 +3,13 @@ use tracing::debug;
 
 use super::ApiClient;
 
+/// The prompt body's `model` object for a `provider/model` reference, or
+/// `None` if it has no provider part.
+fn prompt_model(model: &str) -> Option<serde_json::Value> {
+    let (provider_id, model_id) = model.split_once('/')?;
+    Some(serde_json::json!({ "providerID": provider_id, "modelID": model_id }))
+}
+
 impl ApiClient {
     /// Send a message to a session via the OpenCode API.
     ///
@@ -37,25 +44,32 @@ impl ApiClient {
     ///
     /// Uses `POST /session/{id}/prompt_async` with `system: true`.
     /// The server processes it without blocking and the AI will respond to it.
+    /// `model` (`provider/model`) picks the model for this prompt; `None`
+    /// leaves it to the session's default.
     pub async fn send_system_message_async(
         &self,
         base_url: &str,
         project_dir: &str,
         session_id: &str,
         text: &str,
+        model: Option<&str>,
     ) -> Result<()> {
         let url = format!("{}/session/{}/prompt_async", base_url, session_id);
         debug!(url, session_id, "Sending async system message to session");
 
+        let mut body = serde_json::json!({
+            "system": "true",
+            "parts": [{ "type": "text", "text": text }]
+        });
+        if let Some(model) = model.and_then(prompt_model) {
+            body["model"] = model;
+        }
         let resp = self
             .client
             .post(&url)
             .header("x-opencode-directory", project_dir)
             .header("Accept", "application/json")
-            .json(&serde_json::json!({
-                "system": "true",
-                "parts": [{ "type": "text", "text": text }]
-            }))
+            .json(&body)
             .send()
             .await
             .context("Failed to send async system message to opencode session")?;
@@ -154,4 +168,44 @@ impl ApiClient {
 
         Ok(messages)
     }
+
+    /// Fetch the full message history of a session, oldest first.
+    ///
+    /// Uses `GET /session/{id}/message` with the project directory header.
+    /// Returns the raw `{ info, parts }` items, for callers that need every
+    /// part (text, tool calls, files) rather than just user text.
+    pub async fn fetch_messages(
+        &self,
+        base_url: &str,
+        project_dir: &str,
+        session_id: &str,
+    ) -> Result<Vec<serde_json::Value>> {
+        let url = format!("{}/session/{}/message", base_url, session_id);
+        debug!(url, session_id, "Fetching full session history");
+
+        let response = self
+            .client
+            .get(&url)
+            .header("x-opencode-directory", project_dir)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .context("Failed to fetch session messages")?;
+
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("Fetching session messages failed: HTTP {}", status);
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .context("Failed to parse session messages response")?;
+
+        Ok(match body {
+            serde_json::Value::Array(items) => items,
+            serde_json::Value::Object(map) => map.into_iter().map(|(_, v)| v).collect(),
+            _ => Vec::new(),
+        })
+    }
 }
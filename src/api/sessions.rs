@@ -129,4 +129,38 @@ impl ApiClient {
 
         Ok(())
     }
+
+    /// Delete a session and its messages.
+    ///
+    /// Uses `DELETE /session/{id}`.
+    pub async fn delete_session(
+        &self,
+        base_url: &str,
+        project_dir: &str,
+        session_id: &str,
+    ) -> Result<()> {
+        let url = format!("{}/session/{}", base_url, session_id);
+        debug!(url, session_id, "Deleting session");
+
+        let resp = self
+            .client
+            .delete(&url)
+            .header("x-opencode-directory", project_dir)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .context("Failed to send delete request to opencode server")?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "Session delete rejected by server: HTTP {} — {}",
+                status,
+                body
+            );
+        }
+
+        Ok(())
+    }
 }
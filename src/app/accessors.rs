@@ -1,6 +1,10 @@
-//! Small App utility methods: hang detection, dirty flags, toggles.
+//! Small App utility methods: hang detection, dirty flags, toggles,
+//! project-path lookups.
+
+use std::path::Path;
 
 use crate::app::{base_url, App};
+use crate::config::SidebarPosition;
 use crate::ui::layout_manager::PanelId;
 use tracing::{debug, info};
 
@@ -21,7 +25,7 @@ impl App {
         let pty_ms = self
             .session_ownership
             .get(session_id)
-            .and_then(|&pidx| self.projects.get(pidx))
+            .and_then(|path| self.projects.iter().find(|p| &p.path == path))
             .and_then(|p| p.ptys.get(session_id))
             .map(|pty| pty.last_output_at.load(std::sync::atomic::Ordering::Acquire))
             .unwrap_or(0);
@@ -37,6 +41,37 @@ impl App {
         Some(now_ms.saturating_sub(latest_ms) / 1000)
     }
 
+    /// Index of the project at `path`. Per-project maps are keyed by path
+    /// so they stay correct when projects are removed or reordered.
+    pub fn project_index(&self, path: &Path) -> Option<usize> {
+        self.projects.iter().position(|p| p.path == path)
+    }
+
+    /// Whether `session_id` is recorded as belonging to a project other
+    /// than `project_idx`.
+    pub fn session_owned_elsewhere(&self, session_id: &str, project_idx: usize) -> bool {
+        match self.session_ownership.get(session_id) {
+            Some(owner) => self.projects.get(project_idx).map(|p| &p.path) != Some(owner),
+            None => false,
+        }
+    }
+
+    /// Whether the sidebar shows the sessions of `project_idx`.
+    pub fn sessions_expanded(&self, project_idx: usize) -> bool {
+        let path = self.projects.get(project_idx).map(|p| &p.path);
+        path.is_some() && self.sessions_expanded_for.as_ref() == path
+    }
+
+    /// Expand the sessions of `project_idx` in the sidebar, or collapse
+    /// them if they already are.
+    pub fn toggle_sessions_expanded(&mut self, project_idx: usize) {
+        if self.sessions_expanded(project_idx) {
+            self.sessions_expanded_for = None;
+        } else {
+            self.sessions_expanded_for = self.projects.get(project_idx).map(|p| p.path.clone());
+        }
+    }
+
     /// Check and consume the dirty flag on every rendered PTY.
     pub fn drain_pty_dirty_flags(&self) -> bool {
         let project = match self.projects.get(self.active_project) {
@@ -61,12 +96,31 @@ impl App {
         any_dirty
     }
 
+    /// Host terminal size as `(cols, rows)`. When the query fails (e.g.
+    /// mid-handoff between terminals) the last known size is returned
+    /// instead of an error.
+    pub fn terminal_size(&mut self) -> (u16, u16) {
+        match crossterm::terminal::size() {
+            Ok(size) => self.last_terminal_size = size,
+            Err(e) => debug!("Terminal size query failed, using last known: {}", e),
+        }
+        self.last_terminal_size
+    }
+
     #[allow(dead_code)]
     pub fn toggle_sidebar(&mut self) {
         self.layout.toggle_visible(PanelId::Sidebar);
         self.resize_all_ptys();
     }
 
+    /// Move the sidebar to `position`, mirroring the current layout. PTYs
+    /// are resized on the next draw.
+    pub fn set_sidebar_position(&mut self, position: SidebarPosition) {
+        self.config.settings.sidebar_position = position;
+        self.layout
+            .set_sidebar_right(position == SidebarPosition::Right);
+    }
+
     #[allow(dead_code)]
     pub fn toggle_focus(&mut self) {
         let panels = self.layout.visible_panels();
@@ -85,26 +139,57 @@ impl App {
         self.show_cheatsheet = !self.show_cheatsheet;
     }
 
-    /// Close the todo panel. If dirty, send a system message to the AI session.
+    /// Turn mouse capture on or off and save the setting. The event loop
+    /// applies it to the host terminal on the next tick.
+    pub fn toggle_mouse_capture(&mut self) {
+        let on = !self.config.settings.mouse_capture;
+        self.config.settings.mouse_capture = on;
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+        self.toasts.info(if on {
+            "Mouse capture on".to_string()
+        } else {
+            "Mouse capture off: host terminal selection enabled".to_string()
+        });
+    }
+
+    /// Close the todo panel. If dirty, send the todo continuation message
+    /// to the AI session, or open it in the context input for editing first
+    /// when `edit_todo_continuation` is set.
     pub fn close_todo_panel(&mut self) {
         if let Some(panel) = self.todo_panel.take() {
             if panel.dirty {
                 let session_id = panel.session_id.clone();
                 info!(session_id, "Todo panel closed with changes");
-                let proj_dir = self
+                let msg = super::todo::continuation_message(
+                    &self.config.settings.todo_continuation_prompt,
+                    &panel.todos,
+                );
+                if self.config.settings.edit_todo_continuation {
+                    self.open_todo_continuation_input(session_id, &msg);
+                    return;
+                }
+                let project = self
                     .projects
                     .iter()
                     .find(|p| p.active_session.as_deref() == Some(&session_id))
-                    .map(|p| p.path.to_string_lossy().to_string());
-                if let Some(proj_dir) = proj_dir {
+                    .map(|p| {
+                        let model = p.selected_model.clone();
+                        (p.path.to_string_lossy().to_string(), model)
+                    });
+                if let Some((proj_dir, model)) = project {
                     let base = base_url().to_string();
                     tokio::spawn(async move {
                         let client = crate::api::ApiClient::new();
-                        let msg = "[SYSTEM REMINDER - TODO CONTINUATION] The todo list has been \
-                                   updated. Re-read your todos and adjust your work plan accordingly. \
-                                   Mark completed items done and continue with the next pending task.";
                         if let Err(e) = client
-                            .send_system_message_async(&base, &proj_dir, &session_id, msg)
+                            .send_system_message_async(
+                                &base,
+                                &proj_dir,
+                                &session_id,
+                                &msg,
+                                model.as_deref(),
+                            )
                             .await
                         {
                             tracing::error!("Failed to send todo continuation prompt: {e}");
@@ -0,0 +1,235 @@
+//! Per-session timeline of SSE activity, shown in the activity log overlay.
+
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Local};
+
+use super::tool_activity::activity_label;
+use crate::app::{App, BackgroundEvent};
+
+/// Entries kept per session; older ones are dropped first.
+pub const SESSION_ACTIVITY_CAP: usize = 500;
+
+/// What an activity log entry is about (drives its color).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityKind {
+    Session,
+    Tool,
+    Edit,
+    Cost,
+    Permission,
+    Error,
+}
+
+/// One line of a session's activity log.
+#[derive(Debug, Clone)]
+pub struct ActivityEntry {
+    pub at: DateTime<Local>,
+    pub kind: ActivityKind,
+    pub text: String,
+}
+
+/// State for the activity log overlay.
+#[derive(Debug, Clone)]
+pub struct ActivityLogState {
+    pub session_id: String,
+    /// Lines scrolled up from the newest entry; 0 follows new activity.
+    pub scroll: usize,
+}
+
+impl App {
+    /// Append the activity an event represents to its session's log.
+    /// Called before the event is dispatched, so state such as
+    /// `active_sessions` and `session_stats` still holds the old values.
+    pub(crate) fn record_session_activity(&mut self, event: &BackgroundEvent) {
+        let (session_id, kind, text) = match event {
+            BackgroundEvent::SseSessionCreated { session, .. } => {
+                let text = if session.title.is_empty() {
+                    "session created".to_string()
+                } else {
+                    format!("session created: {}", session.title)
+                };
+                (session.id.clone(), ActivityKind::Session, text)
+            }
+            BackgroundEvent::SseSessionBusy { session_id } => {
+                if self.active_sessions.contains(session_id) {
+                    return;
+                }
+                (
+                    session_id.clone(),
+                    ActivityKind::Session,
+                    "busy".to_string(),
+                )
+            }
+            BackgroundEvent::SseSessionIdle { session_id, .. } => (
+                session_id.clone(),
+                ActivityKind::Session,
+                "idle".to_string(),
+            ),
+            BackgroundEvent::SseSessionError { session_id } => {
+                (session_id.clone(), ActivityKind::Error, "error".to_string())
+            }
+            BackgroundEvent::SseToolStarted {
+                project_idx,
+                session_id,
+                call_id,
+                tool,
+                input,
+            } => {
+                // A call is reported again when it goes from pending to running.
+                if self
+                    .tool_activity
+                    .get(session_id)
+                    .is_some_and(|(current, _)| current == call_id)
+                {
+                    return;
+                }
+                let root = self.projects.get(*project_idx).map(|p| p.path.as_path());
+                let text = activity_label(tool, input, root);
+                (session_id.clone(), ActivityKind::Tool, text)
+            }
+            BackgroundEvent::SseFileEdited {
+                project_idx,
+                file_path,
+            } => {
+                // `file.edited` carries no session; credit the project's
+                // active one.
+                let Some(project) = self.projects.get(*project_idx) else {
+                    return;
+                };
+                let Some(session_id) = project.active_session.clone() else {
+                    return;
+                };
+                let path = std::path::Path::new(file_path)
+                    .strip_prefix(&project.path)
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|_| file_path.clone());
+                (session_id, ActivityKind::Edit, format!("edited {path}"))
+            }
+            BackgroundEvent::SseMessageUpdated {
+                session_id, cost, ..
+            } => {
+                // Streaming updates repeat the same cost; log changes only.
+                let previous = self.session_stats.get(session_id).map_or(0.0, |s| s.cost);
+                if (cost - previous).abs() < 0.005 {
+                    return;
+                }
+                (
+                    session_id.clone(),
+                    ActivityKind::Cost,
+                    format!("cost ${cost:.2}"),
+                )
+            }
+            BackgroundEvent::SsePermissionAsked { request, .. } => (
+                request.session_id.clone(),
+                ActivityKind::Permission,
+                format!("permission asked: {}", request.permission),
+            ),
+            BackgroundEvent::SseSessionDeleted { session_id, .. } => {
+                self.session_activity.remove(session_id);
+                return;
+            }
+            _ => return,
+        };
+        self.push_session_activity(session_id, kind, text);
+    }
+
+    /// Append an entry to a session's log, dropping the oldest past the cap.
+    pub(crate) fn push_session_activity(
+        &mut self,
+        session_id: String,
+        kind: ActivityKind,
+        text: String,
+    ) {
+        let log = self.session_activity.entry(session_id).or_default();
+        if log.len() >= SESSION_ACTIVITY_CAP {
+            log.pop_front();
+        }
+        log.push_back(ActivityEntry {
+            at: Local::now(),
+            kind,
+            text,
+        });
+        if self.activity_log.is_some() {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Activity recorded for a session, oldest first.
+    pub fn session_activity_entries(&self, session_id: &str) -> Option<&VecDeque<ActivityEntry>> {
+        self.session_activity.get(session_id)
+    }
+
+    /// Toggle the activity log overlay for the active session.
+    pub fn toggle_activity_log(&mut self) {
+        if self.activity_log.is_some() {
+            self.activity_log = None;
+            return;
+        }
+        let session_id = self
+            .projects
+            .get(self.active_project)
+            .and_then(|p| p.active_session.clone());
+        match session_id {
+            Some(session_id) => {
+                self.activity_log = Some(ActivityLogState {
+                    session_id,
+                    scroll: 0,
+                });
+            }
+            None => {
+                self.toasts.warning("No active session");
+            }
+        }
+        self.needs_redraw = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[test]
+    fn test_session_activity_ring_buffer() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        for i in 0..SESSION_ACTIVITY_CAP + 3 {
+            app.push_session_activity("s1".into(), ActivityKind::Tool, format!("call {i}"));
+        }
+        let log = app.session_activity_entries("s1").unwrap();
+        assert_eq!(log.len(), SESSION_ACTIVITY_CAP);
+        assert_eq!(log.front().unwrap().text, "call 3");
+    }
+
+    #[test]
+    fn test_record_session_activity_skips_repeats() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        let busy = || BackgroundEvent::SseSessionBusy {
+            session_id: "s1".into(),
+        };
+        app.handle_background_event(busy());
+        app.handle_background_event(busy());
+
+        let cost = |cost| BackgroundEvent::SseMessageUpdated {
+            session_id: "s1".into(),
+            cost,
+            input_tokens: 0,
+            output_tokens: 0,
+            reasoning_tokens: 0,
+            cache_read: 0,
+            cache_write: 0,
+        };
+        app.handle_background_event(cost(0.25));
+        app.handle_background_event(cost(0.25));
+
+        let texts: Vec<&str> = app
+            .session_activity_entries("s1")
+            .unwrap()
+            .iter()
+            .map(|e| e.text.as_str())
+            .collect();
+        assert_eq!(texts, ["busy", "cost $0.25"]);
+    }
+}
@@ -12,6 +12,7 @@ use crate::theme::ThemeColors;
 
 impl App {
     pub fn handle_background_event(&mut self, event: BackgroundEvent) {
+        self.record_session_activity(&event);
         match event {
             BackgroundEvent::PtySpawned {
                 project_idx,
@@ -21,8 +22,9 @@ impl App {
                 if let Some(project) = self.projects.get_mut(project_idx) {
                     info!(name = %project.name, session_id, "PTY spawned via background event");
                     project.ptys.insert(session_id.clone(), pty);
-                    project.active_session = Some(session_id);
+                    project.active_session = Some(session_id.clone());
                 }
+                self.prespawn_ptys(project_idx, &session_id);
                 self.resize_all_ptys();
             }
             BackgroundEvent::SessionsFetched {
@@ -36,7 +38,8 @@ impl App {
                         .filter(|s| s.directory == dir)
                         .collect();
                     for s in &filtered {
-                        self.session_ownership.insert(s.id.clone(), project_idx);
+                        self.session_ownership
+                            .insert(s.id.clone(), project.path.clone());
                     }
                     project.sessions = filtered;
                 }
@@ -75,10 +78,8 @@ impl App {
                 project_idx,
                 session,
             } => {
-                if let Some(&owner) = self.session_ownership.get(&session.id) {
-                    if owner != project_idx {
-                        return;
-                    }
+                if self.session_owned_elsewhere(&session.id, project_idx) {
+                    return;
                 }
                 self.active_sessions.insert(session.id.clone());
                 if let Some(project) = self.projects.get_mut(project_idx) {
@@ -111,6 +112,21 @@ impl App {
                     abort_handle.abort();
                 }
             }
+            BackgroundEvent::SseToolStarted {
+                project_idx,
+                session_id,
+                call_id,
+                tool,
+                input,
+            } => {
+                self.handle_sse_tool_started(project_idx, session_id, call_id, &tool, &input);
+            }
+            BackgroundEvent::SseToolFinished {
+                session_id,
+                call_id,
+            } => {
+                self.handle_sse_tool_finished(&session_id, &call_id);
+            }
             BackgroundEvent::SseSessionError { session_id } => {
                 tracing::info!(session_id = %session_id, "SseSessionError: marking session as errored");
                 self.error_sessions.insert(session_id.clone());
@@ -125,7 +141,12 @@ impl App {
                     .find(|s| s.id == session_id)
                     .map(|s| !s.parent_id.is_empty())
                     .unwrap_or(false);
-                if !is_subagent {
+                let is_muted = self
+                    .projects
+                    .iter()
+                    .position(|p| p.sessions.iter().any(|s| s.id == session_id))
+                    .is_some_and(|i| self.is_project_muted(i));
+                if !is_subagent && !is_muted {
                     let is_current = self
                         .projects
                         .iter()
@@ -141,6 +162,44 @@ impl App {
             } => {
                 self.handle_sse_file_edited(project_idx, file_path);
             }
+            BackgroundEvent::LineBlame { message } => {
+                self.toasts.info(message);
+                self.needs_redraw = true;
+            }
+            BackgroundEvent::Toast { message, severity } => {
+                self.toasts.push(severity, message);
+                self.needs_redraw = true;
+            }
+            BackgroundEvent::NvimLspProgress { session_id, status } => {
+                self.lsp_progress_polling = false;
+                let status = status.map(|s| (session_id, s));
+                if status != self.lsp_progress {
+                    self.lsp_progress = status;
+                    self.needs_redraw = true;
+                }
+            }
+            BackgroundEvent::NvimReapChecked {
+                session_id,
+                modified,
+            } => {
+                self.finish_nvim_reap(&session_id, modified);
+            }
+            BackgroundEvent::DirenvResolved { project_path } => {
+                self.finish_direnv(project_path);
+            }
+            BackgroundEvent::GitRootFetched { project_idx, root } => {
+                self.handle_git_root_fetched(project_idx, root);
+                self.needs_redraw = true;
+            }
+            BackgroundEvent::DiffStatFetched { project_idx, stat } => {
+                self.diff_stat_polling = false;
+                if let Some(project) = self.projects.get_mut(project_idx) {
+                    if project.diff_stat != stat {
+                        project.diff_stat = stat;
+                        self.needs_redraw = true;
+                    }
+                }
+            }
             BackgroundEvent::TodosFetched { session_id, todos } => {
                 debug!(session_id, count = todos.len(), "Todos fetched");
                 if let Some(ref mut panel) = self.todo_panel {
@@ -180,6 +239,9 @@ impl App {
             } => {
                 self.handle_sse_permission_asked(project_idx, request);
             }
+            BackgroundEvent::SsePermissionReplied { request_id } => {
+                self.drop_permission_prompt(&request_id);
+            }
             BackgroundEvent::SseQuestionAsked {
                 project_idx,
                 request,
@@ -189,16 +251,50 @@ impl App {
             BackgroundEvent::ModelLimitsFetched {
                 project_idx,
                 context_window,
+                model,
             } => {
-                self.model_limits
-                    .insert(project_idx, ModelLimits { context_window });
+                if let Some(project) = self.projects.get_mut(project_idx) {
+                    self.model_limits
+                        .insert(project.path.clone(), ModelLimits { context_window });
+                    project.model = model;
+                }
                 debug!(project_idx, context_window, "Model context window fetched");
             }
-            BackgroundEvent::McpSocketRequest {
+            BackgroundEvent::ModelsFetched {
                 project_idx,
+                entries,
+            } => {
+                let current = self
+                    .projects
+                    .get(project_idx)
+                    .and_then(|p| p.selected_model.clone().or_else(|| p.model.clone()));
+                if let Some(ref mut picker) = self.model_picker {
+                    if picker.project_idx == project_idx {
+                        picker.set_entries(entries, current.as_deref());
+                    }
+                }
+            }
+            BackgroundEvent::LiveGrepResults {
+                generation,
+                matches,
+                done,
+                error,
+            } => {
+                self.handle_live_grep_results(generation, matches, done, error);
+            }
+            BackgroundEvent::McpSocketRequest {
+                project_path,
                 session_id,
                 pending,
             } => {
+                // Looked up by path: the project may have moved or been
+                // removed since its socket server started.
+                let Some(project_idx) = self.projects.iter().position(|p| p.path == project_path)
+                else {
+                    let response = crate::mcp::SocketResponse::err("Project was removed".into());
+                    let _ = pending.reply_tx.send(response);
+                    return;
+                };
                 let resolved_sid = if session_id.is_empty() {
                     self.projects
                         .get(project_idx)
@@ -207,9 +303,29 @@ impl App {
                 } else {
                     session_id
                 };
-                let response =
-                    self.handle_mcp_request(project_idx, &resolved_sid, &pending.request);
-                let _ = pending.reply_tx.send(response);
+                if self.mcp_op_spawns_shell(project_idx, &resolved_sid, &pending.request.op)
+                    && self.shell_env(project_idx).is_none()
+                {
+                    let work = crate::app::AwaitingEnv::Mcp {
+                        session_id: resolved_sid,
+                        pending,
+                    };
+                    self.await_env(project_idx, work);
+                } else if self.needs_run_approval(project_idx, &pending) {
+                    self.queue_run_approval(crate::app::PendingRunApproval {
+                        project_idx,
+                        session_id: resolved_sid,
+                        pending,
+                    });
+                } else if pending.request.op == "files" {
+                    self.spawn_mcp_files(project_idx, pending);
+                } else if pending.request.op == "gitdiff" {
+                    self.spawn_mcp_gitdiff(project_idx, pending);
+                } else {
+                    let response =
+                        self.handle_mcp_request(project_idx, &resolved_sid, &pending.request);
+                    let _ = pending.reply_tx.send(response);
+                }
             }
             BackgroundEvent::WatcherSessionMessages {
                 session_id,
@@ -249,11 +365,11 @@ impl App {
                         panel.running = None;
                     }
                 }
-                let status = if success { "done" } else { "failed" };
-                self.toast_message = Some((
-                    format!("Routine {}: {}", status, message),
-                    std::time::Instant::now(),
-                ));
+                if success {
+                    self.toasts.success(format!("Routine done: {}", message));
+                } else {
+                    self.toasts.error(format!("Routine failed: {}", message));
+                }
             }
             BackgroundEvent::RoutineCreated { routine } => {
                 debug!(id = %routine.id, name = %routine.name, "Routine created");
@@ -261,10 +377,8 @@ impl App {
                     panel.routines.push(routine.clone());
                     panel.editing = None;
                 }
-                self.toast_message = Some((
-                    format!("Routine created: {}", routine.name),
-                    std::time::Instant::now(),
-                ));
+                self.toasts
+                    .success(format!("Routine created: {}", routine.name));
             }
             BackgroundEvent::RoutineDeleted {
                 routine_id,
@@ -283,15 +397,9 @@ impl App {
                         if panel.selected >= panel.routines.len() {
                             panel.selected = panel.routines.len().saturating_sub(1);
                         }
-                        self.toast_message = Some((
-                            format!("Routine deleted: {}", name),
-                            std::time::Instant::now(),
-                        ));
+                        self.toasts.success(format!("Routine deleted: {}", name));
                     } else {
-                        self.toast_message = Some((
-                            "Failed to delete routine".to_string(),
-                            std::time::Instant::now(),
-                        ));
+                        self.toasts.error("Failed to delete routine");
                     }
                     panel.confirm_delete = None;
                 }
@@ -1,11 +1,19 @@
 use crate::app::{
-    PermissionRequest, QuestionRequest, RoutineItem, SessionInfo, SessionMessage, TodoItem,
+    PermissionRequest, QuestionRequest, RoutineItem, SessionInfo, SessionMessage, ToastSeverity,
+    TodoItem,
 };
 use crate::pty::PtyInstance;
 
 /// Events sent from background tokio tasks back to the main event loop.
 /// The event loop calls `try_recv()` each tick and dispatches to `App::handle_background_event`.
 pub enum BackgroundEvent {
+    /// `git blame` result for the neovim cursor line, shown as a toast.
+    LineBlame { message: String },
+    /// A message from a background task, shown as a toast.
+    Toast {
+        message: String,
+        severity: ToastSeverity,
+    },
     /// A PTY was successfully spawned in a background (spawn_blocking) task.
     PtySpawned {
         project_idx: usize,
@@ -49,6 +57,16 @@ pub enum BackgroundEvent {
     },
     /// SSE: a session became busy (actively processing).
     SseSessionBusy { session_id: String },
+    /// SSE: a tool call started (or its input was updated).
+    SseToolStarted {
+        project_idx: usize,
+        session_id: String,
+        call_id: String,
+        tool: String,
+        input: serde_json::Value,
+    },
+    /// SSE: a tool call completed or failed.
+    SseToolFinished { session_id: String, call_id: String },
     /// SSE: a session encountered an error.
     SseSessionError { session_id: String },
     /// SSE: a file was edited by the AI agent.
@@ -76,22 +94,62 @@ pub enum BackgroundEvent {
         project_idx: usize,
         request: PermissionRequest,
     },
+    /// SSE: a permission request was answered (here or elsewhere).
+    SsePermissionReplied { request_id: String },
     /// SSE: a question was asked by the AI agent.
     SseQuestionAsked {
         project_idx: usize,
         request: QuestionRequest,
     },
-    /// Provider model limits fetched from REST API.
+    /// Provider model limits fetched from REST API, with the default
+    /// `provider/model` if the server reported one.
     ModelLimitsFetched {
         project_idx: usize,
         context_window: u64,
+        model: Option<String>,
     },
-    /// MCP socket request from a bridge process (terminal tool invocation).
-    McpSocketRequest {
+    /// Models available to a project, for the model picker.
+    ModelsFetched {
         project_idx: usize,
+        entries: Vec<crate::app::ModelPickerEntry>,
+    },
+    /// A batch of live grep matches; `done` marks the search's last batch,
+    /// with `error` set if ripgrep failed.
+    LiveGrepResults {
+        generation: u64,
+        matches: Vec<crate::app::GrepMatch>,
+        done: bool,
+        error: Option<String>,
+    },
+    /// MCP socket request from a bridge process (terminal tool invocation),
+    /// for the project at `project_path`.
+    McpSocketRequest {
+        project_path: std::path::PathBuf,
         session_id: String,
         pending: crate::mcp::PendingSocketRequest,
     },
+    /// LSP progress polled from a session's neovim (`None` = idle).
+    NvimLspProgress {
+        session_id: String,
+        status: Option<String>,
+    },
+    /// Whether an idle session's neovim has unsaved buffers (`true` when
+    /// it couldn't be asked); it is reaped only when `false`.
+    NvimReapChecked { session_id: String, modified: bool },
+    /// direnv's export for a project is cached; shells waiting on it can
+    /// be spawned.
+    DirenvResolved { project_path: std::path::PathBuf },
+    /// Git panel header diff-stat was read for a project.
+    DiffStatFetched {
+        project_idx: usize,
+        stat: Option<crate::app::DiffStat>,
+    },
+    /// Git toplevel of a project (`None` outside git), for switching its
+    /// shell tab between the project and git roots.
+    GitRootFetched {
+        project_idx: usize,
+        root: Option<std::path::PathBuf>,
+    },
     /// User messages fetched for the watcher modal "re-inject original" picker.
     WatcherSessionMessages {
         session_id: String,
@@ -14,14 +14,11 @@ impl App {
         project_idx: usize,
         session: SessionInfo,
     ) {
-        let awaiting = self.awaiting_new_session == Some(project_idx);
+        let project_path = self.projects.get(project_idx).map(|p| p.path.clone());
+        let awaiting = project_path.is_some() && self.awaiting_new_session == project_path;
 
-        if !awaiting {
-            if let Some(&owner) = self.session_ownership.get(&session.id) {
-                if owner != project_idx {
-                    return;
-                }
-            }
+        if !awaiting && self.session_owned_elsewhere(&session.id, project_idx) {
+            return;
         }
 
         // Track parent→child relationship for watcher suppression.
@@ -36,6 +33,8 @@ impl App {
                 .or_default()
                 .insert(session.id.clone());
 
+            self.auto_expand_subagents(project_idx, &session.parent_id);
+
             // Spawn a dedicated Slack thread for the subagent.
             if let Some(ref slack_state) = self.slack_state {
                 if let Some(ref auth) = self.slack_auth {
@@ -84,7 +83,7 @@ impl App {
                 );
                 self.active_sessions.insert(session.id.clone());
                 self.session_ownership
-                    .insert(session.id.clone(), project_idx);
+                    .insert(session.id.clone(), project.path.clone());
                 project.sessions.insert(0, session.clone());
             }
             if awaiting {
@@ -112,6 +111,7 @@ impl App {
         self.input_sessions.remove(&session_id);
         self.unseen_sessions.remove(&session_id);
         self.session_ownership.remove(&session_id);
+        self.clear_context_draft(&session_id);
 
         let parent_id = self
             .projects
@@ -131,6 +131,7 @@ impl App {
 
         if let Some(project) = self.projects.get_mut(project_idx) {
             project.sessions.retain(|s| s.id != session_id);
+            project.session_last_active.remove(&session_id);
             if let Some(mut resources) = project.session_resources.remove(&session_id) {
                 for shell_pty in &mut resources.shell_ptys {
                     let _ = shell_pty.kill();
@@ -170,6 +171,7 @@ impl App {
             "SseSessionIdle received"
         );
         self.active_sessions.remove(&session_id);
+        self.tool_activity.remove(&session_id);
         // Clear input-needed state when session goes idle (permissions/questions resolved)
         self.input_sessions.remove(&session_id);
 
@@ -187,7 +189,7 @@ impl App {
         // the currently viewed session for its project.
         // Upstream opencode skips subagent sessions for notifications:
         //   handleSessionIdle: `if (session.parentID) return`
-        if parent_id.is_empty() {
+        if parent_id.is_empty() && !self.is_project_muted(project_idx) {
             let is_current = self
                 .projects
                 .get(project_idx)
@@ -245,6 +247,9 @@ impl App {
             active_project = self.active_project,
             "SseFileEdited received"
         );
+        if let Some(project) = self.projects.get_mut(project_idx) {
+            project.diff_stat_stale = true;
+        }
         if !self.neovim_mcp_enabled
             && self.config.settings.follow_edits_in_neovim
             && project_idx == self.active_project
@@ -160,6 +160,7 @@ impl App {
         if !request.session_id.is_empty() {
             self.input_sessions.insert(request.session_id.clone());
         }
+        self.queue_permission_prompt(project_idx, request.clone());
         if let (Some(ref ss), Some(ref auth)) = (self.slack_state.clone(), self.slack_auth.clone()) {
             let ss = ss.clone();
             let bot_token = auth.bot_token.clone();
@@ -0,0 +1,85 @@
+//! Visual bell: reacting to BEL from the active project's terminal panes.
+
+use std::time::{Duration, Instant};
+
+use crate::app::App;
+use crate::config::VisualBell;
+use crate::ui::layout_manager::PanelId;
+
+/// How long the status bar stays inverted after a bell.
+const BELL_FLASH: Duration = Duration::from_millis(150);
+
+impl App {
+    /// Check the active project's PTYs for new BELs. A bell in the focused
+    /// pane fires the configured visual bell; a bell in a background shell
+    /// tab marks that tab instead. The mark is cleared once the tab shows.
+    ///
+    /// Every other PTY is checked too, so a bell that rang while it was
+    /// hidden doesn't fire once it is shown; hidden shell tabs are marked.
+    pub fn poll_pty_bells(&mut self) {
+        let focused = self.layout.focused;
+        let Some(project) = self.projects.get_mut(self.active_project) else {
+            return;
+        };
+        let mut rang_in = None;
+        if let Some(pty) = project.active_pty_mut() {
+            if pty.take_bell() && focused == PanelId::TerminalPane {
+                rang_in = Some("opencode");
+            }
+        }
+        if let Some(resources) = project.active_resources_mut() {
+            let active_tab = resources.active_shell_tab;
+            let split_tab = resources.shell_split();
+            for (i, shell) in resources.shell_ptys.iter_mut().enumerate() {
+                let rang = shell.take_bell();
+                if i != active_tab {
+                    // The split pane is on screen, so its bell needs no mark.
+                    shell.bell = (shell.bell || rang) && split_tab != Some(i);
+                    continue;
+                }
+                shell.bell = false;
+                if rang && focused == PanelId::IntegratedTerminal {
+                    rang_in = Some("terminal");
+                }
+            }
+            if let Some(ref mut nvim) = resources.neovim_pty {
+                if nvim.take_bell() && focused == PanelId::NeovimPane {
+                    rang_in = Some("neovim");
+                }
+            }
+        }
+        if let Some(ref mut gitui) = project.gitui_pty {
+            if gitui.take_bell() && focused == PanelId::GitPanel {
+                rang_in = Some("gitui");
+            }
+        }
+        for project in &mut self.projects {
+            for pty in project.ptys.values_mut().chain(project.gitui_pty.as_mut()) {
+                pty.take_bell();
+            }
+            for resources in project.session_resources.values_mut() {
+                for shell in &mut resources.shell_ptys {
+                    shell.bell |= shell.take_bell();
+                }
+                if let Some(ref mut nvim) = resources.neovim_pty {
+                    nvim.take_bell();
+                }
+            }
+        }
+        if let Some(pane) = rang_in.filter(|_| !self.is_project_muted(self.active_project)) {
+            self.ring_visual_bell(pane);
+        }
+    }
+
+    fn ring_visual_bell(&mut self, pane: &str) {
+        match self.config.settings.visual_bell {
+            VisualBell::Off => {}
+            VisualBell::Flash => {
+                self.bell_flash_until = Some(Instant::now() + BELL_FLASH);
+            }
+            VisualBell::Toast => {
+                self.toasts.info(format!("Bell in {pane}"));
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Per-project command history kept by opman: command lines submitted in
+//! any shell tab are appended to a file under the config directory, so they
+//! outlive the shells that ran them. A picker searches it and re-runs one.
+
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use crate::app::snippets::SnippetPickerState;
+use crate::app::App;
+use crate::config::Config;
+
+/// Commands offered by the history picker, newest first.
+const HISTORY_LIMIT: usize = 1000;
+
+/// Newest-first unique commands from history file `lines` (oldest first),
+/// at most `limit` of them.
+fn recent_unique<'a>(lines: impl DoubleEndedIterator<Item = &'a str>, limit: usize) -> Vec<String> {
+    let mut seen = HashSet::new();
+    lines
+        .rev()
+        .filter(|l| !l.trim().is_empty() && seen.insert(*l))
+        .take(limit)
+        .map(str::to_string)
+        .collect()
+}
+
+/// The project's recorded commands, newest first.
+pub fn load_history(project: &Path) -> Vec<String> {
+    let Some(contents) = Config::command_history_path(project)
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+    else {
+        return Vec::new();
+    };
+    recent_unique(contents.lines(), HISTORY_LIMIT)
+}
+
+/// Size past which a history file is cut back to its newest half.
+const HISTORY_FILE_BYTES: u64 = 256 * 1024;
+
+/// Append `commands` to the project's history file, creating it readable
+/// by the user only (command lines can hold tokens), and trim it once it
+/// passes [`HISTORY_FILE_BYTES`].
+fn append_history(project: &Path, commands: &[String]) -> anyhow::Result<()> {
+    let path = Config::command_history_path(project)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(&path)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        // Files written before the mode was set.
+        file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    }
+    for command in commands {
+        writeln!(file, "{command}")?;
+    }
+    if file.metadata()?.len() > HISTORY_FILE_BYTES {
+        drop(file);
+        trim_history(&path)?;
+    }
+    Ok(())
+}
+
+/// Keep the newest half of a history file's lines. The trimmed copy is
+/// written beside it and renamed over it, so a crash can't lose both.
+fn trim_history(path: &Path) -> anyhow::Result<()> {
+    let contents = fs::read_to_string(path)?;
+    let lines: Vec<&str> = contents.lines().collect();
+    let kept = lines[lines.len() / 2..].join("\n") + "\n";
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let mut options = OpenOptions::new();
+    options.create(true).write(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(&tmp)?.write_all(kept.as_bytes())?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+impl App {
+    /// Append command lines submitted in shell tabs since the last call to
+    /// their project's history file. Other PTYs' submissions are dropped.
+    pub fn poll_command_history(&mut self) {
+        for project in &mut self.projects {
+            let mut commands = Vec::new();
+            for resources in project.session_resources.values_mut() {
+                for pty in &mut resources.shell_ptys {
+                    commands.append(&mut pty.submitted_commands);
+                }
+                if let Some(ref mut nvim) = resources.neovim_pty {
+                    nvim.submitted_commands.clear();
+                }
+            }
+            for pty in project.ptys.values_mut() {
+                pty.submitted_commands.clear();
+            }
+            if let Some(ref mut gitui) = project.gitui_pty {
+                gitui.submitted_commands.clear();
+            }
+            if commands.is_empty() {
+                continue;
+            }
+            if let Err(e) = append_history(&project.path, &commands) {
+                tracing::warn!(project = %project.name, "Failed to save command history: {e}");
+            }
+        }
+    }
+
+    /// Open the command history picker for the active project.
+    pub fn open_history_picker(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        if project.active_session.is_none() {
+            self.toasts.warning("No active session");
+            return;
+        }
+        self.poll_command_history();
+        let history = load_history(&self.projects[self.active_project].path);
+        self.history_picker = Some(SnippetPickerState::new(self.active_project, history));
+    }
+
+    /// Run the selected history entry in the active shell tab (a new tab if
+    /// that one is busy).
+    pub fn rerun_selected_history(&mut self) {
+        let Some(state) = self.history_picker.take() else {
+            return;
+        };
+        let Some(command) = state.selected_snippet().map(str::to_string) else {
+            return;
+        };
+        if state.project_idx != self.active_project {
+            return;
+        }
+        self.type_into_shell_tab(&format!("{command}\r"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_unique_is_newest_first() {
+        let lines = ["make", "cargo test", "", "ls", "cargo test"];
+        assert_eq!(
+            recent_unique(lines.into_iter(), 10),
+            ["cargo test", "ls", "make"]
+        );
+        assert_eq!(recent_unique(lines.into_iter(), 2), ["cargo test", "ls"]);
+    }
+
+    #[test]
+    fn test_trim_history_keeps_newest_half() {
+        let path = std::env::temp_dir().join(format!("opman-history-{}", std::process::id()));
+        fs::write(&path, "a\nb\nc\nd\n").unwrap();
+        trim_history(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "c\nd\n");
+        fs::remove_file(&path).unwrap();
+    }
+}
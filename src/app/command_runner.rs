@@ -0,0 +1,378 @@
+//! Quick command runner: type (or recall a snippet) shell command and send
+//! it to an integrated terminal tab, then report when it finishes.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::app::{App, ToastSeverity};
+use crate::pty::CommandState;
+use crate::ui::layout_manager::PanelId;
+
+/// State for the command runner overlay.
+pub struct CommandRunnerState {
+    pub project_idx: usize,
+    pub input: String,
+    pub cursor_pos: usize,
+    /// Snippet last recalled with ↑/↓, if any.
+    pub snippet: Option<usize>,
+    /// Shell tab to run in; `None` opens a new tab.
+    pub tab: Option<usize>,
+}
+
+impl CommandRunnerState {
+    pub fn set_input(&mut self, text: &str) {
+        self.input = text.to_string();
+        self.cursor_pos = self.input.len();
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.input.insert(self.cursor_pos, c);
+        self.cursor_pos += c.len_utf8();
+    }
+
+    pub fn backspace(&mut self) {
+        crate::util::delete_char_before(&mut self.input, &mut self.cursor_pos);
+    }
+
+    pub fn cursor_left(&mut self) {
+        self.cursor_pos = crate::util::prev_char_boundary(&self.input, self.cursor_pos);
+    }
+
+    pub fn cursor_right(&mut self) {
+        self.cursor_pos = crate::util::next_char_boundary(&self.input, self.cursor_pos);
+    }
+}
+
+/// A command sent by the runner that hasn't finished yet.
+pub struct RunningCommand {
+    pub command: String,
+    pub started: Instant,
+    /// The tab's OSC 133 command state (shared with its reader thread).
+    pub state: Arc<Mutex<CommandState>>,
+    /// The tab's count of OSC 133 `D` reports, and the count at which
+    /// this command has finished.
+    pub finished: Arc<AtomicU64>,
+    pub done_at: u64,
+}
+
+impl App {
+    /// Open the command runner for the active session. It targets the
+    /// active shell tab unless that tab is busy.
+    pub fn open_command_runner(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        if project.active_session.is_none() {
+            self.toasts.warning("No active session");
+            return;
+        }
+        let tab = project.active_resources().and_then(|r| {
+            let pty = r.active_shell_pty()?;
+            let busy = pty
+                .command_state
+                .lock()
+                .is_ok_and(|s| *s == CommandState::Running);
+            (!busy).then_some(r.active_shell_tab)
+        });
+        self.command_runner = Some(CommandRunnerState {
+            project_idx: self.active_project,
+            input: String::new(),
+            cursor_pos: 0,
+            snippet: None,
+            tab,
+        });
+    }
+
+    /// Names of the active session's shell tabs, for the runner's target.
+    pub fn shell_tab_names(&self) -> Vec<String> {
+        self.projects
+            .get(self.active_project)
+            .and_then(|p| p.active_resources())
+            .map(|r| {
+                r.shell_ptys
+                    .iter()
+                    .enumerate()
+                    .map(|(i, pty)| {
+                        if pty.name.is_empty() {
+                            format!("Tab {}", i + 1)
+                        } else {
+                            pty.name.clone()
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Command snippets saved for a project.
+    pub fn command_snippets(&self, project_idx: usize) -> &[String] {
+        self.config
+            .projects
+            .get(project_idx)
+            .map(|e| e.snippets.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Recall the previous (`-1`) or next (`1`) snippet into the input.
+    pub fn command_runner_browse(&mut self, delta: isize) {
+        let Some(state) = self.command_runner.as_ref() else {
+            return;
+        };
+        let snippets = self.command_snippets(state.project_idx);
+        if snippets.is_empty() {
+            return;
+        }
+        let len = snippets.len() as isize;
+        let next = match state.snippet {
+            Some(i) => (i as isize + delta).rem_euclid(len),
+            None if delta < 0 => len - 1,
+            None => 0,
+        } as usize;
+        let text = snippets[next].clone();
+        if let Some(state) = self.command_runner.as_mut() {
+            state.snippet = Some(next);
+            state.set_input(&text);
+        }
+    }
+
+    /// Cycle the target through the shell tabs and "new tab".
+    pub fn cycle_command_runner_tab(&mut self) {
+        let tabs = self.shell_tab_names().len();
+        if let Some(state) = self.command_runner.as_mut() {
+            state.tab = match state.tab {
+                None if tabs > 0 => Some(0),
+                Some(i) if i + 1 < tabs => Some(i + 1),
+                _ => None,
+            };
+        }
+    }
+
+    /// Save the typed command as a snippet, or remove it if it is one.
+    pub fn toggle_command_runner_snippet(&mut self) {
+        let Some(state) = self.command_runner.as_mut() else {
+            return;
+        };
+        let command = state.input.trim().to_string();
+        let Some(entry) = self.config.projects.get_mut(state.project_idx) else {
+            return;
+        };
+        if command.is_empty() {
+            return;
+        }
+        let message = if let Some(pos) = entry.snippets.iter().position(|c| *c == command) {
+            entry.snippets.remove(pos);
+            state.snippet = None;
+            "Removed from snippets"
+        } else {
+            entry.snippets.push(command);
+            state.snippet = Some(entry.snippets.len() - 1);
+            "Saved as snippet"
+        };
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+        self.toasts.success(message);
+    }
+
+    /// Send the typed command to the target tab (a new one if the target
+    /// is gone or busy), focus the terminal, and watch for completion.
+    pub fn run_command_runner(&mut self) {
+        let Some(state) = self.command_runner.take() else {
+            return;
+        };
+        let command = state.input.trim().to_string();
+        if command.is_empty() || state.project_idx != self.active_project {
+            return;
+        }
+
+        let tab = state.tab.filter(|&i| {
+            self.projects[self.active_project]
+                .active_resources()
+                .and_then(|r| r.shell_ptys.get(i))
+                .is_some_and(|pty| {
+                    pty.command_state
+                        .lock()
+                        .is_ok_and(|s| *s != CommandState::Running)
+                })
+        });
+        match tab {
+            Some(i) => {
+                if let Some(r) = self.projects[self.active_project].active_resources_mut() {
+                    r.select_shell_tab(i);
+                }
+                self.layout.layout_dirty = true;
+            }
+            None => {
+                let has_tabs = self.projects[self.active_project]
+                    .active_resources()
+                    .is_some_and(|r| !r.shell_ptys.is_empty());
+                let opened = if has_tabs {
+                    self.add_shell_tab()
+                } else {
+                    self.ensure_shell_pty()
+                };
+                if !opened {
+                    // Keep the command so Enter can send it once the tab is up.
+                    self.command_runner = Some(state);
+                    self.toast_no_shell_tab();
+                    return;
+                }
+            }
+        }
+
+        let sent = self.projects[self.active_project]
+            .active_resources_mut()
+            .and_then(|r| r.active_shell_pty_mut())
+            .map(|pty| {
+                // A tab that hasn't shown its first prompt yet will report
+                // that prompt's `D` before this command's.
+                let done_at = pty.commands_finished.load(Ordering::Acquire).max(1) + 1;
+                pty.write(format!("{command}\n").as_bytes()).map(|_| {
+                    (
+                        pty.command_state.clone(),
+                        pty.commands_finished.clone(),
+                        done_at,
+                    )
+                })
+            });
+        match sent {
+            Some(Ok((state, finished, done_at))) => {
+                self.running_commands.push(RunningCommand {
+                    command,
+                    started: Instant::now(),
+                    state,
+                    finished,
+                    done_at,
+                });
+            }
+            Some(Err(e)) => {
+                self.toasts.error(format!("Failed to run command: {e}"));
+                return;
+            }
+            None => {
+                self.toasts.error("Could not open a terminal tab");
+                return;
+            }
+        }
+
+        self.layout.set_visible(PanelId::IntegratedTerminal, true);
+        self.layout.focused = PanelId::IntegratedTerminal;
+        self.resize_all_ptys();
+    }
+
+    /// Toast commands sent by the runner once their tab reports them
+    /// finished (OSC 133 `D`). Commands whose tab was closed are dropped.
+    pub fn poll_running_commands(&mut self) {
+        let mut finished = Vec::new();
+        self.running_commands.retain(|run| {
+            if Arc::strong_count(&run.state) == 1 {
+                return false;
+            }
+            if run.finished.load(Ordering::Acquire) < run.done_at {
+                return true;
+            }
+            let state = match run.state.lock() {
+                Ok(s) => s.clone(),
+                Err(_) => return false,
+            };
+            finished.push((run.command.clone(), run.started.elapsed(), state));
+            false
+        });
+        for (command, elapsed, state) in &finished {
+            let (verb, severity) = match state {
+                CommandState::Failure => ("✗ failed", ToastSeverity::Error),
+                _ => ("✓ finished", ToastSeverity::Success),
+            };
+            let command = crate::util::truncate_str(command, 40);
+            self.toasts.push(
+                severity,
+                format!("{command} {verb} ({}s)", elapsed.as_secs()),
+            );
+        }
+        if !finished.is_empty() {
+            self.needs_redraw = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, ProjectEntry};
+
+    #[test]
+    fn test_command_runner_browse_snippets() {
+        let mut config = Config::default();
+        config.projects.push(ProjectEntry {
+            name: "p".into(),
+            path: "/tmp/p".into(),
+            snippets: vec!["cargo test".into(), "cargo clippy".into()],
+            ..Default::default()
+        });
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(config, tx);
+        app.command_runner = Some(CommandRunnerState {
+            project_idx: 0,
+            input: String::new(),
+            cursor_pos: 0,
+            snippet: None,
+            tab: None,
+        });
+
+        app.command_runner_browse(-1);
+        assert_eq!(app.command_runner.as_ref().unwrap().input, "cargo clippy");
+        app.command_runner_browse(1);
+        let state = app.command_runner.as_ref().unwrap();
+        assert_eq!(state.input, "cargo test");
+        assert_eq!(state.cursor_pos, state.input.len());
+    }
+
+    #[test]
+    fn test_poll_running_commands() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        let state = Arc::new(Mutex::new(CommandState::Running));
+        let finished = Arc::new(AtomicU64::new(0));
+        app.running_commands.push(RunningCommand {
+            command: "make".into(),
+            started: Instant::now(),
+            state: state.clone(),
+            finished: finished.clone(),
+            done_at: 2,
+        });
+
+        app.poll_running_commands();
+        assert_eq!(app.running_commands.len(), 1);
+
+        // A new tab's first prompt isn't the command finishing.
+        *state.lock().unwrap() = CommandState::Success;
+        finished.store(1, Ordering::Release);
+        app.poll_running_commands();
+        assert_eq!(app.running_commands.len(), 1);
+        assert!(app.toasts.current().is_none());
+
+        *state.lock().unwrap() = CommandState::Failure;
+        finished.store(2, Ordering::Release);
+        app.poll_running_commands();
+        assert!(app.running_commands.is_empty());
+        let toast = app.toasts.current().unwrap();
+        assert!(toast.text.starts_with("make ✗ failed"));
+        assert_eq!(toast.severity, ToastSeverity::Error);
+
+        // Commands finishing together are each toasted.
+        let state = Arc::new(Mutex::new(CommandState::Success));
+        for command in ["lint", "test"] {
+            app.running_commands.push(RunningCommand {
+                command: command.into(),
+                started: Instant::now(),
+                state: state.clone(),
+                finished: finished.clone(),
+                done_at: 2,
+            });
+        }
+        app.poll_running_commands();
+        assert!(app.running_commands.is_empty());
+        assert_eq!(app.toasts.pending(), 2);
+    }
+}
@@ -7,6 +7,20 @@ pub struct ContextInputState {
     pub cursor_row: usize,
     /// Current cursor column (byte offset within current line).
     pub cursor_col: usize,
+    /// Session the overlay was opened for; drafts are saved under this key.
+    pub session_id: Option<String>,
+    /// A previously saved draft for this session, offered for restore.
+    pub saved_draft: Option<Vec<String>>,
+    /// Show a rendered Markdown preview below the raw input.
+    pub preview: bool,
+    /// Sessions the prompt can be sent to as (session_id, label): the
+    /// active session first, followed by its subagents.
+    pub targets: Vec<(String, String)>,
+    /// Index into `targets` of the session that will receive the prompt.
+    pub target_idx: usize,
+    /// Send the text as-is rather than as user context; set when editing
+    /// the todo continuation message.
+    pub raw: bool,
 }
 
 impl ContextInputState {
@@ -15,6 +29,12 @@ impl ContextInputState {
             lines: vec![String::new()],
             cursor_row: 0,
             cursor_col: 0,
+            session_id: None,
+            saved_draft: None,
+            preview: false,
+            targets: Vec::new(),
+            target_idx: 0,
+            raw: false,
         }
     }
 
@@ -22,6 +42,44 @@ impl ContextInputState {
         self.lines.join("\n")
     }
 
+    /// Session ID the prompt will be sent to, if any.
+    pub fn target_session_id(&self) -> Option<&str> {
+        self.targets
+            .get(self.target_idx)
+            .map(|(id, _)| id.as_str())
+            .or(self.session_id.as_deref())
+    }
+
+    /// Cycle the target session forwards (`true`) or backwards (`false`).
+    pub fn cycle_target(&mut self, forward: bool) {
+        let n = self.targets.len();
+        if n < 2 {
+            return;
+        }
+        self.target_idx = if forward {
+            (self.target_idx + 1) % n
+        } else {
+            (self.target_idx + n - 1) % n
+        };
+    }
+
+    /// True when the buffer holds nothing but whitespace.
+    pub fn is_blank(&self) -> bool {
+        self.lines.iter().all(|l| l.trim().is_empty())
+    }
+
+    /// Replace the buffer with the offered draft, placing the cursor at its end.
+    pub fn restore_draft(&mut self) {
+        if let Some(lines) = self.saved_draft.take() {
+            if lines.is_empty() {
+                return;
+            }
+            self.cursor_row = lines.len() - 1;
+            self.cursor_col = lines[self.cursor_row].len();
+            self.lines = lines;
+        }
+    }
+
     pub fn insert_char(&mut self, c: char) {
         self.lines[self.cursor_row].insert(self.cursor_col, c);
         self.cursor_col += c.len_utf8();
@@ -91,4 +149,172 @@ impl ContextInputState {
             self.cursor_col = self.cursor_col.min(self.lines[self.cursor_row].len());
         }
     }
+
+    /// Move to the start of the previous whitespace-delimited word.
+    /// At the start of a line, jumps to the end of the previous line.
+    pub fn cursor_word_left(&mut self) {
+        if self.cursor_col == 0 {
+            if self.cursor_row > 0 {
+                self.cursor_row -= 1;
+                self.cursor_col = self.lines[self.cursor_row].len();
+            }
+            return;
+        }
+        self.cursor_col = prev_word_boundary(&self.lines[self.cursor_row], self.cursor_col);
+    }
+
+    /// Move to the end of the next whitespace-delimited word.
+    /// At the end of a line, jumps to the start of the next line.
+    pub fn cursor_word_right(&mut self) {
+        let line = &self.lines[self.cursor_row];
+        if self.cursor_col >= line.len() {
+            if self.cursor_row + 1 < self.lines.len() {
+                self.cursor_row += 1;
+                self.cursor_col = 0;
+            }
+            return;
+        }
+        self.cursor_col = next_word_boundary(line, self.cursor_col);
+    }
+
+    /// Delete from the cursor back to the start of the previous word
+    /// (Ctrl+W). At the start of a line, joins it with the previous one.
+    pub fn delete_word_left(&mut self) {
+        if self.cursor_col == 0 {
+            self.backspace();
+            return;
+        }
+        let start = prev_word_boundary(&self.lines[self.cursor_row], self.cursor_col);
+        self.lines[self.cursor_row].replace_range(start..self.cursor_col, "");
+        self.cursor_col = start;
+    }
+
+    /// Delete from the cursor to the end of the next word (Alt+D).
+    /// At the end of a line, joins the next line onto it.
+    pub fn delete_word_right(&mut self) {
+        let line_len = self.lines[self.cursor_row].len();
+        if self.cursor_col >= line_len {
+            if self.cursor_row + 1 < self.lines.len() {
+                let next = self.lines.remove(self.cursor_row + 1);
+                self.lines[self.cursor_row].push_str(&next);
+            }
+            return;
+        }
+        let end = next_word_boundary(&self.lines[self.cursor_row], self.cursor_col);
+        self.lines[self.cursor_row].replace_range(self.cursor_col..end, "");
+    }
+}
+
+/// Byte offset of the start of the word before `col`, skipping any
+/// whitespace immediately left of the cursor first.
+fn prev_word_boundary(line: &str, col: usize) -> usize {
+    let mut chars = line[..col].char_indices().rev().peekable();
+    let mut idx = col;
+    while let Some(&(i, c)) = chars.peek() {
+        if !c.is_whitespace() {
+            break;
+        }
+        idx = i;
+        chars.next();
+    }
+    while let Some(&(i, c)) = chars.peek() {
+        if c.is_whitespace() {
+            break;
+        }
+        idx = i;
+        chars.next();
+    }
+    idx
+}
+
+/// Byte offset just past the end of the word after `col`, skipping any
+/// whitespace immediately right of the cursor first.
+fn next_word_boundary(line: &str, col: usize) -> usize {
+    let mut chars = line[col..].char_indices().peekable();
+    while let Some(&(_, c)) = chars.peek() {
+        if !c.is_whitespace() {
+            break;
+        }
+        chars.next();
+    }
+    while let Some(&(_, c)) = chars.peek() {
+        if c.is_whitespace() {
+            break;
+        }
+        chars.next();
+    }
+    chars.peek().map(|&(i, _)| col + i).unwrap_or(line.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(lines: &[&str], row: usize, col: usize) -> ContextInputState {
+        ContextInputState {
+            lines: lines.iter().map(|l| l.to_string()).collect(),
+            cursor_row: row,
+            cursor_col: col,
+            session_id: None,
+            saved_draft: None,
+            preview: false,
+            targets: Vec::new(),
+            target_idx: 0,
+            raw: false,
+        }
+    }
+
+    #[test]
+    fn test_word_left_skips_whitespace_then_word() {
+        let mut s = state(&["foo bar  baz"], 0, 12);
+        s.cursor_word_left();
+        assert_eq!(s.cursor_col, 9);
+        s.cursor_word_left();
+        assert_eq!(s.cursor_col, 4);
+        s.cursor_word_left();
+        assert_eq!(s.cursor_col, 0);
+    }
+
+    #[test]
+    fn test_word_right_crosses_lines() {
+        let mut s = state(&["foo bar", "baz"], 0, 0);
+        s.cursor_word_right();
+        assert_eq!(s.cursor_col, 3);
+        s.cursor_word_right();
+        assert_eq!(s.cursor_col, 7);
+        s.cursor_word_right();
+        assert_eq!((s.cursor_row, s.cursor_col), (1, 0));
+    }
+
+    #[test]
+    fn test_word_motion_multibyte() {
+        let mut s = state(&["héllo wörld"], 0, "héllo wörld".len());
+        s.cursor_word_left();
+        assert_eq!(s.cursor_col, "héllo ".len());
+        s.cursor_word_left();
+        assert_eq!(s.cursor_col, 0);
+        s.cursor_word_right();
+        assert_eq!(s.cursor_col, "héllo".len());
+    }
+
+    #[test]
+    fn test_delete_word_left_and_right() {
+        let mut s = state(&["one two three"], 0, 7);
+        s.delete_word_left();
+        assert_eq!(s.lines[0], "one  three");
+        assert_eq!(s.cursor_col, 4);
+        s.delete_word_right();
+        assert_eq!(s.lines[0], "one ");
+    }
+
+    #[test]
+    fn test_delete_word_joins_lines_at_edges() {
+        let mut s = state(&["foo", "bar"], 1, 0);
+        s.delete_word_left();
+        assert_eq!(s.lines, vec!["foobar".to_string()]);
+        assert_eq!(s.cursor_col, 3);
+        let mut s = state(&["foo", "bar"], 0, 3);
+        s.delete_word_right();
+        assert_eq!(s.lines, vec!["foobar".to_string()]);
+    }
 }
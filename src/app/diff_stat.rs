@@ -0,0 +1,83 @@
+//! Uncommitted-change summary shown in the git panel header, from
+//! `git diff HEAD --shortstat`.
+
+use std::path::Path;
+use std::process::Command;
+
+/// Files changed and lines added/deleted against HEAD.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStat {
+    pub files: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl DiffStat {
+    pub fn is_clean(&self) -> bool {
+        self.files == 0
+    }
+}
+
+/// Parse `git diff --shortstat` output, e.g.
+/// ` 3 files changed, 120 insertions(+), 45 deletions(-)`. Empty output
+/// means a clean tree.
+pub fn parse_shortstat(output: &str) -> DiffStat {
+    let mut stat = DiffStat::default();
+    for part in output.trim().split(',') {
+        let mut words = part.split_whitespace();
+        let (Some(n), Some(kind)) = (words.next(), words.next()) else {
+            continue;
+        };
+        let Ok(n) = n.parse() else {
+            continue;
+        };
+        if kind.starts_with("file") {
+            stat.files = n;
+        } else if kind.starts_with("insertion") {
+            stat.insertions = n;
+        } else if kind.starts_with("deletion") {
+            stat.deletions = n;
+        }
+    }
+    stat
+}
+
+/// Diff-stat of the work tree at `root` (staged and unstaged), or `None`
+/// when it isn't a git repository or has no commits yet.
+pub fn read_diff_stat(root: &Path) -> Option<DiffStat> {
+    let output = Command::new("git")
+        .args(["diff", "HEAD", "--shortstat"])
+        .current_dir(root)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| parse_shortstat(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_shortstat() {
+        assert_eq!(
+            parse_shortstat(" 3 files changed, 120 insertions(+), 45 deletions(-)\n"),
+            DiffStat {
+                files: 3,
+                insertions: 120,
+                deletions: 45
+            }
+        );
+        assert_eq!(
+            parse_shortstat(" 1 file changed, 2 deletions(-)"),
+            DiffStat {
+                files: 1,
+                insertions: 0,
+                deletions: 2
+            }
+        );
+        assert!(parse_shortstat("").is_clean());
+    }
+}
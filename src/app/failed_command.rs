@@ -0,0 +1,111 @@
+//! Handing a failed shell command back: when the active shell tab's last
+//! command exited non-zero, offer to rerun it or copy it with its output
+//! (for pasting into an agent prompt).
+
+use crate::app::App;
+use crate::clipboard;
+use crate::pty::CommandState;
+
+/// Output lines copied along with a failed command; older ones are cut.
+const OUTPUT_LINE_LIMIT: usize = 500;
+
+impl App {
+    /// Offer the active shell tab's last command for a rerun (`r`) or copy
+    /// (`y`) if it failed.
+    pub fn offer_failed_command(&mut self) {
+        let pty = self
+            .projects
+            .get(self.active_project)
+            .and_then(|p| p.active_resources())
+            .and_then(|r| r.active_shell_pty());
+        let Some(command) = pty.and_then(|pty| pty.last_command.clone()) else {
+            self.toasts.warning("No command run in this terminal yet");
+            return;
+        };
+        let failed = pty.is_some_and(|pty| {
+            pty.command_state
+                .lock()
+                .is_ok_and(|s| *s == CommandState::Failure)
+        });
+        if !failed {
+            self.toasts.info("The last command didn't fail");
+            return;
+        }
+        self.failed_command_prompt = Some(command);
+    }
+
+    /// Run the failed command again in the active shell tab.
+    pub fn rerun_failed_command(&mut self, command: &str) {
+        self.type_into_shell_tab(&format!("{command}\r"));
+    }
+
+    /// Copy the failed command and its output from the active shell tab.
+    pub fn copy_failed_command(&mut self, command: &str) {
+        let output = self
+            .projects
+            .get(self.active_project)
+            .and_then(|p| p.active_resources())
+            .and_then(|r| r.active_shell_pty())
+            .map(|pty| failed_command_output(&pty.buffer_lines(), command))
+            .unwrap_or_default();
+        let text = if output.is_empty() {
+            format!("$ {command}\n")
+        } else {
+            format!("$ {command}\n{output}\n")
+        };
+        match clipboard::copy_to_clipboard(&text) {
+            Ok(()) => self.toasts.success("Copied failed command and output"),
+            Err(e) => self.toasts.error(format!("Copy failed: {e:#}")),
+        }
+    }
+}
+
+/// The output of `command` in a terminal buffer: the lines between the
+/// last prompt line that shows it and the prompt that followed, with
+/// trailing whitespace removed. Empty when the command line can't be found.
+fn failed_command_output(lines: &[String], command: &str) -> String {
+    let lines: Vec<&str> = lines.iter().map(|l| l.trim_end()).collect();
+    // The current prompt is the last non-empty line.
+    let Some(prompt) = lines.iter().rposition(|l| !l.is_empty()) else {
+        return String::new();
+    };
+    let Some(start) = lines[..prompt].iter().rposition(|l| l.contains(command)) else {
+        return String::new();
+    };
+    let mut output = &lines[start + 1..prompt];
+    while let [rest @ .., last] = output {
+        if !last.is_empty() {
+            break;
+        }
+        output = rest;
+    }
+    let skip = output.len().saturating_sub(OUTPUT_LINE_LIMIT);
+    output[skip..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_failed_command_output_between_prompts() {
+        let buffer = lines(
+            "$ cargo build\n   Compiling app\n$ cargo test   \nerror[E0425]: x\n  --> src/lib.rs:1  \n\n$ \n\n",
+        );
+        assert_eq!(
+            failed_command_output(&buffer, "cargo test"),
+            "error[E0425]: x\n  --> src/lib.rs:1"
+        );
+    }
+
+    #[test]
+    fn test_failed_command_output_without_command_line() {
+        let buffer = lines("scrolled away\n$ ");
+        assert_eq!(failed_command_output(&buffer, "make"), "");
+        assert_eq!(failed_command_output(&[], "make"), "");
+    }
+}
@@ -0,0 +1,81 @@
+//! Project file picker: fuzzy-select a file and hand it to the caller.
+
+use std::path::PathBuf;
+
+use crate::app::{App, InputMode};
+use crate::ui::fuzzy_picker::{FuzzyPickerState, PickerTarget};
+use crate::ui::layout_manager::PanelId;
+
+impl App {
+    /// Open the file picker over the active project on top of the context
+    /// input overlay. The context input stays open underneath.
+    pub fn open_context_file_picker(&mut self) {
+        let Some(root) = self.active_project().map(|p| p.path.clone()) else {
+            return;
+        };
+        self.fuzzy_picker = Some(FuzzyPickerState::new_files(
+            root,
+            PickerTarget::ContextInputFile,
+        ));
+        self.input_mode = InputMode::FuzzyPicker;
+    }
+
+    /// Open the file picker over the active project; the picked file is
+    /// opened in the session's neovim.
+    pub fn open_file_finder(&mut self) {
+        let Some(root) = self.active_project().map(|p| p.path.clone()) else {
+            return;
+        };
+        self.fuzzy_picker = Some(FuzzyPickerState::new_files(root, PickerTarget::NeovimFile));
+        self.input_mode = InputMode::FuzzyPicker;
+    }
+
+    /// Open the picked file in the active session's neovim.
+    pub fn confirm_fuzzy_neovim_file(&mut self) {
+        let selected = self.fuzzy_picker.as_ref().and_then(|fp| fp.selected_path());
+        self.cancel_fuzzy_picker();
+        let (Some(rel), Some(root)) = (selected, self.active_project().map(|p| p.path.clone()))
+        else {
+            return;
+        };
+        self.open_in_session_neovim(root.join(rel), None);
+    }
+
+    /// Open `path` (at `line`) in the active session's neovim, starting it
+    /// if needed, then show and focus the neovim pane.
+    pub(crate) fn open_in_session_neovim(&mut self, path: PathBuf, line: Option<i64>) {
+        self.ensure_neovim_pty();
+        let nvim_socket = self
+            .active_project()
+            .and_then(|p| p.active_resources())
+            .and_then(|r| r.neovim_pty.as_ref())
+            .and_then(|nvim| nvim.nvim_listen_addr.clone());
+        let Some(nvim_socket) = nvim_socket else {
+            self.toasts.warning("No neovim running for this session");
+            return;
+        };
+
+        crate::background_tasks::spawn_nvim_open_file(&self.bg_tx, nvim_socket, path, line);
+        if !self.layout.is_visible(PanelId::NeovimPane) {
+            self.layout.set_visible(PanelId::NeovimPane, true);
+            self.resize_all_ptys();
+        }
+        self.layout.focused = PanelId::NeovimPane;
+    }
+
+    /// Insert the picked relative path at the context input cursor and
+    /// return control to the context input.
+    pub fn confirm_fuzzy_context_file(&mut self) {
+        let selected = self
+            .fuzzy_picker
+            .as_ref()
+            .and_then(|fp| fp.selected_path());
+        self.cancel_fuzzy_picker();
+        if let (Some(path), Some(state)) = (selected, self.context_input.as_mut()) {
+            for c in path.chars() {
+                state.insert_char(c);
+            }
+            state.insert_char(' ');
+        }
+    }
+}
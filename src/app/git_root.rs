@@ -0,0 +1,85 @@
+//! Switching the active shell tab between the project root and the git
+//! toplevel, for projects configured as a subdirectory of a repository.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::app::App;
+use crate::pty::CommandState;
+
+/// Toplevel of the git work tree containing `path`, or `None` outside git.
+pub fn read_git_root(path: &Path) -> Option<PathBuf> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .current_dir(path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!root.is_empty()).then(|| PathBuf::from(root))
+}
+
+impl App {
+    /// `cd` the active shell tab to the git root found for a project, or
+    /// back to the project root if the last toggle in that tab went to the
+    /// git root.
+    pub fn handle_git_root_fetched(&mut self, project_idx: usize, root: Option<PathBuf>) {
+        if project_idx != self.active_project {
+            return;
+        }
+        let Some(project_path) = self.projects.get(project_idx).map(|p| p.path.clone()) else {
+            return;
+        };
+        let Some(root) = root else {
+            self.toasts.warning("Project isn't in a git repository");
+            return;
+        };
+        if root == project_path {
+            self.set_shell_at_git_root(false);
+            self.toasts.info("The project root is the git root");
+            return;
+        }
+        // A busy tab gets a new one beside it, which starts at the project
+        // root.
+        let at_git_root = self.projects[project_idx]
+            .active_resources()
+            .and_then(|r| r.active_shell_pty())
+            .is_some_and(|pty| {
+                let idle = pty
+                    .command_state
+                    .lock()
+                    .is_ok_and(|s| *s != CommandState::Running);
+                idle && pty.at_git_root
+            });
+        let target = if at_git_root { project_path } else { root };
+        self.type_into_shell_tab(&format!("cd {}\r", crate::util::shell_escape(&target)));
+        self.set_shell_at_git_root(!at_git_root);
+    }
+
+    /// Record where the git root toggle left the active shell tab.
+    fn set_shell_at_git_root(&mut self, at_git_root: bool) {
+        let pty = self
+            .projects
+            .get_mut(self.active_project)
+            .and_then(|p| p.active_resources_mut())
+            .and_then(|r| r.active_shell_pty_mut());
+        if let Some(pty) = pty {
+            pty.at_git_root = at_git_root;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_git_root_outside_git() {
+        let dir = std::env::temp_dir().join(format!("opman-gitroot-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(read_git_root(&dir), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
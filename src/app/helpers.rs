@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use similar::{ChangeTag, TextDiff};
 
 /// Read the full terminal buffer (scrollback + visible screen) from a vt100 parser.
@@ -148,3 +150,29 @@ pub(crate) fn parse_unified_diff(diff: &str) -> (Vec<usize>, Vec<usize>) {
 
     (added, deleted)
 }
+
+/// Path-like tokens in `text`, in order, each with the line number of a
+/// `path:line` (or `path:line:col`) suffix. A token is path-like if it
+/// contains a `/` or ends in a file extension; URLs, plain numbers and
+/// abbreviations like "e.g." are skipped.
+pub(crate) fn file_refs(text: &str) -> impl Iterator<Item = (PathBuf, Option<i64>)> + '_ {
+    const QUOTES: &[char] = &['`', '"', '\'', '(', ')', '[', ']', '<', '>', ',', ';'];
+    text.split_whitespace().filter_map(|token| {
+        let token = token
+            .trim_start_matches(QUOTES)
+            .trim_end_matches(|c: char| QUOTES.contains(&c) || c == '.' || c == ':');
+        if token.contains("://") {
+            return None;
+        }
+        let mut parts = token.split(':');
+        let path = parts.next()?;
+        let line = parts.next().and_then(|l| l.parse::<i64>().ok());
+        let has_extension = path.rsplit_once('.').is_some_and(|(stem, ext)| {
+            stem.len() > 1
+                && (1..=10).contains(&ext.len())
+                && ext.chars().all(|c| c.is_ascii_alphanumeric())
+                && !ext.chars().all(|c| c.is_ascii_digit())
+        });
+        (path.contains('/') || has_extension).then(|| (PathBuf::from(path), line))
+    })
+}
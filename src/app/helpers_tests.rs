@@ -1,9 +1,10 @@
-/// Tests for standalone helper functions (diff, terminal buffer, session ownership).
+/// Tests for standalone helper functions (diff, terminal buffer, session ownership,
+/// file references).
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use crate::app::helpers::{diff_snapshot_lines, parse_unified_diff};
+    use crate::app::helpers::{diff_snapshot_lines, file_refs, parse_unified_diff};
     use crate::app::SessionInfo;
 
     fn make_session(id: &str) -> SessionInfo {
@@ -232,4 +233,32 @@ diff --git a/src/main.rs b/src/main.rs
         assert!(added.is_empty());
         assert!(deleted.is_empty());
     }
+
+    fn first_ref(text: &str) -> Option<(String, Option<i64>)> {
+        file_refs(text)
+            .next()
+            .map(|(p, l)| (p.to_string_lossy().into_owned(), l))
+    }
+
+    #[test]
+    fn test_file_refs() {
+        assert_eq!(
+            first_ref("Fix the panic in `src/app/todo.rs:42`."),
+            Some(("src/app/todo.rs".into(), Some(42)))
+        );
+        assert_eq!(
+            first_ref("check main.rs:10:5 first"),
+            Some(("main.rs".into(), Some(10)))
+        );
+        assert_eq!(
+            first_ref("Update README.md"),
+            Some(("README.md".into(), None))
+        );
+        assert_eq!(
+            first_ref("bump to 1.5 per https://example.com/a.html then edit src/lib.rs"),
+            Some(("src/lib.rs".into(), None))
+        );
+        assert_eq!(first_ref("Write more tests, e.g. for parsing"), None);
+        assert_eq!(file_refs("see foo.rs:1 and bar/baz.rs:2").count(), 2);
+    }
 }
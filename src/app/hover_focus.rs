@@ -0,0 +1,77 @@
+//! Focus follows mouse: resting the pointer on a panel focuses it.
+
+use std::time::{Duration, Instant};
+
+use crate::app::App;
+use crate::ui::layout_manager::DragState;
+
+/// How long the pointer must stay over a panel before it takes focus, so
+/// crossing a panel on the way elsewhere doesn't steal focus.
+const HOVER_FOCUS_DELAY: Duration = Duration::from_millis(150);
+
+impl App {
+    /// Note the panel under the pointer after a mouse move. Ignored unless
+    /// `focus_follows_mouse` is set, and while a separator is dragged or
+    /// a single panel fills the screen.
+    pub fn track_hover_focus(&mut self, x: u16, y: u16) {
+        if !self.config.settings.focus_follows_mouse
+            || self.layout.drag_state != DragState::None
+            || self.zen_mode
+            || self.popout_mode
+        {
+            self.hover_focus = None;
+            return;
+        }
+        match self.layout.panel_at(x, y) {
+            Some(panel) if panel != self.layout.focused => {
+                if self.hover_focus.map(|(p, _)| p) != Some(panel) {
+                    self.hover_focus = Some((panel, Instant::now()));
+                }
+            }
+            _ => self.hover_focus = None,
+        }
+    }
+
+    /// Focus the hovered panel once the pointer has rested on it for
+    /// [`HOVER_FOCUS_DELAY`]. Returns whether focus changed.
+    pub fn apply_hover_focus(&mut self) -> bool {
+        match self.hover_focus {
+            Some((panel, since)) if since.elapsed() >= HOVER_FOCUS_DELAY => {
+                self.hover_focus = None;
+                if !self.layout.is_visible(panel) {
+                    return false;
+                }
+                self.layout.focused = panel;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::ui::layout_manager::PanelId;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_hover_focus_waits_and_respects_setting() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.layout
+            .restore_visibility([true, true, false, false, false], PanelId::TerminalPane);
+        app.layout.compute_rects(Rect::new(0, 0, 100, 30));
+
+        app.track_hover_focus(1, 1);
+        assert!(app.hover_focus.is_none());
+
+        app.config.settings.focus_follows_mouse = true;
+        app.track_hover_focus(1, 1);
+        assert!(!app.apply_hover_focus());
+        app.hover_focus = Some((PanelId::Sidebar, Instant::now() - HOVER_FOCUS_DELAY));
+        assert!(app.apply_hover_focus());
+        assert_eq!(app.layout.focused, PanelId::Sidebar);
+    }
+}
@@ -0,0 +1,78 @@
+//! Live grep overlay: ripgrep over the active project, results opened in
+//! the session's neovim.
+
+use crate::app::{App, GrepMatch, LiveGrepState, MAX_LIVE_GREP_RESULTS};
+
+impl App {
+    /// Open the live grep overlay over the active project.
+    pub fn open_live_grep(&mut self) {
+        let Some(root) = self.active_project().map(|p| p.path.clone()) else {
+            return;
+        };
+        self.live_grep = Some(LiveGrepState::new(root));
+    }
+
+    /// Close the overlay, stopping any search still running.
+    pub fn close_live_grep(&mut self) {
+        if let Some(mut state) = self.live_grep.take() {
+            state.restart();
+        }
+    }
+
+    /// Search for the current query (after a short debounce in the task),
+    /// replacing the previous results.
+    pub fn search_live_grep(&mut self) {
+        let Some(state) = self.live_grep.as_mut() else {
+            return;
+        };
+        let generation = state.restart();
+        if state.query.is_empty() {
+            return;
+        }
+        crate::background_tasks::spawn_live_grep(
+            &self.bg_tx,
+            state.root.clone(),
+            state.query.clone(),
+            generation,
+            state.generation.clone(),
+        );
+    }
+
+    /// Handle `BackgroundEvent::LiveGrepResults`: append a batch of matches
+    /// for the current search.
+    pub(crate) fn handle_live_grep_results(
+        &mut self,
+        generation: u64,
+        matches: Vec<GrepMatch>,
+        done: bool,
+        error: Option<String>,
+    ) {
+        let Some(state) = self.live_grep.as_mut() else {
+            return;
+        };
+        if !state.is_current(generation) {
+            return;
+        }
+        let room = MAX_LIVE_GREP_RESULTS.saturating_sub(state.results.len());
+        state.results.extend(matches.into_iter().take(room));
+        if done {
+            state.searching = false;
+            state.error = error;
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Open the selected match in the session's neovim at its line.
+    pub fn confirm_live_grep(&mut self) {
+        let Some(state) = self.live_grep.as_ref() else {
+            return;
+        };
+        let Some(m) = state.selected_match() else {
+            return;
+        };
+        let path = state.root.join(&m.path);
+        let line = m.line as i64;
+        self.close_live_grep();
+        self.open_in_session_neovim(path, Some(line));
+    }
+}
@@ -0,0 +1,184 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Matches kept per search; ripgrep is stopped once this many arrive.
+pub const MAX_LIVE_GREP_RESULTS: usize = 500;
+
+/// Characters of a matching line kept for display.
+const MAX_LINE_CHARS: usize = 300;
+
+/// One `file:line` match from ripgrep.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrepMatch {
+    /// Path relative to the project root.
+    pub path: String,
+    pub line: u64,
+    /// The matching line, leading whitespace trimmed.
+    pub text: String,
+    /// Byte ranges of the matched spans within `text`.
+    pub spans: Vec<(usize, usize)>,
+}
+
+/// State for the live grep overlay.
+pub struct LiveGrepState {
+    pub root: PathBuf,
+    pub query: String,
+    pub cursor_pos: usize,
+    pub results: Vec<GrepMatch>,
+    pub selected: usize,
+    pub scroll_offset: usize,
+    /// A search for the current query is still running.
+    pub searching: bool,
+    /// Error from the last search (bad pattern, ripgrep missing).
+    pub error: Option<String>,
+    /// Bumped on every query change; searches for older generations stop
+    /// and their results are dropped.
+    pub generation: Arc<AtomicU64>,
+}
+
+impl LiveGrepState {
+    pub fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            query: String::new(),
+            cursor_pos: 0,
+            results: Vec::new(),
+            selected: 0,
+            scroll_offset: 0,
+            searching: false,
+            error: None,
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Start a new generation for the current query, clearing old results.
+    /// Returns the new generation.
+    pub fn restart(&mut self) -> u64 {
+        self.results.clear();
+        self.selected = 0;
+        self.scroll_offset = 0;
+        self.error = None;
+        self.searching = !self.query.is_empty();
+        self.generation.fetch_add(1, Ordering::AcqRel) + 1
+    }
+
+    /// Whether results for `generation` are still wanted.
+    pub fn is_current(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::Acquire) == generation
+    }
+
+    pub fn selected_match(&self) -> Option<&GrepMatch> {
+        self.results.get(self.selected)
+    }
+
+    pub fn move_up(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        } else if !self.results.is_empty() {
+            self.selected = self.results.len() - 1;
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if !self.results.is_empty() {
+            if self.selected < self.results.len() - 1 {
+                self.selected += 1;
+            } else {
+                self.selected = 0;
+            }
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.query.insert(self.cursor_pos, c);
+        self.cursor_pos += c.len_utf8();
+    }
+
+    /// Delete the char before the cursor. Returns whether the query changed.
+    pub fn backspace(&mut self) -> bool {
+        crate::util::delete_char_before(&mut self.query, &mut self.cursor_pos)
+    }
+
+    pub fn cursor_left(&mut self) {
+        self.cursor_pos = crate::util::prev_char_boundary(&self.query, self.cursor_pos);
+    }
+
+    pub fn cursor_right(&mut self) {
+        self.cursor_pos = crate::util::next_char_boundary(&self.query, self.cursor_pos);
+    }
+}
+
+/// Parse one line of `rg --json` output; `None` for anything but a UTF-8
+/// `match` message.
+pub fn parse_rg_match(line: &str) -> Option<GrepMatch> {
+    let msg: serde_json::Value = serde_json::from_str(line).ok()?;
+    if msg.get("type")?.as_str()? != "match" {
+        return None;
+    }
+    let data = msg.get("data")?;
+    let path = data.pointer("/path/text")?.as_str()?;
+    let raw = data.pointer("/lines/text")?.as_str()?;
+    let line = data.get("line_number")?.as_u64()?;
+
+    let trimmed = raw.trim_start();
+    let offset = raw.len() - trimmed.len();
+    let mut text = trimmed.trim_end().to_string();
+    if let Some((cut, _)) = text.char_indices().nth(MAX_LINE_CHARS) {
+        text.truncate(cut);
+    }
+    let spans = data
+        .get("submatches")
+        .and_then(|s| s.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|m| {
+            let start = (m.get("start")?.as_u64()? as usize).saturating_sub(offset);
+            let end = (m.get("end")?.as_u64()? as usize)
+                .saturating_sub(offset)
+                .min(text.len());
+            (start < end && text.is_char_boundary(start) && text.is_char_boundary(end))
+                .then_some((start, end))
+        })
+        .collect();
+
+    Some(GrepMatch {
+        path: path.strip_prefix("./").unwrap_or(path).to_string(),
+        line,
+        text,
+        spans,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rg_match() {
+        let line = r#"{"type":"match","data":{"path":{"text":"src/main.rs"},"lines":{"text":"    fn main() {\n"},"line_number":12,"absolute_offset":0,"submatches":[{"match":{"text":"main"},"start":7,"end":11}]}}"#;
+        let m = parse_rg_match(line).unwrap();
+        assert_eq!(m.path, "src/main.rs");
+        assert_eq!(m.line, 12);
+        assert_eq!(m.text, "fn main() {");
+        assert_eq!(m.spans, [(3, 7)]);
+        assert_eq!(&m.text[3..7], "main");
+
+        assert!(parse_rg_match(r#"{"type":"begin","data":{}}"#).is_none());
+        assert!(parse_rg_match("not json").is_none());
+    }
+
+    #[test]
+    fn test_restart_invalidates_previous_generation() {
+        let mut state = LiveGrepState::new(PathBuf::from("/tmp"));
+        state.insert_char('x');
+        let first = state.restart();
+        assert!(state.searching && state.is_current(first));
+        let second = state.restart();
+        assert!(!state.is_current(first) && state.is_current(second));
+
+        state.backspace();
+        state.restart();
+        assert!(!state.searching);
+    }
+}
@@ -0,0 +1,198 @@
+//! Overlay tailing opman's own log file. Read once when opened and on
+//! explicit refresh.
+
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use tracing::Level;
+
+use crate::app::App;
+use crate::config::Config;
+
+/// Bytes read from the end of the log file.
+const LOG_TAIL_BYTES: u64 = 256 * 1024;
+
+/// Lines kept from the tail.
+pub const LOG_VIEWER_MAX_LINES: usize = 2000;
+
+/// State for the log viewer overlay.
+#[derive(Debug, Clone)]
+pub struct LogViewerState {
+    pub path: PathBuf,
+    pub lines: Vec<String>,
+    /// Lines scrolled up from the newest one; 0 follows the end.
+    pub scroll: usize,
+    /// Show only WARN and ERROR lines.
+    pub warnings_only: bool,
+}
+
+impl LogViewerState {
+    /// Lines to show under the current filter.
+    pub fn visible_lines(&self) -> Vec<&str> {
+        self.lines
+            .iter()
+            .map(String::as_str)
+            .filter(|l| {
+                !self.warnings_only || matches!(log_line_level(l), Some(Level::WARN | Level::ERROR))
+            })
+            .collect()
+    }
+}
+
+impl App {
+    /// Open the log viewer, or close it if it is open.
+    pub fn toggle_log_viewer(&mut self) {
+        if self.log_viewer.take().is_some() {
+            return;
+        }
+        let path = match Config::log_path() {
+            Ok(path) if path.exists() => path,
+            Ok(path) => {
+                self.toasts
+                    .warning(format!("No log file at {}", path.display()));
+                return;
+            }
+            Err(e) => {
+                self.toasts.error(format!("{e:#}"));
+                return;
+            }
+        };
+        self.log_viewer = Some(LogViewerState {
+            path,
+            lines: Vec::new(),
+            scroll: 0,
+            warnings_only: false,
+        });
+        self.refresh_log_viewer();
+    }
+
+    /// Re-read the tail of the log file, keeping the scroll position.
+    pub fn refresh_log_viewer(&mut self) {
+        let Some(state) = self.log_viewer.as_mut() else {
+            return;
+        };
+        match read_log_tail(&state.path, LOG_TAIL_BYTES) {
+            Ok(lines) => state.lines = lines,
+            Err(e) => {
+                let message = format!("Failed to read {}: {e}", state.path.display());
+                self.toasts.error(message);
+            }
+        }
+    }
+}
+
+/// Read the last `max_bytes` of a log file as lines (ANSI colors removed),
+/// keeping at most [`LOG_VIEWER_MAX_LINES`].
+pub fn read_log_tail(path: &Path, max_bytes: u64) -> std::io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(max_bytes);
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+
+    let text = String::from_utf8_lossy(&buf);
+    let mut lines: Vec<String> = text.lines().map(strip_ansi).collect();
+    // The first line is probably cut short when reading from the middle.
+    if start > 0 && !lines.is_empty() {
+        lines.remove(0);
+    }
+    let excess = lines.len().saturating_sub(LOG_VIEWER_MAX_LINES);
+    lines.drain(..excess);
+    Ok(lines)
+}
+
+/// Level of a `tracing_subscriber` fmt line (`<timestamp>  WARN target: ...`).
+pub fn log_line_level(line: &str) -> Option<Level> {
+    line.split_whitespace().take(3).find_map(|word| match word {
+        "ERROR" => Some(Level::ERROR),
+        "WARN" => Some(Level::WARN),
+        "INFO" => Some(Level::INFO),
+        "DEBUG" => Some(Level::DEBUG),
+        "TRACE" => Some(Level::TRACE),
+        _ => None,
+    })
+}
+
+/// Remove ANSI escape sequences (the fmt layer colors its output; shell
+/// tab output logs carry the full range). Text before a carriage return
+/// is dropped, as a terminal would overwrite it.
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => match chars.next() {
+                // CSI: ESC [ params final-byte
+                Some('[') => {
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC: ESC ] ... BEL or ESC \
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                            break;
+                        }
+                    }
+                }
+                // Character set designation: ESC ( B and the like.
+                Some('(' | ')') => {
+                    chars.next();
+                }
+                _ => {}
+            },
+            '\r' if chars.peek().is_some() => out.clear(),
+            c if c.is_control() && c != '\t' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_line_level() {
+        let line = strip_ansi(
+            "\x1b[2m2026-10-16T09:12:01.123Z\x1b[0m \x1b[33m WARN\x1b[0m \x1b[2mopman::app\x1b[0m: Failed to save config",
+        );
+        assert_eq!(
+            line,
+            "2026-10-16T09:12:01.123Z  WARN opman::app: Failed to save config"
+        );
+        assert_eq!(log_line_level(&line), Some(Level::WARN));
+        assert_eq!(log_line_level("no level here at all"), None);
+    }
+
+    #[test]
+    fn test_strip_ansi_terminal_output() {
+        assert_eq!(
+            strip_ansi("\x1b]133;A\x07$ \x1b]0;title\x1b\\\x1b(Bls\x08"),
+            "$ ls"
+        );
+        assert_eq!(
+            strip_ansi("Downloading 10%\rDownloading 100%"),
+            "Downloading 100%"
+        );
+    }
+
+    #[test]
+    fn test_read_log_tail_drops_partial_first_line() {
+        let path = std::env::temp_dir().join(format!("opman-log-tail-{}.log", std::process::id()));
+        std::fs::write(&path, "first line\nsecond line\nthird line\n").unwrap();
+
+        let all = read_log_tail(&path, 1024).unwrap();
+        assert_eq!(all, ["first line", "second line", "third line"]);
+        let tail = read_log_tail(&path, 15).unwrap();
+        assert_eq!(tail, ["third line"]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
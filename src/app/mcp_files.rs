@@ -0,0 +1,200 @@
+//! The MCP `files` op: list a project's files without going through a
+//! terminal tab. The listing runs on a blocking thread, off the event loop.
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+use ignore::overrides::{Override, OverrideBuilder};
+use ignore::WalkBuilder;
+
+use crate::app::App;
+use crate::mcp::{PendingSocketRequest, SocketResponse};
+
+/// Paths returned beyond this are summarised as a count.
+pub const MCP_FILES_LIMIT: usize = 5000;
+
+impl App {
+    /// Answer a `files` request from a blocking task, replying on the
+    /// request's channel when the listing is done.
+    pub(crate) fn spawn_mcp_files(&self, project_idx: usize, pending: PendingSocketRequest) {
+        let project_path = match self.blocking_op_root(project_idx, &pending.request.op) {
+            Ok(path) => path,
+            Err(response) => {
+                let _ = pending.reply_tx.send(response);
+                return;
+            }
+        };
+        tokio::task::spawn_blocking(move || {
+            let response = match list_project_files(&project_path, pending.request.glob.as_deref())
+            {
+                Ok(files) => SocketResponse::ok_text(format_file_list(&files)),
+                Err(e) => SocketResponse::err(format!("{e:#}")),
+            };
+            let _ = pending.reply_tx.send(response);
+        });
+    }
+
+    /// The project root for an op answered on a blocking thread, or the
+    /// error to reply with when the op isn't permitted or the project is
+    /// gone.
+    pub(crate) fn blocking_op_root(
+        &self,
+        project_idx: usize,
+        op: &str,
+    ) -> Result<PathBuf, SocketResponse> {
+        let permitted = crate::mcp::op_permitted(
+            self.config
+                .projects
+                .get(project_idx)
+                .and_then(|e| e.mcp_allowed_ops.as_deref()),
+            op,
+        );
+        if !permitted {
+            return Err(SocketResponse::err("operation not permitted".into()));
+        }
+        self.projects
+            .get(project_idx)
+            .map(|p| p.path.clone())
+            .ok_or_else(|| SocketResponse::err("Project not found".into()))
+    }
+}
+
+/// Files of the project at `root`, relative to it: `git ls-files` in a git
+/// work tree, otherwise a walk that honours ignore files. `glob` filters
+/// gitignore-style (`*.rs` matches at any depth, `src/**` under `src`).
+/// Listing stops after `MCP_FILES_LIMIT + 1` files, enough to tell that
+/// the result was cut short.
+pub fn list_project_files(root: &Path, glob: Option<&str>) -> Result<Vec<String>> {
+    list_files_capped(root, glob, MCP_FILES_LIMIT + 1)
+}
+
+fn list_files_capped(root: &Path, glob: Option<&str>, limit: usize) -> Result<Vec<String>> {
+    let filter = match glob {
+        Some(glob) => Some(
+            OverrideBuilder::new(root)
+                .add(glob)
+                .and_then(|b| b.build())
+                .with_context(|| format!("Invalid glob '{glob}'"))?,
+        ),
+        None => None,
+    };
+    let keep = |file: &str| filter.as_ref().map_or(true, |f| matches_glob(f, file));
+    Ok(match git_ls_files(root, &keep, limit) {
+        Some(files) => files,
+        None => walk_files(root, &keep, limit),
+    })
+}
+
+/// Format a file list for an MCP text response, capped at
+/// [`MCP_FILES_LIMIT`]. The listing stops early, so only the fact that
+/// more files exist is known, not how many.
+pub fn format_file_list(files: &[String]) -> String {
+    if files.is_empty() {
+        return "No matching files".into();
+    }
+    let mut text = files
+        .iter()
+        .take(MCP_FILES_LIMIT)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join("\n");
+    if files.len() > MCP_FILES_LIMIT {
+        text.push_str("\n… more files not shown (narrow with a glob)");
+    }
+    text
+}
+
+fn matches_glob(filter: &Override, file: &str) -> bool {
+    filter.matched(file, false).is_whitelist()
+}
+
+/// Up to `limit` tracked files under `root` passing `keep`, or `None` when
+/// it isn't in a git work tree (or git is missing). Paths outside `root`
+/// are never listed: git scopes `ls-files` to the current directory.
+fn git_ls_files(root: &Path, keep: &dyn Fn(&str) -> bool, limit: usize) -> Option<Vec<String>> {
+    let mut child = Command::new("git")
+        .args(["ls-files", "-z"])
+        .current_dir(root)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let stdout = child.stdout.take()?;
+    let files: Vec<String> = BufReader::new(stdout)
+        .split(0)
+        .map_while(Result::ok)
+        .filter(|p| !p.is_empty())
+        .map(|p| String::from_utf8_lossy(&p).into_owned())
+        .filter(|f| keep(f))
+        .take(limit)
+        .collect();
+    if files.len() == limit {
+        // Enough; don't wait for git to list the rest.
+        let _ = child.kill();
+        let _ = child.wait();
+        return Some(files);
+    }
+    child.wait().ok()?.success().then_some(files)
+}
+
+/// Up to `limit` files under `root` passing `keep`, for projects outside
+/// git, in path order and skipping hidden and ignored paths. Symlinks
+/// aren't followed, so the walk stays inside.
+fn walk_files(root: &Path, keep: &dyn Fn(&str) -> bool, limit: usize) -> Vec<String> {
+    WalkBuilder::new(root)
+        .require_git(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .build()
+        .flatten()
+        .filter(|e| e.file_type().is_some_and(|t| t.is_file()))
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(root)
+                .ok()
+                .map(|p| p.to_string_lossy().into_owned())
+        })
+        .filter(|f| keep(f))
+        .take(limit)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("opman-files-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src/main.rs"), "").unwrap();
+        std::fs::write(dir.join("README.md"), "").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_walk_fallback_and_glob() {
+        let dir = temp_project("walk");
+        assert_eq!(
+            list_project_files(&dir, None).unwrap(),
+            ["README.md", "src/main.rs"]
+        );
+        assert_eq!(
+            list_project_files(&dir, Some("*.rs")).unwrap(),
+            ["src/main.rs"]
+        );
+        assert!(list_project_files(&dir, Some("[")).is_err());
+        assert_eq!(list_files_capped(&dir, None, 1).unwrap(), ["README.md"]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_file_list_caps_output() {
+        assert_eq!(format_file_list(&[]), "No matching files");
+        let files: Vec<String> = (0..MCP_FILES_LIMIT + 1).map(|i| format!("f{i}")).collect();
+        let text = format_file_list(&files);
+        assert_eq!(text.lines().count(), MCP_FILES_LIMIT + 1);
+        assert!(text.ends_with("… more files not shown (narrow with a glob)"));
+    }
+}
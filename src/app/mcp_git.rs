@@ -0,0 +1,126 @@
+//! The MCP `gitdiff` op: a consolidated view of the project's uncommitted
+//! changes. Git runs on a blocking thread, off the event loop.
+
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{bail, Context, Result};
+
+use crate::app::App;
+use crate::mcp::{PendingSocketRequest, SocketResponse};
+
+/// Bytes of diff text returned; the rest is cut with a note.
+pub const MCP_DIFF_LIMIT: usize = 64 * 1024;
+
+impl App {
+    /// Answer a `gitdiff` request from a blocking task, replying on the
+    /// request's channel when git is done.
+    pub(crate) fn spawn_mcp_gitdiff(&self, project_idx: usize, pending: PendingSocketRequest) {
+        let project_path = match self.blocking_op_root(project_idx, &pending.request.op) {
+            Ok(path) => path,
+            Err(response) => {
+                let _ = pending.reply_tx.send(response);
+                return;
+            }
+        };
+        tokio::task::spawn_blocking(move || {
+            let response =
+                match git_diff_summary(&project_path, pending.request.full.unwrap_or(false)) {
+                    Ok(text) => SocketResponse::ok_text(text),
+                    Err(e) => SocketResponse::err(format!("{e:#}")),
+                };
+            let _ = pending.reply_tx.send(response);
+        });
+    }
+}
+
+/// Staged, unstaged and untracked changes of the project at `root` as
+/// `git diff --stat` summaries, followed by the patches when `full` is set.
+pub fn git_diff_summary(root: &Path, full: bool) -> Result<String> {
+    let staged = git(root, &["diff", "--cached", "--stat"])?;
+    let unstaged = git(root, &["diff", "--stat"])?;
+    let untracked = git(root, &["ls-files", "--others", "--exclude-standard"])?;
+    if staged.is_empty() && unstaged.is_empty() && untracked.is_empty() {
+        return Ok("No changes: the working tree matches HEAD".into());
+    }
+
+    let mut out = String::new();
+    for (title, body) in [
+        ("Staged", &staged),
+        ("Unstaged", &unstaged),
+        ("Untracked", &untracked),
+    ] {
+        if !body.is_empty() {
+            out.push_str(&format!("{title}:\n{body}\n"));
+        }
+    }
+    if full {
+        for args in [["diff", "--cached"], ["diff", "--"]] {
+            let patch = git(root, &args)?;
+            if !patch.is_empty() {
+                out.push('\n');
+                out.push_str(&patch);
+                out.push('\n');
+            }
+        }
+    }
+    Ok(cap_output(out.trim_end(), MCP_DIFF_LIMIT))
+}
+
+/// Run git in `root` and return its trimmed stdout.
+fn git(root: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(root)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git {} failed: {}", args[0], stderr.trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+/// Cut `text` to at most `limit` bytes on a line boundary, noting how much
+/// was left out.
+fn cap_output(text: &str, limit: usize) -> String {
+    if text.len() <= limit {
+        return text.to_string();
+    }
+    let mut end = limit;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let end = text[..end].rfind('\n').unwrap_or(end);
+    let omitted = text[end..].lines().filter(|l| !l.is_empty()).count();
+    format!(
+        "{}\n… {omitted} more lines (diff truncated at {} KiB)",
+        &text[..end],
+        limit / 1024
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cap_output_cuts_on_line_boundary() {
+        assert_eq!(cap_output("a\nb", 10), "a\nb");
+        let text = "line one\nline two\nline three";
+        assert_eq!(
+            cap_output(text, 12),
+            "line one\n… 2 more lines (diff truncated at 0 KiB)"
+        );
+    }
+
+    #[test]
+    fn test_git_diff_summary_outside_git() {
+        let dir = std::env::temp_dir().join(format!("opman-gitdiff-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(git_diff_summary(&dir, false).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
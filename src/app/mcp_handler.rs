@@ -1,13 +1,41 @@
+use crate::app::output_log::shell_output_log;
 use crate::app::{App, SessionResources};
 use crate::mcp::{SocketResponse, TabInfo};
 use crate::pty::PtyInstance;
 
+/// Reply to a shell-starting request that got here before direnv's export.
+const DIRENV_LOADING: &str = "The project's .envrc is still loading; try again";
+
+/// Terminal ops that need at least one shell tab; one is started when the
+/// session has none.
+fn op_needs_shell(op: &str) -> bool {
+    matches!(op, "read" | "run" | "close" | "rename" | "status")
+}
+
 impl App {
+    /// Whether answering `op` for a session starts a shell, which needs the
+    /// project's direnv export.
+    pub(crate) fn mcp_op_spawns_shell(
+        &self,
+        project_idx: usize,
+        session_id: &str,
+        op: &str,
+    ) -> bool {
+        let has_shell = self
+            .projects
+            .get(project_idx)
+            .and_then(|p| p.session_resources.get(session_id))
+            .is_some_and(|r| !r.shell_ptys.is_empty());
+        op == "new" || (op_needs_shell(op) && !has_shell)
+    }
+
     /// Handle an incoming MCP socket request for a given project/session.
     ///
     /// This dispatches terminal operations (read, run, list, new, close, rename,
     /// status) directly, and delegates neovim operations to
-    /// [`handle_nvim_operation`](Self::handle_nvim_operation).
+    /// [`handle_nvim_operation`](Self::handle_nvim_operation). `files` and
+    /// `gitdiff` are answered off the event loop instead (see
+    /// [`spawn_mcp_gitdiff`](Self::spawn_mcp_gitdiff)).
     pub(crate) fn handle_mcp_request(
         &mut self,
         project_idx: usize,
@@ -27,6 +55,10 @@ impl App {
             .unwrap_or((24, 80));
         let theme_envs = self.theme.pty_env_vars();
         let td = crate::theme_gen::theme_dir();
+        let scrollback = self.config.settings.scrollback_lines;
+        // `None` while direnv is resolving; requests that start a shell
+        // wait for it before getting here (see `mcp_op_spawns_shell`).
+        let shell_env = self.shell_env(project_idx);
         let terminal_command = self
             .config
             .projects
@@ -35,6 +67,17 @@ impl App {
             .or(self.config.settings.default_terminal_command.as_deref())
             .map(|s| s.to_string());
 
+        let permitted = crate::mcp::op_permitted(
+            self.config
+                .projects
+                .get(project_idx)
+                .and_then(|e| e.mcp_allowed_ops.as_deref()),
+            &request.op,
+        );
+        if !permitted {
+            return SocketResponse::err("operation not permitted".into());
+        }
+
         let project = match self.projects.get_mut(project_idx) {
             Some(p) => p,
             None => return SocketResponse::err("Project not found".into()),
@@ -45,24 +88,25 @@ impl App {
             .entry(session_id.to_string())
             .or_insert_with(SessionResources::new);
 
-        // Determine if this is a terminal op that needs at least one shell tab.
-        let needs_shell = matches!(
-            request.op.as_str(),
-            "read" | "run" | "close" | "rename" | "status"
-        );
+        let needs_shell = op_needs_shell(&request.op);
         // Determine if this is a neovim op.
         let needs_neovim = request.op.starts_with("nvim_");
 
         // Lazily spawn a shell PTY if needed and none exist.
         if needs_shell && resources.shell_ptys.is_empty() {
+            let Some(env) = shell_env.as_deref() else {
+                return SocketResponse::err(DIRENV_LOADING.into());
+            };
             match PtyInstance::spawn_shell(
                 shell_size.0,
                 shell_size.1,
+                scrollback,
                 &project_path,
-                &theme_envs,
+                env,
                 Some(&td),
                 terminal_command.as_deref(),
                 None,
+                shell_output_log(&self.config.settings, &project_path, session_id, None),
             ) {
                 Ok(shell) => {
                     resources.shell_ptys.push(shell);
@@ -79,6 +123,7 @@ impl App {
             match PtyInstance::spawn_neovim(
                 nvim_size.0,
                 nvim_size.1,
+                scrollback,
                 &project_path,
                 &theme_envs,
                 Some(&td),
@@ -88,7 +133,7 @@ impl App {
                     // Register nvim socket in shared registry for off-main-loop handling.
                     if let Some(ref addr) = nvim.nvim_listen_addr {
                         let reg = self.nvim_registry.clone();
-                        let key = (project_idx, session_id.to_string());
+                        let key = (project_path.clone(), session_id.to_string());
                         let addr = addr.clone();
                         tokio::spawn(async move {
                             reg.write().await.insert(key, addr);
@@ -142,6 +187,9 @@ impl App {
                         };
                         match pty.write(&bytes) {
                             Ok(_) => {
+                                if !is_ctrl_c {
+                                    pty.last_mcp_command = Some(command.clone());
+                                }
                                 SocketResponse::ok_text(format!("Command sent to tab {}", tab_idx))
                             }
                             Err(e) => {
@@ -170,19 +218,25 @@ impl App {
                 SocketResponse::ok_tabs(tabs)
             }
             "new" => {
+                let Some(env) = shell_env.as_deref() else {
+                    return SocketResponse::err(DIRENV_LOADING.into());
+                };
                 match PtyInstance::spawn_shell(
                     shell_size.0,
                     shell_size.1,
+                    scrollback,
                     &project_path,
-                    &theme_envs,
+                    env,
                     Some(&td),
                     terminal_command.as_deref(),
                     request.name.clone(),
+                    shell_output_log(&self.config.settings, &project_path, session_id, None),
                 ) {
                     Ok(shell) => {
                         resources.shell_ptys.push(shell);
                         let new_idx = resources.shell_ptys.len() - 1;
                         resources.active_shell_tab = new_idx;
+                        self.layout.layout_dirty = true;
                         SocketResponse::ok_tab_created(new_idx)
                     }
                     Err(e) => SocketResponse::err(format!("Failed to spawn shell: {}", e)),
@@ -196,11 +250,9 @@ impl App {
                 if resources.shell_ptys.len() <= 1 {
                     return SocketResponse::err("Cannot close the last tab".into());
                 }
-                let mut pty = resources.shell_ptys.remove(tab_idx);
+                let mut pty = resources.remove_shell_tab(tab_idx);
                 let _ = pty.kill();
-                if resources.active_shell_tab >= resources.shell_ptys.len() {
-                    resources.active_shell_tab = resources.shell_ptys.len().saturating_sub(1);
-                }
+                self.layout.layout_dirty = true;
                 SocketResponse::ok_empty()
             }
             "rename" => {
@@ -244,7 +296,7 @@ impl App {
             | "nvim_diagnostics" | "nvim_definition" | "nvim_references" | "nvim_hover"
             | "nvim_symbols" | "nvim_code_actions" | "nvim_eval" | "nvim_grep" | "nvim_diff"
             | "nvim_write" | "nvim_edit_and_save" | "nvim_undo" | "nvim_rename" | "nvim_format"
-            | "nvim_signature" => {
+            | "nvim_signature" | "nvim_selection" | "nvim_definition_context" => {
                 // Resolve neovim socket address.
                 let nvim_socket = match &resources.neovim_pty {
                     Some(pty) => match &pty.nvim_listen_addr {
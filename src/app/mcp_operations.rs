@@ -103,6 +103,13 @@ impl super::App {
                 );
                 SocketResponse::ok_text(info)
             }
+            "nvim_selection" => match crate::nvim_rpc::nvim_visual_selection(nvim_socket) {
+                Ok(Some(sel)) => SocketResponse::ok_text(sel.to_markdown()),
+                Ok(None) => SocketResponse::ok_text(
+                    "No visual selection in the current Neovim buffer. Ask the user to select text (v/V) first.".into(),
+                ),
+                Err(e) => SocketResponse::err(format!("Failed to get visual selection: {}", e)),
+            },
             "nvim_diagnostics" => match crate::nvim_rpc::nvim_lsp_diagnostics(
                 nvim_socket,
                 buf,
@@ -120,6 +127,19 @@ impl super::App {
                 Ok(output) => SocketResponse::ok_text(output),
                 Err(e) => SocketResponse::err(format!("Failed to get definition: {}", e)),
             },
+            "nvim_definition_context" => {
+                let context = request.context_lines.unwrap_or(10) as i64;
+                match crate::nvim_rpc::nvim_lsp_definition_context(
+                    nvim_socket,
+                    buf,
+                    request.line,
+                    request.col,
+                    context,
+                ) {
+                    Ok(output) => SocketResponse::ok_text(output),
+                    Err(e) => SocketResponse::err(format!("Failed to get definition: {}", e)),
+                }
+            }
             "nvim_references" => match crate::nvim_rpc::nvim_lsp_references(
                 nvim_socket,
                 buf,
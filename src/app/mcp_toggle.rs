@@ -0,0 +1,97 @@
+//! Runtime start/stop of the per-project MCP socket servers.
+
+use crate::app::App;
+use crate::mcp;
+
+impl App {
+    /// Spawn socket servers and write `opencode.json` for every project
+    /// according to `self.mcp_flags`.
+    pub(crate) fn start_mcp_servers(&mut self) {
+        let flags = self.mcp_flags;
+        if !flags.any() {
+            return;
+        }
+        for i in 0..self.projects.len() {
+            let project_path = self.projects[i].path.clone();
+            let allowed_ops = self
+                .config
+                .projects
+                .get(i)
+                .and_then(|e| e.mcp_allowed_ops.clone());
+            if flags.needs_socket() {
+                mcp::spawn_socket_server(
+                    &project_path,
+                    self.bg_tx.clone(),
+                    self.nvim_registry.clone(),
+                    self.last_mcp_activity_ms.clone(),
+                    self.mcp_control.clone(),
+                    allowed_ops.clone(),
+                );
+            }
+            if let Err(e) = mcp::write_opencode_json(
+                &project_path,
+                flags.terminal,
+                flags.neovim,
+                flags.time,
+                flags.ui,
+                allowed_ops.as_deref(),
+                false,
+            ) {
+                tracing::warn!(
+                    "Failed to write opencode.json for {}: {}",
+                    project_path.display(),
+                    e
+                );
+            }
+        }
+        // Neovim MCP owns file edits, so follow-edits stays off while it runs.
+        self.neovim_mcp_enabled = flags.neovim;
+        self.mcp_enabled = true;
+    }
+
+    /// Stop accepting MCP connections, remove the socket files and drop the
+    /// `mcp` entries opman wrote to each project's `opencode.json`.
+    ///
+    /// The bash/edit permission denies are left in place: the agent should
+    /// not silently regain native tools just because opman's were paused.
+    /// They are removed when opman exits.
+    pub(crate) fn stop_mcp_servers(&mut self) {
+        self.mcp_control.begin_shutdown();
+        self.reject_all_run_approvals();
+        for project in &self.projects {
+            mcp::cleanup_socket(&project.path);
+            if let Err(e) =
+                mcp::write_opencode_json(&project.path, false, false, false, false, None, true)
+            {
+                tracing::warn!(
+                    "Failed to write opencode.json for {}: {}",
+                    project.path.display(),
+                    e
+                );
+            }
+        }
+        self.neovim_mcp_enabled = false;
+        self.mcp_enabled = false;
+    }
+
+    /// Toggle all MCP servers. A no-op (with a hint) when opman was started
+    /// without any MCP flags.
+    pub fn toggle_mcp(&mut self) {
+        if !self.mcp_flags.any() {
+            self.toasts.warning("MCP not configured (start with --mcp)");
+            return;
+        }
+        let msg = if self.mcp_enabled {
+            self.stop_mcp_servers();
+            "MCP servers stopped"
+        } else {
+            // The shutdown signal is one-shot; restarted servers need a
+            // fresh control so they don't see the old `true` immediately.
+            self.mcp_control = mcp::SocketServerControl::new();
+            self.start_mcp_servers();
+            "MCP servers started"
+        };
+        tracing::info!("{msg}");
+        self.toasts.info(msg.to_string());
+    }
+}
@@ -1,20 +1,48 @@
 // ── Sub-modules ─────────────────────────────────────────────────────
 mod accessors;
+mod activity_log;
 mod background;
 mod background_event;
 mod background_sse;
 mod background_sse_slack;
+mod bell;
+mod command_history;
+mod command_runner;
 mod completions;
 mod context_input;
+mod diff_stat;
+mod failed_command;
+mod file_picker;
+mod git_root;
 pub mod helpers;
 #[cfg(test)]
 mod helpers_tests;
+mod hover_focus;
+mod live_grep;
+mod live_grep_types;
+mod log_viewer;
+mod mcp_files;
+mod mcp_git;
 mod mcp_handler;
 mod mcp_operations;
+mod mcp_toggle;
+mod model_picker;
+mod model_picker_types;
+mod mute;
+mod output_log;
 mod project;
+mod project_layout;
+mod permission_prompt;
+mod persist;
+mod process_overview;
+mod pty_cache;
 mod pty_management;
+mod pty_respawn;
+mod run_approval;
 mod session;
+mod session_bulk;
 mod session_selector_types;
+mod shell_env;
 mod sidebar;
 mod slack_actions;
 mod slack_commands;
@@ -31,28 +59,52 @@ mod slack_triage;
 mod slack_triage_result;
 mod slack_triage_run;
 mod slack_types;
+mod snippets;
+mod status_snapshot;
+mod terminal_command;
+mod todo;
+mod toast;
+mod tool_activity;
+mod transcript;
 mod types;
 mod watcher;
 mod watcher_types;
 
 // ── Re-exports ──────────────────────────────────────────────────────
+pub use activity_log::{ActivityEntry, ActivityKind, ActivityLogState, SESSION_ACTIVITY_CAP};
 pub use background_event::BackgroundEvent;
+pub use command_runner::{CommandRunnerState, RunningCommand};
 pub use context_input::ContextInputState;
+pub use diff_stat::{read_diff_stat, DiffStat};
+pub use git_root::read_git_root;
 pub use helpers::{diff_snapshot_lines, read_full_terminal_buffer};
+pub use live_grep_types::{parse_rg_match, GrepMatch, LiveGrepState, MAX_LIVE_GREP_RESULTS};
+pub use log_viewer::{log_line_level, LogViewerState};
+pub use model_picker_types::{model_entries, ModelPickerEntry, ModelPickerState};
+pub use permission_prompt::{PendingPermission, PermissionReply};
+pub use process_overview::{ProcessOverviewState, ProcessRow};
+pub use run_approval::PendingRunApproval;
 pub use session_selector_types::{ServerStatus, SessionSelectorEntry, SessionSelectorState};
+pub use shell_env::AwaitingEnv;
 pub use slack_types::PendingSlackMessage;
+pub use snippets::SnippetPickerState;
+pub use status_snapshot::{StatusHandle, StatusSnapshot};
+pub use terminal_command::TerminalCommandState;
+pub use todo::next_priority;
+pub use toast::{Toast, ToastQueue, ToastSeverity};
+pub use transcript::{transcript_dir, transcript_file_name, transcript_markdown};
 pub use types::*;
 pub use watcher_types::*;
 
 // ── Imports ─────────────────────────────────────────────────────────
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use tokio::sync::mpsc;
 
 use crate::command_palette::CommandPalette;
-use crate::config::Config;
+use crate::config::{Config, SidebarPosition, UiState};
 use crate::theme::ThemeColors;
 use crate::ui::fuzzy_picker::FuzzyPickerState;
 use crate::ui::layout_manager::{LayoutManager, PanelId};
@@ -67,13 +119,23 @@ pub struct App {
     pub should_quit: bool,
     pub sidebar_selection: usize,
     pub sidebar_cursor: usize,
+    /// First sidebar row on screen. Updated while rendering so the cursor
+    /// stays in view; read by the mouse handler to map clicks to rows.
+    pub sidebar_scroll_offset: std::cell::Cell<usize>,
     pub sidebar_pending_g: bool,
+    /// First `g` of a `gg` typed in an overlay list (todo, config panel).
+    pub list_pending_g: bool,
     pub config: Config,
     pub input_mode: InputMode,
     pub input_buffer: String,
     pub input_cursor: usize,
     pub pending_remove: Option<usize>,
     pub confirm_delete: Option<usize>,
+    /// Project waiting for a y/N before all its PTYs are respawned.
+    pub confirm_respawn: Option<usize>,
+    /// Failed shell command waiting for `r` (rerun) or `y` (copy with its
+    /// output).
+    pub failed_command_prompt: Option<String>,
     pub completions: Vec<String>,
     pub completion_selected: usize,
     pub completions_visible: bool,
@@ -85,17 +147,22 @@ pub struct App {
     pub session_search_all: Vec<SessionInfo>,
     pub session_search_results: Vec<SessionInfo>,
     pub session_search_selected: usize,
-    pub pinned_sessions: HashMap<usize, Vec<String>>,
+    /// Sessions pinned in the sidebar, keyed by project path.
+    pub pinned_sessions: HashMap<PathBuf, Vec<String>>,
     pub pending_session_select: Option<(usize, String)>,
     pub fuzzy_picker: Option<FuzzyPickerState>,
-    /// Which project index currently has its sessions expanded in the sidebar.
-    pub sessions_expanded_for: Option<usize>,
+    /// Path of the project whose sessions are expanded in the sidebar.
+    pub sessions_expanded_for: Option<PathBuf>,
     /// Set by input handler when user triggers "New Session".
     pub pending_new_session: Option<usize>,
-    /// Persists until SSE session.created arrives for this project.
-    pub awaiting_new_session: Option<usize>,
+    /// Path of the project waiting for SSE session.created after "New Session".
+    pub awaiting_new_session: Option<PathBuf>,
     /// Session IDs that are currently active/running (not idle).
     pub active_sessions: HashSet<String>,
+    /// Tool call each session is running right now: `(call_id, label)`.
+    pub tool_activity: HashMap<String, (String, String)>,
+    /// Recent SSE activity per session, oldest first (capped).
+    pub session_activity: HashMap<String, VecDeque<ActivityEntry>>,
     /// Session IDs that have encountered an error.
     pub error_sessions: HashSet<String>,
     /// Session IDs that need user input (pending permission or question).
@@ -106,13 +173,27 @@ pub struct App {
     pub pulse_phase: f64,
     /// Which parent session ID has its subagent sessions expanded.
     pub subagents_expanded_for: Option<String>,
+    /// Parent session the user last collapsed manually, and when.
+    pub subagents_collapsed_at: Option<(String, std::time::Instant)>,
     pub vim_mode: VimMode,
+    /// Digits typed in resize mode: the next resize sets the focused
+    /// panel to that many cells instead of nudging it.
+    pub resize_count: String,
     pub escape_tracker: EscapeTracker,
     pub command_palette: CommandPalette,
     pub which_key: WhichKeyState,
     pub runtime_keymap: Vec<RuntimeKeyBinding>,
     pub zen_mode: bool,
     pub pre_zen_state: Option<([bool; 5], PanelId)>,
+    /// Panel under the resting mouse pointer and since when, waiting to
+    /// take focus (focus follows mouse).
+    pub hover_focus: Option<(PanelId, std::time::Instant)>,
+    /// Read-only zen: no status bar or overlays, input ignored except the
+    /// `presentation_exit` key and agent prompts.
+    pub presentation_mode: bool,
+    /// Panel zen was on when presentation mode started (`None` = zen was
+    /// off), restored on leaving it.
+    pub pre_presentation_zen: Option<PanelId>,
     pub popout_mode: bool,
     pub pre_popout_state: Option<([bool; 5], PanelId)>,
     pub popout_windows: Vec<std::process::Child>,
@@ -120,15 +201,58 @@ pub struct App {
     pub config_panel_selected: usize,
     pub show_slack_log: bool,
     pub slack_log_scroll: usize,
+    /// Process/memory overview overlay (`None` = closed).
+    pub process_overview: Option<ProcessOverviewState>,
+    /// Session activity log overlay (`None` = closed).
+    pub activity_log: Option<ActivityLogState>,
+    /// Tail of opman's own log file (`None` = closed).
+    pub log_viewer: Option<LogViewerState>,
     pub session_selector: Option<SessionSelectorState>,
+    /// Model switcher overlay (`None` = closed).
+    pub model_picker: Option<ModelPickerState>,
+    /// Project-wide live grep overlay (`None` = closed).
+    pub live_grep: Option<LiveGrepState>,
+    /// Quick command runner overlay (`None` = closed).
+    pub command_runner: Option<CommandRunnerState>,
+    /// Runner commands still executing, watched for completion.
+    pub running_commands: Vec<RunningCommand>,
+    /// Command snippet picker overlay (`None` = closed).
+    pub snippet_picker: Option<SnippetPickerState>,
+    /// Command history picker overlay (`None` = closed).
+    pub history_picker: Option<SnippetPickerState>,
+    /// Per-project terminal command prompt (`None` = closed).
+    pub terminal_command: Option<TerminalCommandState>,
     pub todo_panel: Option<TodoPanelState>,
     pub routine_panel: Option<RoutinePanelState>,
     pub session_stats: HashMap<String, SessionStats>,
-    pub model_limits: HashMap<usize, ModelLimits>,
+    /// Model limits keyed by project path.
+    pub model_limits: HashMap<PathBuf, ModelLimits>,
     pub neovim_mcp_enabled: bool,
     pub bg_tx: mpsc::UnboundedSender<BackgroundEvent>,
     pub nvim_registry: crate::mcp::NvimSocketRegistry,
-    pub toast_message: Option<(String, std::time::Instant)>,
+    /// Status-bar toasts, shown one at a time.
+    pub toasts: ToastQueue,
+    /// Invert the status bar until this instant (visual bell).
+    pub bell_flash_until: Option<std::time::Instant>,
+    /// Latest LSP progress summary from a session's neovim, keyed by session.
+    pub lsp_progress: Option<(String, String)>,
+    /// True while an LSP progress poll is in flight (one at a time).
+    pub lsp_progress_polling: bool,
+    /// True while a git panel diff-stat refresh is in flight.
+    pub diff_stat_polling: bool,
+    /// Sessions whose idle neovim is being asked for unsaved buffers
+    /// before it is reaped.
+    pub nvim_reap_checks: HashSet<String>,
+    /// Projects whose direnv export is being resolved off the event loop.
+    pub direnv_loading: HashSet<PathBuf>,
+    /// Shell spawns and MCP requests waiting on those exports.
+    pub awaiting_env: Vec<(PathBuf, AwaitingEnv)>,
+    /// Projects whose first opencode PTY also starts neovim
+    /// (`startup.prespawn_neovim`; see [`App::prespawn_ptys`]).
+    pub prespawn_neovim_for: HashSet<PathBuf>,
+    /// Project whose first opencode PTY also starts a shell tab
+    /// (`startup.prespawn_shell`).
+    pub prespawn_shell_for: Option<PathBuf>,
     pub terminal_selection: Option<TerminalSelection>,
     pub terminal_search: Option<TerminalSearchState>,
     pub context_input: Option<ContextInputState>,
@@ -136,11 +260,31 @@ pub struct App {
     pub watcher_modal: Option<WatcherModalState>,
     pub watcher_pending: HashMap<String, tokio::task::AbortHandle>,
     pub watcher_idle_since: HashMap<String, std::time::Instant>,
-    pub session_ownership: HashMap<String, usize>,
+    /// Owning project path of each known session.
+    pub session_ownership: HashMap<String, PathBuf>,
     pub session_children: HashMap<String, HashSet<String>>,
     pub needs_redraw: bool,
+    /// Host terminal `(cols, rows)` as last seen; see [`App::terminal_size`].
+    pub last_terminal_size: (u16, u16),
     pub status_bar_url_range: std::cell::Cell<Option<(u16, u16)>>,
+    /// Where the focused terminal pane wants the host cursor this frame:
+    /// `(x, y, DECSCUSR Ps)`. Set during `ui::draw`.
+    pub host_cursor: std::cell::Cell<Option<(u16, u16, u8)>>,
     pub last_mcp_activity_ms: Arc<std::sync::atomic::AtomicU64>,
+    /// Shutdown/drain coordination shared with every MCP socket server.
+    pub mcp_control: crate::mcp::SocketServerControl,
+    /// MCP servers selected on the command line; reused when toggling.
+    pub mcp_flags: crate::mcp::McpFlags,
+    /// Whether the MCP socket servers are currently running.
+    pub mcp_enabled: bool,
+    /// MCP `run` requests waiting for approval (dry-run mode), oldest first.
+    pub run_approvals: VecDeque<PendingRunApproval>,
+    /// Whether the front agent prompt (approval, else permission) has been
+    /// focused with
+    /// `review_agent_prompt` and reads keys; until then it is only a banner.
+    pub agent_prompt_focused: bool,
+    /// Agent permission requests awaiting an answer, oldest first.
+    pub permission_prompts: VecDeque<PendingPermission>,
     pub last_message_event_at: HashMap<String, std::time::Instant>,
     pub slack_state: Option<Arc<tokio::sync::Mutex<crate::slack::SlackState>>>,
     pub slack_auth: Option<crate::slack::SlackAuth>,
@@ -149,11 +293,43 @@ pub struct App {
     /// `None` when the web server is disabled.  Used by the TUI routine
     /// panel to call routine methods directly (no HTTP round-trip needed).
     pub web_state: Option<crate::web::WebStateHandle>,
+    /// Snapshot served by the `--status-port` endpoint; `None` when it
+    /// is off.
+    pub status_handle: Option<StatusHandle>,
+    /// UI state persisted across restarts (`state.json`).
+    pub ui_state: UiState,
+    /// Where `ui_state` is read from and saved to; `None` persists nothing.
+    pub ui_state_path: Option<PathBuf>,
+    /// Project whose saved layout waits to be applied, and when it became
+    /// active (see `apply_pending_project_layout`).
+    pub pending_project_layout: Option<(PathBuf, std::time::Instant)>,
 }
 
 impl App {
-    /// Create a new `App` from a loaded configuration.
+    /// Create a new `App` from a loaded configuration, restoring the UI
+    /// state from `state.json`.
     pub fn new(config: Config, bg_tx: mpsc::UnboundedSender<BackgroundEvent>) -> Self {
+        let state_path = UiState::state_path()
+            .map_err(|e| tracing::warn!("No UI state path: {e}"))
+            .ok();
+        Self::with_state_path(config, bg_tx, state_path)
+    }
+
+    /// An `App` that neither reads nor writes `state.json`, so tests don't
+    /// depend on (or clobber) the user's saved state.
+    #[cfg(test)]
+    pub(crate) fn new_for_test(
+        config: Config,
+        bg_tx: mpsc::UnboundedSender<BackgroundEvent>,
+    ) -> Self {
+        Self::with_state_path(config, bg_tx, None)
+    }
+
+    fn with_state_path(
+        config: Config,
+        bg_tx: mpsc::UnboundedSender<BackgroundEvent>,
+        ui_state_path: Option<PathBuf>,
+    ) -> Self {
         let projects: Vec<Project> = config
             .projects
             .iter()
@@ -167,19 +343,26 @@ impl App {
                 gitui_pty: None,
                 sessions: Vec::new(),
                 git_branch: String::new(),
+                diff_stat: None,
+                diff_stat_stale: true,
+                session_last_active: HashMap::new(),
+                model: None,
+                selected_model: None,
             })
             .collect();
         let theme = crate::theme::load_theme();
         let runtime_keymap = crate::which_key::build_keymap(&config.keybindings);
         let space_children = crate::which_key::build_space_children(&config.keybindings);
         let command_palette = CommandPalette::new(&config.keybindings);
-        Self {
+        let mut app = Self {
             active_project: 0,
             layout: LayoutManager::new(),
             should_quit: false,
             sidebar_selection: 0,
             sidebar_cursor: 0,
+            sidebar_scroll_offset: std::cell::Cell::new(0),
             sidebar_pending_g: false,
+            list_pending_g: false,
             projects,
             config,
             input_mode: InputMode::Normal,
@@ -187,6 +370,8 @@ impl App {
             input_cursor: 0,
             pending_remove: None,
             confirm_delete: None,
+            confirm_respawn: None,
+            failed_command_prompt: None,
             completions: Vec::new(),
             completion_selected: 0,
             completions_visible: false,
@@ -205,18 +390,25 @@ impl App {
             pending_new_session: None,
             awaiting_new_session: None,
             active_sessions: HashSet::new(),
+            tool_activity: HashMap::new(),
+            session_activity: HashMap::new(),
             error_sessions: HashSet::new(),
             input_sessions: HashSet::new(),
             unseen_sessions: HashSet::new(),
             pulse_phase: 0.0,
             subagents_expanded_for: None,
+            subagents_collapsed_at: None,
             vim_mode: VimMode::Normal,
+            resize_count: String::new(),
             escape_tracker: EscapeTracker::new(),
             command_palette,
             which_key: WhichKeyState::new(space_children),
             runtime_keymap,
             zen_mode: false,
             pre_zen_state: None,
+            hover_focus: None,
+            presentation_mode: false,
+            pre_presentation_zen: None,
             popout_mode: false,
             pre_popout_state: None,
             popout_windows: Vec::new(),
@@ -224,7 +416,17 @@ impl App {
             config_panel_selected: 0,
             show_slack_log: false,
             slack_log_scroll: 0,
+            process_overview: None,
+            activity_log: None,
+            log_viewer: None,
             session_selector: None,
+            model_picker: None,
+            live_grep: None,
+            command_runner: None,
+            running_commands: Vec::new(),
+            snippet_picker: None,
+            history_picker: None,
+            terminal_command: None,
             todo_panel: None,
             routine_panel: None,
             session_stats: HashMap::new(),
@@ -232,7 +434,16 @@ impl App {
             neovim_mcp_enabled: false,
             bg_tx,
             nvim_registry: crate::mcp::new_nvim_socket_registry(),
-            toast_message: None,
+            toasts: ToastQueue::default(),
+            bell_flash_until: None,
+            lsp_progress: None,
+            lsp_progress_polling: false,
+            nvim_reap_checks: HashSet::new(),
+            direnv_loading: HashSet::new(),
+            awaiting_env: Vec::new(),
+            prespawn_neovim_for: HashSet::new(),
+            prespawn_shell_for: None,
+            diff_stat_polling: false,
             terminal_selection: None,
             terminal_search: None,
             context_input: None,
@@ -243,14 +454,32 @@ impl App {
             session_ownership: HashMap::new(),
             session_children: HashMap::new(),
             needs_redraw: true,
+            last_terminal_size: (80, 24),
             status_bar_url_range: std::cell::Cell::new(None),
+            host_cursor: std::cell::Cell::new(None),
             last_mcp_activity_ms: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+            mcp_control: crate::mcp::SocketServerControl::new(),
+            mcp_flags: crate::mcp::McpFlags::default(),
+            mcp_enabled: false,
+            run_approvals: VecDeque::new(),
+            agent_prompt_focused: false,
+            permission_prompts: VecDeque::new(),
             last_message_event_at: HashMap::new(),
             slack_state: None,
             slack_auth: None,
             pending_slack_messages: Vec::new(),
             web_state: None,
-        }
+            status_handle: None,
+            ui_state: Self::load_ui_state(ui_state_path.as_deref()),
+            ui_state_path,
+            pending_project_layout: None,
+        };
+        app.layout.min_width = app.config.settings.min_panel_width;
+        app.layout.min_height = app.config.settings.min_panel_height;
+        app.layout
+            .set_sidebar_right(app.config.settings.sidebar_position == SidebarPosition::Right);
+        app.restore_layout_from_state();
+        app
     }
 
     pub fn active_project(&self) -> Option<&Project> {
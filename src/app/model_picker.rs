@@ -0,0 +1,33 @@
+use crate::app::{App, ModelPickerState};
+
+impl App {
+    /// Open the model picker for the active project and fetch its models.
+    pub fn open_model_picker(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        let dir = project.path.to_string_lossy().to_string();
+        self.model_picker = Some(ModelPickerState::new(self.active_project));
+        crate::background_tasks::spawn_model_list_fetch(&self.bg_tx, self.active_project, dir);
+    }
+
+    /// Switch the picker's project to the selected model and close the
+    /// picker. The model goes with the prompts opman sends to the project's
+    /// sessions; the project's opencode config is not touched.
+    pub fn confirm_model_picker(&mut self) {
+        let Some(picker) = self.model_picker.as_ref() else {
+            return;
+        };
+        let Some(entry) = picker.selected_entry() else {
+            return;
+        };
+        let project_idx = picker.project_idx;
+        let model = entry.model_ref();
+        self.model_picker = None;
+        if let Some(project) = self.projects.get_mut(project_idx) {
+            self.toasts
+                .success(format!("Model for opman's prompts: {model}"));
+            project.selected_model = Some(model);
+        }
+    }
+}
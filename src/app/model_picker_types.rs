@@ -0,0 +1,242 @@
+use nucleo::pattern::{AtomKind, CaseMatching, Normalization, Pattern};
+use nucleo::{Config, Matcher, Utf32Str};
+
+/// A single model in the model picker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelPickerEntry {
+    pub provider_id: String,
+    pub model_id: String,
+    /// Display name of the model (falls back to its ID).
+    pub name: String,
+}
+
+impl ModelPickerEntry {
+    /// The `provider/model` reference opencode expects.
+    pub fn model_ref(&self) -> String {
+        format!("{}/{}", self.provider_id, self.model_id)
+    }
+}
+
+/// State for the model picker overlay.
+pub struct ModelPickerState {
+    /// Project whose model is switched.
+    pub project_idx: usize,
+    /// `None` while the model list is still being fetched.
+    pub entries: Option<Vec<ModelPickerEntry>>,
+    pub query: String,
+    pub cursor_pos: usize,
+    pub selected: usize,
+    pub scroll_offset: usize,
+    pub filtered: Vec<usize>,
+    matcher: Matcher,
+}
+
+impl ModelPickerState {
+    pub fn new(project_idx: usize) -> Self {
+        Self {
+            project_idx,
+            entries: None,
+            query: String::new(),
+            cursor_pos: 0,
+            selected: 0,
+            scroll_offset: 0,
+            filtered: Vec::new(),
+            matcher: Matcher::new(Config::DEFAULT),
+        }
+    }
+
+    /// Install the fetched models, selecting `current` if it's among them.
+    pub fn set_entries(&mut self, entries: Vec<ModelPickerEntry>, current: Option<&str>) {
+        self.entries = Some(entries);
+        self.update_filter();
+        if let Some(current) = current {
+            let entries = self.entries.as_deref().unwrap_or_default();
+            if let Some(pos) = self
+                .filtered
+                .iter()
+                .position(|&i| entries[i].model_ref() == current)
+            {
+                self.selected = pos;
+            }
+        }
+    }
+
+    /// The entry under the cursor.
+    pub fn selected_entry(&self) -> Option<&ModelPickerEntry> {
+        let entries = self.entries.as_ref()?;
+        entries.get(*self.filtered.get(self.selected)?)
+    }
+
+    /// Recompute filtered indices (best fuzzy match first) from the query.
+    pub fn update_filter(&mut self) {
+        let entries = self.entries.as_deref().unwrap_or_default();
+        if self.query.is_empty() {
+            self.filtered = (0..entries.len()).collect();
+        } else {
+            let pattern = Pattern::new(
+                &self.query,
+                CaseMatching::Smart,
+                Normalization::Smart,
+                AtomKind::Fuzzy,
+            );
+            let mut buf = Vec::new();
+            let mut scored: Vec<(usize, u32)> = entries
+                .iter()
+                .enumerate()
+                .filter_map(|(i, e)| {
+                    let haystack = format!("{} {}", e.model_ref(), e.name);
+                    let score =
+                        pattern.score(Utf32Str::new(&haystack, &mut buf), &mut self.matcher)?;
+                    Some((i, score))
+                })
+                .collect();
+            scored.sort_by(|a, b| b.1.cmp(&a.1));
+            self.filtered = scored.into_iter().map(|(i, _)| i).collect();
+        }
+        if self.selected >= self.filtered.len() {
+            self.selected = self.filtered.len().saturating_sub(1);
+        }
+        self.scroll_offset = 0;
+    }
+
+    pub fn move_up(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        } else if !self.filtered.is_empty() {
+            self.selected = self.filtered.len() - 1;
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if !self.filtered.is_empty() {
+            if self.selected < self.filtered.len() - 1 {
+                self.selected += 1;
+            } else {
+                self.selected = 0;
+            }
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.query.insert(self.cursor_pos, c);
+        self.cursor_pos += c.len_utf8();
+        self.update_filter();
+    }
+
+    pub fn backspace(&mut self) {
+        if crate::util::delete_char_before(&mut self.query, &mut self.cursor_pos) {
+            self.update_filter();
+        }
+    }
+
+    pub fn cursor_left(&mut self) {
+        self.cursor_pos = crate::util::prev_char_boundary(&self.query, self.cursor_pos);
+    }
+
+    pub fn cursor_right(&mut self) {
+        self.cursor_pos = crate::util::next_char_boundary(&self.query, self.cursor_pos);
+    }
+}
+
+/// Models from a `GET /provider` response, limited to connected providers
+/// when the server reports them, sorted by provider then name.
+pub fn model_entries(body: &serde_json::Value) -> Vec<ModelPickerEntry> {
+    let providers = body
+        .as_array()
+        .or_else(|| body.get("all").and_then(|a| a.as_array()));
+    let connected: Vec<&str> = body
+        .get("connected")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|p| p.as_str())
+        .collect();
+
+    let mut entries = Vec::new();
+    for provider in providers.into_iter().flatten() {
+        let Some(provider_id) = provider.get("id").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        if !connected.is_empty() && !connected.contains(&provider_id) {
+            continue;
+        }
+        let Some(models) = provider.get("models").and_then(|m| m.as_object()) else {
+            continue;
+        };
+        for (model_id, model) in models {
+            let name = model
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or(model_id);
+            entries.push(ModelPickerEntry {
+                provider_id: provider_id.to_string(),
+                model_id: model_id.clone(),
+                name: name.to_string(),
+            });
+        }
+    }
+    entries.sort_by(|a, b| (&a.provider_id, &a.name).cmp(&(&b.provider_id, &b.name)));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(provider: &str, model: &str) -> ModelPickerEntry {
+        ModelPickerEntry {
+            provider_id: provider.into(),
+            model_id: model.into(),
+            name: model.into(),
+        }
+    }
+
+    #[test]
+    fn test_model_entries_keeps_connected_providers() {
+        let body = serde_json::json!({
+            "all": [
+                {"id": "openai", "models": {"gpt-5": {"name": "GPT-5"}}},
+                {"id": "anthropic", "models": {
+                    "claude-sonnet-4": {"name": "Claude Sonnet 4"},
+                    "claude-haiku-4": {}
+                }}
+            ],
+            "connected": ["anthropic"],
+            "default": {"anthropic": "claude-sonnet-4"}
+        });
+        let refs: Vec<(String, String)> = model_entries(&body)
+            .into_iter()
+            .map(|e| (e.model_ref(), e.name))
+            .collect();
+        assert_eq!(
+            refs,
+            [
+                ("anthropic/claude-sonnet-4".into(), "Claude Sonnet 4".into()),
+                ("anthropic/claude-haiku-4".into(), "claude-haiku-4".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_and_select_current() {
+        let mut state = ModelPickerState::new(0);
+        state.set_entries(
+            vec![
+                entry("anthropic", "claude-sonnet-4"),
+                entry("openai", "gpt-5"),
+                entry("openai", "gpt-5-mini"),
+            ],
+            Some("openai/gpt-5"),
+        );
+        assert_eq!(state.selected_entry().unwrap().model_ref(), "openai/gpt-5");
+
+        for c in "sonnet".chars() {
+            state.insert_char(c);
+        }
+        assert_eq!(state.filtered, [0]);
+        assert_eq!(
+            state.selected_entry().unwrap().model_ref(),
+            "anthropic/claude-sonnet-4"
+        );
+    }
+}
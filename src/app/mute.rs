@@ -0,0 +1,71 @@
+//! Per-project notification muting, keyed by project path in the
+//! settings so it survives removing and re-adding a project.
+
+use crate::app::App;
+
+impl App {
+    /// Whether notifications from the project at `project_idx` are muted.
+    pub fn is_project_muted(&self, project_idx: usize) -> bool {
+        self.projects
+            .get(project_idx)
+            .is_some_and(|p| self.config.settings.muted_projects.contains(&p.path))
+    }
+
+    /// Mute or unmute the active project's notifications.
+    pub fn toggle_project_mute(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        let (name, path) = (project.name.clone(), project.path.clone());
+        let muted = &mut self.config.settings.muted_projects;
+        let now_muted = match muted.iter().position(|p| *p == path) {
+            Some(i) => {
+                muted.remove(i);
+                false
+            }
+            None => {
+                muted.push(path);
+                true
+            }
+        };
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+        let verb = if now_muted { "Muted" } else { "Unmuted" };
+        self.toasts.info(format!("{verb} notifications for {name}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::app::{App, BackgroundEvent};
+    use crate::config::{Config, ProjectEntry};
+
+    #[test]
+    fn test_muted_project_skips_idle_mark() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.add_project(ProjectEntry {
+            name: "api".into(),
+            path: "/tmp/api".into(),
+            ..Default::default()
+        });
+        app.config.settings.muted_projects = vec![PathBuf::from("/tmp/api")];
+        assert!(app.is_project_muted(0));
+
+        app.handle_background_event(BackgroundEvent::SseSessionIdle {
+            session_id: "s-1".into(),
+            project_idx: 0,
+        });
+        assert!(!app.unseen_sessions.contains("s-1"));
+
+        app.config.settings.muted_projects.clear();
+        app.handle_background_event(BackgroundEvent::SseSessionIdle {
+            session_id: "s-1".into(),
+            project_idx: 0,
+        });
+        assert!(app.unseen_sessions.contains("s-1"));
+    }
+}
@@ -0,0 +1,126 @@
+//! Shell tab output logs: opening the on-disk mirror for new shell tabs
+//! when enabled, and showing the active tab's log in the log viewer.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::app::{App, LogViewerState};
+use crate::config::{Config, Settings};
+use crate::pty::OutputLog;
+
+/// Logs not written to for this long are deleted when a new one opens.
+const STALE_LOG_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// An id for a new shell tab's log, unique across tabs and runs: tab
+/// indices shift as tabs close, so they can't name a tab's log.
+fn new_tab_log_id() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    format!("{millis}-{}", NEXT.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Output log for a shell tab of `project_path`'s `session_id`, or `None`
+/// when output logging is off. A restarted tab passes its `previous` log
+/// to keep writing there; a new tab gets a log of its own.
+pub(crate) fn shell_output_log(
+    settings: &Settings,
+    project_path: &Path,
+    session_id: &str,
+    previous: Option<&Path>,
+) -> Option<OutputLog> {
+    if !settings.terminal_output_log {
+        return None;
+    }
+    let max_bytes = settings.terminal_output_log_kib.saturating_mul(1024);
+    if let Some(path) = previous {
+        return OutputLog::open(path, max_bytes);
+    }
+    let path = match Config::terminal_output_log_path(project_path, session_id, &new_tab_log_id()) {
+        Ok(path) => path,
+        Err(e) => {
+            tracing::warn!("No terminal output log path: {e:#}");
+            return None;
+        }
+    };
+    if let Some(dir) = path.parent() {
+        remove_stale_logs(dir);
+    }
+    OutputLog::open(&path, max_bytes)
+}
+
+/// Delete logs in `dir` untouched for [`STALE_LOG_AGE`], so logs of
+/// closed tabs don't pile up.
+fn remove_stale_logs(dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let stale = entry
+            .metadata()
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > STALE_LOG_AGE);
+        if stale && path.extension().is_some_and(|ext| ext == "log") {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// The newest log of `session_id` in `project_path`, e.g. one written
+/// before a crash or restart.
+fn newest_session_log(project_path: &Path, session_id: &str) -> Option<PathBuf> {
+    let dir = Config::terminal_output_log_dir(project_path).ok()?;
+    let prefix = format!("{session_id}-");
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with(&prefix))
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .max()
+        .map(|(_, path)| path)
+}
+
+impl App {
+    /// Show the active shell tab's output log in the log viewer. Without
+    /// one (logging off, or the tab started before it was turned on), the
+    /// session's newest log is shown instead, which after a crash or
+    /// restart holds the output from before.
+    pub fn open_terminal_output_log(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        let (Some(session_id), Some(resources)) = (
+            project.active_session.as_deref(),
+            project.active_resources(),
+        ) else {
+            self.toasts.warning("No terminal open");
+            return;
+        };
+        let path = resources
+            .active_shell_pty()
+            .and_then(|pty| pty.output_log_path.clone())
+            .filter(|path| path.exists())
+            .or_else(|| newest_session_log(&project.path, session_id));
+        let Some(path) = path else {
+            let hint = if self.config.settings.terminal_output_log {
+                "No output logged for this terminal yet"
+            } else {
+                "Terminal output logging is off (enable it in settings)"
+            };
+            self.toasts.warning(hint);
+            return;
+        };
+        self.log_viewer = Some(LogViewerState {
+            path,
+            lines: Vec::new(),
+            scroll: 0,
+            warnings_only: false,
+        });
+        self.refresh_log_viewer();
+    }
+}
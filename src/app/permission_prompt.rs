@@ -0,0 +1,121 @@
+//! In-TUI prompt for agent permission requests (`permission.asked`).
+
+use crate::app::{App, PermissionRequest};
+
+/// A permission request waiting for the user's answer.
+pub struct PendingPermission {
+    pub project_idx: usize,
+    pub request: PermissionRequest,
+}
+
+/// How a permission request is answered; the values are the API's replies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionReply {
+    Once,
+    Always,
+    Reject,
+}
+
+impl PermissionReply {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PermissionReply::Once => "once",
+            PermissionReply::Always => "always",
+            PermissionReply::Reject => "reject",
+        }
+    }
+}
+
+impl App {
+    /// Queue a permission request for the prompt, ignoring repeats.
+    pub(crate) fn queue_permission_prompt(
+        &mut self,
+        project_idx: usize,
+        request: PermissionRequest,
+    ) {
+        if self
+            .permission_prompts
+            .iter()
+            .any(|p| p.request.id == request.id)
+        {
+            return;
+        }
+        self.permission_prompts.push_back(PendingPermission {
+            project_idx,
+            request,
+        });
+        self.needs_redraw = true;
+    }
+
+    /// Forget a request that was answered, here or elsewhere (the
+    /// opencode pane, Slack, the web UI).
+    pub(crate) fn drop_permission_prompt(&mut self, request_id: &str) {
+        let before = self.permission_prompts.len();
+        let was_front = self
+            .permission_prompts
+            .front()
+            .is_some_and(|p| p.request.id == request_id);
+        self.permission_prompts
+            .retain(|p| p.request.id != request_id);
+        // A focused prompt must not silently become the next request.
+        if was_front && self.run_approvals.is_empty() {
+            self.agent_prompt_focused = false;
+        }
+        if self.permission_prompts.len() != before {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Answer the oldest queued request. It is dropped from the queue
+    /// right away; a failed reply is reported as a toast.
+    pub fn resolve_permission_prompt(&mut self, reply: PermissionReply) {
+        let Some(pending) = self.permission_prompts.pop_front() else {
+            return;
+        };
+        self.agent_prompt_focused = false;
+        if let Some(project) = self.projects.get(pending.project_idx) {
+            crate::background_tasks::spawn_permission_reply(
+                &self.bg_tx,
+                project.path.to_string_lossy().to_string(),
+                pending.request.id,
+                reply.as_str(),
+            );
+        }
+        self.needs_redraw = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn request(id: &str) -> PermissionRequest {
+        PermissionRequest {
+            id: id.to_string(),
+            session_id: "s1".to_string(),
+            permission: "bash".to_string(),
+            patterns: vec!["rm -rf build".to_string()],
+            metadata: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_permission_prompt_queue() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.queue_permission_prompt(0, request("p1"));
+        app.queue_permission_prompt(0, request("p2"));
+        app.queue_permission_prompt(0, request("p1"));
+        assert_eq!(app.permission_prompts.len(), 2);
+
+        // Answered elsewhere.
+        app.drop_permission_prompt("p1");
+        assert_eq!(app.permission_prompts.len(), 1);
+        assert_eq!(app.permission_prompts[0].request.id, "p2");
+
+        // No project 0 here, so nothing is sent; the prompt still closes.
+        app.resolve_permission_prompt(PermissionReply::Reject);
+        assert!(app.permission_prompts.is_empty());
+    }
+}
@@ -0,0 +1,135 @@
+//! Persisted UI state: restore on startup, snapshot on exit.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::app::{App, ContextInputState};
+use crate::config::{LayoutSnapshot, UiState};
+
+impl App {
+    /// Load the state file at `path` (`None`: nothing persisted), falling
+    /// back to an empty state on any error.
+    pub(super) fn load_ui_state(path: Option<&Path>) -> UiState {
+        let Some(path) = path else {
+            return UiState::default();
+        };
+        UiState::load(path).unwrap_or_else(|e| {
+            tracing::warn!("Failed to load UI state: {e}");
+            UiState::default()
+        })
+    }
+
+    /// Write `ui_state` to the state file, if this app has one.
+    fn write_ui_state(&self) -> anyhow::Result<()> {
+        match self.ui_state_path {
+            Some(ref path) => self.ui_state.save(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Re-apply the panel layout from the last session, unless the
+    /// `startup.panels` setting names the panels to show.
+    pub(super) fn restore_layout_from_state(&mut self) {
+        if let Some(ref panels) = self.config.settings.startup.panels {
+            self.layout.show_only(panels);
+            return;
+        }
+        if let Some(ref snapshot) = self.ui_state.layout {
+            self.layout.restore_snapshot(
+                snapshot.panel_visible,
+                snapshot.focused,
+                snapshot.root.as_ref(),
+            );
+        }
+    }
+
+    /// Capture the current layout (also as the active project's layout) and
+    /// any open context draft, and write the state file. In zen mode the
+    /// pre-zen layout is saved, so the next launch doesn't come up with a
+    /// single maximised panel; its panel sizes are not known then.
+    pub fn save_ui_state(&mut self) {
+        self.remember_project_layout();
+        if let Some(state) = self.context_input.as_ref().filter(|s| !s.is_blank()) {
+            if let Some(ref sid) = state.session_id {
+                self.ui_state
+                    .context_drafts
+                    .insert(sid.clone(), state.lines.clone());
+            }
+        }
+        self.ui_state.layout = Some(match self.pre_zen_state.or(self.pre_popout_state) {
+            Some((panel_visible, focused)) => LayoutSnapshot {
+                panel_visible,
+                focused,
+                root: None,
+            },
+            None => self.layout_snapshot(),
+        });
+        if let Err(e) = self.write_ui_state() {
+            tracing::warn!("Failed to save UI state: {e}");
+        }
+    }
+
+    /// Open the context input overlay for the active session, offering
+    /// any draft left behind by a previous cancel. The session's subagents
+    /// are listed as alternative send targets.
+    pub fn open_context_input(&mut self) {
+        let mut state = ContextInputState::new();
+        state.session_id = self
+            .active_project()
+            .and_then(|p| p.active_session.clone());
+        if let Some(ref sid) = state.session_id {
+            state.saved_draft = self.ui_state.context_drafts.get(sid).cloned();
+            state.targets.push((sid.clone(), "this session".to_string()));
+            for sub in self.subagent_sessions(self.active_project, sid) {
+                let label = if sub.title.is_empty() {
+                    sub.id.clone()
+                } else {
+                    sub.title.clone()
+                };
+                state.targets.push((sub.id.clone(), label));
+            }
+        }
+        self.context_input = Some(state);
+    }
+
+    /// Close the context input overlay, saving its buffer as a draft.
+    /// Blank buffers leave any existing draft untouched.
+    pub fn close_context_input(&mut self) {
+        let Some(state) = self.context_input.take() else {
+            return;
+        };
+        let Some(sid) = state.session_id.clone() else {
+            return;
+        };
+        if state.is_blank() {
+            return;
+        }
+        self.ui_state.context_drafts.insert(sid, state.lines);
+        if let Err(e) = self.write_ui_state() {
+            tracing::warn!("Failed to save context draft: {e}");
+        }
+    }
+
+    /// Forget the saved draft for a session once its prompt is submitted
+    /// or the session is deleted.
+    pub fn clear_context_draft(&mut self, session_id: &str) {
+        if self.ui_state.context_drafts.remove(session_id).is_some() {
+            if let Err(e) = self.write_ui_state() {
+                tracing::warn!("Failed to save UI state: {e}");
+            }
+        }
+    }
+
+    /// Forget the saved drafts of deleted sessions.
+    pub(crate) fn clear_context_drafts(&mut self, session_ids: &HashSet<String>) {
+        let before = self.ui_state.context_drafts.len();
+        self.ui_state
+            .context_drafts
+            .retain(|sid, _| !session_ids.contains(sid));
+        if self.ui_state.context_drafts.len() != before {
+            if let Err(e) = self.write_ui_state() {
+                tracing::warn!("Failed to save UI state: {e}");
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Diagnostic snapshot of every PTY child process opman is keeping alive.
+
+use crate::app::App;
+use crate::process_health::footprint::process_rss_bytes;
+use crate::pty::PtyInstance;
+
+/// One live PTY child in the process overview.
+#[derive(Debug, Clone)]
+pub struct ProcessRow {
+    pub project: String,
+    /// Session title (or ID); empty for per-project processes like gitui.
+    pub session: String,
+    /// `"opencode"`, `"shell"`, `"neovim"` or `"gitui"`.
+    pub kind: &'static str,
+    pub pid: Option<u32>,
+    /// Resident memory in bytes, when it could be read.
+    pub rss: Option<u64>,
+}
+
+/// State for the process overview overlay. Captured once when opened and
+/// on explicit refresh — reading `/proc` every frame is not worth it.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessOverviewState {
+    pub rows: Vec<ProcessRow>,
+    pub scroll: usize,
+}
+
+impl ProcessOverviewState {
+    /// Sum of all readable RSS values.
+    pub fn total_rss(&self) -> u64 {
+        self.rows.iter().filter_map(|r| r.rss).sum()
+    }
+}
+
+impl App {
+    /// Open (or refresh) the process overview overlay.
+    pub fn open_process_overview(&mut self) {
+        let scroll = self.process_overview.as_ref().map_or(0, |s| s.scroll);
+        let mut rows = Vec::new();
+        for project in &self.projects {
+            let session_label = |sid: &str| {
+                project
+                    .sessions
+                    .iter()
+                    .find(|s| s.id == sid && !s.title.is_empty())
+                    .map(|s| s.title.clone())
+                    .unwrap_or_else(|| sid.to_string())
+            };
+            let mut push = |session: String, kind: &'static str, pty: &PtyInstance| {
+                let pid = pty.process_id();
+                rows.push(ProcessRow {
+                    project: project.name.clone(),
+                    session,
+                    kind,
+                    pid,
+                    rss: pid.and_then(process_rss_bytes),
+                });
+            };
+
+            let mut sids: Vec<&String> = project
+                .ptys
+                .keys()
+                .chain(project.session_resources.keys())
+                .collect();
+            sids.sort();
+            sids.dedup();
+            for sid in sids {
+                if let Some(pty) = project.ptys.get(sid) {
+                    push(session_label(sid), "opencode", pty);
+                }
+                if let Some(resources) = project.session_resources.get(sid) {
+                    for shell in &resources.shell_ptys {
+                        push(session_label(sid), "shell", shell);
+                    }
+                    if let Some(ref nvim) = resources.neovim_pty {
+                        push(session_label(sid), "neovim", nvim);
+                    }
+                }
+            }
+            if let Some(ref gitui) = project.gitui_pty {
+                push(String::new(), "gitui", gitui);
+            }
+        }
+        self.process_overview = Some(ProcessOverviewState { rows, scroll });
+    }
+}
@@ -1,17 +1,24 @@
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use anyhow::Result;
 
 use crate::app::App;
-use crate::app::{InputMode, Project, ServerStatus};
+use crate::app::{InputMode, PendingRunApproval, Project, ServerStatus};
 use crate::config::ProjectEntry;
+use crate::mcp::SocketResponse;
 use crate::ui::fuzzy_picker::FuzzyPickerState;
 
 impl App {
-    /// Switch the active project by index.
+    /// Switch the active project by index. The outgoing project's layout
+    /// is remembered and the incoming one's is queued for restore.
     pub fn switch_project(&mut self, index: usize) {
         if index < self.projects.len() {
-            self.active_project = index;
+            if index != self.active_project {
+                self.remember_project_layout();
+                self.active_project = index;
+                self.queue_project_layout();
+            }
             self.resize_all_ptys();
         }
     }
@@ -26,22 +33,45 @@ impl App {
             gitui_pty: None,
             sessions: Vec::new(),
             git_branch: String::new(),
+            diff_stat: None,
+            diff_stat_stale: true,
+            session_last_active: std::collections::HashMap::new(),
+            model: None,
+            selected_model: None,
         };
         self.projects.push(project);
         self.config.projects.push(entry);
     }
 
     pub fn start_add_project(&mut self) {
-        let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+        let roots = self.project_search_roots();
         let existing: Vec<String> = self
             .projects
             .iter()
             .map(|p| p.path.to_string_lossy().to_string())
             .collect();
-        self.fuzzy_picker = Some(FuzzyPickerState::new_with_existing(home, existing));
+        self.fuzzy_picker = Some(FuzzyPickerState::new_with_existing(roots, existing));
         self.input_mode = InputMode::FuzzyPicker;
     }
 
+    /// Existing directories from `project_search_roots`, or the home
+    /// directory when none are configured or none exist.
+    fn project_search_roots(&self) -> Vec<PathBuf> {
+        let roots: Vec<PathBuf> = self
+            .config
+            .settings
+            .project_search_roots
+            .iter()
+            .map(|root| PathBuf::from(self.expand_tilde(root)))
+            .filter(|root| root.is_dir())
+            .collect();
+        if roots.is_empty() {
+            vec![dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"))]
+        } else {
+            roots
+        }
+    }
+
     /// Cancel the fuzzy picker and return to normal mode.
     pub fn cancel_fuzzy_picker(&mut self) {
         self.fuzzy_picker = None;
@@ -98,7 +128,7 @@ impl App {
         let entry = ProjectEntry {
             name,
             path: path_str,
-            terminal_command: None,
+            ..Default::default()
         };
         self.add_project(entry);
         self.config.save()?;
@@ -130,7 +160,7 @@ impl App {
         let entry = ProjectEntry {
             name,
             path: path_str,
-            terminal_command: None,
+            ..Default::default()
         };
         self.add_project(entry);
         self.config.save()?;
@@ -143,11 +173,9 @@ impl App {
     }
 
     pub fn remove_project(&mut self, index: usize) -> Result<()> {
-        if index >= self.projects.len() {
+        let Some(mut project) = self.detach_project(index) else {
             return Ok(());
-        }
-
-        let mut project = self.projects.remove(index);
+        };
 
         for (_, pty) in project.ptys.iter_mut() {
             let _ = pty.kill();
@@ -155,14 +183,102 @@ impl App {
         project.ptys.clear();
         drop(project);
 
-        self.config.projects.remove(index);
         self.config.save()?;
+        Ok(())
+    }
+
+    /// Remove a project, everything keyed by its path and the session-keyed
+    /// state of its sessions, and fix up indices into `projects`. Does not
+    /// touch its PTYs or save.
+    fn detach_project(&mut self, index: usize) -> Option<Project> {
+        if index >= self.projects.len() {
+            return None;
+        }
+
+        let project = self.projects.remove(index);
+        self.config.projects.remove(index);
+
+        self.pinned_sessions.remove(&project.path);
+        self.model_limits.remove(&project.path);
+        self.ui_state.project_layouts.remove(&project.path);
+
+        // Session-keyed state of the project's sessions.
+        let mut session_ids: HashSet<String> =
+            project.sessions.iter().map(|s| s.id.clone()).collect();
+        self.session_ownership.retain(|sid, owner| {
+            let owned = *owner == project.path;
+            if owned {
+                session_ids.insert(sid.clone());
+            }
+            !owned
+        });
+        for sid in &session_ids {
+            self.session_stats.remove(sid);
+            self.active_sessions.remove(sid);
+            self.tool_activity.remove(sid);
+            self.error_sessions.remove(sid);
+            self.input_sessions.remove(sid);
+            self.unseen_sessions.remove(sid);
+            self.session_children.remove(sid);
+            self.session_activity.remove(sid);
+            self.ui_state.context_drafts.remove(sid);
+        }
+        if self.sessions_expanded_for.as_ref() == Some(&project.path) {
+            self.sessions_expanded_for = None;
+        }
+        if self.awaiting_new_session.as_ref() == Some(&project.path) {
+            self.awaiting_new_session = None;
+        }
+
+        // Queued agent prompts hold indices into `projects`: answer the
+        // removed project's so its bridges don't hang, and shift the rest.
+        let queued = self.run_approvals.len() + self.permission_prompts.len();
+        for approval in std::mem::take(&mut self.run_approvals) {
+            if approval.project_idx == index {
+                let _ = approval
+                    .pending
+                    .reply_tx
+                    .send(SocketResponse::err("Project was removed".into()));
+                continue;
+            }
+            let project_idx = approval.project_idx - usize::from(approval.project_idx > index);
+            self.run_approvals.push_back(PendingRunApproval {
+                project_idx,
+                ..approval
+            });
+        }
+        self.permission_prompts.retain(|p| p.project_idx != index);
+        for prompt in self.permission_prompts.iter_mut() {
+            if prompt.project_idx > index {
+                prompt.project_idx -= 1;
+            }
+        }
+        if self.run_approvals.len() + self.permission_prompts.len() != queued {
+            self.agent_prompt_focused = false;
+        }
+
+        self.direnv_loading.remove(&project.path);
+        self.drop_awaiting_env(&project.path);
+
+        // The nvim registry is keyed by path; drop the project's sockets.
+        if let Ok(mut registry) = self.nvim_registry.try_write() {
+            registry.retain(|(path, _), _| *path != project.path);
+        } else {
+            let registry = self.nvim_registry.clone();
+            let path = project.path.clone();
+            tokio::spawn(async move {
+                registry.write().await.retain(|(p, _), _| *p != path);
+            });
+        }
 
         if self.projects.is_empty() {
             self.active_project = 0;
             self.sidebar_selection = 0;
             self.sidebar_cursor = 0;
         } else {
+            if self.active_project > index {
+                self.active_project -= 1;
+            }
             if self.active_project >= self.projects.len() {
                 self.active_project = self.projects.len().saturating_sub(1);
             }
@@ -171,7 +287,7 @@ impl App {
             self.sidebar_cursor = self.sidebar_cursor.min(max);
         }
 
-        Ok(())
+        Some(project)
     }
 
     /// Derive the server status for a given project.
@@ -180,3 +296,174 @@ impl App {
         ServerStatus::Running
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{ActivityKind, BackgroundEvent, PermissionRequest, SessionInfo};
+    use crate::config::Config;
+    use crate::mcp::{PendingSocketRequest, SocketRequest};
+
+    /// App with projects `a`, `b`, `c`, each owning one session `s-<name>`
+    /// and a distinct context window.
+    fn app_with_three_projects() -> App {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        for (i, name) in ["a", "b", "c"].into_iter().enumerate() {
+            let path = format!("/nonexistent/opman-test/{name}");
+            app.add_project(ProjectEntry {
+                name: name.to_string(),
+                path: path.clone(),
+                ..Default::default()
+            });
+            app.handle_background_event(BackgroundEvent::SessionsFetched {
+                project_idx: i,
+                sessions: vec![SessionInfo {
+                    id: format!("s-{name}"),
+                    directory: path,
+                    ..Default::default()
+                }],
+            });
+            app.handle_background_event(BackgroundEvent::ModelLimitsFetched {
+                project_idx: i,
+                context_window: 1000 * (i as u64 + 1),
+                model: None,
+            });
+        }
+        app
+    }
+
+    fn owner_index(app: &App, session_id: &str) -> Option<usize> {
+        app.project_index(app.session_ownership.get(session_id)?)
+    }
+
+    #[test]
+    fn test_remove_middle_project_keeps_per_project_state() {
+        let mut app = app_with_three_projects();
+        app.active_project = 2;
+        app.session_search_results = vec![app.projects[2].sessions[0].clone()];
+        assert_eq!(app.pin_selected_session().as_deref(), Some("s-c"));
+        app.toggle_sessions_expanded(2);
+
+        let removed = app.detach_project(1).expect("project removed");
+        assert_eq!(removed.name, "b");
+
+        // `c` moved from index 2 to 1; its state follows it.
+        assert_eq!(app.active_project, 1);
+        assert_eq!(owner_index(&app, "s-a"), Some(0));
+        assert_eq!(owner_index(&app, "s-c"), Some(1));
+        assert_eq!(owner_index(&app, "s-b"), None);
+        assert!(!app.session_owned_elsewhere("s-c", 1));
+        assert!(app.session_owned_elsewhere("s-c", 0));
+
+        let limit = |idx: usize| app.model_limits[&app.projects[idx].path].context_window;
+        assert_eq!(limit(0), 1000);
+        assert_eq!(limit(1), 3000);
+        assert_eq!(app.model_limits.len(), 2);
+
+        assert!(app.sessions_expanded(1));
+        let visible: Vec<&str> = app.visible_sessions(1).iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(visible, ["s-c"]);
+        assert!(app.pinned_sessions[&app.projects[1].path].contains(&"s-c".to_string()));
+    }
+
+    #[test]
+    fn test_remove_middle_project_drops_its_session_state() {
+        let mut app = app_with_three_projects();
+        for sid in ["s-a", "s-b", "s-c"] {
+            app.session_stats.insert(sid.to_string(), Default::default());
+            app.unseen_sessions.insert(sid.to_string());
+            app.ui_state
+                .context_drafts
+                .insert(sid.to_string(), vec!["draft".to_string()]);
+            app.push_session_activity(sid.to_string(), ActivityKind::Tool, "bash".into());
+        }
+        app.session_stats.get_mut("s-c").unwrap().cost = 1.5;
+
+        app.detach_project(1);
+
+        assert!(!app.ui_state.context_drafts.contains_key("s-b"));
+        assert!(app.ui_state.context_drafts.contains_key("s-c"));
+        assert!(!app.session_stats.contains_key("s-b"));
+        assert!(!app.unseen_sessions.contains("s-b"));
+        assert!(app.session_stats.contains_key("s-a"));
+        assert_eq!(app.session_stats["s-c"].cost, 1.5);
+        assert!(app.unseen_sessions.contains("s-c"));
+        assert!(app.session_activity_entries("s-b").is_none());
+        assert!(app.session_activity_entries("s-c").is_some());
+    }
+
+    #[test]
+    fn test_remove_project_answers_and_shifts_queued_prompts() {
+        let mut app = app_with_three_projects();
+        let mut replies = Vec::new();
+        for project_idx in [1, 2] {
+            let (reply_tx, reply_rx) = tokio::sync::oneshot::channel();
+            replies.push(reply_rx);
+            app.queue_run_approval(PendingRunApproval {
+                project_idx,
+                session_id: "s1".into(),
+                pending: PendingSocketRequest {
+                    request: SocketRequest {
+                        op: "run".into(),
+                        command: Some("make".into()),
+                        ..Default::default()
+                    },
+                    reply_tx,
+                },
+            });
+            app.queue_permission_prompt(
+                project_idx,
+                PermissionRequest {
+                    id: format!("perm-{project_idx}"),
+                    session_id: "s1".into(),
+                    permission: "bash".into(),
+                    patterns: Vec::new(),
+                    metadata: serde_json::Value::Null,
+                },
+            );
+        }
+        app.agent_prompt_focused = true;
+
+        app.detach_project(1);
+
+        assert!(!replies[0].try_recv().unwrap().ok);
+        assert!(replies[1].try_recv().is_err());
+        let run_idx: Vec<usize> = app.run_approvals.iter().map(|a| a.project_idx).collect();
+        assert_eq!(run_idx, [1]);
+        let perm: Vec<(usize, &str)> = app
+            .permission_prompts
+            .iter()
+            .map(|p| (p.project_idx, p.request.id.as_str()))
+            .collect();
+        assert_eq!(perm, [(1, "perm-2")]);
+        assert!(!app.agent_prompt_focused);
+    }
+
+    #[test]
+    fn test_remove_expanded_project_collapses_sidebar() {
+        let mut app = app_with_three_projects();
+        app.toggle_sessions_expanded(1);
+        app.detach_project(1);
+        assert_eq!(app.sessions_expanded_for, None);
+        assert!(!app.sessions_expanded(1));
+    }
+
+    #[test]
+    fn test_project_search_roots_fall_back_to_home() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
+        assert_eq!(app.project_search_roots(), [home.clone()]);
+
+        let tmp = std::env::temp_dir();
+        app.config.settings.project_search_roots = vec![
+            "/nonexistent/opman-test/repos".to_string(),
+            tmp.to_string_lossy().to_string(),
+        ];
+        assert_eq!(app.project_search_roots(), [tmp]);
+
+        app.config.settings.project_search_roots = vec!["/nonexistent/opman-test".to_string()];
+        assert_eq!(app.project_search_roots(), [home]);
+    }
+}
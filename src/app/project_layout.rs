@@ -0,0 +1,142 @@
+//! Per-project panel layout, saved when switching away from a project and
+//! restored when switching back.
+
+use std::time::{Duration, Instant};
+
+use crate::app::App;
+use crate::config::LayoutSnapshot;
+use crate::ui::layout_manager::PanelId;
+
+/// How long a project must stay active before its saved layout is applied,
+/// so flicking through projects doesn't rebuild the layout and spawn panel
+/// PTYs for every project passed on the way.
+const PROJECT_LAYOUT_SETTLE: Duration = Duration::from_millis(250);
+
+impl App {
+    /// The current layout as a snapshot.
+    pub(super) fn layout_snapshot(&self) -> LayoutSnapshot {
+        LayoutSnapshot {
+            panel_visible: self.layout.panel_visible,
+            focused: self.layout.focused,
+            root: Some(self.layout.root.clone()),
+        }
+    }
+
+    /// Save the current layout as the active project's. Skipped in zen and
+    /// pop-out mode, whose layouts are temporary.
+    pub(super) fn remember_project_layout(&mut self) {
+        if self.zen_mode || self.popout_mode {
+            return;
+        }
+        let Some(path) = self.active_project().map(|p| p.path.clone()) else {
+            return;
+        };
+        let snapshot = self.layout_snapshot();
+        self.ui_state.project_layouts.insert(path, snapshot);
+    }
+
+    /// Queue the active project's saved layout, if it has one, to be
+    /// applied once it has been active for `PROJECT_LAYOUT_SETTLE`.
+    pub(super) fn queue_project_layout(&mut self) {
+        self.pending_project_layout = self
+            .active_project()
+            .map(|p| p.path.clone())
+            .filter(|path| self.ui_state.project_layouts.contains_key(path))
+            .map(|path| (path, Instant::now()));
+    }
+
+    /// Apply a queued project layout once switching has settled: restore
+    /// it, start PTYs for panels it makes visible and resize everything.
+    pub fn apply_pending_project_layout(&mut self) {
+        let Some((path, queued_at)) = self.pending_project_layout.clone() else {
+            return;
+        };
+        if queued_at.elapsed() < PROJECT_LAYOUT_SETTLE {
+            return;
+        }
+        self.pending_project_layout = None;
+        if self.zen_mode
+            || self.popout_mode
+            || self.active_project().map(|p| &p.path) != Some(&path)
+        {
+            return;
+        }
+        let Some(snapshot) = self.ui_state.project_layouts.get(&path).cloned() else {
+            return;
+        };
+
+        let pty_panels = [
+            PanelId::IntegratedTerminal,
+            PanelId::NeovimPane,
+            PanelId::GitPanel,
+        ];
+        let was_visible = pty_panels.map(|panel| self.layout.is_visible(panel));
+        self.layout.restore_snapshot(
+            snapshot.panel_visible,
+            snapshot.focused,
+            snapshot.root.as_ref(),
+        );
+        for (panel, was_visible) in pty_panels.into_iter().zip(was_visible) {
+            if was_visible || !self.layout.is_visible(panel) {
+                continue;
+            }
+            match panel {
+                PanelId::IntegratedTerminal => {
+                    self.ensure_shell_pty();
+                }
+                PanelId::NeovimPane => self.ensure_neovim_pty(),
+                _ => self.ensure_gitui_pty(),
+            }
+        }
+        self.resize_all_ptys();
+        self.needs_redraw = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, ProjectEntry, UiState};
+
+    fn settle(app: &mut App) {
+        if let Some((_, queued_at)) = app.pending_project_layout.as_mut() {
+            *queued_at -= PROJECT_LAYOUT_SETTLE;
+        }
+        app.apply_pending_project_layout();
+    }
+
+    #[test]
+    fn test_switching_projects_restores_each_layout() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.ui_state = UiState::default();
+        for name in ["a", "b"] {
+            app.add_project(ProjectEntry {
+                name: name.to_string(),
+                path: format!("/nonexistent/opman-test/{name}"),
+                ..Default::default()
+            });
+        }
+
+        app.layout.set_visible(PanelId::NeovimPane, true);
+        app.switch_project(1);
+        assert!(app.pending_project_layout.is_none());
+        app.layout.set_visible(PanelId::NeovimPane, false);
+        app.layout.set_visible(PanelId::Sidebar, false);
+
+        app.switch_project(0);
+        app.apply_pending_project_layout();
+        assert!(
+            !app.layout.is_visible(PanelId::NeovimPane),
+            "applied before settling"
+        );
+        settle(&mut app);
+        assert!(app.layout.is_visible(PanelId::NeovimPane));
+        assert!(app.layout.is_visible(PanelId::Sidebar));
+
+        app.switch_project(1);
+        settle(&mut app);
+        assert!(!app.layout.is_visible(PanelId::NeovimPane));
+        assert!(!app.layout.is_visible(PanelId::Sidebar));
+    }
+}
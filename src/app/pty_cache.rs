@@ -0,0 +1,150 @@
+//! Bounding live PTYs: LRU eviction of cached session PTYs and idle
+//! reaping of per-session shell/neovim PTYs.
+
+use std::time::{Duration, Instant};
+
+use tracing::info;
+
+use crate::app::App;
+use crate::pty::CommandState;
+
+impl App {
+    /// Record activation time for every project's active session, then
+    /// evict least-recently-activated session PTYs beyond the configured cap.
+    ///
+    /// The active session, the pending `__new__` PTY and sessions that are
+    /// currently busy are never evicted. Evicted sessions respawn their PTY
+    /// on next selection like any uncached session.
+    pub fn enforce_pty_cache_limit(&mut self) {
+        let cap = self.config.settings.max_cached_session_ptys;
+        let now = Instant::now();
+        for project in &mut self.projects {
+            if let Some(ref sid) = project.active_session {
+                project.session_last_active.insert(sid.clone(), now);
+            }
+            // Forget sessions that no longer hold anything to evict or reap.
+            project.session_last_active.retain(|sid, _| {
+                project.active_session.as_deref() == Some(sid.as_str())
+                    || project.ptys.contains_key(sid)
+                    || project.session_resources.contains_key(sid)
+            });
+            if cap == 0 {
+                continue;
+            }
+            while project.ptys.len() > cap {
+                let victim = project
+                    .ptys
+                    .keys()
+                    .filter(|sid| {
+                        project.active_session.as_deref() != Some(sid.as_str())
+                            && sid.as_str() != "__new__"
+                            && !self.active_sessions.contains(*sid)
+                    })
+                    .min_by_key(|sid| project.session_last_active.get(*sid).copied())
+                    .cloned();
+                let Some(victim) = victim else {
+                    break;
+                };
+                if let Some(mut pty) = project.ptys.remove(&victim) {
+                    let _ = pty.kill();
+                }
+                info!(
+                    name = %project.name,
+                    session_id = %victim,
+                    cap,
+                    "Evicted least-recently-used session PTY"
+                );
+            }
+        }
+    }
+
+    /// Kill the shell and neovim PTYs of sessions that have not been active
+    /// for longer than the configured idle timeout. The opencode PTY and
+    /// file snapshots are kept; `ensure_shell_pty`/`ensure_neovim_pty`
+    /// respawn the reaped PTYs when the session is next shown. Shell tabs
+    /// are kept while any of them runs a command. Neovim is first asked for
+    /// unsaved buffers on a blocking thread, and reaped by
+    /// [`Self::finish_nvim_reap`] only if it has none.
+    pub fn reap_idle_session_resources(&mut self) {
+        let timeout = self.config.settings.idle_session_resource_timeout_secs;
+        if timeout == 0 {
+            return;
+        }
+        let timeout = Duration::from_secs(timeout);
+        let now = Instant::now();
+        let mut nvim_checks = Vec::new();
+        for project in &mut self.projects {
+            for (sid, resources) in project.session_resources.iter_mut() {
+                if project.active_session.as_deref() == Some(sid.as_str()) {
+                    continue;
+                }
+                if resources.shell_ptys.is_empty() && resources.neovim_pty.is_none() {
+                    continue;
+                }
+                // Sessions never seen active (e.g. spawned via MCP) start
+                // their idle clock now.
+                let last_active = *project
+                    .session_last_active
+                    .entry(sid.clone())
+                    .or_insert(now);
+                if now.duration_since(last_active) < timeout {
+                    continue;
+                }
+                let shells_busy = resources.shell_ptys.iter().any(|pty| {
+                    pty.command_state
+                        .lock()
+                        .is_ok_and(|s| *s == CommandState::Running)
+                });
+                if !shells_busy && !resources.shell_ptys.is_empty() {
+                    for mut shell_pty in resources.shell_ptys.drain(..) {
+                        let _ = shell_pty.kill();
+                    }
+                    resources.active_shell_tab = 0;
+                    resources.split_shell_tab = None;
+                    info!(name = %project.name, session_id = %sid, "Reaped idle session shells");
+                }
+                let addr = resources
+                    .neovim_pty
+                    .as_ref()
+                    .and_then(|nvim| nvim.nvim_listen_addr.clone());
+                if let Some(addr) = addr {
+                    if self.nvim_reap_checks.insert(sid.clone()) {
+                        nvim_checks.push((sid.clone(), addr));
+                    }
+                }
+            }
+        }
+        for (sid, addr) in nvim_checks {
+            crate::background_tasks::spawn_nvim_reap_check(&self.bg_tx, sid, addr);
+        }
+    }
+
+    /// Reap an idle session's neovim once it reported no unsaved buffers,
+    /// unless the session was shown again while it was being asked.
+    pub fn finish_nvim_reap(&mut self, session_id: &str, modified: bool) {
+        self.nvim_reap_checks.remove(session_id);
+        if modified {
+            return;
+        }
+        let timeout = Duration::from_secs(self.config.settings.idle_session_resource_timeout_secs);
+        for project in &mut self.projects {
+            if project.active_session.as_deref() == Some(session_id) {
+                continue;
+            }
+            let idle = project
+                .session_last_active
+                .get(session_id)
+                .is_some_and(|t| t.elapsed() >= timeout);
+            let Some(resources) = project.session_resources.get_mut(session_id) else {
+                continue;
+            };
+            if !idle {
+                continue;
+            }
+            if let Some(mut nvim) = resources.neovim_pty.take() {
+                let _ = nvim.kill();
+                info!(name = %project.name, session_id, "Reaped idle session neovim");
+            }
+        }
+    }
+}
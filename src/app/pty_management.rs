@@ -1,5 +1,8 @@
+use ratatui::layout::Rect;
+
+use crate::app::output_log::shell_output_log;
 use crate::app::App;
-use crate::app::SessionResources;
+use crate::app::{AwaitingEnv, SessionResources};
 use crate::pty::PtyInstance;
 use crate::theme_gen;
 use crate::ui::layout_manager::PanelId;
@@ -24,8 +27,17 @@ impl App {
                 if let Some(rect) = shell_rect {
                     if rect.width > 0 && rect.height > 0 {
                         let content_height = rect.height.saturating_sub(1).max(1);
-                        for shell_pty in &mut resources.shell_ptys {
-                            let _ = shell_pty.resize(content_height, rect.width);
+                        let content = Rect {
+                            height: content_height,
+                            ..rect
+                        };
+                        let panes = resources.shell_panes(content);
+                        for (i, shell_pty) in resources.shell_ptys.iter_mut().enumerate() {
+                            let width = panes
+                                .iter()
+                                .find(|(tab, _)| *tab == i)
+                                .map_or(rect.width, |(_, pane)| pane.width);
+                            let _ = shell_pty.resize(content_height, width);
                         }
                     }
                 }
@@ -37,6 +49,7 @@ impl App {
                 }
             }
             if let (Some(ref mut gitui_pty), Some(rect)) = (&mut project.gitui_pty, git_rect) {
+                let rect = crate::ui::gitui_pane::gitui_area(rect);
                 if rect.width > 0 && rect.height > 0 {
                     let _ = gitui_pty.resize(rect.height, rect.width);
                 }
@@ -70,30 +83,74 @@ impl App {
             .unwrap_or((0, 0))
     }
 
-    pub fn ensure_shell_pty(&mut self) {
+    /// The command the terminal pane attaches with for a project: its own
+    /// `opencode_command`, then the global setting (`None` = `opencode`).
+    pub fn opencode_command(&self, index: usize) -> Option<String> {
+        self.config
+            .projects
+            .get(index)
+            .and_then(|e| e.opencode_command.clone())
+            .or_else(|| self.config.settings.opencode_command.clone())
+    }
+
+    /// Open the active session's first shell tab if it has none. Returns
+    /// whether it has one now; `false` while waiting on direnv (the tab
+    /// opens once its export is resolved) or when the spawn failed.
+    pub fn ensure_shell_pty(&mut self) -> bool {
+        let index = self.active_project;
+        let Some(sid) = self
+            .projects
+            .get(index)
+            .and_then(|p| p.active_session.clone())
+        else {
+            return false;
+        };
+        self.spawn_shell_tab(index, &sid, false)
+    }
+
+    /// Open another shell tab for the active session and make it active.
+    /// Returns whether it opened (see [`ensure_shell_pty`](Self::ensure_shell_pty)).
+    pub fn add_shell_tab(&mut self) -> bool {
         let index = self.active_project;
+        let Some(sid) = self
+            .projects
+            .get(index)
+            .and_then(|p| p.active_session.clone())
+        else {
+            return false;
+        };
+        self.spawn_shell_tab(index, &sid, true)
+    }
+
+    /// Open a shell tab for session `sid` of project `index` and make it
+    /// active: another one when `new_tab`, else the first if there is
+    /// none. Waits (see [`AwaitingEnv`]) when the project's direnv export
+    /// isn't resolved yet. Returns whether the session has the tab.
+    pub(crate) fn spawn_shell_tab(&mut self, index: usize, sid: &str, new_tab: bool) -> bool {
         if index >= self.projects.len() {
-            return;
+            return false;
         }
-        let sid = match self.projects[index].active_session.clone() {
-            Some(s) => s,
-            None => return,
-        };
-        {
-            let resources = self.projects[index]
-                .session_resources
-                .entry(sid.clone())
-                .or_insert_with(SessionResources::new);
-            if !resources.shell_ptys.is_empty() {
-                return;
-            }
+        let resources = self.projects[index]
+            .session_resources
+            .entry(sid.to_string())
+            .or_insert_with(SessionResources::new);
+        if !new_tab && !resources.shell_ptys.is_empty() {
+            return true;
         }
+        let Some(env) = self.shell_env(index) else {
+            let session_id = sid.to_string();
+            let work = AwaitingEnv::ShellTab {
+                session_id,
+                new_tab,
+            };
+            self.await_env(index, work);
+            return false;
+        };
         let shell_rows = self
             .layout
             .panel_rect(PanelId::IntegratedTerminal)
             .map(|r| (r.height.saturating_sub(1).max(2), r.width.max(2)))
             .unwrap_or((24, 80));
-        let theme_envs = self.theme.pty_env_vars();
         let td = theme_gen::theme_dir();
         let command = self
             .config
@@ -105,71 +162,93 @@ impl App {
         match PtyInstance::spawn_shell(
             shell_rows.0,
             shell_rows.1,
+            self.config.settings.scrollback_lines,
             &self.projects[index].path,
-            &theme_envs,
+            &env,
             Some(&td),
             command,
             None,
+            shell_output_log(&self.config.settings, &self.projects[index].path, sid, None),
         ) {
             Ok(shell) => {
                 let resources = self.projects[index]
                     .session_resources
-                    .entry(sid)
+                    .entry(sid.to_string())
                     .or_insert_with(SessionResources::new);
                 resources.shell_ptys.push(shell);
-                resources.active_shell_tab = 0;
+                resources.active_shell_tab = resources.shell_ptys.len() - 1;
+                true
+            }
+            Err(e) => {
+                tracing::warn!(
+                    project = %self.projects[index].name,
+                    "Failed to spawn shell PTY: {}", e
+                );
+                false
             }
-            Err(e) => tracing::warn!(
-                project = %self.projects[index].name,
-                "Failed to spawn shell PTY: {}", e
-            ),
         }
     }
 
-    pub fn add_shell_tab(&mut self) {
-        let index = self.active_project;
-        if index >= self.projects.len() {
-            return;
+    /// Show two shell tabs side by side, or go back to one. Splitting
+    /// pairs the active tab with its neighbour, or opens a new tab when
+    /// there is only one.
+    pub fn toggle_shell_split(&mut self) {
+        if let Some(resources) = self
+            .projects
+            .get_mut(self.active_project)
+            .and_then(|p| p.active_resources_mut())
+        {
+            if resources.shell_split().is_some() {
+                resources.split_shell_tab = None;
+                self.layout.layout_dirty = true;
+                return;
+            }
         }
-        let sid = match self.projects[index].active_session.clone() {
-            Some(s) => s,
-            None => return,
+        self.ensure_shell_pty();
+        let Some((len, active)) = self
+            .projects
+            .get(self.active_project)
+            .and_then(|p| p.active_resources())
+            .map(|r| (r.shell_ptys.len(), r.active_shell_tab))
+        else {
+            return;
         };
-        let shell_rows = self
-            .layout
-            .panel_rect(PanelId::IntegratedTerminal)
-            .map(|r| (r.height.saturating_sub(1).max(2), r.width.max(2)))
-            .unwrap_or((24, 80));
-        let theme_envs = self.theme.pty_env_vars();
-        let td = theme_gen::theme_dir();
-        let command = self
-            .config
+        let other = match len {
+            0 => return,
+            1 => {
+                // The new tab becomes active beside the existing one.
+                self.add_shell_tab();
+                active
+            }
+            _ if active == 0 => 1,
+            _ => active - 1,
+        };
+        if let Some(resources) = self
             .projects
-            .get(index)
-            .and_then(|e| e.terminal_command.as_deref())
-            .or(self.config.settings.default_terminal_command.as_deref());
-
-        match PtyInstance::spawn_shell(
-            shell_rows.0,
-            shell_rows.1,
-            &self.projects[index].path,
-            &theme_envs,
-            Some(&td),
-            command,
-            None,
-        ) {
-            Ok(shell) => {
-                let resources = self.projects[index]
-                    .session_resources
-                    .entry(sid)
-                    .or_insert_with(SessionResources::new);
-                resources.shell_ptys.push(shell);
-                resources.active_shell_tab = resources.shell_ptys.len() - 1;
+            .get_mut(self.active_project)
+            .and_then(|p| p.active_resources_mut())
+        {
+            if resources.shell_ptys.len() > 1 {
+                resources.split_shell_tab = Some(other);
             }
-            Err(e) => tracing::warn!(
-                project = %self.projects[index].name,
-                "Failed to spawn shell tab PTY: {}", e
-            ),
+        }
+        self.layout.layout_dirty = true;
+    }
+
+    /// Start the PTYs `[settings.startup]` asked for once project
+    /// `project_idx` has its first session `sid` (see `setup.rs`).
+    pub(crate) fn prespawn_ptys(&mut self, project_idx: usize, sid: &str) {
+        let Some(path) = self.projects.get(project_idx).map(|p| p.path.clone()) else {
+            return;
+        };
+        if self.prespawn_shell_for.as_ref() == Some(&path) {
+            self.prespawn_shell_for = None;
+            self.spawn_shell_tab(project_idx, sid, false);
+        }
+        if self.prespawn_neovim_for.remove(&path) {
+            let saved = std::mem::replace(&mut self.active_project, project_idx);
+            self.ensure_neovim_pty();
+            self.active_project = saved;
         }
     }
 
@@ -201,6 +280,7 @@ impl App {
         match PtyInstance::spawn_neovim(
             nvim_size.0,
             nvim_size.1,
+            self.config.settings.scrollback_lines,
             &self.projects[index].path,
             &theme_envs,
             Some(&td),
@@ -209,7 +289,7 @@ impl App {
             Ok(nvim) => {
                 if let Some(ref addr) = nvim.nvim_listen_addr {
                     let reg = self.nvim_registry.clone();
-                    let key = (index, sid.clone());
+                    let key = (self.projects[index].path.clone(), sid.clone());
                     let addr = addr.clone();
                     tokio::spawn(async move {
                         reg.write().await.insert(key, addr);
@@ -239,6 +319,7 @@ impl App {
         let git_size = self
             .layout
             .panel_rect(PanelId::GitPanel)
+            .map(crate::ui::gitui_pane::gitui_area)
             .map(|r| (r.height.max(2), r.width.max(2)))
             .unwrap_or((24, 80));
         let theme_path = theme_gen::theme_dir().join("gitui/opencode.ron");
@@ -250,6 +331,7 @@ impl App {
         match PtyInstance::spawn_gitui(
             git_size.0,
             git_size.1,
+            self.config.settings.scrollback_lines,
             &self.projects[index].path,
             theme_ref,
         ) {
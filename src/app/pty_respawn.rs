@@ -0,0 +1,124 @@
+//! Recovery for PTYs in a bad state: kill and respawn every PTY of a
+//! project, keeping the active session and the shell tab layout.
+
+use tracing::info;
+
+use crate::app::output_log::shell_output_log;
+use crate::app::{App, AwaitingEnv};
+use crate::pty::PtyInstance;
+use crate::theme_gen;
+
+impl App {
+    /// Ask to respawn the active project's PTYs; `y` confirms.
+    pub fn request_respawn_ptys(&mut self) {
+        if self.active_project < self.projects.len() {
+            self.confirm_respawn = Some(self.active_project);
+        }
+    }
+
+    /// Kill and respawn every PTY of a project. The active session's
+    /// opencode PTY comes back through the usual session-select path,
+    /// shell tabs are replaced in place (names, order, active tab and
+    /// split kept), and neovim and gitui restart if they were running.
+    /// Other sessions' opencode and neovim PTYs respawn when next shown.
+    pub fn respawn_project_ptys(&mut self, project_idx: usize) {
+        let Some(project) = self.projects.get_mut(project_idx) else {
+            return;
+        };
+        let mut killed = 0;
+        for (_, mut pty) in project.ptys.drain() {
+            let _ = pty.kill();
+            killed += 1;
+        }
+        let active = project.active_session.clone();
+        let mut had_neovim = false;
+        for (sid, resources) in project.session_resources.iter_mut() {
+            if let Some(mut nvim) = resources.neovim_pty.take() {
+                let _ = nvim.kill();
+                killed += 1;
+                had_neovim |= active.as_deref() == Some(sid.as_str());
+            }
+        }
+        let had_gitui = match project.gitui_pty.take() {
+            Some(mut gitui) => {
+                let _ = gitui.kill();
+                killed += 1;
+                true
+            }
+            None => false,
+        };
+        info!(name = %project.name, killed, "Respawning project PTYs");
+
+        match active {
+            Some(sid) if sid == "__new__" => self.pending_new_session = Some(project_idx),
+            Some(sid) => self.pending_session_select = Some((project_idx, sid)),
+            None => {}
+        }
+        let shells = self.respawn_shell_tabs(project_idx);
+        if project_idx == self.active_project {
+            if had_neovim {
+                self.ensure_neovim_pty();
+            }
+            if had_gitui {
+                self.ensure_gitui_pty();
+            }
+        }
+        self.resize_all_ptys();
+        self.toasts
+            .success(format!("Restarted {} PTYs", killed + shells));
+    }
+
+    /// Replace every shell tab of a project with a fresh one running the
+    /// project's terminal command, keeping tab names and sizes. Returns
+    /// how many tabs were restarted; none yet while waiting on direnv (see
+    /// [`AwaitingEnv`]).
+    pub fn respawn_shell_tabs(&mut self, project_idx: usize) -> usize {
+        let Some(shell_envs) = self.shell_env(project_idx) else {
+            self.await_env(project_idx, AwaitingEnv::RespawnShells);
+            return 0;
+        };
+        let Some(project) = self.projects.get_mut(project_idx) else {
+            return 0;
+        };
+        let command = self
+            .config
+            .projects
+            .get(project_idx)
+            .and_then(|e| e.terminal_command.as_deref())
+            .or(self.config.settings.default_terminal_command.as_deref());
+        let td = theme_gen::theme_dir();
+        let scrollback = self.config.settings.scrollback_lines;
+        let mut restarted = 0;
+        for (session_id, resources) in project.session_resources.iter_mut() {
+            for pty in resources.shell_ptys.iter_mut() {
+                let name = (!pty.name.is_empty()).then(|| pty.name.clone());
+                match PtyInstance::spawn_shell(
+                    pty.rows,
+                    pty.cols,
+                    scrollback,
+                    &project.path,
+                    &shell_envs,
+                    Some(&td),
+                    command,
+                    name,
+                    shell_output_log(
+                        &self.config.settings,
+                        &project.path,
+                        session_id,
+                        pty.output_log_path.as_deref(),
+                    ),
+                ) {
+                    Ok(shell) => {
+                        *pty = shell;
+                        restarted += 1;
+                    }
+                    Err(e) => tracing::warn!(
+                        project = %project.name,
+                        "Failed to respawn shell tab PTY: {}", e
+                    ),
+                }
+            }
+        }
+        restarted
+    }
+}
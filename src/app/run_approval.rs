@@ -0,0 +1,81 @@
+//! Human-in-the-loop gate for the MCP `run` op ("dry-run" mode).
+
+use crate::app::App;
+use crate::mcp::{PendingSocketRequest, SocketResponse};
+
+/// A `run` request held until the user approves or rejects it. The bridge
+/// stays blocked on `pending.reply_tx` the whole time.
+pub struct PendingRunApproval {
+    pub project_idx: usize,
+    pub session_id: String,
+    pub pending: PendingSocketRequest,
+}
+
+impl PendingRunApproval {
+    /// The proposed command line.
+    pub fn command(&self) -> &str {
+        self.pending.request.command.as_deref().unwrap_or("")
+    }
+}
+
+impl App {
+    /// Whether `pending` must wait for approval: a `run` (other than a bare
+    /// Ctrl-C interrupt) in a project with dry-run on.
+    pub(crate) fn needs_run_approval(
+        &self,
+        project_idx: usize,
+        pending: &PendingSocketRequest,
+    ) -> bool {
+        let request = &pending.request;
+        if request.op != "run" || request.command.as_deref() == Some("\x03") {
+            return false;
+        }
+        self.config
+            .projects
+            .get(project_idx)
+            .and_then(|e| e.mcp_run_dry_run)
+            .unwrap_or(self.config.settings.mcp_run_dry_run)
+    }
+
+    /// Queue a `run` for approval and announce it with a toast.
+    pub(crate) fn queue_run_approval(&mut self, approval: PendingRunApproval) {
+        let msg = format!(
+            "Agent wants to run: {}",
+            crate::util::truncate_str(approval.command(), 48)
+        );
+        self.toasts.warning(msg);
+        self.run_approvals.push_back(approval);
+        self.needs_redraw = true;
+    }
+
+    /// Answer the oldest queued `run`: execute it, or reply with a rejection.
+    pub fn resolve_run_approval(&mut self, approve: bool) {
+        let Some(approval) = self.run_approvals.pop_front() else {
+            return;
+        };
+        // The next prompt needs its own deliberate focus.
+        self.agent_prompt_focused = false;
+        let response = if approve {
+            self.handle_mcp_request(
+                approval.project_idx,
+                &approval.session_id,
+                &approval.pending.request,
+            )
+        } else {
+            SocketResponse::err(format!(
+                "Command rejected by the user (dry-run): {}",
+                approval.command()
+            ))
+        };
+        let _ = approval.pending.reply_tx.send(response);
+        self.needs_redraw = true;
+    }
+
+    /// Reject everything still waiting, e.g. when MCP is stopped or on quit,
+    /// so no bridge is left hanging.
+    pub(crate) fn reject_all_run_approvals(&mut self) {
+        while !self.run_approvals.is_empty() {
+            self.resolve_run_approval(false);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::app::App;
 use crate::app::SessionSelectorEntry;
 use crate::app::SessionSelectorState;
@@ -38,6 +40,8 @@ impl App {
             selected: 0,
             scroll_offset: 0,
             filtered,
+            marked: HashSet::new(),
+            confirm_delete: false,
         });
     }
 
@@ -75,7 +79,8 @@ impl App {
             .session_search_results
             .get(self.session_search_selected)?
             .clone();
-        let entry = self.pinned_sessions.entry(self.active_project).or_default();
+        let path = self.projects.get(self.active_project)?.path.clone();
+        let entry = self.pinned_sessions.entry(path).or_default();
         if !entry.contains(&session.id) {
             entry.push(session.id.clone());
         }
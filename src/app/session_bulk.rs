@@ -0,0 +1,159 @@
+//! Bulk actions on the sessions marked in the session selector (or the
+//! highlighted one when none are marked).
+
+use std::collections::HashSet;
+
+use crate::app::App;
+
+impl App {
+    /// Pin the selector's target sessions to their projects' sidebars.
+    pub fn bulk_pin_sessions(&mut self) {
+        let Some(state) = self.session_selector.as_mut() else {
+            return;
+        };
+        let targets: Vec<(usize, String)> = state
+            .action_targets()
+            .into_iter()
+            .map(|e| (e.project_idx, e.session.id.clone()))
+            .collect();
+        state.marked.clear();
+        let mut pinned = 0;
+        for (project_idx, session_id) in targets {
+            let Some(project) = self.projects.get(project_idx) else {
+                continue;
+            };
+            let entry = self
+                .pinned_sessions
+                .entry(project.path.clone())
+                .or_default();
+            if !entry.contains(&session_id) {
+                entry.push(session_id);
+                pinned += 1;
+            }
+        }
+        self.toasts.info(format!("Pinned {pinned} session(s)"));
+    }
+
+    /// Ask to delete the selector's target sessions; `y` confirms.
+    pub fn request_bulk_delete_sessions(&mut self) {
+        if let Some(state) = self.session_selector.as_mut() {
+            state.confirm_delete = !state.action_targets().is_empty();
+        }
+    }
+
+    /// Delete the selector's target sessions through the API, dropping
+    /// them from the selector right away.
+    pub fn bulk_delete_sessions(&mut self) {
+        let Some(state) = self.session_selector.as_mut() else {
+            return;
+        };
+        state.confirm_delete = false;
+        let mut session_ids = HashSet::new();
+        let mut deletions = Vec::new();
+        for entry in state.action_targets() {
+            let Some(project) = self.projects.get(entry.project_idx) else {
+                continue;
+            };
+            session_ids.insert(entry.session.id.clone());
+            deletions.push((
+                project.path.to_string_lossy().to_string(),
+                entry.session.id.clone(),
+            ));
+        }
+        state.remove_sessions(&session_ids);
+        self.clear_context_drafts(&session_ids);
+        if !deletions.is_empty() {
+            crate::background_tasks::spawn_sessions_delete(&self.bg_tx, deletions);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    use crate::app::{App, BackgroundEvent, SessionInfo};
+    use crate::config::{Config, ProjectEntry};
+
+    /// App with projects `a` and `b`, each owning sessions `s-<name>1` and
+    /// `s-<name>2`, and the session selector open with `marks` marked.
+    fn app_with_selector(marks: &[&str]) -> App {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        for (i, name) in ["a", "b"].into_iter().enumerate() {
+            let path = format!("/nonexistent/opman-test/{name}");
+            app.add_project(ProjectEntry {
+                name: name.to_string(),
+                path: path.clone(),
+                ..Default::default()
+            });
+            app.handle_background_event(BackgroundEvent::SessionsFetched {
+                project_idx: i,
+                sessions: ["1", "2"]
+                    .map(|n| SessionInfo {
+                        id: format!("s-{name}{n}"),
+                        directory: path.clone(),
+                        ..Default::default()
+                    })
+                    .to_vec(),
+            });
+        }
+        app.open_session_selector();
+        let state = app.session_selector.as_mut().unwrap();
+        for id in marks {
+            let idx = state.entries.iter().position(|e| e.session.id == *id);
+            state.marked.insert(idx.unwrap());
+        }
+        app
+    }
+
+    fn selector_ids(app: &App) -> Vec<&str> {
+        let state = app.session_selector.as_ref().unwrap();
+        let mut ids: Vec<&str> = state
+            .entries
+            .iter()
+            .map(|e| e.session.id.as_str())
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    fn press(app: &mut App, c: char) {
+        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        crate::input::handle_key_event(app, key).unwrap();
+    }
+
+    #[test]
+    fn test_bulk_pin_marked_sessions() {
+        let mut app = app_with_selector(&["s-a1", "s-b2"]);
+        app.bulk_pin_sessions();
+        let pinned = |idx: usize| app.pinned_sessions[&app.projects[idx].path].clone();
+        assert_eq!(pinned(0), ["s-a1"]);
+        assert_eq!(pinned(1), ["s-b2"]);
+        assert!(app.session_selector.unwrap().marked.is_empty());
+    }
+
+    #[test]
+    fn test_bulk_delete_asks_first_and_other_keys_cancel() {
+        let mut app = app_with_selector(&["s-a1", "s-b2"]);
+        app.request_bulk_delete_sessions();
+        assert!(app.session_selector.as_ref().unwrap().confirm_delete);
+
+        press(&mut app, 'n');
+        let state = app.session_selector.as_ref().unwrap();
+        assert!(!state.confirm_delete);
+        assert_eq!(state.marked.len(), 2);
+        assert_eq!(selector_ids(&app), ["s-a1", "s-a2", "s-b1", "s-b2"]);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_delete_drops_targets_from_selector() {
+        let mut app = app_with_selector(&["s-a1", "s-b2"]);
+        app.request_bulk_delete_sessions();
+        press(&mut app, 'y');
+        let state = app.session_selector.as_ref().unwrap();
+        assert!(!state.confirm_delete);
+        assert!(state.marked.is_empty());
+        assert_eq!(selector_ids(&app), ["s-a2", "s-b1"]);
+    }
+}
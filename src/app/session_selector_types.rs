@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use crate::app::SessionInfo;
 
 /// A single entry in the cross-project session selector.
@@ -16,6 +18,10 @@ pub struct SessionSelectorState {
     pub selected: usize,
     pub scroll_offset: usize,
     pub filtered: Vec<usize>,
+    /// Entries (indices into `entries`) marked for a bulk action.
+    pub marked: HashSet<usize>,
+    /// Waiting for `y` to delete the bulk action's sessions.
+    pub confirm_delete: bool,
 }
 
 impl SessionSelectorState {
@@ -60,6 +66,44 @@ impl SessionSelectorState {
         }
     }
 
+    /// Mark or unmark the highlighted entry, then move to the next one.
+    pub fn toggle_mark(&mut self) {
+        let Some(&entry) = self.filtered.get(self.selected) else {
+            return;
+        };
+        if !self.marked.remove(&entry) {
+            self.marked.insert(entry);
+        }
+        if self.selected + 1 < self.filtered.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Entries a bulk action applies to: the marked ones, or the
+    /// highlighted one when nothing is marked.
+    pub fn action_targets(&self) -> Vec<&SessionSelectorEntry> {
+        if self.marked.is_empty() {
+            return self
+                .filtered
+                .get(self.selected)
+                .map(|&i| &self.entries[i])
+                .into_iter()
+                .collect();
+        }
+        let mut marked: Vec<usize> = self.marked.iter().copied().collect();
+        marked.sort_unstable();
+        marked.into_iter().map(|i| &self.entries[i]).collect()
+    }
+
+    /// Drop the entries for `session_ids` (e.g. after deleting them) and
+    /// clear the marks.
+    pub fn remove_sessions(&mut self, session_ids: &HashSet<String>) {
+        self.entries
+            .retain(|e| !session_ids.contains(&e.session.id));
+        self.marked.clear();
+        self.update_filter();
+    }
+
     pub fn insert_char(&mut self, c: char) {
         self.query.insert(self.cursor_pos, c);
         self.cursor_pos += c.len_utf8();
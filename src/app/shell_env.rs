@@ -0,0 +1,180 @@
+//! Shell environments that wait on direnv. A new shell needs the project's
+//! direnv export; when it isn't cached yet it is resolved on a blocking
+//! thread, and the spawn (or the MCP request that needs one) waits for
+//! `BackgroundEvent::DirenvResolved` instead of blocking the event loop.
+
+use std::path::{Path, PathBuf};
+
+use crate::app::{App, BackgroundEvent};
+use crate::mcp::{PendingSocketRequest, SocketResponse};
+use crate::pty::{with_direnv, EnvVar};
+
+/// Work held until a project's direnv export is resolved.
+pub enum AwaitingEnv {
+    /// A shell tab for a session: another one when `new_tab`, else its
+    /// first.
+    ShellTab { session_id: String, new_tab: bool },
+    /// Restart the project's shell tabs (see `respawn_shell_tabs`).
+    RespawnShells,
+    /// An MCP request that starts a shell.
+    Mcp {
+        session_id: String,
+        pending: PendingSocketRequest,
+    },
+}
+
+impl App {
+    /// Environment for a new shell in project `project_idx`: the theme's
+    /// variables plus, when enabled, direnv's. `None` while direnv's export
+    /// is being resolved; resolving starts if it hasn't already.
+    pub(crate) fn shell_env(&mut self, project_idx: usize) -> Option<Vec<EnvVar>> {
+        let path = self.projects.get(project_idx)?.path.clone();
+        let theme_envs = self.theme.pty_env_vars();
+        if let Some(env) = with_direnv(&theme_envs, &path, self.config.settings.direnv) {
+            return Some(env);
+        }
+        if self.direnv_loading.insert(path.clone()) {
+            crate::background_tasks::spawn_direnv_resolve(&self.bg_tx, path);
+        }
+        None
+    }
+
+    /// Hold `work` until project `project_idx`'s direnv export is resolved.
+    /// A first shell tab or a respawn already waiting isn't queued twice
+    /// (`ensure_shell_pty` runs on every draw).
+    pub(crate) fn await_env(&mut self, project_idx: usize, work: AwaitingEnv) {
+        let Some(path) = self.projects.get(project_idx).map(|p| p.path.clone()) else {
+            return;
+        };
+        let queued = self.awaiting_env.iter().any(|(p, w)| {
+            *p == path
+                && match (w, &work) {
+                    (
+                        AwaitingEnv::ShellTab {
+                            session_id: a,
+                            new_tab: false,
+                        },
+                        AwaitingEnv::ShellTab {
+                            session_id: b,
+                            new_tab: false,
+                        },
+                    ) => a == b,
+                    (AwaitingEnv::RespawnShells, AwaitingEnv::RespawnShells) => true,
+                    _ => false,
+                }
+        });
+        if !queued {
+            self.awaiting_env.push((path, work));
+        }
+    }
+
+    /// direnv's export for `project_path` is cached: run what waited on it.
+    pub(crate) fn finish_direnv(&mut self, project_path: PathBuf) {
+        self.direnv_loading.remove(&project_path);
+        let (ready, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.awaiting_env)
+            .into_iter()
+            .partition(|(path, _)| *path == project_path);
+        self.awaiting_env = waiting;
+        let project_idx = self.projects.iter().position(|p| p.path == project_path);
+        for (_, work) in ready {
+            match work {
+                AwaitingEnv::ShellTab {
+                    session_id,
+                    new_tab,
+                } => {
+                    if let Some(idx) = project_idx {
+                        self.spawn_shell_tab(idx, &session_id, new_tab);
+                    }
+                }
+                AwaitingEnv::RespawnShells => {
+                    if let Some(idx) = project_idx {
+                        self.respawn_shell_tabs(idx);
+                    }
+                }
+                // Dispatched again; a removed project gets its error there.
+                AwaitingEnv::Mcp {
+                    session_id,
+                    pending,
+                } => {
+                    self.handle_background_event(BackgroundEvent::McpSocketRequest {
+                        project_path: project_path.clone(),
+                        session_id,
+                        pending,
+                    });
+                }
+            }
+        }
+        self.layout.layout_dirty = true;
+        self.resize_all_ptys();
+        self.needs_redraw = true;
+    }
+
+    /// Drop the work waiting on a removed project's export, answering its
+    /// MCP requests.
+    pub(crate) fn drop_awaiting_env(&mut self, project_path: &Path) {
+        let (dropped, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.awaiting_env)
+            .into_iter()
+            .partition(|(path, _)| path.as_path() == project_path);
+        self.awaiting_env = waiting;
+        for (_, work) in dropped {
+            if let AwaitingEnv::Mcp { pending, .. } = work {
+                let _ = pending
+                    .reply_tx
+                    .send(SocketResponse::err("Project was removed".into()));
+            }
+        }
+    }
+
+    /// Report that a shell tab couldn't be opened for the active project:
+    /// it is waiting on direnv, or the spawn failed.
+    pub(crate) fn toast_no_shell_tab(&mut self) {
+        let loading = self
+            .projects
+            .get(self.active_project)
+            .is_some_and(|p| self.direnv_loading.contains(&p.path));
+        if loading {
+            self.toasts
+                .info("Loading the project's .envrc; try again once the terminal opens");
+        } else {
+            self.toasts.error("Could not open a terminal tab");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, ProjectEntry};
+
+    #[test]
+    fn test_first_shell_tab_waits_once() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.add_project(ProjectEntry {
+            name: "p".into(),
+            path: "/nonexistent/opman-test/p".into(),
+            ..Default::default()
+        });
+        for _ in 0..3 {
+            app.await_env(
+                0,
+                AwaitingEnv::ShellTab {
+                    session_id: "s1".into(),
+                    new_tab: false,
+                },
+            );
+        }
+        app.await_env(
+            0,
+            AwaitingEnv::ShellTab {
+                session_id: "s1".into(),
+                new_tab: true,
+            },
+        );
+        assert_eq!(app.awaiting_env.len(), 2);
+
+        let path = app.projects[0].path.clone();
+        app.drop_awaiting_env(&path);
+        assert!(app.awaiting_env.is_empty());
+    }
+}
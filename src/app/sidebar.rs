@@ -1,13 +1,17 @@
 use crate::app::App;
 use crate::app::{SessionInfo, SidebarItem};
 
+/// Rows kept between the sidebar cursor and the viewport edge while
+/// scrolling, when the viewport is tall enough.
+const SIDEBAR_SCROLL_MARGIN: usize = 2;
+
 impl App {
     /// Get the sessions to display in the sidebar for a project (max 5 latest + pinned).
     /// Only returns parent sessions (parent_id is empty).
     /// Only returns sessions if this project is the one currently expanded.
     pub fn visible_sessions(&self, project_idx: usize) -> Vec<&SessionInfo> {
         // Only one project can have sessions expanded at a time
-        if self.sessions_expanded_for != Some(project_idx) {
+        if !self.sessions_expanded(project_idx) {
             return Vec::new();
         }
 
@@ -15,7 +19,7 @@ impl App {
             Some(p) => p,
             None => return Vec::new(),
         };
-        let pinned = self.pinned_sessions.get(&project_idx);
+        let pinned = self.pinned_sessions.get(&project.path);
         let mut visible: Vec<&SessionInfo> = Vec::new();
 
         // Always show pinned sessions first (only parent sessions)
@@ -63,9 +67,35 @@ impl App {
             .collect()
     }
 
+    /// Toggle the subagent list under a parent session (user action).
+    /// Collapsing is remembered so auto-expand doesn't immediately undo it.
+    pub fn toggle_subagents(&mut self, session_id: String) {
+        if self.subagents_expanded_for.as_deref() == Some(&session_id) {
+            self.subagents_expanded_for = None;
+            self.subagents_collapsed_at = Some((session_id, std::time::Instant::now()));
+        } else {
+            self.subagents_expanded_for = Some(session_id);
+        }
+    }
+
+    /// Expand a parent's subagents after it spawned one, if the setting is
+    /// on, the parent belongs to the active project, and the user hasn't
+    /// collapsed that parent within the last 30 seconds.
+    pub(crate) fn auto_expand_subagents(&mut self, project_idx: usize, parent_id: &str) {
+        if !self.config.settings.auto_expand_subagents || project_idx != self.active_project {
+            return;
+        }
+        if let Some((ref collapsed, at)) = self.subagents_collapsed_at {
+            if collapsed == parent_id && at.elapsed() < std::time::Duration::from_secs(30) {
+                return;
+            }
+        }
+        self.subagents_expanded_for = Some(parent_id.to_string());
+    }
+
     /// Whether a project has more parent sessions than what's visible.
     pub fn has_more_sessions(&self, project_idx: usize) -> bool {
-        if self.sessions_expanded_for != Some(project_idx) {
+        if !self.sessions_expanded(project_idx) {
             return false;
         }
         self.projects
@@ -74,17 +104,40 @@ impl App {
             .unwrap_or(false)
     }
 
+    /// Project indices in sidebar order: pinned projects first, each group
+    /// in the order the projects were added.
+    pub fn sidebar_project_order(&self) -> Vec<usize> {
+        let pinned = |i: &usize| self.config.projects.get(*i).is_some_and(|e| e.pinned);
+        let (mut order, rest): (Vec<usize>, Vec<usize>) =
+            (0..self.projects.len()).partition(pinned);
+        order.extend(rest);
+        order
+    }
+
+    /// Pin or unpin a project and keep the sidebar cursor on its row.
+    pub fn toggle_project_pin(&mut self, project_idx: usize) -> anyhow::Result<()> {
+        let Some(entry) = self.config.projects.get_mut(project_idx) else {
+            return Ok(());
+        };
+        entry.pinned = !entry.pinned;
+        self.config.save()?;
+        if let Some(flat) = self.sidebar_index_for_project(project_idx) {
+            self.sidebar_cursor = flat;
+        }
+        Ok(())
+    }
+
     /// Map a flat sidebar_selection index to the item it represents.
     pub fn sidebar_item_at(&self, selection: usize) -> Option<SidebarItem> {
         let mut idx = 0;
-        for (i, _project) in self.projects.iter().enumerate() {
+        for i in self.sidebar_project_order() {
             if idx == selection {
                 return Some(SidebarItem::Project(i));
             }
             idx += 1;
 
             // "New Session" item appears when sessions are expanded
-            if self.sessions_expanded_for == Some(i) {
+            if self.sessions_expanded(i) {
                 if idx == selection {
                     return Some(SidebarItem::NewSession(i));
                 }
@@ -126,9 +179,9 @@ impl App {
     /// Total number of items in the sidebar (for navigation bounds).
     pub fn sidebar_item_count(&self) -> usize {
         let mut count = 0;
-        for (i, _) in self.projects.iter().enumerate() {
+        for i in self.sidebar_project_order() {
             count += 1; // project
-            if self.sessions_expanded_for == Some(i) {
+            if self.sessions_expanded(i) {
                 count += 1; // "New Session"
             }
             let vis = self.visible_sessions(i);
@@ -146,14 +199,41 @@ impl App {
         count
     }
 
+    /// Compute the flat sidebar index of a project's own row.
+    fn sidebar_index_for_project(&self, project_idx: usize) -> Option<usize> {
+        let mut idx = 0;
+        for i in self.sidebar_project_order() {
+            if i == project_idx {
+                return Some(idx);
+            }
+            idx += 1; // project row
+
+            if self.sessions_expanded(i) {
+                idx += 1; // "New Session"
+            }
+
+            for session in &self.visible_sessions(i) {
+                idx += 1;
+                if self.subagents_expanded_for.as_deref() == Some(&session.id) {
+                    idx += self.subagent_sessions(i, &session.id).len();
+                }
+            }
+
+            if self.has_more_sessions(i) {
+                idx += 1;
+            }
+        }
+        None
+    }
+
     /// Compute the flat sidebar index for a given project + session ID.
     /// Returns `None` if the session is not currently visible in the sidebar.
     fn sidebar_index_for_session(&self, project_idx: usize, session_id: &str) -> Option<usize> {
         let mut idx = 0;
-        for (i, _) in self.projects.iter().enumerate() {
+        for i in self.sidebar_project_order() {
             idx += 1; // project row
 
-            if self.sessions_expanded_for == Some(i) {
+            if self.sessions_expanded(i) {
                 idx += 1; // "New Session"
             }
 
@@ -191,4 +271,61 @@ impl App {
             }
         }
     }
+
+    /// Scroll offset that keeps `sidebar_cursor` in view in a viewport of
+    /// `viewport` rows showing `total` rows, moving the previous offset only
+    /// when the cursor gets within [`SIDEBAR_SCROLL_MARGIN`] of an edge.
+    /// Stores the result in `sidebar_scroll_offset`.
+    pub fn update_sidebar_scroll(&self, viewport: usize, total: usize) -> usize {
+        let offset = sidebar_scroll_offset(
+            self.sidebar_scroll_offset.get(),
+            self.sidebar_cursor,
+            viewport,
+            total,
+        );
+        self.sidebar_scroll_offset.set(offset);
+        offset
+    }
+}
+
+fn sidebar_scroll_offset(offset: usize, cursor: usize, viewport: usize, total: usize) -> usize {
+    if viewport == 0 {
+        return 0;
+    }
+    let margin = SIDEBAR_SCROLL_MARGIN.min(viewport.saturating_sub(1) / 2);
+    let offset = if cursor < offset + margin {
+        cursor.saturating_sub(margin)
+    } else if cursor + margin >= offset + viewport {
+        cursor + margin + 1 - viewport
+    } else {
+        offset
+    };
+    offset.min(total.saturating_sub(viewport))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sidebar_scroll_follows_cursor_near_edges() {
+        // 10 visible rows out of 30.
+        assert_eq!(sidebar_scroll_offset(0, 0, 10, 30), 0);
+        assert_eq!(sidebar_scroll_offset(0, 7, 10, 30), 0);
+        assert_eq!(sidebar_scroll_offset(0, 8, 10, 30), 1);
+        assert_eq!(sidebar_scroll_offset(5, 9, 10, 30), 5);
+        assert_eq!(sidebar_scroll_offset(5, 6, 10, 30), 4);
+        assert_eq!(sidebar_scroll_offset(5, 0, 10, 30), 0);
+        // Never past the last full page.
+        assert_eq!(sidebar_scroll_offset(0, 29, 10, 30), 20);
+        assert_eq!(sidebar_scroll_offset(25, 29, 10, 30), 20);
+    }
+
+    #[test]
+    fn test_sidebar_scroll_small_viewports() {
+        assert_eq!(sidebar_scroll_offset(3, 4, 0, 30), 0);
+        assert_eq!(sidebar_scroll_offset(0, 4, 1, 30), 4);
+        // Everything fits: no scrolling.
+        assert_eq!(sidebar_scroll_offset(0, 5, 10, 6), 0);
+    }
 }
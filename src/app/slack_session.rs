@@ -23,7 +23,7 @@ impl App {
                     self.add_project(ProjectEntry {
                         name: "slack-triage".to_string(),
                         path: triage_canon.to_string_lossy().to_string(),
-                        terminal_command: None,
+                        ..Default::default()
                     });
                     let _ = self.config.save();
                 }
@@ -206,10 +206,8 @@ impl App {
         match result {
             Ok(auth) => {
                 info!("Slack OAuth completed successfully");
-                self.toast_message = Some((
-                    "Slack connected! Add app_token to slack_auth.yaml".to_string(),
-                    std::time::Instant::now(),
-                ));
+                self.toasts
+                    .success("Slack connected! Add app_token to slack_auth.yaml");
                 if let Some(ref state) = self.slack_state {
                     let st = state.clone();
                     tokio::spawn(async move {
@@ -223,10 +221,7 @@ impl App {
             }
             Err(e) => {
                 tracing::error!("Slack OAuth failed: {}", e);
-                self.toast_message = Some((
-                    format!("Slack OAuth failed: {}", e),
-                    std::time::Instant::now(),
-                ));
+                self.toasts.error(format!("Slack OAuth failed: {}", e));
                 if let Some(ref state) = self.slack_state {
                     let st = state.clone();
                     let err_msg = e.to_string();
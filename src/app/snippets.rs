@@ -0,0 +1,260 @@
+//! Per-project command snippets: a fuzzy picker that types the chosen
+//! snippet into a shell tab, and saving a tab's last command as a snippet.
+
+use nucleo::pattern::{AtomKind, CaseMatching, Normalization, Pattern};
+use nucleo::{Config, Matcher, Utf32Str};
+
+use crate::app::App;
+use crate::pty::CommandState;
+use crate::ui::layout_manager::PanelId;
+
+/// State for the snippet picker overlay.
+pub struct SnippetPickerState {
+    pub project_idx: usize,
+    /// The project's snippets when the picker opened.
+    pub snippets: Vec<String>,
+    pub query: String,
+    pub cursor_pos: usize,
+    pub selected: usize,
+    pub filtered: Vec<usize>,
+    matcher: Matcher,
+}
+
+impl SnippetPickerState {
+    pub fn new(project_idx: usize, snippets: Vec<String>) -> Self {
+        let mut state = Self {
+            project_idx,
+            snippets,
+            query: String::new(),
+            cursor_pos: 0,
+            selected: 0,
+            filtered: Vec::new(),
+            matcher: Matcher::new(Config::DEFAULT),
+        };
+        state.update_filter();
+        state
+    }
+
+    /// The snippet under the cursor.
+    pub fn selected_snippet(&self) -> Option<&str> {
+        self.snippets
+            .get(*self.filtered.get(self.selected)?)
+            .map(String::as_str)
+    }
+
+    /// Recompute filtered indices (best fuzzy match first) from the query.
+    pub fn update_filter(&mut self) {
+        if self.query.is_empty() {
+            self.filtered = (0..self.snippets.len()).collect();
+        } else {
+            let pattern = Pattern::new(
+                &self.query,
+                CaseMatching::Smart,
+                Normalization::Smart,
+                AtomKind::Fuzzy,
+            );
+            let mut buf = Vec::new();
+            let mut scored: Vec<(usize, u32)> = self
+                .snippets
+                .iter()
+                .enumerate()
+                .filter_map(|(i, s)| {
+                    let score = pattern.score(Utf32Str::new(s, &mut buf), &mut self.matcher)?;
+                    Some((i, score))
+                })
+                .collect();
+            scored.sort_by(|a, b| b.1.cmp(&a.1));
+            self.filtered = scored.into_iter().map(|(i, _)| i).collect();
+        }
+        if self.selected >= self.filtered.len() {
+            self.selected = self.filtered.len().saturating_sub(1);
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        } else if !self.filtered.is_empty() {
+            self.selected = self.filtered.len() - 1;
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if !self.filtered.is_empty() {
+            if self.selected < self.filtered.len() - 1 {
+                self.selected += 1;
+            } else {
+                self.selected = 0;
+            }
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.query.insert(self.cursor_pos, c);
+        self.cursor_pos += c.len_utf8();
+        self.update_filter();
+    }
+
+    pub fn backspace(&mut self) {
+        if crate::util::delete_char_before(&mut self.query, &mut self.cursor_pos) {
+            self.update_filter();
+        }
+    }
+
+    pub fn cursor_left(&mut self) {
+        self.cursor_pos = crate::util::prev_char_boundary(&self.query, self.cursor_pos);
+    }
+
+    pub fn cursor_right(&mut self) {
+        self.cursor_pos = crate::util::next_char_boundary(&self.query, self.cursor_pos);
+    }
+}
+
+impl App {
+    /// Open the snippet picker for the active project.
+    pub fn open_snippet_picker(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        if project.active_session.is_none() {
+            self.toasts.warning("No active session");
+            return;
+        }
+        let snippets = self.command_snippets(self.active_project).to_vec();
+        self.snippet_picker = Some(SnippetPickerState::new(self.active_project, snippets));
+    }
+
+    /// Type the selected snippet at the active shell tab's prompt (a new
+    /// tab if that one is busy) without running it, and focus the terminal.
+    pub fn insert_selected_snippet(&mut self) {
+        let Some(state) = self.snippet_picker.take() else {
+            return;
+        };
+        let Some(snippet) = state.selected_snippet().map(str::to_string) else {
+            return;
+        };
+        if state.project_idx != self.active_project {
+            return;
+        }
+        self.type_into_shell_tab(&snippet);
+    }
+
+    /// Write `text` to the active shell tab (a new tab if that one is
+    /// running a command) and focus the terminal.
+    pub(super) fn type_into_shell_tab(&mut self, text: &str) {
+        let busy = self.projects[self.active_project]
+            .active_resources()
+            .and_then(|r| r.active_shell_pty())
+            .map(|pty| {
+                pty.command_state
+                    .lock()
+                    .is_ok_and(|s| *s == CommandState::Running)
+            });
+        let opened = match busy {
+            Some(true) => self.add_shell_tab(),
+            Some(false) => true,
+            None => self.ensure_shell_pty(),
+        };
+        if !opened {
+            self.toast_no_shell_tab();
+            return;
+        }
+
+        let written = self.projects[self.active_project]
+            .active_resources_mut()
+            .and_then(|r| r.active_shell_pty_mut())
+            .map(|pty| pty.write(text.as_bytes()));
+        match written {
+            Some(Ok(())) => {}
+            Some(Err(e)) => {
+                self.toasts
+                    .error(format!("Failed to write to terminal: {e}"));
+                return;
+            }
+            None => {
+                self.toasts.error("Could not open a terminal tab");
+                return;
+            }
+        }
+
+        self.layout.set_visible(PanelId::IntegratedTerminal, true);
+        self.layout.focused = PanelId::IntegratedTerminal;
+        self.resize_all_ptys();
+    }
+
+    /// Remove the selected snippet from the project's config.
+    pub fn delete_selected_snippet(&mut self) {
+        let Some(state) = self.snippet_picker.as_mut() else {
+            return;
+        };
+        let Some(&idx) = state.filtered.get(state.selected) else {
+            return;
+        };
+        let removed = state.snippets.remove(idx);
+        state.update_filter();
+        if let Some(entry) = self.config.projects.get_mut(state.project_idx) {
+            entry.snippets.retain(|s| *s != removed);
+        }
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+    }
+
+    /// Save the last command run in the active shell tab as a snippet for
+    /// the active project.
+    pub fn save_last_command_as_snippet(&mut self) {
+        let command = self
+            .projects
+            .get(self.active_project)
+            .and_then(|p| p.active_resources())
+            .and_then(|r| r.active_shell_pty())
+            .and_then(|pty| pty.last_command.clone());
+        let Some(command) = command else {
+            self.toasts.warning("No command run in this terminal yet");
+            return;
+        };
+        let Some(entry) = self.config.projects.get_mut(self.active_project) else {
+            return;
+        };
+        let short = crate::util::truncate_str(&command, 40);
+        if entry.snippets.contains(&command) {
+            self.toasts.info(format!("Already a snippet: {short}"));
+            return;
+        }
+        entry.snippets.push(command.clone());
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+        self.toasts.success(format!("Saved snippet: {short}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snippet_picker_filters_fuzzily() {
+        let mut state = SnippetPickerState::new(
+            0,
+            vec![
+                "cargo test".into(),
+                "npm run deploy".into(),
+                "cargo clippy".into(),
+            ],
+        );
+        assert_eq!(state.filtered, [0, 1, 2]);
+
+        for c in "dpl".chars() {
+            state.insert_char(c);
+        }
+        assert_eq!(state.selected_snippet(), Some("npm run deploy"));
+
+        state.backspace();
+        state.backspace();
+        state.backspace();
+        state.insert_char('z');
+        assert!(state.filtered.is_empty());
+        assert_eq!(state.selected_snippet(), None);
+    }
+}
@@ -0,0 +1,125 @@
+//! Read-only summary of opman's state served by the `--status-port`
+//! endpoint, so scripts and status lines can read it without the TUI.
+
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use serde::Serialize;
+
+use crate::app::App;
+
+/// The latest [`StatusSnapshot`], shared with the status endpoint.
+pub type StatusHandle = Arc<RwLock<StatusSnapshot>>;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StatusSnapshot {
+    /// Name of the project shown in the TUI.
+    pub active_project: Option<String>,
+    pub projects: Vec<ProjectStatus>,
+    /// Sessions that are running (not idle), across all projects.
+    pub active_sessions: Vec<String>,
+    /// Cost in USD of every session seen since opman started.
+    pub total_cost: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectStatus {
+    pub name: String,
+    pub path: PathBuf,
+    pub active_session: Option<String>,
+    pub active_session_title: Option<String>,
+    /// How many of the project's sessions are running.
+    pub busy_sessions: usize,
+    pub git_branch: String,
+}
+
+impl StatusSnapshot {
+    pub fn from_app(app: &App) -> Self {
+        let projects = app
+            .projects
+            .iter()
+            .map(|p| {
+                let active = p.active_session.clone().filter(|sid| sid != "__new__");
+                let title = active.as_ref().and_then(|sid| {
+                    p.sessions
+                        .iter()
+                        .find(|s| &s.id == sid)
+                        .map(|s| s.title.clone())
+                });
+                ProjectStatus {
+                    name: p.name.clone(),
+                    path: p.path.clone(),
+                    active_session: active,
+                    active_session_title: title,
+                    busy_sessions: p
+                        .sessions
+                        .iter()
+                        .filter(|s| app.active_sessions.contains(&s.id))
+                        .count(),
+                    git_branch: p.git_branch.clone(),
+                }
+            })
+            .collect();
+        let mut active_sessions: Vec<String> = app.active_sessions.iter().cloned().collect();
+        active_sessions.sort();
+        Self {
+            active_project: app.active_project().map(|p| p.name.clone()),
+            projects,
+            active_sessions,
+            total_cost: app.session_stats.values().map(|s| s.cost).sum(),
+        }
+    }
+}
+
+impl App {
+    /// Refresh the snapshot served by the status endpoint, if it is on.
+    pub fn publish_status(&self) {
+        let Some(ref handle) = self.status_handle else {
+            return;
+        };
+        let snapshot = StatusSnapshot::from_app(self);
+        if let Ok(mut current) = handle.write() {
+            *current = snapshot;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{BackgroundEvent, SessionInfo};
+    use crate::config::{Config, ProjectEntry};
+
+    #[test]
+    fn test_snapshot_reports_projects_and_cost() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.add_project(ProjectEntry {
+            name: "web".to_string(),
+            path: "/nonexistent/opman-test/web".to_string(),
+            ..Default::default()
+        });
+        app.handle_background_event(BackgroundEvent::SessionsFetched {
+            project_idx: 0,
+            sessions: vec![SessionInfo {
+                id: "s-1".to_string(),
+                title: "Fix login".to_string(),
+                directory: "/nonexistent/opman-test/web".to_string(),
+                ..Default::default()
+            }],
+        });
+        app.projects[0].active_session = Some("s-1".to_string());
+        app.active_sessions.insert("s-1".to_string());
+        app.session_stats
+            .insert("s-1".to_string(), Default::default());
+        app.session_stats.get_mut("s-1").unwrap().cost = 0.75;
+
+        let snapshot = StatusSnapshot::from_app(&app);
+        assert_eq!(snapshot.active_project.as_deref(), Some("web"));
+        assert_eq!(snapshot.active_sessions, ["s-1"]);
+        assert_eq!(snapshot.total_cost, 0.75);
+        let project = &snapshot.projects[0];
+        assert_eq!(project.active_session_title.as_deref(), Some("Fix login"));
+        assert_eq!(project.busy_sessions, 1);
+    }
+}
@@ -0,0 +1,127 @@
+//! Per-project terminal command: a prompt that sets the project's
+//! `terminal_command` (prefilled from the active shell tab's program),
+//! saves it, and restarts the project's shell tabs with it.
+
+use crate::app::App;
+use crate::pty::{CommandState, PtyInstance};
+
+/// State for the terminal command prompt overlay.
+pub struct TerminalCommandState {
+    pub project_idx: usize,
+    pub input: String,
+    pub cursor_pos: usize,
+    /// Enter was pressed while tabs were busy; the next Enter restarts
+    /// them anyway.
+    pub confirm_busy: bool,
+}
+
+impl TerminalCommandState {
+    pub fn insert_char(&mut self, c: char) {
+        self.input.insert(self.cursor_pos, c);
+        self.cursor_pos += c.len_utf8();
+        self.confirm_busy = false;
+    }
+
+    pub fn backspace(&mut self) {
+        if crate::util::delete_char_before(&mut self.input, &mut self.cursor_pos) {
+            self.confirm_busy = false;
+        }
+    }
+
+    pub fn cursor_left(&mut self) {
+        self.cursor_pos = crate::util::prev_char_boundary(&self.input, self.cursor_pos);
+    }
+
+    pub fn cursor_right(&mut self) {
+        self.cursor_pos = crate::util::next_char_boundary(&self.input, self.cursor_pos);
+    }
+}
+
+impl App {
+    /// Open the terminal command prompt for the active project, prefilled
+    /// with the program in the active shell tab (or the saved command).
+    pub fn open_terminal_command_prompt(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        let input = project
+            .active_resources()
+            .and_then(|r| r.active_shell_pty())
+            .and_then(PtyInstance::foreground_program)
+            .or_else(|| {
+                self.config
+                    .projects
+                    .get(self.active_project)
+                    .and_then(|e| e.terminal_command.clone())
+            })
+            .unwrap_or_default();
+        self.terminal_command = Some(TerminalCommandState {
+            project_idx: self.active_project,
+            cursor_pos: input.len(),
+            input,
+            confirm_busy: false,
+        });
+    }
+
+    /// Shell tabs of a project (across all its sessions) with a command
+    /// still running.
+    pub fn busy_shell_tabs(&self, project_idx: usize) -> usize {
+        self.projects.get(project_idx).map_or(0, |p| {
+            p.session_resources
+                .values()
+                .flat_map(|r| &r.shell_ptys)
+                .filter(|pty| {
+                    pty.command_state
+                        .lock()
+                        .is_ok_and(|s| *s == CommandState::Running)
+                })
+                .count()
+        })
+    }
+
+    /// Validate and save the typed command, then restart the project's
+    /// shell tabs with it. An empty command goes back to the default.
+    /// Busy tabs need a second Enter.
+    pub fn submit_terminal_command_prompt(&mut self) {
+        let Some(state) = self.terminal_command.as_ref() else {
+            return;
+        };
+        let command = state.input.trim().to_string();
+        let project_idx = state.project_idx;
+        let confirmed = state.confirm_busy;
+        if !command.is_empty() && crate::util::which(&command).is_none() {
+            self.toasts
+                .error(format!("{command}: not found or not executable"));
+            return;
+        }
+        if !confirmed && self.busy_shell_tabs(project_idx) > 0 {
+            if let Some(state) = self.terminal_command.as_mut() {
+                state.confirm_busy = true;
+            }
+            return;
+        }
+        self.terminal_command = None;
+
+        let Some(entry) = self.config.projects.get_mut(project_idx) else {
+            return;
+        };
+        entry.terminal_command = (!command.is_empty()).then(|| command.clone());
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+
+        let restarted = self.respawn_shell_tabs(project_idx);
+        let shown = if command.is_empty() {
+            "default shell"
+        } else {
+            command.as_str()
+        };
+        let message = match restarted {
+            0 => format!("Terminal command: {shown}"),
+            1 => format!("Terminal command: {shown} (restarted 1 tab)"),
+            n => format!("Terminal command: {shown} (restarted {n} tabs)"),
+        };
+        self.toasts.success(message);
+        self.resize_all_ptys();
+    }
+}
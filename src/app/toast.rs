@@ -0,0 +1,182 @@
+//! Status-bar toasts, shown one after another.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Pending toasts beyond this are dropped, oldest first.
+const MAX_PENDING_TOASTS: usize = 8;
+
+/// How a toast is colored (and labelled for web clients).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastSeverity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    /// Level name used by the web UI's toasts.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ToastSeverity::Info => "info",
+            ToastSeverity::Success => "success",
+            ToastSeverity::Warning => "warning",
+            ToastSeverity::Error => "error",
+        }
+    }
+
+    /// How long a toast stays on screen given the configured base
+    /// duration: warnings linger 1.5× and errors 2× as long.
+    pub fn duration(self, base: Duration) -> Duration {
+        match self {
+            ToastSeverity::Info | ToastSeverity::Success => base,
+            ToastSeverity::Warning => base * 3 / 2,
+            ToastSeverity::Error => base * 2,
+        }
+    }
+}
+
+/// A status-bar notification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub text: String,
+    pub severity: ToastSeverity,
+    /// When the toast was queued; reset when it reaches the screen so every
+    /// toast is shown for its full duration.
+    pub created: Instant,
+}
+
+/// Queue of status-bar toasts. The front one is on screen; the rest wait
+/// their turn.
+#[derive(Debug, Default)]
+pub struct ToastQueue {
+    queue: VecDeque<Toast>,
+}
+
+impl ToastQueue {
+    /// Queue a message. Repeating the last queued toast is a no-op.
+    pub fn push(&mut self, severity: ToastSeverity, text: impl Into<String>) {
+        let text = text.into();
+        if self
+            .queue
+            .back()
+            .is_some_and(|t| t.text == text && t.severity == severity)
+        {
+            return;
+        }
+        if self.queue.len() > MAX_PENDING_TOASTS {
+            self.queue.remove(1);
+        }
+        self.queue.push_back(Toast {
+            text,
+            severity,
+            created: Instant::now(),
+        });
+    }
+
+    pub fn info(&mut self, text: impl Into<String>) {
+        self.push(ToastSeverity::Info, text);
+    }
+
+    pub fn success(&mut self, text: impl Into<String>) {
+        self.push(ToastSeverity::Success, text);
+    }
+
+    pub fn warning(&mut self, text: impl Into<String>) {
+        self.push(ToastSeverity::Warning, text);
+    }
+
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.push(ToastSeverity::Error, text);
+    }
+
+    /// The toast on screen.
+    pub fn current(&self) -> Option<&Toast> {
+        self.queue.front()
+    }
+
+    /// Number of toasts waiting behind the current one.
+    pub fn pending(&self) -> usize {
+        self.queue.len().saturating_sub(1)
+    }
+
+    /// Retire the current toast once it has been shown for its duration
+    /// (see [`ToastSeverity::duration`]) and show the next. Returns whether
+    /// the status bar needs a redraw.
+    pub fn advance(&mut self, base: Duration) -> bool {
+        let expired = self
+            .queue
+            .front()
+            .is_some_and(|t| t.created.elapsed() >= t.severity.duration(base));
+        if !expired {
+            return false;
+        }
+        self.queue.pop_front();
+        if let Some(next) = self.queue.front_mut() {
+            next.created = Instant::now();
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: Duration = Duration::from_secs(2);
+
+    fn expire_current(toasts: &mut ToastQueue) {
+        let toast = &mut toasts.queue[0];
+        toast.created = Instant::now() - toast.severity.duration(BASE);
+    }
+
+    #[test]
+    fn test_toasts_are_shown_in_order() {
+        let mut toasts = ToastQueue::default();
+        toasts.success("Copied!");
+        toasts.success("Copied!");
+        toasts.info("Model: gpt");
+        toasts.error("Failed to spawn external windows");
+        assert_eq!(toasts.current().unwrap().text, "Copied!");
+        assert_eq!(toasts.pending(), 2);
+
+        assert!(!toasts.advance(BASE));
+        expire_current(&mut toasts);
+        assert!(toasts.advance(BASE));
+        let toast = toasts.current().unwrap();
+        assert_eq!(toast.text, "Model: gpt");
+        assert_eq!(toast.severity, ToastSeverity::Info);
+        assert!(toast.created.elapsed() < BASE);
+
+        expire_current(&mut toasts);
+        toasts.advance(BASE);
+        expire_current(&mut toasts);
+        assert!(toasts.advance(BASE));
+        assert!(toasts.current().is_none());
+        assert!(!toasts.advance(BASE));
+    }
+
+    #[test]
+    fn test_errors_linger_longer() {
+        let mut toasts = ToastQueue::default();
+        toasts.error("Failed to open an external terminal");
+        toasts.queue[0].created = Instant::now() - BASE;
+        assert!(!toasts.advance(BASE));
+        assert_eq!(
+            ToastSeverity::Warning.duration(BASE),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[test]
+    fn test_pending_toasts_are_capped() {
+        let mut toasts = ToastQueue::default();
+        for i in 0..20 {
+            toasts.info(format!("toast {i}"));
+        }
+        assert_eq!(toasts.pending(), MAX_PENDING_TOASTS);
+        assert_eq!(toasts.current().unwrap().text, "toast 0");
+        assert_eq!(toasts.queue.back().unwrap().text, "toast 19");
+    }
+}
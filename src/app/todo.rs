@@ -0,0 +1,105 @@
+//! Todo panel actions that reach outside the panel.
+
+use crate::app::{App, ContextInputState, TodoItem};
+use crate::ui::layout_manager::PanelId;
+
+impl App {
+    /// Open the first `file[:line]` reference in the selected todo in the
+    /// session's neovim, then close the panel and focus the neovim pane.
+    pub fn reveal_selected_todo(&mut self) {
+        let Some(state) = self.todo_panel.as_ref() else {
+            return;
+        };
+        let Some(todo) = state.todos.get(state.selected) else {
+            return;
+        };
+        let Some((file, line)) = super::helpers::file_refs(&todo.content).next() else {
+            self.toasts.warning("No file reference in this todo");
+            return;
+        };
+
+        let session_id = state.session_id.clone();
+        let target = self.projects.iter().find_map(|p| {
+            let nvim = p.session_resources.get(&session_id)?.neovim_pty.as_ref()?;
+            Some((p.path.join(&file), nvim.nvim_listen_addr.clone()?))
+        });
+        let Some((path, nvim_socket)) = target else {
+            self.toasts.warning("No neovim running for this session");
+            return;
+        };
+
+        crate::background_tasks::spawn_nvim_open_file(&self.bg_tx, nvim_socket, path, line);
+        self.close_todo_panel();
+        if !self.layout.is_visible(PanelId::NeovimPane) {
+            self.layout.set_visible(PanelId::NeovimPane, true);
+            self.resize_all_ptys();
+        }
+        self.layout.focused = PanelId::NeovimPane;
+    }
+
+    /// Open the context input with the todo continuation message, to be
+    /// sent as-is to `session_id` on submit or dropped on cancel.
+    pub(super) fn open_todo_continuation_input(&mut self, session_id: String, msg: &str) {
+        let mut state = ContextInputState::new();
+        state.lines = msg.lines().map(str::to_string).collect();
+        if state.lines.is_empty() {
+            state.lines.push(String::new());
+        }
+        state.cursor_row = state.lines.len() - 1;
+        state.cursor_col = state.lines[state.cursor_row].len();
+        state
+            .targets
+            .push((session_id, "todo continuation".to_string()));
+        state.raw = true;
+        self.context_input = Some(state);
+    }
+}
+
+/// Fill the continuation `template`'s `{todos}` placeholder with one line
+/// per todo: `- [status] (priority) content`.
+pub(super) fn continuation_message(template: &str, todos: &[TodoItem]) -> String {
+    if !template.contains("{todos}") {
+        return template.to_string();
+    }
+    let summary = todos
+        .iter()
+        .map(|t| format!("- [{}] ({}) {}", t.status, t.priority, t.content))
+        .collect::<Vec<_>>()
+        .join("\n");
+    template.replace("{todos}", &summary)
+}
+
+/// The priority after `priority` in the low → medium → high cycle.
+pub fn next_priority(priority: &str) -> &'static str {
+    match priority {
+        "low" => "medium",
+        "medium" => "high",
+        _ => "low",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_continuation_message_fills_placeholder() {
+        let todos = vec![
+            TodoItem {
+                content: "Fix parser".into(),
+                status: "completed".into(),
+                priority: "high".into(),
+            },
+            TodoItem {
+                content: "Add docs".into(),
+                status: "pending".into(),
+                priority: "low".into(),
+            },
+        ];
+        assert_eq!(
+            continuation_message("Prioritize high items.\n{todos}", &todos),
+            "Prioritize high items.\n- [completed] (high) Fix parser\n- [pending] (low) Add docs"
+        );
+        assert_eq!(continuation_message("Keep going.", &todos), "Keep going.");
+    }
+}
@@ -0,0 +1,102 @@
+//! The tool call each session is running right now, for the status bar.
+
+use std::path::Path;
+
+use crate::app::App;
+
+impl App {
+    /// Handle `BackgroundEvent::SseToolStarted`: remember the call as the
+    /// session's current activity.
+    pub(crate) fn handle_sse_tool_started(
+        &mut self,
+        project_idx: usize,
+        session_id: String,
+        call_id: String,
+        tool: &str,
+        input: &serde_json::Value,
+    ) {
+        let root = self.projects.get(project_idx).map(|p| p.path.as_path());
+        let label = activity_label(tool, input, root);
+        self.tool_activity.insert(session_id, (call_id, label));
+        self.needs_redraw = true;
+    }
+
+    /// Handle `BackgroundEvent::SseToolFinished`: clear the session's
+    /// activity if it still shows that call.
+    pub(crate) fn handle_sse_tool_finished(&mut self, session_id: &str, call_id: &str) {
+        if self
+            .tool_activity
+            .get(session_id)
+            .is_some_and(|(current, _)| current == call_id)
+        {
+            self.tool_activity.remove(session_id);
+            self.needs_redraw = true;
+        }
+    }
+}
+
+/// Short description of a tool call, e.g. `running: cargo test` or
+/// `editing: src/app.rs`. Paths under `root` are shown relative to it.
+pub(crate) fn activity_label(tool: &str, input: &serde_json::Value, root: Option<&Path>) -> String {
+    let field = |key: &str| input.get(key).and_then(|v| v.as_str()).unwrap_or("");
+    let path = |key: &str| {
+        let p = field(key);
+        root.and_then(|r| Path::new(p).strip_prefix(r).ok())
+            .map(|rel| rel.to_string_lossy().to_string())
+            .unwrap_or_else(|| p.to_string())
+    };
+    let (verb, detail) = match tool {
+        "bash" => (
+            "running",
+            field("command").lines().next().unwrap_or("").to_string(),
+        ),
+        "edit" | "write" | "patch" | "multiedit" => ("editing", path("filePath")),
+        "read" => ("reading", path("filePath")),
+        "list" => ("listing", path("path")),
+        "grep" | "glob" => ("searching", field("pattern").to_string()),
+        "webfetch" => ("fetching", field("url").to_string()),
+        "task" => ("delegating", field("description").to_string()),
+        _ => ("running", String::new()),
+    };
+    if detail.is_empty() {
+        format!("running: {tool}")
+    } else {
+        format!("{verb}: {detail}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_activity_label() {
+        let root = Path::new("/work/proj");
+        assert_eq!(
+            activity_label(
+                "edit",
+                &json!({"filePath": "/work/proj/src/app.rs"}),
+                Some(root)
+            ),
+            "editing: src/app.rs"
+        );
+        assert_eq!(
+            activity_label(
+                "bash",
+                &json!({"command": "cargo test\necho done"}),
+                Some(root)
+            ),
+            "running: cargo test"
+        );
+        assert_eq!(
+            activity_label("grep", &json!({"pattern": "fn main"}), None),
+            "searching: fn main"
+        );
+        assert_eq!(
+            activity_label("todowrite", &json!({}), None),
+            "running: todowrite"
+        );
+        assert_eq!(activity_label("read", &json!({}), None), "running: read");
+    }
+}
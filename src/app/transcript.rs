@@ -0,0 +1,224 @@
+//! Export a session's message history as a Markdown transcript.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local};
+use serde_json::Value;
+
+use crate::app::App;
+
+/// Tool output longer than this (in chars) is cut in the transcript.
+const MAX_TOOL_OUTPUT_CHARS: usize = 4000;
+
+impl App {
+    /// Export the active session's transcript in the background; the
+    /// written path (or the failure) is reported as a toast.
+    pub fn export_transcript(&mut self) {
+        let Some(project) = self.projects.get(self.active_project) else {
+            return;
+        };
+        let Some(session_id) = project.active_session.clone() else {
+            self.toasts.warning("No active session");
+            self.needs_redraw = true;
+            return;
+        };
+        let title = project
+            .sessions
+            .iter()
+            .find(|s| s.id == session_id)
+            .map(|s| s.title.clone())
+            .unwrap_or_default();
+        crate::background_tasks::spawn_transcript_export(
+            &self.bg_tx,
+            project.path.to_string_lossy().to_string(),
+            session_id,
+            title,
+        );
+    }
+}
+
+/// Directory transcripts are written to: `~/.local/share/opman/transcripts`
+/// (or the platform's data directory).
+pub fn transcript_dir() -> Result<PathBuf> {
+    Ok(dirs::data_dir()
+        .context("Could not determine data directory")?
+        .join("opman")
+        .join("transcripts"))
+}
+
+/// File name for a session's transcript: the slugified title (if any)
+/// followed by the session ID.
+pub fn transcript_file_name(title: &str, session_id: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.chars().count() >= 50 {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        format!("{session_id}.md")
+    } else {
+        format!("{slug}-{session_id}.md")
+    }
+}
+
+/// Render a session's messages (the raw `{ info, parts }` items from
+/// `GET /session/{id}/message`) as Markdown: prompts, responses and tool
+/// calls, in order.
+pub fn transcript_markdown(title: &str, session_id: &str, messages: &[Value]) -> String {
+    let heading = if title.is_empty() { session_id } else { title };
+    let mut out = format!(
+        "# {heading}\n\nSession `{session_id}` · exported {}\n",
+        Local::now().format("%Y-%m-%d %H:%M")
+    );
+
+    for message in messages {
+        let info = message.get("info").unwrap_or(message);
+        let role = match info.get("role").and_then(|r| r.as_str()) {
+            Some("user") => "User",
+            Some("assistant") => "Assistant",
+            _ => continue,
+        };
+        let parts = message
+            .get("parts")
+            .and_then(|p| p.as_array())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let mut body = String::new();
+        for part in parts {
+            match part.get("type").and_then(|t| t.as_str()).unwrap_or("") {
+                "text" | "" => {
+                    let synthetic = part.get("synthetic").and_then(|s| s.as_bool()) == Some(true);
+                    let text = part.get("text").and_then(|t| t.as_str()).unwrap_or("");
+                    if !synthetic && !text.trim().is_empty() {
+                        body.push_str(text.trim());
+                        body.push_str("\n\n");
+                    }
+                }
+                "tool" => body.push_str(&tool_markdown(part)),
+                "file" => {
+                    let name = part
+                        .get("filename")
+                        .or_else(|| part.get("url"))
+                        .and_then(|f| f.as_str())
+                        .unwrap_or("file");
+                    body.push_str(&format!("*Attached: {name}*\n\n"));
+                }
+                _ => {}
+            }
+        }
+        if body.is_empty() {
+            continue;
+        }
+
+        let time = info
+            .pointer("/time/created")
+            .and_then(|t| t.as_i64())
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|t| format!(" · {}", t.with_timezone(&Local).format("%H:%M:%S")))
+            .unwrap_or_default();
+        let model = info
+            .get("modelID")
+            .and_then(|m| m.as_str())
+            .filter(|_| role == "Assistant")
+            .map(|m| format!(" ({m})"))
+            .unwrap_or_default();
+        out.push_str(&format!("\n## {role}{model}{time}\n\n"));
+        out.push_str(body.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// A tool part as a small heading with its input and (truncated) output.
+fn tool_markdown(part: &Value) -> String {
+    let tool = part.get("tool").and_then(|t| t.as_str()).unwrap_or("tool");
+    let state = part.get("state").unwrap_or(&Value::Null);
+    let status = state
+        .get("status")
+        .and_then(|s| s.as_str())
+        .unwrap_or("unknown");
+
+    let mut out = format!("**Tool `{tool}`** ({status})\n\n");
+    if let Some(input) = state.get("input").filter(|i| !i.is_null()) {
+        let input = serde_json::to_string_pretty(input).unwrap_or_default();
+        out.push_str(&fenced(&input, "json"));
+    }
+    let full = state
+        .get("output")
+        .or_else(|| state.get("error"))
+        .and_then(|o| o.as_str())
+        .unwrap_or("");
+    if !full.trim().is_empty() {
+        let mut output: String = full.chars().take(MAX_TOOL_OUTPUT_CHARS).collect();
+        if output.len() < full.len() {
+            output.push_str("\n… (truncated)");
+        }
+        out.push_str(&fenced(output.trim_end(), ""));
+    }
+    out
+}
+
+/// Wrap `content` in a code fence longer than any backtick run inside it.
+fn fenced(content: &str, lang: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in content.chars() {
+        run = if c == '`' { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    let fence = "`".repeat((longest + 1).max(3));
+    format!("{fence}{lang}\n{content}\n{fence}\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_transcript_file_name() {
+        assert_eq!(
+            transcript_file_name("Fix the build!", "ses_1"),
+            "fix-the-build-ses_1.md"
+        );
+        assert_eq!(transcript_file_name("", "ses_1"), "ses_1.md");
+    }
+
+    #[test]
+    fn test_transcript_markdown() {
+        let messages = vec![
+            json!({
+                "info": {"role": "user"},
+                "parts": [{"type": "text", "text": "Run the tests"}]
+            }),
+            json!({
+                "info": {"role": "assistant", "modelID": "claude-sonnet-4"},
+                "parts": [
+                    {"type": "step-start"},
+                    {"type": "tool", "tool": "bash", "state": {
+                        "status": "completed",
+                        "input": {"command": "cargo test"},
+                        "output": "ok ```"
+                    }},
+                    {"type": "text", "text": "All green."}
+                ]
+            }),
+        ];
+        let md = transcript_markdown("Tests", "ses_1", &messages);
+        assert!(md.starts_with("# Tests\n"));
+        assert!(md.contains("\n## User\n\nRun the tests\n"));
+        assert!(md.contains("\n## Assistant (claude-sonnet-4)\n\n**Tool `bash`** (completed)"));
+        assert!(md.contains("\"command\": \"cargo test\""));
+        assert!(md.contains("````\nok ```\n````"));
+        assert!(md.trim_end().ends_with("All green."));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::pty::PtyInstance;
 use crate::ui::layout_manager::PanelId;
+use ratatui::layout::Rect;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -54,6 +55,19 @@ pub struct Project {
     pub sessions: Vec<SessionInfo>,
     /// Git branch name (best-effort, may be empty).
     pub git_branch: String,
+    /// Uncommitted changes for the git panel header (`None` = unknown or
+    /// not a git repository).
+    pub diff_stat: Option<crate::app::DiffStat>,
+    /// The repo may have changed since `diff_stat` was read.
+    pub diff_stat_stale: bool,
+    /// When each session was last the active session. Drives LRU eviction
+    /// of `ptys` and idle reaping of `session_resources`.
+    pub session_last_active: HashMap<String, std::time::Instant>,
+    /// Default `provider/model` reported by the server (best-effort).
+    pub model: Option<String>,
+    /// `provider/model` chosen in the model picker. Sent with the prompts
+    /// opman sends; the project's opencode config is left alone.
+    pub selected_model: Option<String>,
 }
 
 impl Project {
@@ -120,6 +134,8 @@ pub struct SessionResources {
     pub shell_ptys: Vec<PtyInstance>,
     /// Index of the active shell tab.
     pub active_shell_tab: usize,
+    /// Tab shown beside the active one when the terminal is split.
+    pub split_shell_tab: Option<usize>,
     /// PTY running neovim for this session.
     pub neovim_pty: Option<PtyInstance>,
     /// Snapshot of file contents *before* the latest edit.
@@ -133,6 +149,7 @@ impl SessionResources {
         Self {
             shell_ptys: Vec::new(),
             active_shell_tab: 0,
+            split_shell_tab: None,
             neovim_pty: None,
             file_snapshots: HashMap::new(),
         }
@@ -145,7 +162,82 @@ impl SessionResources {
     pub fn active_shell_pty_mut(&mut self) -> Option<&mut PtyInstance> {
         self.shell_ptys.get_mut(self.active_shell_tab)
     }
+
+    /// The tab shown beside the active one, if the terminal is split.
+    pub fn shell_split(&self) -> Option<usize> {
+        self.split_shell_tab
+            .filter(|&i| i != self.active_shell_tab && i < self.shell_ptys.len())
+    }
+
+    /// Make `idx` the active tab. Selecting the split tab swaps the two
+    /// panes' focus rather than closing the split.
+    pub fn select_shell_tab(&mut self, idx: usize) {
+        if idx >= self.shell_ptys.len() {
+            return;
+        }
+        if self.shell_split() == Some(idx) {
+            self.split_shell_tab = Some(self.active_shell_tab);
+        }
+        self.active_shell_tab = idx;
+    }
+
+    /// Remove a shell tab, keeping the active and split tabs pointing at
+    /// the same shells. Closing one pane of a split leaves the other pane
+    /// on its own; closing the active tab otherwise activates its neighbour.
+    pub fn remove_shell_tab(&mut self, idx: usize) -> PtyInstance {
+        let split = self.shell_split();
+        let pty = self.shell_ptys.remove(idx);
+        let last = self.shell_ptys.len().saturating_sub(1);
+        let active = if self.active_shell_tab == idx {
+            split
+                .and_then(|s| index_after_removal(s, idx))
+                .unwrap_or(idx)
+        } else {
+            index_after_removal(self.active_shell_tab, idx).unwrap_or(0)
+        };
+        self.active_shell_tab = active.min(last);
+        self.split_shell_tab = split
+            .and_then(|s| index_after_removal(s, idx))
+            .filter(|&s| s != self.active_shell_tab);
+        pty
+    }
+
+    /// Where each visible shell tab is drawn within the terminal's content
+    /// area (below the tab bar).
+    pub fn shell_panes(&self, area: Rect) -> Vec<(usize, Rect)> {
+        shell_pane_layout(self.active_shell_tab, self.shell_split(), area)
+    }
+}
+
+/// Lay out the active shell tab alone, or side by side with `split`
+/// (lower tab index on the left) with a one-column divider between them.
+pub fn shell_pane_layout(active: usize, split: Option<usize>, area: Rect) -> Vec<(usize, Rect)> {
+    let Some(split) = split.filter(|_| area.width >= 3) else {
+        return vec![(active, area)];
+    };
+    let left_width = (area.width - 1) / 2;
+    let left = Rect {
+        width: left_width,
+        ..area
+    };
+    let right = Rect {
+        x: area.x + left_width + 1,
+        width: area.width - left_width - 1,
+        ..area
+    };
+    vec![(active.min(split), left), (active.max(split), right)]
+}
+
+/// Index of an element at `idx` after the element at `removed` is removed
+/// from its list; `None` if it was the removed one.
+fn index_after_removal(idx: usize, removed: usize) -> Option<usize> {
+    match idx.cmp(&removed) {
+        std::cmp::Ordering::Less => Some(idx),
+        std::cmp::Ordering::Equal => None,
+        std::cmp::Ordering::Greater => Some(idx - 1),
+    }
 }
+
 /// Per-session token/cost statistics, updated via SSE `message.updated` events.
 #[derive(Debug, Clone, Default)]
 pub struct SessionStats {
@@ -447,14 +539,56 @@ impl RoutinePanelState {
 }
 
 /// State for terminal text search overlay.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TerminalSearchState {
     /// The search query string.
     pub query: String,
     /// Cursor position within the query string.
     pub cursor: usize,
-    /// All match positions as (row, col, length) tuples.
+    /// All match positions as (line, col, length) tuples; lines count
+    /// from the oldest scrollback line.
     pub matches: Vec<(usize, usize, usize)>,
     /// Index of the currently highlighted match.
     pub current_match: usize,
+    /// Scrollback lines above the screen when the matches were found, for
+    /// mapping match lines to rows of the view.
+    pub scrollback_len: usize,
+    /// Match case exactly instead of ignoring it.
+    pub case_sensitive: bool,
+    /// Treat the query as a regular expression.
+    pub regex: bool,
+    /// The query is a regex that doesn't compile, so it's matched literally.
+    pub invalid_regex: bool,
+    /// Buffer lines the matches were found in, reused while
+    /// the PTY's output and size are unchanged.
+    pub lines: Vec<String>,
+    /// (last output time, scrollback length, screen size) of the PTY when
+    /// `lines` was read.
+    pub lines_stamp: Option<(u64, usize, (u16, u16))>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_pane_layout() {
+        let area = Rect::new(10, 5, 81, 20);
+        assert_eq!(shell_pane_layout(2, None, area), [(2, area)]);
+
+        let panes = shell_pane_layout(3, Some(1), area);
+        assert_eq!(panes[0], (1, Rect::new(10, 5, 40, 20)));
+        assert_eq!(panes[1], (3, Rect::new(51, 5, 40, 20)));
+
+        // Too narrow to split.
+        let narrow = Rect::new(0, 0, 2, 5);
+        assert_eq!(shell_pane_layout(0, Some(1), narrow), [(0, narrow)]);
+    }
+
+    #[test]
+    fn test_index_after_removal() {
+        assert_eq!(index_after_removal(1, 2), Some(1));
+        assert_eq!(index_after_removal(2, 2), None);
+        assert_eq!(index_after_removal(3, 2), Some(2));
+    }
 }
@@ -34,11 +34,11 @@ impl App {
         };
         let api = crate::api::ApiClient::new();
         let base_url = crate::app::base_url().to_string();
-        let project_dir = self
-            .projects
-            .get(watcher.project_idx)
+        let project = self.projects.get(watcher.project_idx);
+        let project_dir = project
             .map(|p| p.path.display().to_string())
             .unwrap_or_default();
+        let model = project.and_then(|p| p.selected_model.clone());
         let sid = session_id.to_string();
         tracing::info!(
             session_id = %sid,
@@ -59,7 +59,13 @@ impl App {
             }
             full_msg.push_str(&msg);
             if let Err(e) = api
-                .send_system_message_async(&base_url, &project_dir, &sid, &full_msg)
+                .send_system_message_async(
+                    &base_url,
+                    &project_dir,
+                    &sid,
+                    &full_msg,
+                    model.as_deref(),
+                )
                 .await
             {
                 tracing::warn!("Watcher failed to send message to {}: {}", sid, e);
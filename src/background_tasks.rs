@@ -1,20 +1,24 @@
 use std::path::PathBuf;
 
+use anyhow::Context;
 use tokio::sync::mpsc;
 
 use crate::api;
-use crate::app::{self, BackgroundEvent};
+use crate::app::{self, BackgroundEvent, ToastSeverity};
 use crate::pty;
 
 /// Spawn a background task to activate a project (PTY spawn).
-/// Sends BackgroundEvent::PtySpawned on success.
+/// Sends BackgroundEvent::PtySpawned on success, a toast on failure.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn spawn_activate_project(
     bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
     project_idx: usize,
     project_path: PathBuf,
     terminal_rows: u16,
     terminal_cols: u16,
+    scrollback: usize,
     theme_envs: Vec<(String, String)>,
+    opencode_command: Option<String>,
 ) {
     let tx = bg_tx.clone();
     let base_url = crate::app::base_url().to_string();
@@ -23,9 +27,11 @@ pub(crate) fn spawn_activate_project(
             &base_url,
             terminal_rows,
             terminal_cols,
+            scrollback,
             &project_path,
             None,
             &theme_envs,
+            opencode_command.as_deref(),
         ) {
             Ok(pty) => {
                 let _ = tx.send(BackgroundEvent::PtySpawned {
@@ -36,7 +42,11 @@ pub(crate) fn spawn_activate_project(
                 let _ = tx.send(BackgroundEvent::ProjectActivated { project_idx });
             }
             Err(e) => {
-                tracing::warn!(project_idx, "Background PTY spawn failed: {}", e);
+                tracing::warn!(project_idx, "Background PTY spawn failed: {:#}", e);
+                let _ = tx.send(BackgroundEvent::Toast {
+                    message: format!("{e:#}"),
+                    severity: ToastSeverity::Error,
+                });
             }
         }
     });
@@ -120,7 +130,193 @@ pub(crate) fn spawn_single_session_fetch(
     });
 }
 
+/// Poll a session's neovim for LSP progress on a blocking thread.
+/// Sends BackgroundEvent::NvimLspProgress with the result (errors read as idle).
+pub(crate) fn spawn_lsp_progress_poll(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    session_id: String,
+    nvim_socket: PathBuf,
+) {
+    let tx = bg_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let status = crate::nvim_rpc::nvim_lsp_progress(&nvim_socket).unwrap_or(None);
+        let _ = tx.send(BackgroundEvent::NvimLspProgress { session_id, status });
+    });
+}
+
+/// Ask an idle session's neovim whether it has unsaved buffers on a
+/// blocking thread (`BackgroundEvent::NvimReapChecked`). Errors count as
+/// unsaved, so a neovim that can't answer is kept.
+pub(crate) fn spawn_nvim_reap_check(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    session_id: String,
+    nvim_socket: PathBuf,
+) {
+    let tx = bg_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let modified = crate::nvim_rpc::nvim_has_modified_buffers(&nvim_socket).unwrap_or(true);
+        let _ = tx.send(BackgroundEvent::NvimReapChecked {
+            session_id,
+            modified,
+        });
+    });
+}
+
+/// Export a project's `.envrc` through direnv on a blocking thread, for
+/// shells waiting on it (`BackgroundEvent::DirenvResolved`).
+pub(crate) fn spawn_direnv_resolve(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    project_path: PathBuf,
+) {
+    let tx = bg_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        crate::pty::resolve_direnv(&project_path);
+        let _ = tx.send(BackgroundEvent::DirenvResolved { project_path });
+    });
+}
+
+/// Read a project's diff-stat for the git panel header on a blocking
+/// thread (`BackgroundEvent::DiffStatFetched`).
+pub(crate) fn spawn_diff_stat_fetch(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    project_idx: usize,
+    project_path: PathBuf,
+) {
+    let tx = bg_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let stat = app::read_diff_stat(&project_path);
+        let _ = tx.send(BackgroundEvent::DiffStatFetched { project_idx, stat });
+    });
+}
+
+/// Find a project's git toplevel on a blocking thread
+/// (`BackgroundEvent::GitRootFetched`).
+pub(crate) fn spawn_git_root_fetch(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    project_idx: usize,
+    project_path: PathBuf,
+) {
+    let tx = bg_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let root = app::read_git_root(&project_path);
+        let _ = tx.send(BackgroundEvent::GitRootFetched { project_idx, root });
+    });
+}
+
+/// Blame the line under the cursor in a session's neovim and report it as
+/// a toast (`BackgroundEvent::LineBlame`).
+pub(crate) fn spawn_blame_current_line(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    nvim_socket: PathBuf,
+) {
+    let tx = bg_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        let message = blame_current_line(&nvim_socket).unwrap_or_else(|e| format!("Blame: {e}"));
+        let _ = tx.send(BackgroundEvent::LineBlame { message });
+    });
+}
+
+/// Open a file (at a line) in a session's neovim on a blocking thread.
+/// Waits briefly for the RPC socket so a neovim started just before can
+/// be used. Failures are reported as a toast (`BackgroundEvent::Toast`).
+pub(crate) fn spawn_nvim_open_file(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    nvim_socket: PathBuf,
+    file: PathBuf,
+    line: Option<i64>,
+) {
+    let tx = bg_tx.clone();
+    tokio::task::spawn_blocking(move || {
+        for _ in 0..20 {
+            if nvim_socket.exists() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        let file_str = file.to_string_lossy();
+        if let Err(e) = crate::nvim_rpc::nvim_open_file(&nvim_socket, &file_str, line) {
+            let _ = tx.send(BackgroundEvent::Toast {
+                message: format!("Failed to open {file_str}: {e}"),
+                severity: ToastSeverity::Error,
+            });
+        }
+    });
+}
+
+fn blame_current_line(nvim_socket: &std::path::Path) -> anyhow::Result<String> {
+    let file = crate::nvim_rpc::nvim_buf_get_name(nvim_socket, 0)?;
+    if file.is_empty() {
+        anyhow::bail!("current buffer has no file");
+    }
+    let (line, _) = crate::nvim_rpc::nvim_cursor_pos(nvim_socket)?;
+    let contents = crate::nvim_rpc::nvim_buf_get_lines(nvim_socket, 0, 0, -1)?.join("\n") + "\n";
+    blame_line(std::path::Path::new(&file), line, &contents)
+}
+
+/// Blame `line` of `file` as it reads in `contents` (the editor's buffer,
+/// unsaved edits included), so the line number matches what the user
+/// sees rather than the file on disk.
+fn blame_line(file: &std::path::Path, line: i64, contents: &str) -> anyhow::Result<String> {
+    use std::io::Write;
+
+    let dir = file.parent().unwrap_or(std::path::Path::new("."));
+    let mut child = std::process::Command::new("git")
+        .arg("blame")
+        .arg("--porcelain")
+        .arg("--contents")
+        .arg("-")
+        .arg("-L")
+        .arg(format!("{line},{line}"))
+        .arg("--")
+        .arg(file)
+        .current_dir(dir)
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(contents.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let err = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("{}", err.lines().next().unwrap_or("git blame failed"));
+    }
+    let porcelain = String::from_utf8_lossy(&output.stdout);
+    let summary = blame_summary(&porcelain).unwrap_or_else(|| "no blame output".into());
+    Ok(format!("L{line} {summary}"))
+}
+
+/// Condense `git blame --porcelain` output for one line into
+/// `"abc1234 Author, 2024-01-31: summary"`.
+fn blame_summary(porcelain: &str) -> Option<String> {
+    let mut lines = porcelain.lines();
+    let hash = lines.next()?.split_whitespace().next()?;
+    if hash.chars().all(|c| c == '0') {
+        return Some("not committed yet".into());
+    }
+    let (mut author, mut time, mut summary) = ("", None, "");
+    for l in lines {
+        if let Some(v) = l.strip_prefix("author ") {
+            author = v;
+        } else if let Some(v) = l.strip_prefix("author-time ") {
+            time = v.parse::<i64>().ok();
+        } else if let Some(v) = l.strip_prefix("summary ") {
+            summary = v;
+        }
+    }
+    let date = time
+        .and_then(|t| chrono::DateTime::from_timestamp(t, 0))
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_default();
+    Some(format!(
+        "{} {author}, {date}: {summary}",
+        &hash[..hash.len().min(7)]
+    ))
+}
+
 /// Spawn a background task to select a session via the API, then respawn PTY.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn spawn_session_select(
     bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
     project_idx: usize,
@@ -129,7 +325,9 @@ pub(crate) fn spawn_session_select(
     project_path: PathBuf,
     terminal_rows: u16,
     terminal_cols: u16,
+    scrollback: usize,
     theme_envs: Vec<(String, String)>,
+    opencode_command: Option<String>,
 ) {
     let tx = bg_tx.clone();
     let base_url = crate::app::base_url().to_string();
@@ -154,9 +352,11 @@ pub(crate) fn spawn_session_select(
                 &url,
                 terminal_rows,
                 terminal_cols,
+                scrollback,
                 &path,
                 Some(&sid_for_pty),
                 &theme_envs,
+                opencode_command.as_deref(),
             ) {
                 Ok(pty) => {
                     let _ = tx2.send(BackgroundEvent::PtySpawned {
@@ -168,9 +368,13 @@ pub(crate) fn spawn_session_select(
                 Err(e) => {
                     tracing::warn!(
                         project_idx,
-                        "PTY respawn after session select failed: {}",
+                        "PTY respawn after session select failed: {:#}",
                         e
                     );
+                    let _ = tx2.send(BackgroundEvent::Toast {
+                        message: format!("{e:#}"),
+                        severity: ToastSeverity::Error,
+                    });
                 }
             }
         })
@@ -178,3 +382,279 @@ pub(crate) fn spawn_session_select(
         .ok();
     });
 }
+
+/// Fetch the models available to a project for the model picker.
+/// Sends `ModelsFetched`, or a toast on failure.
+pub(crate) fn spawn_model_list_fetch(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    project_idx: usize,
+    project_dir: String,
+) {
+    let tx = bg_tx.clone();
+    let base_url = crate::app::base_url().to_string();
+    tokio::spawn(async move {
+        let client = api::ApiClient::new();
+        match client.fetch_providers(&base_url, &project_dir).await {
+            Ok(body) => {
+                let _ = tx.send(BackgroundEvent::ModelsFetched {
+                    project_idx,
+                    entries: app::model_entries(&body),
+                });
+            }
+            Err(e) => {
+                let _ = tx.send(BackgroundEvent::Toast {
+                    message: format!("{e:#}"),
+                    severity: ToastSeverity::Error,
+                });
+            }
+        }
+    });
+}
+
+/// Delete sessions, given as `(project_dir, session_id)` pairs, via the
+/// API. The outcome is reported as one toast; the sidebar drops deleted
+/// sessions when the server's `session.deleted` events arrive.
+pub(crate) fn spawn_sessions_delete(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    sessions: Vec<(String, String)>,
+) {
+    let tx = bg_tx.clone();
+    let base_url = crate::app::base_url().to_string();
+    tokio::spawn(async move {
+        let client = api::ApiClient::new();
+        let mut failures = Vec::new();
+        for (project_dir, session_id) in &sessions {
+            if let Err(e) = client
+                .delete_session(&base_url, project_dir, session_id)
+                .await
+            {
+                tracing::warn!(session_id, "Failed to delete session: {e:#}");
+                failures.push(e);
+            }
+        }
+        let deleted = sessions.len() - failures.len();
+        let (message, severity) = match failures.first() {
+            None => (
+                format!("Deleted {deleted} session(s)"),
+                ToastSeverity::Success,
+            ),
+            Some(e) => (
+                format!("Deleted {deleted} of {} session(s): {e:#}", sessions.len()),
+                ToastSeverity::Error,
+            ),
+        };
+        let _ = tx.send(BackgroundEvent::Toast { message, severity });
+    });
+}
+
+/// Answer a permission request via the API. Failures are reported as a toast.
+pub(crate) fn spawn_permission_reply(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    project_dir: String,
+    request_id: String,
+    reply: &'static str,
+) {
+    let tx = bg_tx.clone();
+    let base_url = crate::app::base_url().to_string();
+    tokio::spawn(async move {
+        let client = api::ApiClient::new();
+        if let Err(e) = client
+            .reply_permission(&base_url, &project_dir, &request_id, reply)
+            .await
+        {
+            let _ = tx.send(BackgroundEvent::Toast {
+                message: format!("{e:#}"),
+                severity: ToastSeverity::Error,
+            });
+        }
+    });
+}
+
+/// Fetch a session's full history and write it as a Markdown transcript.
+/// The written path, or the failure, is reported as a toast.
+pub(crate) fn spawn_transcript_export(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    project_dir: String,
+    session_id: String,
+    title: String,
+) {
+    let tx = bg_tx.clone();
+    let base_url = crate::app::base_url().to_string();
+    tokio::spawn(async move {
+        let client = api::ApiClient::new();
+        let result = async {
+            let messages = client
+                .fetch_messages(&base_url, &project_dir, &session_id)
+                .await?;
+            let markdown = app::transcript_markdown(&title, &session_id, &messages);
+            let dir = app::transcript_dir()?;
+            tokio::fs::create_dir_all(&dir)
+                .await
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+            let path = dir.join(app::transcript_file_name(&title, &session_id));
+            tokio::fs::write(&path, markdown)
+                .await
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            anyhow::Ok(path)
+        }
+        .await;
+        let (message, severity) = match result {
+            Ok(path) => (
+                format!("Transcript saved to {}", path.display()),
+                ToastSeverity::Success,
+            ),
+            Err(e) => (
+                format!("Transcript export failed: {e:#}"),
+                ToastSeverity::Error,
+            ),
+        };
+        let _ = tx.send(BackgroundEvent::Toast { message, severity });
+    });
+}
+
+/// Run ripgrep for the live grep overlay after a short debounce, streaming
+/// matches back in batches (`LiveGrepResults`). Stops early once the query
+/// has changed (`current` moved past `generation`) or enough matches came in.
+pub(crate) fn spawn_live_grep(
+    bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
+    root: PathBuf,
+    query: String,
+    generation: u64,
+    current: std::sync::Arc<std::sync::atomic::AtomicU64>,
+) {
+    use std::sync::atomic::Ordering;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+
+    const DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(150);
+    const BATCH: usize = 50;
+
+    let tx = bg_tx.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(DEBOUNCE).await;
+        let is_current = || current.load(Ordering::Acquire) == generation;
+        if !is_current() {
+            return;
+        }
+        let send = |matches, done, error| {
+            let _ = tx.send(BackgroundEvent::LiveGrepResults {
+                generation,
+                matches,
+                done,
+                error,
+            });
+        };
+
+        let child = tokio::process::Command::new("rg")
+            .args(["--json", "--smart-case", "--", query.as_str()])
+            .current_dir(&root)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                send(
+                    Vec::new(),
+                    true,
+                    Some("ripgrep (rg) not found in PATH".into()),
+                );
+                return;
+            }
+            Err(e) => {
+                send(Vec::new(), true, Some(format!("Failed to run rg: {e}")));
+                return;
+            }
+        };
+
+        let Some(stdout) = child.stdout.take() else {
+            return;
+        };
+        // Drained alongside stdout, so rg never blocks on a full stderr
+        // pipe while stdout is read.
+        let stderr = child.stderr.take().map(|mut err| {
+            tokio::spawn(async move {
+                let mut text = String::new();
+                let _ = err.read_to_string(&mut text).await;
+                text
+            })
+        });
+        let mut lines = BufReader::new(stdout).lines();
+        let mut batch = Vec::new();
+        let mut total = 0;
+        while let Ok(Some(line)) = lines.next_line().await {
+            if !is_current() {
+                return;
+            }
+            let Some(m) = app::parse_rg_match(&line) else {
+                continue;
+            };
+            batch.push(m);
+            total += 1;
+            if total >= app::MAX_LIVE_GREP_RESULTS {
+                send(batch, true, None);
+                return;
+            }
+            if batch.len() >= BATCH {
+                send(std::mem::take(&mut batch), false, None);
+            }
+        }
+
+        // rg exits 1 for "no matches" and 2 for errors (e.g. a bad regex).
+        let stderr = match stderr {
+            Some(task) => task.await.unwrap_or_default(),
+            None => String::new(),
+        };
+        let error = match child.wait().await {
+            Ok(status) if status.code() == Some(2) && total == 0 => Some(
+                stderr
+                    .lines()
+                    .find(|l| !l.trim().is_empty())
+                    .unwrap_or("rg failed")
+                    .to_string(),
+            ),
+            _ => None,
+        };
+        send(batch, true, error);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blame_line_follows_unsaved_edits() {
+        let dir = std::env::temp_dir().join(format!("opman-blame-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(["-c", "user.name=Tester", "-c", "user.email=t@example.com"])
+                .args(args)
+                .current_dir(&dir)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {args:?} failed");
+        };
+        git(&["init", "-q"]);
+        let file = dir.join("notes.txt");
+        std::fs::write(&file, "first\nsecond\n").unwrap();
+        git(&["add", "notes.txt"]);
+        git(&["commit", "-q", "-m", "add notes"]);
+
+        // A line inserted in the buffer shifts the committed ones down.
+        let buffer = "inserted\nfirst\nsecond\n";
+        let new_line = blame_line(&file, 1, buffer).unwrap();
+        assert_eq!(new_line, "L1 not committed yet");
+        let moved = blame_line(&file, 3, buffer).unwrap();
+        assert!(
+            moved.starts_with("L3 ") && moved.contains(" Tester, "),
+            "{moved}"
+        );
+        assert!(moved.ends_with(": add notes"), "{moved}");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
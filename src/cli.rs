@@ -131,6 +131,12 @@ pub(crate) struct Cli {
     /// Enable the UI render MCP server (A2UI)
     #[arg(long)]
     pub ui_mcp: bool,
+
+    // ── Status endpoint ─────────────────────────────────────────────
+    /// Serve opman's live state as JSON at http://127.0.0.1:PORT/status
+    /// (read-only, localhost only; 0 picks a free port)
+    #[arg(long, value_name = "PORT")]
+    pub status_port: Option<u16>,
 }
 
 #[derive(Subcommand)]
@@ -0,0 +1,76 @@
+//! System clipboard and URL opener. Both shell out to a platform tool:
+//! `pbcopy` / `open` on macOS, `wl-copy`, `xclip` or `xsel` / `xdg-open`
+//! elsewhere, whichever is installed.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+/// Clipboard writers in order of preference, with the arguments that make
+/// them read the text from stdin into the clipboard.
+const COPY_COMMANDS: &[(&str, &[&str])] = &[
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+];
+
+/// Program that opens a URL with the user's default handler.
+#[cfg(target_os = "macos")]
+const OPEN_COMMAND: &str = "open";
+#[cfg(not(target_os = "macos"))]
+const OPEN_COMMAND: &str = "xdg-open";
+
+/// Put `text` on the system clipboard. Installed tools are tried in order
+/// until one succeeds (`wl-copy` fails outside Wayland, for instance).
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    let mut last_error = None;
+    for (program, args) in COPY_COMMANDS {
+        if crate::util::which(program).is_none() {
+            continue;
+        }
+        match pipe_to(program, args, text) {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                tracing::debug!("Clipboard via {program} failed: {e:#}");
+                last_error = Some(e);
+            }
+        }
+    }
+    Err(last_error
+        .unwrap_or_else(|| anyhow!("No clipboard tool found (install wl-copy, xclip or xsel)")))
+}
+
+/// Open `url` with the system handler without waiting for it.
+pub fn open_url(url: &str) -> Result<()> {
+    Command::new(OPEN_COMMAND)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {OPEN_COMMAND}"))?;
+    Ok(())
+}
+
+/// Run `program` with `text` on its stdin. Output is discarded so it can't
+/// draw over the TUI.
+fn pipe_to(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("Failed to run {program}"))?;
+    // Dropping stdin closes it, so the tool sees EOF.
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("{program} exited with {status}");
+    }
+    Ok(())
+}
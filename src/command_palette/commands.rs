@@ -23,6 +23,12 @@ pub fn build_commands(keys: &KeyBindings) -> Vec<CommandEntry> {
             keys_hint: leader_nested_hint(keys, &keys.leader_terminal, &keys.terminal_new_tab),
             action: CommandAction::NewTerminalTab,
         },
+        CommandEntry {
+            name: "Split Terminal".into(),
+            shorthand: "split".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_terminal, &keys.terminal_split_tab),
+            action: CommandAction::SplitTerminalTab,
+        },
         CommandEntry {
             name: "Next Terminal Tab".into(),
             shorthand: "nexttab".into(),
@@ -101,6 +107,36 @@ pub fn build_commands(keys: &KeyBindings) -> Vec<CommandEntry> {
             keys_hint: leader_nested_hint(keys, &keys.leader_swap, &keys.swap_git),
             action: CommandAction::SwapWithGit,
         },
+        CommandEntry {
+            name: "Focus: Sidebar".into(),
+            shorthand: "focus jump go sidebar projects".into(),
+            keys_hint: "".into(),
+            action: CommandAction::FocusSidebar,
+        },
+        CommandEntry {
+            name: "Focus: OpenCode".into(),
+            shorthand: "focus jump go opencode agent".into(),
+            keys_hint: "".into(),
+            action: CommandAction::FocusOpencode,
+        },
+        CommandEntry {
+            name: "Focus: Terminal".into(),
+            shorthand: "focus jump go terminal shell".into(),
+            keys_hint: "".into(),
+            action: CommandAction::FocusTerminal,
+        },
+        CommandEntry {
+            name: "Focus: Neovim".into(),
+            shorthand: "focus jump go neovim editor".into(),
+            keys_hint: "".into(),
+            action: CommandAction::FocusNeovim,
+        },
+        CommandEntry {
+            name: "Focus: Git".into(),
+            shorthand: "focus jump go git gitui".into(),
+            keys_hint: "".into(),
+            action: CommandAction::FocusGit,
+        },
         CommandEntry {
             name: "Toggle Git Panel".into(),
             shorthand: "git".into(),
@@ -143,6 +179,18 @@ pub fn build_commands(keys: &KeyBindings) -> Vec<CommandEntry> {
             keys_hint: leader_nested_hint(keys, &keys.leader_project, &keys.project_sessions),
             action: CommandAction::SessionSelector,
         },
+        CommandEntry {
+            name: "Switch Model".into(),
+            shorthand: "model provider llm".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_project, &keys.project_model),
+            action: CommandAction::ModelPicker,
+        },
+        CommandEntry {
+            name: "Mute Project Notifications".into(),
+            shorthand: "mute unmute notify quiet".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_project, &keys.project_mute),
+            action: CommandAction::ToggleProjectMute,
+        },
         CommandEntry {
             name: "Todo List".into(),
             shorthand: "todos".into(),
@@ -179,6 +227,12 @@ pub fn build_commands(keys: &KeyBindings) -> Vec<CommandEntry> {
             keys_hint: leader_nested_hint(keys, &keys.leader_zen, &keys.zen_git),
             action: CommandAction::ZenGit,
         },
+        CommandEntry {
+            name: "Presentation Mode".into(),
+            shorthand: "presentation present demo screen share fullscreen read-only zen".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_zen, &keys.zen_presentation),
+            action: CommandAction::PresentationMode,
+        },
         CommandEntry {
             name: "Search Terminal".into(),
             shorthand: "search terminal find".into(),
@@ -251,5 +305,123 @@ pub fn build_commands(keys: &KeyBindings) -> Vec<CommandEntry> {
             keys_hint: leader_hint(keys, &keys.leader_routine),
             action: CommandAction::ToggleRoutinePanel,
         },
+        CommandEntry {
+            name: "Process Overview".into(),
+            shorthand: "processes memory ps footprint".into(),
+            keys_hint: "".into(),
+            action: CommandAction::ProcessOverview,
+        },
+        CommandEntry {
+            name: "Session Activity Log".into(),
+            shorthand: "activity log timeline events history audit".into(),
+            keys_hint: "".into(),
+            action: CommandAction::ActivityLog,
+        },
+        CommandEntry {
+            name: "Opman Log".into(),
+            shorthand: "log debug errors warnings opman diagnostics tail".into(),
+            keys_hint: "".into(),
+            action: CommandAction::LogViewer,
+        },
+        CommandEntry {
+            name: "Export Session Transcript".into(),
+            shorthand: "export transcript save markdown archive share".into(),
+            keys_hint: "".into(),
+            action: CommandAction::ExportTranscript,
+        },
+        CommandEntry {
+            name: "Toggle MCP Servers".into(),
+            shorthand: "mcp servers start stop tools".into(),
+            keys_hint: leader_hint(keys, &keys.leader_mcp),
+            action: CommandAction::ToggleMcp,
+        },
+        CommandEntry {
+            name: "Blame Current Line".into(),
+            shorthand: "git blame who line neovim".into(),
+            keys_hint: leader_hint(keys, &keys.leader_blame),
+            action: CommandAction::BlameLine,
+        },
+        CommandEntry {
+            name: "Open in External Editor".into(),
+            shorthand: "editor external open file line code helix".into(),
+            keys_hint: leader_hint(keys, &keys.leader_editor),
+            action: CommandAction::OpenInExternalEditor,
+        },
+        CommandEntry {
+            name: "Find File".into(),
+            shorthand: "find file open quick fuzzy neovim".into(),
+            keys_hint: leader_hint(keys, &keys.leader_find_file),
+            action: CommandAction::FindFile,
+        },
+        CommandEntry {
+            name: "Live Grep".into(),
+            shorthand: "grep search text content ripgrep rg find in files".into(),
+            keys_hint: leader_hint(keys, &keys.leader_live_grep),
+            action: CommandAction::LiveGrep,
+        },
+        CommandEntry {
+            name: "Run Command".into(),
+            shorthand: "run command shell terminal snippet execute".into(),
+            keys_hint: leader_hint(keys, &keys.leader_run_command),
+            action: CommandAction::RunCommand,
+        },
+        CommandEntry {
+            name: "Command Snippets".into(),
+            shorthand: "snippets saved commands shell terminal insert".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_terminal, &keys.terminal_snippets),
+            action: CommandAction::CommandSnippets,
+        },
+        CommandEntry {
+            name: "Save Last Command as Snippet".into(),
+            shorthand: "snippet save last command terminal shell".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_terminal, &keys.terminal_save_snippet),
+            action: CommandAction::SaveLastCommand,
+        },
+        CommandEntry {
+            name: "Set Project Terminal Command".into(),
+            shorthand: "shell terminal command program fish nu zsh project".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_terminal, &keys.terminal_set_command),
+            action: CommandAction::SetTerminalCommand,
+        },
+        CommandEntry {
+            name: "Command History".into(),
+            shorthand: "history commands recent rerun shell terminal search".into(),
+            keys_hint: leader_nested_hint(keys, &keys.leader_terminal, &keys.terminal_history),
+            action: CommandAction::CommandHistory,
+        },
+        CommandEntry {
+            name: "Rerun or Copy Failed Command".into(),
+            shorthand: "failed error exit status rerun retry copy output shell terminal".into(),
+            keys_hint: leader_nested_hint(
+                keys,
+                &keys.leader_terminal,
+                &keys.terminal_failed_command,
+            ),
+            action: CommandAction::FailedCommand,
+        },
+        CommandEntry {
+            name: "Toggle Terminal Git Root".into(),
+            shorthand: "cd git root toplevel monorepo project directory shell terminal".into(),
+            keys_hint: "".into(),
+            action: CommandAction::ShellGitRoot,
+        },
+        CommandEntry {
+            name: "Terminal Output Log".into(),
+            shorthand: "terminal output log recover scrollback crash restart history".into(),
+            keys_hint: "".into(),
+            action: CommandAction::TerminalOutputLog,
+        },
+        CommandEntry {
+            name: "Respawn All Terminals".into(),
+            shorthand: "respawn restart reset recover pty terminals garbled".into(),
+            keys_hint: "".into(),
+            action: CommandAction::RespawnPtys,
+        },
+        CommandEntry {
+            name: "Toggle Mouse Capture".into(),
+            shorthand: "mouse capture select selection copy native terminal".into(),
+            keys_hint: leader_hint(keys, &keys.leader_mouse),
+            action: CommandAction::ToggleMouseCapture,
+        },
     ]
 }
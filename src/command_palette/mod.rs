@@ -26,6 +26,7 @@ pub enum CommandAction {
     ZenOpencode,
     ZenNeovim,
     ZenGit,
+    PresentationMode,
     ConfigPanel,
     Quit,
     // Mode transitions
@@ -45,9 +46,18 @@ pub enum CommandAction {
     SwapWithTerminal,
     SwapWithNeovim,
     SwapWithGit,
+    // Show (if hidden) and focus a panel
+    FocusSidebar,
+    FocusOpencode,
+    FocusTerminal,
+    FocusNeovim,
+    FocusGit,
     SessionSelector,
+    ModelPicker,
+    ToggleProjectMute,
     ToggleTodoPanel,
     NewTerminalTab,
+    SplitTerminalTab,
     NextTerminalTab,
     PrevTerminalTab,
     CloseTerminalTab,
@@ -63,6 +73,25 @@ pub enum CommandAction {
     SlackStatus,
     SlackLogs,
     ToggleRoutinePanel,
+    ProcessOverview,
+    ActivityLog,
+    LogViewer,
+    ExportTranscript,
+    ToggleMcp,
+    BlameLine,
+    OpenInExternalEditor,
+    FindFile,
+    LiveGrep,
+    RunCommand,
+    CommandSnippets,
+    SaveLastCommand,
+    SetTerminalCommand,
+    CommandHistory,
+    FailedCommand,
+    ShellGitRoot,
+    TerminalOutputLog,
+    RespawnPtys,
+    ToggleMouseCapture,
 }
 
 pub struct CommandEntry {
@@ -101,6 +101,15 @@ pub(crate) fn default_zen_neovim() -> String {
 pub(crate) fn default_zen_git() -> String {
     "g".into()
 }
+pub(crate) fn default_zen_presentation() -> String {
+    "p".into()
+}
+pub(crate) fn default_presentation_exit() -> String {
+    "esc".into()
+}
+pub(crate) fn default_review_agent_prompt() -> String {
+    "alt+a".into()
+}
 pub(crate) fn default_leader_config() -> String {
     "c".into()
 }
@@ -122,6 +131,27 @@ pub(crate) fn default_leader_slack() -> String {
 pub(crate) fn default_leader_routine() -> String {
     "R".into()
 }
+pub(crate) fn default_leader_mcp() -> String {
+    "M".into()
+}
+pub(crate) fn default_leader_blame() -> String {
+    "b".into()
+}
+pub(crate) fn default_leader_editor() -> String {
+    "e".into()
+}
+pub(crate) fn default_leader_find_file() -> String {
+    "f".into()
+}
+pub(crate) fn default_leader_live_grep() -> String {
+    "F".into()
+}
+pub(crate) fn default_leader_run_command() -> String {
+    "x".into()
+}
+pub(crate) fn default_leader_mouse() -> String {
+    "m".into()
+}
 pub(crate) fn default_leader_project() -> String {
     "p".into()
 }
@@ -134,6 +164,12 @@ pub(crate) fn default_project_add() -> String {
 pub(crate) fn default_project_sessions() -> String {
     "s".into()
 }
+pub(crate) fn default_project_model() -> String {
+    "m".into()
+}
+pub(crate) fn default_project_mute() -> String {
+    "n".into()
+}
 pub(crate) fn default_leader_window() -> String {
     "w".into()
 }
@@ -179,6 +215,9 @@ pub(crate) fn default_terminal_toggle() -> String {
 pub(crate) fn default_terminal_new_tab() -> String {
     "n".into()
 }
+pub(crate) fn default_terminal_split_tab() -> String {
+    "v".into()
+}
 pub(crate) fn default_terminal_next_tab() -> String {
     "]".into()
 }
@@ -191,3 +230,18 @@ pub(crate) fn default_terminal_close_tab() -> String {
 pub(crate) fn default_terminal_search() -> String {
     "f".into()
 }
+pub(crate) fn default_terminal_snippets() -> String {
+    "s".into()
+}
+pub(crate) fn default_terminal_save_snippet() -> String {
+    "S".into()
+}
+pub(crate) fn default_terminal_set_command() -> String {
+    "c".into()
+}
+pub(crate) fn default_terminal_history() -> String {
+    "h".into()
+}
+pub(crate) fn default_terminal_failed_command() -> String {
+    "r".into()
+}
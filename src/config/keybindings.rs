@@ -89,6 +89,14 @@ pub struct KeyBindings {
     pub zen_neovim: String,
     #[serde(default = "crate::config::key_defaults::default_zen_git")]
     pub zen_git: String,
+    #[serde(default = "crate::config::key_defaults::default_zen_presentation")]
+    pub zen_presentation: String,
+    /// The only key read in presentation mode; it leaves the mode.
+    #[serde(default = "crate::config::key_defaults::default_presentation_exit")]
+    pub presentation_exit: String,
+    /// Focuses a waiting agent prompt; only then does it read y/n.
+    #[serde(default = "crate::config::key_defaults::default_review_agent_prompt")]
+    pub review_agent_prompt: String,
     #[serde(default = "crate::config::key_defaults::default_leader_config")]
     pub leader_config: String,
     #[serde(default = "crate::config::key_defaults::default_leader_search")]
@@ -103,11 +111,27 @@ pub struct KeyBindings {
     pub leader_slack: String,
     #[serde(default = "crate::config::key_defaults::default_leader_routine")]
     pub leader_routine: String,
+    #[serde(default = "crate::config::key_defaults::default_leader_mcp")]
+    pub leader_mcp: String,
+    #[serde(default = "crate::config::key_defaults::default_leader_blame")]
+    pub leader_blame: String,
+    #[serde(default = "crate::config::key_defaults::default_leader_editor")]
+    pub leader_editor: String,
+    #[serde(default = "crate::config::key_defaults::default_leader_find_file")]
+    pub leader_find_file: String,
+    #[serde(default = "crate::config::key_defaults::default_leader_live_grep")]
+    pub leader_live_grep: String,
+    #[serde(default = "crate::config::key_defaults::default_leader_run_command")]
+    pub leader_run_command: String,
+    #[serde(default = "crate::config::key_defaults::default_leader_mouse")]
+    pub leader_mouse: String,
     // ── Leader → Terminal sub-bindings ──────────────────────────────
     #[serde(default = "crate::config::key_defaults::default_terminal_toggle")]
     pub terminal_toggle: String,
     #[serde(default = "crate::config::key_defaults::default_terminal_new_tab")]
     pub terminal_new_tab: String,
+    #[serde(default = "crate::config::key_defaults::default_terminal_split_tab")]
+    pub terminal_split_tab: String,
     #[serde(default = "crate::config::key_defaults::default_terminal_next_tab")]
     pub terminal_next_tab: String,
     #[serde(default = "crate::config::key_defaults::default_terminal_prev_tab")]
@@ -116,6 +140,16 @@ pub struct KeyBindings {
     pub terminal_close_tab: String,
     #[serde(default = "crate::config::key_defaults::default_terminal_search")]
     pub terminal_search: String,
+    #[serde(default = "crate::config::key_defaults::default_terminal_snippets")]
+    pub terminal_snippets: String,
+    #[serde(default = "crate::config::key_defaults::default_terminal_save_snippet")]
+    pub terminal_save_snippet: String,
+    #[serde(default = "crate::config::key_defaults::default_terminal_set_command")]
+    pub terminal_set_command: String,
+    #[serde(default = "crate::config::key_defaults::default_terminal_history")]
+    pub terminal_history: String,
+    #[serde(default = "crate::config::key_defaults::default_terminal_failed_command")]
+    pub terminal_failed_command: String,
 
     // ── Leader → Project sub-bindings ───────────────────────────────
     #[serde(default = "crate::config::key_defaults::default_leader_project")]
@@ -126,6 +160,10 @@ pub struct KeyBindings {
     pub project_add: String,
     #[serde(default = "crate::config::key_defaults::default_project_sessions")]
     pub project_sessions: String,
+    #[serde(default = "crate::config::key_defaults::default_project_model")]
+    pub project_model: String,
+    #[serde(default = "crate::config::key_defaults::default_project_mute")]
+    pub project_mute: String,
 
     // ── Leader → Window sub-bindings ────────────────────────────────
     #[serde(default = "crate::config::key_defaults::default_leader_window")]
@@ -193,6 +231,9 @@ impl Default for KeyBindings {
             zen_opencode: key_defaults::default_zen_opencode(),
             zen_neovim: key_defaults::default_zen_neovim(),
             zen_git: key_defaults::default_zen_git(),
+            zen_presentation: key_defaults::default_zen_presentation(),
+            presentation_exit: key_defaults::default_presentation_exit(),
+            review_agent_prompt: key_defaults::default_review_agent_prompt(),
             leader_config: key_defaults::default_leader_config(),
             leader_search: key_defaults::default_leader_search(),
             leader_quit: key_defaults::default_leader_quit(),
@@ -200,16 +241,31 @@ impl Default for KeyBindings {
             leader_context: key_defaults::default_leader_context(),
             leader_slack: key_defaults::default_leader_slack(),
             leader_routine: key_defaults::default_leader_routine(),
+            leader_mcp: key_defaults::default_leader_mcp(),
+            leader_blame: key_defaults::default_leader_blame(),
+            leader_editor: key_defaults::default_leader_editor(),
+            leader_find_file: key_defaults::default_leader_find_file(),
+            leader_live_grep: key_defaults::default_leader_live_grep(),
+            leader_run_command: key_defaults::default_leader_run_command(),
+            leader_mouse: key_defaults::default_leader_mouse(),
             terminal_toggle: key_defaults::default_terminal_toggle(),
             terminal_new_tab: key_defaults::default_terminal_new_tab(),
+            terminal_split_tab: key_defaults::default_terminal_split_tab(),
             terminal_next_tab: key_defaults::default_terminal_next_tab(),
             terminal_prev_tab: key_defaults::default_terminal_prev_tab(),
             terminal_close_tab: key_defaults::default_terminal_close_tab(),
             terminal_search: key_defaults::default_terminal_search(),
+            terminal_snippets: key_defaults::default_terminal_snippets(),
+            terminal_save_snippet: key_defaults::default_terminal_save_snippet(),
+            terminal_set_command: key_defaults::default_terminal_set_command(),
+            terminal_history: key_defaults::default_terminal_history(),
+            terminal_failed_command: key_defaults::default_terminal_failed_command(),
             leader_project: key_defaults::default_leader_project(),
             project_picker: key_defaults::default_project_picker(),
             project_add: key_defaults::default_project_add(),
             project_sessions: key_defaults::default_project_sessions(),
+            project_model: key_defaults::default_project_model(),
+            project_mute: key_defaults::default_project_mute(),
             leader_window: key_defaults::default_leader_window(),
             window_left: key_defaults::default_window_left(),
             window_right: key_defaults::default_window_right(),
@@ -1,12 +1,16 @@
 mod key_defaults;
 mod key_parser;
 mod keybindings;
+mod startup;
+mod state;
 
 pub use key_parser::{format_key_display, parse_key_combo};
 pub use keybindings::KeyBindings;
+pub use startup::StartupSettings;
+pub use state::{LayoutSnapshot, UiState};
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -14,7 +18,7 @@ use serde::{Deserialize, Serialize};
 // ── Types ───────────────────────────────────────────────────────────────
 
 /// A single project entry in the configuration file.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProjectEntry {
     /// Display name for the project.
     pub name: String,
@@ -24,6 +28,29 @@ pub struct ProjectEntry {
     /// If not set, falls back to the global default_terminal_command, then to $SHELL.
     #[serde(default)]
     pub terminal_command: Option<String>,
+    /// MCP socket ops the agent may use in this project (e.g. `["read",
+    /// "list", "nvim_*"]`). A trailing `*` matches by prefix. Unset means
+    /// every op is allowed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_allowed_ops: Option<Vec<String>>,
+    /// Per-project override of `settings.mcp_run_dry_run`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_run_dry_run: Option<bool>,
+    /// Keep this project above unpinned ones in the sidebar.
+    #[serde(default)]
+    pub pinned: bool,
+    /// Per-project override of `settings.opencode_command`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opencode_command: Option<String>,
+    /// Saved command snippets, offered by the command runner and the
+    /// snippet picker. Read from `favorite_commands` too, the name they
+    /// were first saved under.
+    #[serde(
+        default,
+        alias = "favorite_commands",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub snippets: Vec<String>,
 }
 
 /// Settings toggled via the config panel.
@@ -44,6 +71,256 @@ pub struct Settings {
     /// Slack integration settings.
     #[serde(default)]
     pub slack: crate::slack::SlackSettings,
+    /// Expand a parent session's subagents in the sidebar as soon as it
+    /// spawns one (active project only).
+    #[serde(default)]
+    pub auto_expand_subagents: bool,
+    /// Maximum number of opencode session PTYs kept alive per project.
+    /// The least-recently-activated idle ones are killed beyond this.
+    /// 0 = unlimited, the default.
+    #[serde(default)]
+    pub max_cached_session_ptys: usize,
+    /// Lines of scrollback kept by each terminal pane (default 1,000,000).
+    /// Applies to PTYs spawned after a change.
+    #[serde(default = "default_scrollback_lines")]
+    pub scrollback_lines: usize,
+    /// Load the project's `.envrc` through direnv into new shell tabs.
+    #[serde(default)]
+    pub direnv: bool,
+    /// Mirror each shell tab's raw output to a file on disk, so recent
+    /// output can be recovered after a crash or restart.
+    #[serde(default)]
+    pub terminal_output_log: bool,
+    /// Size cap of each shell tab's output log, in KiB.
+    #[serde(default = "default_terminal_output_log_kib")]
+    pub terminal_output_log_kib: u64,
+    /// Seconds a session may stay inactive before its shell and neovim
+    /// PTYs are killed (respawned on next activation). 0 = never, the
+    /// default. Busy shells and neovims with unsaved buffers are kept.
+    #[serde(default)]
+    pub idle_session_resource_timeout_secs: u64,
+    /// Paste into the neovim pane with `nvim_put` over RPC instead of
+    /// bracketed paste, so auto-indent can't mangle the text.
+    #[serde(default)]
+    pub neovim_rpc_paste: bool,
+    /// Hold every MCP `run` for approval in the TUI instead of executing
+    /// it straight away. Projects can override with `mcp_run_dry_run`.
+    #[serde(default)]
+    pub mcp_run_dry_run: bool,
+    /// Cursor shape in the focused terminal pane; `auto` follows what the
+    /// program asks for (DECSCUSR).
+    #[serde(default)]
+    pub cursor_shape: CursorShape,
+    /// Let the cursor blink when the program asks for it. When off the
+    /// cursor is always steady.
+    #[serde(default = "default_cursor_blink")]
+    pub cursor_blink: bool,
+    /// What to do when a terminal pane rings the bell (BEL).
+    #[serde(default)]
+    pub visual_bell: VisualBell,
+    /// How long status-bar toasts stay on screen, in milliseconds.
+    /// Warnings stay 1.5× and errors 2× as long. Default is 2000.
+    #[serde(default = "default_toast_duration_ms")]
+    pub toast_duration_ms: u64,
+    /// Message sent to the session when the todo panel is closed after
+    /// edits. `{todos}` is replaced with the current todo list.
+    #[serde(default = "default_todo_continuation_prompt")]
+    pub todo_continuation_prompt: String,
+    /// Open the todo continuation message for editing instead of sending
+    /// it straight away.
+    #[serde(default)]
+    pub edit_todo_continuation: bool,
+    /// Command that opens a file in an external editor, run in a new
+    /// terminal window. `{file}` and `{line}` are substituted (e.g.
+    /// `hx {file}:{line}`, `code -g {file}:{line}`); without `{file}` the
+    /// path is appended.
+    #[serde(default)]
+    pub external_editor: Option<String>,
+    /// Program (and extra flags) the terminal pane attaches to the server
+    /// with, e.g. `opencode --model anthropic/claude-sonnet-4`. The flags
+    /// are appended after opman's own `attach <url> --dir ...` arguments
+    /// and may be quoted as in a shell. If not set, runs `opencode`.
+    #[serde(default)]
+    pub opencode_command: Option<String>,
+    /// Directories the add-project picker searches, e.g. `~/src` or
+    /// `/opt/repos`. `~` expands to the home directory. Missing entries
+    /// are skipped; when none exist the picker searches the home directory.
+    #[serde(default)]
+    pub project_search_roots: Vec<String>,
+    /// Capture the mouse for clicks, scrolling and pane selection. When
+    /// off, the host terminal's own text selection works across opman.
+    #[serde(default = "default_mouse_capture")]
+    pub mouse_capture: bool,
+    /// Side of the screen the sidebar column sits on. `right` mirrors the
+    /// whole layout.
+    #[serde(default)]
+    pub sidebar_position: SidebarPosition,
+    /// Full or compact (initials and status dots) sidebar rows; `auto`
+    /// goes compact on narrow sidebars.
+    #[serde(default)]
+    pub sidebar_density: SidebarDensity,
+    /// Smallest width (columns) and height (rows) a panel can be dragged
+    /// or resized to. Hiding a panel is unaffected.
+    #[serde(default = "default_min_panel_width")]
+    pub min_panel_width: u16,
+    #[serde(default = "default_min_panel_height")]
+    pub min_panel_height: u16,
+    /// Focus the panel under the mouse pointer once it rests there,
+    /// without a click.
+    #[serde(default)]
+    pub focus_follows_mouse: bool,
+    /// Strip trailing whitespace from each line of text copied from a
+    /// terminal selection.
+    #[serde(default = "default_trim_copied_selection")]
+    pub trim_copied_selection: bool,
+    /// What to open and spawn at launch.
+    #[serde(default)]
+    pub startup: StartupSettings,
+    /// Paths of projects whose notifications (idle/error marks and visual
+    /// bells) are muted.
+    #[serde(default)]
+    pub muted_projects: Vec<PathBuf>,
+}
+
+/// Cursor shape override for terminal panes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CursorShape {
+    #[default]
+    Auto,
+    Block,
+    Underline,
+    Bar,
+}
+
+/// Side of the screen the sidebar is placed on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SidebarPosition {
+    #[default]
+    Left,
+    Right,
+}
+
+impl SidebarPosition {
+    pub fn label(self) -> &'static str {
+        match self {
+            SidebarPosition::Left => "left",
+            SidebarPosition::Right => "right",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            SidebarPosition::Left => SidebarPosition::Right,
+            SidebarPosition::Right => SidebarPosition::Left,
+        }
+    }
+}
+
+/// How much text sidebar rows show.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SidebarDensity {
+    /// Compact when the sidebar is narrower than [`COMPACT_SIDEBAR_WIDTH`].
+    #[default]
+    Auto,
+    Full,
+    Compact,
+}
+
+/// Sidebar width (columns) below which `auto` density goes compact.
+pub const COMPACT_SIDEBAR_WIDTH: u16 = 24;
+
+impl SidebarDensity {
+    const ALL: [SidebarDensity; 3] = [
+        SidebarDensity::Auto,
+        SidebarDensity::Full,
+        SidebarDensity::Compact,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SidebarDensity::Auto => "auto",
+            SidebarDensity::Full => "full",
+            SidebarDensity::Compact => "compact",
+        }
+    }
+
+    /// Whether a sidebar `width` columns wide renders compact rows.
+    pub fn is_compact(self, width: u16) -> bool {
+        match self {
+            SidebarDensity::Auto => width < COMPACT_SIDEBAR_WIDTH,
+            SidebarDensity::Full => false,
+            SidebarDensity::Compact => true,
+        }
+    }
+
+    /// The next density (or previous, for a negative `step`), wrapping around.
+    pub fn cycle(self, step: i16) -> Self {
+        cycle(&Self::ALL, self, step)
+    }
+}
+
+/// The entry after `current` in `all` (or before it, for a negative
+/// `step`), wrapping around.
+fn cycle<T: Copy + PartialEq>(all: &[T], current: T, step: i16) -> T {
+    let len = all.len() as i16;
+    let idx = all.iter().position(|v| *v == current).unwrap_or(0) as i16;
+    let next = if step < 0 { idx - 1 } else { idx + 1 };
+    all[next.rem_euclid(len) as usize]
+}
+
+/// Reaction to BEL from the focused terminal pane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VisualBell {
+    #[default]
+    Off,
+    /// Briefly invert the status bar.
+    Flash,
+    /// Show a toast naming the pane.
+    Toast,
+}
+
+impl VisualBell {
+    const ALL: [VisualBell; 3] = [VisualBell::Off, VisualBell::Flash, VisualBell::Toast];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            VisualBell::Off => "off",
+            VisualBell::Flash => "flash",
+            VisualBell::Toast => "toast",
+        }
+    }
+
+    /// The next mode (or previous, for a negative `step`), wrapping around.
+    pub fn cycle(self, step: i16) -> Self {
+        cycle(&Self::ALL, self, step)
+    }
+}
+
+impl CursorShape {
+    const ALL: [CursorShape; 4] = [
+        CursorShape::Auto,
+        CursorShape::Block,
+        CursorShape::Underline,
+        CursorShape::Bar,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CursorShape::Auto => "auto",
+            CursorShape::Block => "block",
+            CursorShape::Underline => "underline",
+            CursorShape::Bar => "bar",
+        }
+    }
+
+    /// The next shape (or previous, for a negative `step`), wrapping around.
+    pub fn cycle(self, step: i16) -> Self {
+        cycle(&Self::ALL, self, step)
+    }
 }
 
 impl Default for Settings {
@@ -53,16 +330,81 @@ impl Default for Settings {
             default_terminal_command: None,
             unfocused_dim_percent: 20,
             slack: crate::slack::SlackSettings::default(),
+            auto_expand_subagents: false,
+            max_cached_session_ptys: 0,
+            scrollback_lines: default_scrollback_lines(),
+            direnv: false,
+            terminal_output_log: false,
+            terminal_output_log_kib: default_terminal_output_log_kib(),
+            idle_session_resource_timeout_secs: 0,
+            neovim_rpc_paste: false,
+            mcp_run_dry_run: false,
+            cursor_shape: CursorShape::Auto,
+            cursor_blink: true,
+            visual_bell: VisualBell::Off,
+            toast_duration_ms: default_toast_duration_ms(),
+            todo_continuation_prompt: default_todo_continuation_prompt(),
+            edit_todo_continuation: false,
+            external_editor: None,
+            opencode_command: None,
+            project_search_roots: Vec::new(),
+            mouse_capture: true,
+            sidebar_position: SidebarPosition::Left,
+            sidebar_density: SidebarDensity::Auto,
+            min_panel_width: default_min_panel_width(),
+            min_panel_height: default_min_panel_height(),
+            focus_follows_mouse: false,
+            trim_copied_selection: true,
+            startup: StartupSettings::default(),
+            muted_projects: Vec::new(),
         }
     }
 }
 
+/// Short, filesystem-safe name for a project path.
+fn project_path_hash(project_path: &Path) -> String {
+    use sha2::{Digest, Sha256};
+
+    let digest = Sha256::digest(project_path.as_os_str().as_encoded_bytes());
+    digest[..8].iter().map(|b| format!("{b:02x}")).collect()
+}
+
 fn default_follow_edits() -> bool {
     true
 }
+fn default_cursor_blink() -> bool {
+    true
+}
+fn default_mouse_capture() -> bool {
+    true
+}
+fn default_trim_copied_selection() -> bool {
+    true
+}
+fn default_todo_continuation_prompt() -> String {
+    "[SYSTEM REMINDER - TODO CONTINUATION] The todo list has been updated. Re-read your todos \
+     and adjust your work plan accordingly. Mark completed items done and continue with the \
+     next pending task."
+        .to_string()
+}
 fn default_unfocused_dim_percent() -> u8 {
     20
 }
+fn default_scrollback_lines() -> usize {
+    1_000_000
+}
+fn default_terminal_output_log_kib() -> u64 {
+    1024
+}
+fn default_toast_duration_ms() -> u64 {
+    2000
+}
+fn default_min_panel_width() -> u16 {
+    10
+}
+fn default_min_panel_height() -> u16 {
+    3
+}
 
 // ── Config ──────────────────────────────────────────────────────────────
 
@@ -100,6 +442,60 @@ impl Config {
         Ok(config_dir.join("config.toml"))
     }
 
+    /// Return the path to opman's log file:
+    /// `~/.config/opman/opman.log`
+    pub fn log_path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir()
+            .context("Could not determine config directory")?
+            .join("opman");
+        Ok(config_dir.join("opman.log"))
+    }
+
+    /// Return the path to a shell tab's output log:
+    /// `~/.config/opman/terminal-logs/<project>/<session>-<tab_id>.log`,
+    /// where `<project>` is a hash of the project path and `<tab_id>` is
+    /// the id the tab got when it was opened.
+    pub fn terminal_output_log_path(
+        project_path: &Path,
+        session_id: &str,
+        tab_id: &str,
+    ) -> Result<PathBuf> {
+        Ok(Self::terminal_output_log_dir(project_path)?.join(format!("{session_id}-{tab_id}.log")))
+    }
+
+    /// Return the directory holding a project's shell tab output logs.
+    pub fn terminal_output_log_dir(project_path: &Path) -> Result<PathBuf> {
+        let config_dir = dirs::config_dir()
+            .context("Could not determine config directory")?
+            .join("opman");
+        Ok(config_dir
+            .join("terminal-logs")
+            .join(project_path_hash(project_path)))
+    }
+
+    /// Return the path to a project's command history (see
+    /// `app::command_history`): `~/.config/opman/history/<project>`.
+    pub fn command_history_path(project_path: &Path) -> Result<PathBuf> {
+        let config_dir = dirs::config_dir()
+            .context("Could not determine config directory")?
+            .join("opman");
+        Ok(config_dir
+            .join("history")
+            .join(project_path_hash(project_path)))
+    }
+
+    /// Return the path to the record of the `permission` entries opman
+    /// added to a project's opencode.json:
+    /// `~/.config/opman/opencode-permissions/<project>.json`.
+    pub fn opencode_permissions_path(project_path: &Path) -> Result<PathBuf> {
+        let config_dir = dirs::config_dir()
+            .context("Could not determine config directory")?
+            .join("opman");
+        Ok(config_dir
+            .join("opencode-permissions")
+            .join(format!("{}.json", project_path_hash(project_path))))
+    }
+
     /// Return the legacy config directory path for migration.
     fn legacy_config_dir() -> Option<PathBuf> {
         dirs::config_dir().map(|d| d.join("opencode-manager"))
@@ -0,0 +1,78 @@
+//! `[settings.startup]`: what opman opens and spawns at launch.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ui::layout_manager::PanelId;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartupSettings {
+    /// Activate a project (spawning its opencode PTY) at launch. When off,
+    /// nothing is spawned until a project is picked in the sidebar.
+    #[serde(default = "default_activate_project")]
+    pub activate_project: bool,
+    /// Name of the project to activate; the first project when unset or
+    /// not found.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Panels shown at launch instead of the layout remembered from the
+    /// last run, e.g. `["Sidebar", "TerminalPane", "NeovimPane"]`.
+    #[serde(default)]
+    pub panels: Option<Vec<PanelId>>,
+    /// Start neovim for every project as soon as its first session is
+    /// attached, before the neovim panel is opened. Unset means only when
+    /// the neovim MCP server is enabled.
+    #[serde(default)]
+    pub prespawn_neovim: Option<bool>,
+    /// Start a shell tab for the startup project's session at launch, even
+    /// while the terminal panel is hidden, so opening it is instant.
+    #[serde(default)]
+    pub prespawn_shell: bool,
+}
+
+impl Default for StartupSettings {
+    fn default() -> Self {
+        Self {
+            activate_project: true,
+            project: None,
+            panels: None,
+            prespawn_neovim: None,
+            prespawn_shell: false,
+        }
+    }
+}
+
+impl StartupSettings {
+    /// Index of the project to activate among the project `names`, or
+    /// `None` when activation is off.
+    pub fn project_index<'a>(&self, mut names: impl Iterator<Item = &'a str>) -> Option<usize> {
+        if !self.activate_project {
+            return None;
+        }
+        let Some(ref wanted) = self.project else {
+            return Some(0);
+        };
+        Some(names.position(|n| n == wanted).unwrap_or(0))
+    }
+}
+
+fn default_activate_project() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_index() {
+        let names = ["api", "web"];
+        let mut startup = StartupSettings::default();
+        assert_eq!(startup.project_index(names.into_iter()), Some(0));
+        startup.project = Some("web".into());
+        assert_eq!(startup.project_index(names.into_iter()), Some(1));
+        startup.project = Some("gone".into());
+        assert_eq!(startup.project_index(names.into_iter()), Some(0));
+        startup.activate_project = false;
+        assert_eq!(startup.project_index(names.into_iter()), None);
+    }
+}
@@ -0,0 +1,83 @@
+// ── Persisted UI state ──────────────────────────────────────────────────
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::ui::layout_manager::{LayoutNode, PanelId};
+
+/// Panel visibility, focus and sizes, captured on exit ("last session"
+/// restore) and per project when switching away from it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutSnapshot {
+    /// Mirrors `LayoutManager.panel_visible`.
+    pub panel_visible: [bool; 5],
+    /// The panel that had focus.
+    pub focused: PanelId,
+    /// Split tree, which carries the panel sizes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<LayoutNode>,
+}
+
+/// UI state that survives restarts but is not user configuration.
+///
+/// Stored separately from `config.toml` so that runtime state never
+/// rewrites the user's hand-edited config file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UiState {
+    /// Layout at the time of the last clean exit.
+    #[serde(default)]
+    pub layout: Option<LayoutSnapshot>,
+    /// Unsent context-input drafts, keyed by session ID.
+    #[serde(default)]
+    pub context_drafts: HashMap<String, Vec<String>>,
+    /// Layout last used in each project, keyed by project path.
+    #[serde(default)]
+    pub project_layouts: HashMap<PathBuf, LayoutSnapshot>,
+}
+
+impl UiState {
+    /// Return the path to the state file:
+    /// `~/.config/opman/state.json`
+    pub fn state_path() -> Result<PathBuf> {
+        let config_dir = dirs::config_dir()
+            .context("Could not determine config directory")?
+            .join("opman");
+        Ok(config_dir.join("state.json"))
+    }
+
+    /// Load the state from `path`, or return the default if the file
+    /// doesn't exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read state from {}", path.display()))?;
+        let state: UiState = serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse state from {}", path.display()))?;
+        Ok(state)
+    }
+
+    /// Save the state to `path`, creating parent directories as needed.
+    /// It is written to a temporary file next to `path` and renamed over
+    /// it, so a crash mid-write leaves the previous state intact.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create state directory {}", parent.display())
+            })?;
+        }
+        let contents = serde_json::to_string_pretty(self).context("Failed to serialize state")?;
+        let mut tmp_path = path.to_path_buf().into_os_string();
+        tmp_path.push(".tmp");
+        fs::write(&tmp_path, contents)
+            .with_context(|| format!("Failed to write state to {}", path.display()))?;
+        fs::rename(&tmp_path, path)
+            .with_context(|| format!("Failed to replace state at {}", path.display()))?;
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use crossterm::event::{KeyCode, KeyModifiers};
 use crate::app::App;
 use crate::background_tasks::{spawn_activate_project, spawn_single_session_fetch};
 use crate::input;
-use crate::mouse_handler::update_terminal_search_matches;
+use crate::mouse_handler::{step_terminal_search, update_terminal_search_matches};
 use crate::ui::layout_manager::PanelId;
 
 /// Handle a key event inside the event loop.
@@ -18,31 +18,7 @@ pub(crate) fn handle_key_in_loop(app: &mut App, key: crossterm::event::KeyEvent)
                 true
             }
             KeyCode::Enter => {
-                // Next match
-                if let Some(ref mut search) = app.terminal_search {
-                    if !search.matches.is_empty() {
-                        search.current_match = (search.current_match + 1) % search.matches.len();
-                        // Scroll to match
-                        let (match_row, _, _) = search.matches[search.current_match];
-                        if let Some(project) = app.projects.get_mut(app.active_project) {
-                            if let Some(pty) = project.active_shell_pty_mut() {
-                                // Count lines and set scrollback in one lock
-                                // to avoid clone + double-lock.
-                                if let Ok(mut p) = pty.parser.lock() {
-                                    let rows = p.screen().size().0 as usize;
-                                    let total = p.screen().contents().lines().count();
-                                    if match_row + rows < total {
-                                        pty.scroll_offset = total - match_row - rows;
-                                        p.set_scrollback(pty.scroll_offset);
-                                    } else {
-                                        pty.scroll_offset = 0;
-                                        p.set_scrollback(0);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+                step_terminal_search(app, true);
                 true
             }
             KeyCode::Backspace => {
@@ -56,25 +32,22 @@ pub(crate) fn handle_key_in_loop(app: &mut App, key: crossterm::event::KeyEvent)
             }
             KeyCode::Char(c) => {
                 if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'n' {
-                    // Ctrl+N = next match
+                    step_terminal_search(app, true);
+                    true
+                } else if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'p' {
+                    step_terminal_search(app, false);
+                    true
+                } else if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'r' {
                     if let Some(ref mut search) = app.terminal_search {
-                        if !search.matches.is_empty() {
-                            search.current_match =
-                                (search.current_match + 1) % search.matches.len();
-                        }
+                        search.regex = !search.regex;
                     }
+                    update_terminal_search_matches(app);
                     true
-                } else if key.modifiers.contains(KeyModifiers::CONTROL) && c == 'p' {
-                    // Ctrl+P = prev match
+                } else if key.modifiers.contains(KeyModifiers::ALT) && c == 'c' {
                     if let Some(ref mut search) = app.terminal_search {
-                        if !search.matches.is_empty() {
-                            search.current_match = if search.current_match == 0 {
-                                search.matches.len().saturating_sub(1)
-                            } else {
-                                search.current_match - 1
-                            };
-                        }
+                        search.case_sensitive = !search.case_sensitive;
                     }
+                    update_terminal_search_matches(app);
                     true
                 } else if key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT {
                     // Regular character input
@@ -156,7 +129,7 @@ pub(crate) fn handle_key_in_loop(app: &mut App, key: crossterm::event::KeyEvent)
                 .map(|p| p.ptys.is_empty())
                 .unwrap_or(false)
             {
-                let (cols, rows) = crossterm::terminal::size().unwrap_or((80, 24));
+                let (cols, rows) = app.terminal_size();
                 let content_area = ratatui::layout::Rect::new(0, 0, cols, rows.saturating_sub(1));
                 app.layout.compute_rects(content_area);
                 let (inner_cols, inner_rows) = app
@@ -166,8 +139,16 @@ pub(crate) fn handle_key_in_loop(app: &mut App, key: crossterm::event::KeyEvent)
                     .unwrap_or((cols.saturating_sub(32), rows.saturating_sub(2)));
                 let path = app.projects[new_idx].path.clone();
                 let theme_envs = app.theme.pty_env_vars();
+                let opencode_command = app.opencode_command(new_idx);
                 spawn_activate_project(
-                    &app.bg_tx, new_idx, path, inner_rows, inner_cols, theme_envs,
+                    &app.bg_tx,
+                    new_idx,
+                    path,
+                    inner_rows,
+                    inner_cols,
+                    app.config.settings.scrollback_lines,
+                    theme_envs,
+                    opencode_command,
                 );
             }
         }
@@ -2,19 +2,35 @@ use std::io;
 use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
-use crossterm::event::{self, Event};
+use crossterm::cursor::SetCursorStyle;
+use crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, Event};
+use crossterm::ExecutableCommand;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use tokio::sync::mpsc;
 
-use crate::app::{App, BackgroundEvent};
-use crate::background_tasks::{spawn_session_fetch, spawn_session_select};
+use crate::app::{App, BackgroundEvent, Toast, ToastSeverity};
+use crate::background_tasks::{
+    spawn_diff_stat_fetch, spawn_lsp_progress_poll, spawn_session_fetch, spawn_session_select,
+};
 use crate::event_input;
 use crate::event_mouse;
 use crate::input::resize_ptys;
 use crate::ui;
 use crate::web;
 
+/// Minimum gap between redraws caused only by PTY output (~30fps), so a
+/// streaming build doesn't pin the loop at the 60fps input tick.
+const PTY_REDRAW_INTERVAL: Duration = Duration::from_millis(33);
+
+/// Longest wait for input before the loop runs again (60fps).
+const INPUT_TICK: Duration = Duration::from_millis(16);
+
+/// Consecutive failed draws tolerated before the loop gives up. A draw
+/// fails when its terminal size query does, which can happen briefly
+/// while the terminal is handed over; a failure that persists is real.
+const MAX_DRAW_FAILURES: u32 = 60;
+
 /// The main event loop — polls for input and redraws the UI each tick.
 /// Never blocks on network/process operations; all async work uses background tasks.
 pub(crate) async fn run_event_loop(
@@ -25,25 +41,73 @@ pub(crate) async fn run_event_loop(
     web_state_handle: Option<web::WebStateHandle>,
 ) -> Result<()> {
     let mut last_session_fetch = Instant::now();
+    let mut last_lsp_progress_poll = Instant::now();
+    let mut last_diff_stat_poll = Instant::now();
+    let mut last_idle_reap = Instant::now();
+    let mut last_status_publish = Instant::now();
     let mut last_theme_reload = Instant::now();
+    let mut last_pty_redraw = Instant::now();
     let last_blink_toggle = Instant::now();
     // Previous pulse_phase value, used to detect changes worth redrawing.
     let mut prev_pulse_phase: f64 = 0.0;
     let mut last_countdown_redraw = Instant::now();
-    // Track last toast message to detect new toasts for web broadcast.
-    let mut prev_toast_msg: Option<String> = None;
+    // Toast last on screen, to detect new toasts for web broadcast.
+    let mut prev_toast: Option<Toast> = None;
+    // DECSCUSR style last sent to the host terminal (0 = its default).
+    let mut applied_cursor_style: u8 = 0;
+    // Mouse capture state of the host terminal, set up in main.
+    let mut applied_mouse_capture = app.config.settings.mouse_capture;
+    let mut draw_failures: u32 = 0;
 
     loop {
+        // A panic anywhere restored the terminal (see `install_panic_hook`);
+        // stop drawing and shut down.
+        if crate::PANICKED.load(std::sync::atomic::Ordering::Acquire) {
+            break;
+        }
+
         // ── 1. Draw the UI only when something actually changed ──────
         // Collect PTY dirty flags (new terminal output from reader threads).
-        let any_pty_dirty = app.drain_pty_dirty_flags();
+        // Output-only redraws are capped at PTY_REDRAW_INTERVAL; the flags
+        // stay set until then. Input-driven redraws go out immediately.
+        let pty_redraw_due = last_pty_redraw.elapsed() >= PTY_REDRAW_INTERVAL;
+        let any_pty_dirty = (app.needs_redraw || pty_redraw_due) && app.drain_pty_dirty_flags();
         if app.needs_redraw || any_pty_dirty {
+            last_pty_redraw = Instant::now();
+            app.poll_pty_bells();
+            app.poll_running_commands();
+            app.poll_command_history();
             app.sync_sidebar_to_active_session();
-            terminal.draw(|frame| ui::draw(frame, app))?;
-            app.needs_redraw = false;
+            if let Err(e) = terminal.draw(|frame| ui::draw(frame, app)) {
+                draw_failures += 1;
+                if draw_failures >= MAX_DRAW_FAILURES {
+                    return Err(e).context("Failed to draw");
+                }
+                tracing::debug!("Draw failed, retrying next tick: {}", e);
+                app.needs_redraw = true;
+            } else {
+                draw_failures = 0;
+                app.needs_redraw = false;
+            }
+            if let Some((_, _, style)) = app.host_cursor.get() {
+                if style != applied_cursor_style {
+                    terminal.backend_mut().execute(cursor_style_command(style))?;
+                    applied_cursor_style = style;
+                }
+            }
+            let mouse_capture = app.config.settings.mouse_capture;
+            if mouse_capture != applied_mouse_capture {
+                if mouse_capture {
+                    terminal.backend_mut().execute(EnableMouseCapture)?;
+                } else {
+                    terminal.backend_mut().execute(DisableMouseCapture)?;
+                }
+                applied_mouse_capture = mouse_capture;
+            }
         }
 
         if app.should_quit {
+            drain_mcp_requests(app, &mut bg_rx).await;
             break;
         }
 
@@ -53,6 +117,13 @@ pub(crate) async fn run_event_loop(
             app.needs_redraw = true;
         }
 
+        // ── 2.5. Evict LRU session PTYs, reap idle shell/neovim PTYs ──
+        app.enforce_pty_cache_limit();
+        if last_idle_reap.elapsed() > Duration::from_secs(5) {
+            app.reap_idle_session_resources();
+            last_idle_reap = Instant::now();
+        }
+
         // ── 3. Handle pending project removal ────────────────────────
         if let Some(idx) = app.pending_remove.take() {
             app.remove_project(idx)?;
@@ -65,6 +136,9 @@ pub(crate) async fn run_event_loop(
         // ── 4.5. Handle pending new session (PTY without --session) ──
         handle_pending_new_session(app);
 
+        // ── 4.6. Restore the active project's layout once switching settles ──
+        app.apply_pending_project_layout();
+
         // ── 5. (removed: gitui subprocess replaced by native git panel) ──
 
         // ── 6. Check for KV file changes (theme reload) ─────────────
@@ -76,6 +150,49 @@ pub(crate) async fn run_event_loop(
             last_session_fetch = Instant::now();
         }
 
+        // ── 7.1. Poll the active neovim for LSP progress (throttled) ──
+        if !app.lsp_progress_polling && last_lsp_progress_poll.elapsed() > Duration::from_secs(1) {
+            last_lsp_progress_poll = Instant::now();
+            let target = app.active_project().and_then(|p| {
+                let sid = p.active_session.clone()?;
+                let nvim = p.active_resources()?.neovim_pty.as_ref()?;
+                Some((sid, nvim.nvim_listen_addr.clone()?))
+            });
+            if let Some((sid, addr)) = target {
+                app.lsp_progress_polling = true;
+                spawn_lsp_progress_poll(&app.bg_tx, sid, addr);
+            } else if app.lsp_progress.take().is_some() {
+                app.needs_redraw = true;
+            }
+        }
+
+        // ── 7.2. Refresh the git panel's diff-stat header (throttled) ──
+        if !app.diff_stat_polling
+            && last_diff_stat_poll.elapsed() > Duration::from_secs(1)
+            && app
+                .layout
+                .is_visible(crate::ui::layout_manager::PanelId::GitPanel)
+        {
+            let idx = app.active_project;
+            let elapsed = last_diff_stat_poll.elapsed();
+            if let Some(project) = app
+                .projects
+                .get_mut(idx)
+                .filter(|p| p.diff_stat_stale || elapsed > Duration::from_secs(5))
+            {
+                project.diff_stat_stale = false;
+                app.diff_stat_polling = true;
+                last_diff_stat_poll = Instant::now();
+                spawn_diff_stat_fetch(&app.bg_tx, idx, project.path.clone());
+            }
+        }
+
+        // ── 7.3. Refresh the status endpoint's snapshot (throttled) ──
+        if app.status_handle.is_some() && last_status_publish.elapsed() > Duration::from_secs(1) {
+            last_status_publish = Instant::now();
+            app.publish_status();
+        }
+
         // ── 7.5. Update pulse phase for active session dots ─────────
         update_pulse_phase(
             app,
@@ -92,21 +209,30 @@ pub(crate) async fn run_event_loop(
         }
 
         // ── 7.7. Clear expired toast notifications ─────────────────────
-        if let Some((_, ts)) = &app.toast_message {
-            if ts.elapsed() > std::time::Duration::from_secs(2) {
-                app.toast_message = None;
-                app.needs_redraw = true;
-            }
+        let toast_duration = Duration::from_millis(app.config.settings.toast_duration_ms);
+        if app.toasts.advance(toast_duration) {
+            app.needs_redraw = true;
+        }
+
+        // ── 7.7.1. End the visual bell flash ─────────────────────────
+        if app.bell_flash_until.is_some_and(|until| Instant::now() >= until) {
+            app.bell_flash_until = None;
+            app.needs_redraw = true;
+        }
+
+        // ── 7.7.2. Focus follows mouse ───────────────────────────────
+        if app.apply_hover_focus() {
+            app.needs_redraw = true;
         }
 
         // ── 7.8. Broadcast new toasts to web clients ──────────────────
         {
-            let current_msg = app.toast_message.as_ref().map(|(m, _)| m.clone());
-            if current_msg != prev_toast_msg {
-                if let (Some(ref msg), Some(ref wsh)) = (&current_msg, &web_state_handle) {
-                    wsh.broadcast_toast(msg.clone(), "info");
+            let current = app.toasts.current().cloned();
+            if current != prev_toast {
+                if let (Some(ref toast), Some(ref wsh)) = (&current, &web_state_handle) {
+                    wsh.broadcast_toast(toast.text.clone(), toast.severity.as_str());
                 }
-                prev_toast_msg = current_msg;
+                prev_toast = current;
             }
         }
 
@@ -115,7 +241,17 @@ pub(crate) async fn run_event_loop(
         // multiple keystrokes may arrive within one 16ms frame; process
         // them back-to-back so the PTY receives them without draw-cycle
         // latency between each keystroke.
-        if event::poll(Duration::from_millis(16)).context("Event poll failed")? {
+        //
+        // The wait ends early when an output redraw comes due, so output
+        // redraws keep PTY_REDRAW_INTERVAL rather than waiting for the
+        // next tick after it (three ticks, ~20fps).
+        let until_pty_redraw = PTY_REDRAW_INTERVAL.saturating_sub(last_pty_redraw.elapsed());
+        let wait = if until_pty_redraw.is_zero() {
+            INPUT_TICK
+        } else {
+            until_pty_redraw.min(INPUT_TICK)
+        };
+        if event::poll(wait).context("Event poll failed")? {
             loop {
                 // Any crossterm event (key, mouse, paste, resize) means the UI
                 // needs to update, so mark dirty unconditionally.
@@ -128,6 +264,7 @@ pub(crate) async fn run_event_loop(
                         crate::input::handle_paste(app, &text);
                     }
                     Event::Resize(cols, rows) => {
+                        app.last_terminal_size = (cols, rows);
                         let content_area =
                             ratatui::layout::Rect::new(0, 0, cols, rows.saturating_sub(1));
                         app.layout.compute_rects(content_area);
@@ -151,6 +288,39 @@ pub(crate) async fn run_event_loop(
     Ok(())
 }
 
+/// Map a DECSCUSR `Ps` to the crossterm command that requests it.
+fn cursor_style_command(ps: u8) -> SetCursorStyle {
+    match ps {
+        1 => SetCursorStyle::BlinkingBlock,
+        2 => SetCursorStyle::SteadyBlock,
+        3 => SetCursorStyle::BlinkingUnderScore,
+        4 => SetCursorStyle::SteadyUnderScore,
+        5 => SetCursorStyle::BlinkingBar,
+        6 => SetCursorStyle::SteadyBar,
+        _ => SetCursorStyle::DefaultUserShape,
+    }
+}
+
+/// Stop accepting MCP socket connections and keep servicing background events
+/// until every in-flight MCP request has replied, bounded by `DRAIN_TIMEOUT`.
+/// Prevents bridges from seeing a broken pipe when the user quits mid tool call.
+async fn drain_mcp_requests(app: &mut App, bg_rx: &mut mpsc::UnboundedReceiver<BackgroundEvent>) {
+    app.mcp_control.begin_shutdown();
+    app.reject_all_run_approvals();
+    let deadline = Instant::now() + crate::mcp::DRAIN_TIMEOUT;
+    while app.mcp_control.in_flight() > 0 && Instant::now() < deadline {
+        match tokio::time::timeout(Duration::from_millis(50), bg_rx.recv()).await {
+            Ok(Some(event)) => app.handle_background_event(event),
+            Ok(None) => break,
+            Err(_) => {} // poll tick — re-check in-flight count
+        }
+    }
+    let remaining = app.mcp_control.in_flight();
+    if remaining > 0 {
+        tracing::warn!(remaining, "Shutdown drain timed out with MCP requests in flight");
+    }
+}
+
 /// Handle pending session select from `app.pending_session_select`.
 fn handle_pending_session_select(app: &mut App) {
     if let Some((proj_idx, session_id)) = app.pending_session_select.take() {
@@ -175,6 +345,7 @@ fn handle_pending_session_select(app: &mut App) {
                     .map(|r| (r.width, r.height))
                     .unwrap_or((80, 24));
                 let theme_envs = app.theme.pty_env_vars();
+                let opencode_command = app.opencode_command(proj_idx);
                 spawn_session_select(
                     &app.bg_tx,
                     proj_idx,
@@ -183,7 +354,9 @@ fn handle_pending_session_select(app: &mut App) {
                     project_path,
                     inner_rows,
                     inner_cols,
+                    app.config.settings.scrollback_lines,
                     theme_envs,
+                    opencode_command,
                 );
             }
         }
@@ -194,9 +367,9 @@ fn handle_pending_session_select(app: &mut App) {
 fn handle_pending_new_session(app: &mut App) {
     if let Some(proj_idx) = app.pending_new_session.take() {
         app.needs_redraw = true;
-        app.awaiting_new_session = Some(proj_idx);
         if let Some(project) = app.projects.get(proj_idx) {
             let project_path = project.path.clone();
+            app.awaiting_new_session = Some(project_path.clone());
             let (inner_cols, inner_rows) = app
                 .layout
                 .panel_rect(crate::ui::layout_manager::PanelId::TerminalPane)
@@ -205,6 +378,8 @@ fn handle_pending_new_session(app: &mut App) {
             let bg_tx = app.bg_tx.clone();
             let base_url = crate::app::base_url().to_string();
             let theme_envs = app.theme.pty_env_vars();
+            let opencode_command = app.opencode_command(proj_idx);
+            let scrollback = app.config.settings.scrollback_lines;
             tokio::spawn(async move {
                 let idx = proj_idx;
                 match tokio::task::spawn_blocking(move || {
@@ -212,9 +387,11 @@ fn handle_pending_new_session(app: &mut App) {
                         &base_url,
                         inner_rows,
                         inner_cols,
+                        scrollback,
                         &project_path,
                         None,
                         &theme_envs,
+                        opencode_command.as_deref(),
                     )
                 })
                 .await
@@ -227,7 +404,11 @@ fn handle_pending_new_session(app: &mut App) {
                         });
                     }
                     Ok(Err(e)) => {
-                        tracing::error!("Failed to spawn new session PTY: {e}");
+                        tracing::error!("Failed to spawn new session PTY: {e:#}");
+                        let _ = bg_tx.send(BackgroundEvent::Toast {
+                            message: format!("{e:#}"),
+                            severity: ToastSeverity::Error,
+                        });
                     }
                     Err(e) => {
                         tracing::error!("New session PTY task panicked: {e}");
@@ -4,16 +4,23 @@ use crate::app::{self, App};
 use crate::input;
 use crate::mouse_handler::{forward_mouse_to_pty, handle_integrated_terminal_mouse};
 use crate::ui::layout_manager::PanelId;
+use crate::ui::sidebar::{session_row_region, RowRegion};
 
 /// Handle a mouse event inside the event loop.
 pub(crate) fn handle_mouse_in_loop(
     app: &mut App,
     mouse_event: crossterm::event::MouseEvent,
 ) -> Result<()> {
-    let (cols, rows) = crossterm::terminal::size()?;
+    if app.presentation_mode {
+        return Ok(());
+    }
+    let (cols, rows) = app.terminal_size();
     let area = ratatui::layout::Rect::new(0, 0, cols, rows.saturating_sub(1));
 
     app.layout.compute_rects(area);
+    if mouse_event.kind == crossterm::event::MouseEventKind::Moved {
+        app.track_hover_focus(mouse_event.column, mouse_event.row);
+    }
     let dragging = app.layout.handle_mouse(mouse_event, area);
     if dragging.is_some() {
         app.layout.compute_rects(area);
@@ -32,20 +39,10 @@ pub(crate) fn handle_mouse_in_loop(
                     let col = mouse_event.column;
                     if col >= start_x && col < end_x {
                         let url = crate::app::base_url();
-                        use std::io::Write as _;
-                        use std::process::{Command, Stdio};
-                        if let Ok(mut child) =
-                            Command::new("pbcopy").stdin(Stdio::piped()).spawn()
-                        {
-                            if let Some(stdin) = child.stdin.as_mut() {
-                                let _ = stdin.write_all(url.as_bytes());
-                            }
-                            let _ = child.wait();
+                        match crate::clipboard::copy_to_clipboard(url) {
+                            Ok(()) => app.toasts.success("Server URL copied!"),
+                            Err(e) => app.toasts.error(format!("Copy failed: {e:#}")),
                         }
-                        app.toast_message = Some((
-                            "Server URL copied!".to_string(),
-                            std::time::Instant::now(),
-                        ));
                         app.needs_redraw = true;
                     }
                 }
@@ -92,7 +89,8 @@ fn handle_mouse_on_panel(
                         rect.y,
                         PanelId::TerminalPane,
                         &mut app.terminal_selection,
-                        &mut app.toast_message,
+                        &mut app.toasts,
+                        app.config.settings.trim_copied_selection,
                     );
                 }
             }
@@ -115,7 +113,8 @@ fn handle_mouse_on_panel(
                         rect.y,
                         PanelId::NeovimPane,
                         &mut app.terminal_selection,
-                        &mut app.toast_message,
+                        &mut app.toasts,
+                        app.config.settings.trim_copied_selection,
                     );
                 }
             }
@@ -124,11 +123,11 @@ fn handle_mouse_on_panel(
             if let Some(project) =
                 app.projects.get_mut(app.active_project)
             {
-                if let Some((pty, rect)) = project
-                    .gitui_pty
-                    .as_mut()
-                    .zip(app.layout.panel_rect(PanelId::GitPanel))
-                {
+                if let Some((pty, rect)) = project.gitui_pty.as_mut().zip(
+                    app.layout
+                        .panel_rect(PanelId::GitPanel)
+                        .map(crate::ui::gitui_pane::gitui_area),
+                ) {
                     forward_mouse_to_pty(
                         pty,
                         &mouse_event,
@@ -136,7 +135,8 @@ fn handle_mouse_on_panel(
                         rect.y,
                         PanelId::GitPanel,
                         &mut app.terminal_selection,
-                        &mut app.toast_message,
+                        &mut app.toasts,
+                        app.config.settings.trim_copied_selection,
                     );
                 }
             }
@@ -157,11 +157,12 @@ fn handle_sidebar_click(
         if let Some(rect) = app.layout.panel_rect(PanelId::Sidebar) {
             let relative_y =
                 mouse_event.row.saturating_sub(rect.y) as usize;
+            let index = app.sidebar_scroll_offset.get() + relative_y;
             let item_count = app.sidebar_item_count();
-            if relative_y < item_count {
-                app.sidebar_cursor = relative_y;
+            if index < item_count {
+                app.sidebar_cursor = index;
                 app.layout.focused = PanelId::Sidebar;
-                if let Some(item) = app.sidebar_item_at(relative_y) {
+                if let Some(item) = app.sidebar_item_at(index) {
                     handle_sidebar_item_click(app, item, mouse_event, rect)?;
                 }
             }
@@ -182,11 +183,7 @@ fn handle_sidebar_item_click(
             if app.active_project != idx {
                 app.switch_project(idx);
             }
-            if app.sessions_expanded_for == Some(idx) {
-                app.sessions_expanded_for = None;
-            } else {
-                app.sessions_expanded_for = Some(idx);
-            }
+            app.toggle_sessions_expanded(idx);
         }
         app::SidebarItem::NewSession(proj_idx) => {
             if app.active_project != proj_idx {
@@ -208,19 +205,16 @@ fn handle_sidebar_item_click(
             if app.active_project != proj_idx {
                 app.switch_project(proj_idx);
             }
-            // Check if click is on the arrow (▶/▼) column to toggle subagents
+            // The arrow (▶/▼) and count badge toggle subagents; the rest selects.
             let relative_x = mouse_event.column.saturating_sub(rect.x) as usize;
-            let has_subagents = !app.subagent_sessions(proj_idx, &session_id).is_empty();
-            // Arrow sits at columns 6-9 ("    └ " = 6, optional "● " = +2, arrow "▶ " = 2)
-            if has_subagents && relative_x >= 6 && relative_x <= 9 {
-                if app.subagents_expanded_for.as_deref() == Some(&session_id) {
-                    app.subagents_expanded_for = None;
-                } else {
-                    app.subagents_expanded_for = Some(session_id);
+            let subagent_count = app.subagent_sessions(proj_idx, &session_id).len();
+            let compact = app.config.settings.sidebar_density.is_compact(rect.width);
+            match session_row_region(subagent_count, relative_x, compact) {
+                RowRegion::SubagentToggle => app.toggle_subagents(session_id),
+                RowRegion::Body => {
+                    select_session_or_pending(app, proj_idx, session_id);
+                    app.layout.focused = PanelId::TerminalPane;
                 }
-            } else {
-                select_session_or_pending(app, proj_idx, session_id);
-                app.layout.focused = PanelId::TerminalPane;
             }
         }
         app::SidebarItem::MoreSessions(proj_idx) => {
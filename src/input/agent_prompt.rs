@@ -0,0 +1,112 @@
+//! Keys for agent prompts: dry-run command approvals, then permission
+//! requests (from any project). A waiting prompt is a banner that leaves keys alone until `review_agent_prompt` focuses
+//! it, so typing into a terminal never answers one by accident.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::{App, PermissionReply};
+
+/// Route `key` to the front agent prompt. Returns whether it was consumed:
+/// the focus key, or any key while the prompt is focused (y runs or allows
+/// once, A allows always, n rejects, Esc goes back to the banner, anything
+/// else is ignored).
+pub(super) fn handle_agent_prompt_keys(app: &mut App, key: KeyEvent) -> bool {
+    if app.run_approvals.is_empty() && app.permission_prompts.is_empty() {
+        app.agent_prompt_focused = false;
+        return false;
+    }
+    if !app.agent_prompt_focused {
+        let review = crate::config::parse_key_combo(&app.config.keybindings.review_agent_prompt);
+        if review.is_ok_and(|combo| combo.matches(&key)) {
+            app.agent_prompt_focused = true;
+            app.needs_redraw = true;
+            return true;
+        }
+        return false;
+    }
+    let approval = !app.run_approvals.is_empty();
+    match key.code {
+        KeyCode::Char('y') | KeyCode::Char('Y') if approval => app.resolve_run_approval(true),
+        KeyCode::Char('n') | KeyCode::Char('N') if approval => app.resolve_run_approval(false),
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.resolve_permission_prompt(PermissionReply::Once)
+        }
+        KeyCode::Char('A') => app.resolve_permission_prompt(PermissionReply::Always),
+        KeyCode::Char('n') | KeyCode::Char('N') => {
+            app.resolve_permission_prompt(PermissionReply::Reject)
+        }
+        KeyCode::Esc => {
+            app.agent_prompt_focused = false;
+            app.needs_redraw = true;
+        }
+        _ => {}
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{PendingRunApproval, PermissionRequest};
+    use crate::config::Config;
+    use crate::mcp::{PendingSocketRequest, SocketRequest};
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_approval_needs_focus_before_answering() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        let (reply_tx, mut reply_rx) = tokio::sync::oneshot::channel();
+        app.queue_run_approval(PendingRunApproval {
+            project_idx: 0,
+            session_id: "s1".into(),
+            pending: PendingSocketRequest {
+                request: SocketRequest {
+                    op: "run".into(),
+                    command: Some("rm -rf build".into()),
+                    ..Default::default()
+                },
+                reply_tx,
+            },
+        });
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        // Typing meant for a terminal passes by the banner.
+        assert!(!handle_agent_prompt_keys(&mut app, key(KeyCode::Char('y'))));
+        assert_eq!(app.run_approvals.len(), 1);
+
+        let review = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::ALT);
+        assert!(handle_agent_prompt_keys(&mut app, review));
+        assert!(handle_agent_prompt_keys(&mut app, key(KeyCode::Char('x'))));
+        assert!(handle_agent_prompt_keys(&mut app, key(KeyCode::Char('n'))));
+        assert!(app.run_approvals.is_empty());
+        assert!(!app.agent_prompt_focused);
+        assert!(!reply_rx.try_recv().unwrap().ok);
+    }
+
+    #[test]
+    fn test_permission_prompt_needs_focus_before_answering() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.queue_permission_prompt(
+            0,
+            PermissionRequest {
+                id: "p1".into(),
+                session_id: "s1".into(),
+                permission: "bash".into(),
+                patterns: vec!["rm -rf build".into()],
+                metadata: serde_json::Value::Null,
+            },
+        );
+        let shift_a = KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT);
+        assert!(!handle_agent_prompt_keys(&mut app, shift_a));
+        assert_eq!(app.permission_prompts.len(), 1);
+
+        let review = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::ALT);
+        assert!(handle_agent_prompt_keys(&mut app, review));
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert!(handle_agent_prompt_keys(&mut app, esc));
+        assert!(!app.agent_prompt_focused);
+        assert_eq!(app.permission_prompts.len(), 1);
+    }
+}
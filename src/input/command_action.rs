@@ -170,6 +170,9 @@ pub(super) fn execute_command_action(app: &mut App, action: CommandAction) -> Re
         CommandAction::ZenGit => {
             super::popout::zen_panel(app, PanelId::GitPanel);
         }
+        CommandAction::PresentationMode => {
+            super::popout::toggle_presentation(app);
+        }
         CommandAction::PopOutPanels => {
             super::popout::popout_panels(app);
         }
@@ -33,23 +33,12 @@ pub(super) fn execute_command_action_ext(app: &mut App, action: CommandAction) -
         }
         CommandAction::ResizeMode => {
             app.vim_mode = VimMode::Resize;
+            app.resize_count.clear();
         }
-        CommandAction::ResizeLeft => {
-            app.layout.resize_focused(-1, 0);
-            resize_ptys(app);
-        }
-        CommandAction::ResizeRight => {
-            app.layout.resize_focused(1, 0);
-            resize_ptys(app);
-        }
-        CommandAction::ResizeDown => {
-            app.layout.resize_focused(0, 1);
-            resize_ptys(app);
-        }
-        CommandAction::ResizeUp => {
-            app.layout.resize_focused(0, -1);
-            resize_ptys(app);
-        }
+        CommandAction::ResizeLeft => resize_focused(app, -1, 0),
+        CommandAction::ResizeRight => resize_focused(app, 1, 0),
+        CommandAction::ResizeDown => resize_focused(app, 0, 1),
+        CommandAction::ResizeUp => resize_focused(app, 0, -1),
         CommandAction::ToggleCheatsheet => {
             app.toggle_cheatsheet();
         }
@@ -76,6 +65,12 @@ pub(super) fn execute_command_action_ext(app: &mut App, action: CommandAction) -
         CommandAction::SessionSelector => {
             app.open_session_selector();
         }
+        CommandAction::ModelPicker => {
+            app.open_model_picker();
+        }
+        CommandAction::ToggleProjectMute => {
+            app.toggle_project_mute();
+        }
         CommandAction::ToggleTodoPanel => {
             if app.todo_panel.is_some() {
                 app.close_todo_panel();
@@ -107,9 +102,9 @@ pub(super) fn execute_command_action_ext(app: &mut App, action: CommandAction) -
         }
         CommandAction::ContextInput => {
             if app.context_input.is_some() {
-                app.context_input = None;
+                app.close_context_input();
             } else {
-                app.context_input = Some(crate::app::ContextInputState::new());
+                app.open_context_input();
             }
         }
         CommandAction::NewTerminalTab => {
@@ -118,102 +113,66 @@ pub(super) fn execute_command_action_ext(app: &mut App, action: CommandAction) -
             app.layout.focused = PanelId::IntegratedTerminal;
             resize_ptys(app);
         }
+        CommandAction::SplitTerminalTab => {
+            app.toggle_shell_split();
+            app.layout.set_visible(PanelId::IntegratedTerminal, true);
+            app.layout.focused = PanelId::IntegratedTerminal;
+            resize_ptys(app);
+        }
         CommandAction::NextTerminalTab => {
             if let Some(project) = app.projects.get_mut(app.active_project) {
                 if let Some(resources) = project.active_resources_mut() {
                     if !resources.shell_ptys.is_empty() {
-                        resources.active_shell_tab =
-                            (resources.active_shell_tab + 1) % resources.shell_ptys.len();
+                        let next = (resources.active_shell_tab + 1) % resources.shell_ptys.len();
+                        resources.select_shell_tab(next);
                     }
                 }
             }
+            resize_ptys(app);
         }
         CommandAction::PrevTerminalTab => {
             if let Some(project) = app.projects.get_mut(app.active_project) {
                 if let Some(resources) = project.active_resources_mut() {
                     if !resources.shell_ptys.is_empty() {
-                        resources.active_shell_tab = if resources.active_shell_tab == 0 {
+                        let prev = if resources.active_shell_tab == 0 {
                             resources.shell_ptys.len() - 1
                         } else {
                             resources.active_shell_tab - 1
                         };
+                        resources.select_shell_tab(prev);
                     }
                 }
             }
+            resize_ptys(app);
         }
         CommandAction::CloseTerminalTab => {
             let mut should_hide = false;
             if let Some(project) = app.projects.get_mut(app.active_project) {
                 if let Some(resources) = project.active_resources_mut() {
                     if resources.shell_ptys.len() > 1 {
-                        resources.shell_ptys.remove(resources.active_shell_tab);
-                        if resources.active_shell_tab >= resources.shell_ptys.len() {
-                            resources.active_shell_tab =
-                                resources.shell_ptys.len().saturating_sub(1);
-                        }
+                        resources.remove_shell_tab(resources.active_shell_tab);
                     } else if resources.shell_ptys.len() == 1 {
                         resources.shell_ptys.clear();
                         resources.active_shell_tab = 0;
+                        resources.split_shell_tab = None;
                         should_hide = true;
                     }
                 }
             }
             if should_hide {
                 app.layout.set_visible(PanelId::IntegratedTerminal, false);
-                resize_ptys(app);
             }
+            // The tab now shown may have been sized while hidden.
+            resize_ptys(app);
         }
         CommandAction::SearchTerminal => {
-            app.terminal_search = Some(crate::app::TerminalSearchState {
-                query: String::new(),
-                cursor: 0,
-                matches: Vec::new(),
-                current_match: 0,
-            });
+            app.terminal_search = Some(crate::app::TerminalSearchState::default());
         }
         CommandAction::SearchNextMatch => {
-            if let Some(ref mut search) = app.terminal_search {
-                if !search.matches.is_empty() {
-                    search.current_match = (search.current_match + 1) % search.matches.len();
-                    let (match_row, _, _) = search.matches[search.current_match];
-                    if let Some(project) = app.projects.get_mut(app.active_project) {
-                        if let Some(pty) = project.active_shell_pty_mut() {
-                            if let Ok(mut p) = pty.parser.lock() {
-                                let rows = p.screen().size().0 as usize;
-                                if match_row >= rows {
-                                    let target_scrollback = match_row - rows + 1;
-                                    p.set_scrollback(target_scrollback);
-                                    pty.scroll_offset = p.screen().scrollback();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+            crate::mouse_handler::step_terminal_search(app, true);
         }
         CommandAction::SearchPrevMatch => {
-            if let Some(ref mut search) = app.terminal_search {
-                if !search.matches.is_empty() {
-                    search.current_match = if search.current_match == 0 {
-                        search.matches.len() - 1
-                    } else {
-                        search.current_match - 1
-                    };
-                    let (match_row, _, _) = search.matches[search.current_match];
-                    if let Some(project) = app.projects.get_mut(app.active_project) {
-                        if let Some(pty) = project.active_shell_pty_mut() {
-                            if let Ok(mut p) = pty.parser.lock() {
-                                let rows = p.screen().size().0 as usize;
-                                if match_row >= rows {
-                                    let target_scrollback = match_row - rows + 1;
-                                    p.set_scrollback(target_scrollback);
-                                    pty.scroll_offset = p.screen().scrollback();
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+            crate::mouse_handler::step_terminal_search(app, false);
         }
         CommandAction::ToggleRoutinePanel => {
             if app.routine_panel.is_some() {
@@ -235,8 +194,86 @@ pub(super) fn execute_command_action_ext(app: &mut App, action: CommandAction) -
                 }
             }
         }
+        CommandAction::ProcessOverview => {
+            if app.process_overview.is_some() {
+                app.process_overview = None;
+            } else {
+                app.open_process_overview();
+            }
+        }
+        CommandAction::ActivityLog => app.toggle_activity_log(),
+        CommandAction::LogViewer => app.toggle_log_viewer(),
+        CommandAction::ExportTranscript => app.export_transcript(),
+        CommandAction::FocusSidebar => focus_panel(app, PanelId::Sidebar),
+        CommandAction::FocusOpencode => focus_panel(app, PanelId::TerminalPane),
+        CommandAction::FocusTerminal => focus_panel(app, PanelId::IntegratedTerminal),
+        CommandAction::FocusNeovim => focus_panel(app, PanelId::NeovimPane),
+        CommandAction::FocusGit => focus_panel(app, PanelId::GitPanel),
+        CommandAction::ToggleMcp => app.toggle_mcp(),
+        CommandAction::BlameLine => {
+            let addr = app
+                .active_project()
+                .and_then(|p| p.active_resources())
+                .and_then(|r| r.neovim_pty.as_ref())
+                .and_then(|p| p.nvim_listen_addr.clone());
+            match addr {
+                Some(addr) => crate::background_tasks::spawn_blame_current_line(&app.bg_tx, addr),
+                None => {
+                    app.toasts.warning("No neovim running for this session");
+                }
+            }
+        }
+        CommandAction::OpenInExternalEditor => super::popout::open_in_external_editor(app),
+        CommandAction::FindFile => app.open_file_finder(),
+        CommandAction::LiveGrep => app.open_live_grep(),
+        CommandAction::RunCommand => app.open_command_runner(),
+        CommandAction::CommandSnippets => app.open_snippet_picker(),
+        CommandAction::SaveLastCommand => app.save_last_command_as_snippet(),
+        CommandAction::SetTerminalCommand => app.open_terminal_command_prompt(),
+        CommandAction::CommandHistory => app.open_history_picker(),
+        CommandAction::FailedCommand => app.offer_failed_command(),
+        CommandAction::TerminalOutputLog => app.open_terminal_output_log(),
+        CommandAction::ShellGitRoot => {
+            if let Some(project) = app.projects.get(app.active_project) {
+                crate::background_tasks::spawn_git_root_fetch(
+                    &app.bg_tx,
+                    app.active_project,
+                    project.path.clone(),
+                );
+            }
+        }
+        CommandAction::RespawnPtys => app.request_respawn_ptys(),
+        CommandAction::ToggleMouseCapture => app.toggle_mouse_capture(),
         // Actions already handled in command_action.rs — should not reach here
         _ => {}
     }
     Ok(())
 }
+
+/// Show `panel` if it is hidden, start its PTY if it needs one, and focus it.
+fn focus_panel(app: &mut App, panel: PanelId) {
+    if !app.layout.is_visible(panel) {
+        app.layout.set_visible(panel, true);
+    }
+    match panel {
+        PanelId::IntegratedTerminal => {
+            app.ensure_shell_pty();
+        }
+        PanelId::NeovimPane => app.ensure_neovim_pty(),
+        PanelId::GitPanel => app.ensure_gitui_pty(),
+        _ => {}
+    }
+    app.layout.focused = panel;
+    resize_ptys(app);
+}
+
+/// Resize the focused panel towards (`dx`, `dy`): to the typed resize count
+/// in cells if there is one, otherwise by one step.
+fn resize_focused(app: &mut App, dx: i16, dy: i16) {
+    match app.resize_count.parse::<u16>() {
+        Ok(size) if size > 0 => app.layout.resize_focused_to(dx, dy, size),
+        _ => app.layout.resize_focused(dx, dy),
+    }
+    app.resize_count.clear();
+    resize_ptys(app);
+}
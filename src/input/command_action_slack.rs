@@ -11,16 +11,11 @@ pub(super) fn handle_slack_action(app: &mut App, action: CommandAction) -> Resul
             match crate::slack::SlackAuth::load() {
                 Ok(Some(auth)) => {
                     if auth.app_token.is_empty() {
-                        app.toast_message = Some((
-                            "Slack: bot_token present but app_token missing. Add it to slack_auth.yaml.".to_string(),
-                            std::time::Instant::now(),
-                        ));
+                        app.toasts.warning("Slack: bot_token present but app_token missing. Add it to slack_auth.yaml.");
                     } else if app.slack_state.is_some() {
                         // Already connected — show status.
-                        app.toast_message = Some((
-                            "Slack: already connected via Socket Mode.".to_string(),
-                            std::time::Instant::now(),
-                        ));
+                        app.toasts
+                            .warning("Slack: already connected via Socket Mode.");
                     } else {
                         // Have full credentials but not started — start now.
                         let slack_state = std::sync::Arc::new(tokio::sync::Mutex::new(
@@ -57,23 +52,16 @@ pub(super) fn handle_slack_action(app: &mut App, action: CommandAction) -> Resul
                             .await;
                         });
 
-                        app.toast_message = Some((
-                            "Slack: connecting via Socket Mode...".to_string(),
-                            std::time::Instant::now(),
-                        ));
+                        app.toasts.info("Slack: connecting via Socket Mode...");
                     }
                 }
                 Ok(None) => {
-                    app.toast_message = Some((
-                        "No slack_auth.yaml found. Create it with your tokens.".to_string(),
-                        std::time::Instant::now(),
-                    ));
+                    app.toasts
+                        .warning("No slack_auth.yaml found. Create it with your tokens.");
                 }
                 Err(e) => {
-                    app.toast_message = Some((
-                        format!("Failed to load Slack auth: {}", e),
-                        std::time::Instant::now(),
-                    ));
+                    app.toasts
+                        .error(format!("Failed to load Slack auth: {}", e));
                 }
             }
         }
@@ -84,10 +72,8 @@ pub(super) fn handle_slack_action(app: &mut App, action: CommandAction) -> Resul
                     let client_id = auth.client_id.clone();
                     let client_secret = auth.client_secret.clone();
                     let bg_tx = app.bg_tx.clone();
-                    app.toast_message = Some((
-                        "Slack: starting OAuth flow, check your browser...".to_string(),
-                        std::time::Instant::now(),
-                    ));
+                    app.toasts
+                        .info("Slack: starting OAuth flow, check your browser...");
                     tokio::spawn(async move {
                         let result = crate::slack::run_oauth_flow(&client_id, &client_secret).await;
                         let _ = bg_tx.send(crate::app::BackgroundEvent::SlackEvent(
@@ -96,22 +82,14 @@ pub(super) fn handle_slack_action(app: &mut App, action: CommandAction) -> Resul
                     });
                 }
                 Ok(Some(_)) => {
-                    app.toast_message = Some((
-                        "Slack: client_id/client_secret missing in slack_auth.yaml".to_string(),
-                        std::time::Instant::now(),
-                    ));
+                    app.toasts
+                        .warning("Slack: client_id/client_secret missing in slack_auth.yaml");
                 }
                 Ok(None) => {
-                    app.toast_message = Some((
-                        "No slack_auth.yaml found. Create it with client_id and client_secret first.".to_string(),
-                        std::time::Instant::now(),
-                    ));
+                    app.toasts.warning("No slack_auth.yaml found. Create it with client_id and client_secret first.");
                 }
                 Err(e) => {
-                    app.toast_message = Some((
-                        format!("Slack auth error: {}", e),
-                        std::time::Instant::now(),
-                    ));
+                    app.toasts.error(format!("Slack auth error: {}", e));
                 }
             }
         }
@@ -119,15 +97,9 @@ pub(super) fn handle_slack_action(app: &mut App, action: CommandAction) -> Resul
             if app.slack_state.is_some() {
                 app.slack_state = None;
                 app.slack_auth = None;
-                app.toast_message = Some((
-                    "Slack: disconnected.".to_string(),
-                    std::time::Instant::now(),
-                ));
+                app.toasts.success("Slack: disconnected.");
             } else {
-                app.toast_message = Some((
-                    "Slack: not connected.".to_string(),
-                    std::time::Instant::now(),
-                ));
+                app.toasts.warning("Slack: not connected.");
             }
         }
         CommandAction::SlackStatus => {
@@ -159,7 +131,7 @@ pub(super) fn handle_slack_action(app: &mut App, action: CommandAction) -> Resul
             } else {
                 "Slack: not initialized".to_string()
             };
-            app.toast_message = Some((status_msg, std::time::Instant::now()));
+            app.toasts.info(status_msg);
         }
         CommandAction::SlackLogs => {
             app.show_slack_log = !app.show_slack_log;
@@ -1,7 +1,9 @@
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 
+use super::list_nav::{apply_list_motion, list_motion};
 use crate::app::App;
+use crate::config::SidebarPosition;
 
 pub(super) fn handle_slack_log_keys(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
@@ -19,7 +21,107 @@ pub(super) fn handle_slack_log_keys(app: &mut App, key: KeyEvent) -> Result<()>
     Ok(())
 }
 
+pub(super) fn handle_activity_log_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    let len = app
+        .activity_log
+        .as_ref()
+        .and_then(|s| app.session_activity_entries(&s.session_id))
+        .map_or(0, |e| e.len());
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.activity_log = None;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            if let Some(ref mut state) = app.activity_log {
+                state.scroll = (state.scroll + 1).min(len.saturating_sub(1));
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if let Some(ref mut state) = app.activity_log {
+                state.scroll = state.scroll.saturating_sub(1);
+            }
+        }
+        KeyCode::Char('G') | KeyCode::End => {
+            if let Some(ref mut state) = app.activity_log {
+                state.scroll = 0;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+pub(super) fn handle_log_viewer_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    let Some(ref mut state) = app.log_viewer else {
+        return Ok(());
+    };
+    let len = state.visible_lines().len();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.log_viewer = None;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            state.scroll = (state.scroll + 1).min(len.saturating_sub(1));
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            state.scroll = state.scroll.saturating_sub(1);
+        }
+        KeyCode::PageUp => {
+            state.scroll = (state.scroll + 20).min(len.saturating_sub(1));
+        }
+        KeyCode::PageDown => {
+            state.scroll = state.scroll.saturating_sub(20);
+        }
+        KeyCode::Char('G') | KeyCode::End => {
+            state.scroll = 0;
+        }
+        KeyCode::Char('w') => {
+            state.warnings_only = !state.warnings_only;
+            state.scroll = 0;
+        }
+        KeyCode::Char('r') => {
+            app.refresh_log_viewer();
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+pub(super) fn handle_process_overview_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.process_overview = None;
+        }
+        KeyCode::Char('r') => {
+            app.open_process_overview();
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            if let Some(ref mut state) = app.process_overview {
+                state.scroll = state.scroll.saturating_sub(1);
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            if let Some(ref mut state) = app.process_overview {
+                if state.scroll + 1 < state.rows.len() {
+                    state.scroll += 1;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
 pub(super) fn handle_config_panel_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    if let Some(motion) = list_motion(&key, Some(&mut app.list_pending_g)) {
+        (app.config_panel_selected, _) = apply_list_motion(
+            motion,
+            config_panel_setting_count(),
+            app.config_panel_selected,
+            0,
+        );
+        return Ok(());
+    }
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.show_config_panel = false;
@@ -50,7 +152,7 @@ pub(super) fn handle_config_panel_keys(app: &mut App, key: KeyEvent) -> Result<(
 }
 
 fn config_panel_setting_count() -> usize {
-    4
+    18
 }
 
 fn toggle_config_setting(app: &mut App) {
@@ -62,6 +164,51 @@ fn toggle_config_setting(app: &mut App) {
         2 => {
             app.config.settings.slack.enabled = !app.config.settings.slack.enabled;
         }
+        4 => {
+            app.config.settings.auto_expand_subagents =
+                !app.config.settings.auto_expand_subagents;
+        }
+        5 => {
+            app.config.settings.neovim_rpc_paste = !app.config.settings.neovim_rpc_paste;
+        }
+        6 => {
+            app.config.settings.mcp_run_dry_run = !app.config.settings.mcp_run_dry_run;
+        }
+        7 => {
+            app.config.settings.cursor_shape = app.config.settings.cursor_shape.cycle(1);
+        }
+        8 => {
+            app.config.settings.cursor_blink = !app.config.settings.cursor_blink;
+        }
+        9 => {
+            app.config.settings.visual_bell = app.config.settings.visual_bell.cycle(1);
+        }
+        10 => {
+            app.config.settings.edit_todo_continuation =
+                !app.config.settings.edit_todo_continuation;
+        }
+        11 => {
+            app.config.settings.mouse_capture = !app.config.settings.mouse_capture;
+        }
+        12 => {
+            let position = app.config.settings.sidebar_position.toggled();
+            app.set_sidebar_position(position);
+        }
+        13 => {
+            app.config.settings.focus_follows_mouse = !app.config.settings.focus_follows_mouse;
+        }
+        14 => {
+            app.config.settings.trim_copied_selection = !app.config.settings.trim_copied_selection;
+        }
+        15 => {
+            app.config.settings.sidebar_density = app.config.settings.sidebar_density.cycle(1);
+        }
+        16 => {
+            app.config.settings.direnv = !app.config.settings.direnv;
+        }
+        17 => {
+            app.config.settings.terminal_output_log = !app.config.settings.terminal_output_log;
+        }
         _ => {}
     }
     if let Err(e) = app.config.save() {
@@ -69,8 +216,8 @@ fn toggle_config_setting(app: &mut App) {
     }
 }
 
-/// Adjust a numeric setting by `delta` (clamped to valid range).
-/// Only applies to percentage-type or seconds-type settings; ignored for booleans.
+/// Adjust a numeric setting by `delta` (clamped to valid range), or step a
+/// choice setting in the direction of `delta`. Ignored for booleans.
 fn adjust_config_setting(app: &mut App, delta: i16) {
     match app.config_panel_selected {
         1 => {
@@ -85,6 +232,23 @@ fn adjust_config_setting(app: &mut App, delta: i16) {
             app.config.settings.slack.relay_buffer_secs =
                 cur.saturating_add(step).clamp(1, 60) as u64;
         }
+        7 => {
+            app.config.settings.cursor_shape = app.config.settings.cursor_shape.cycle(delta);
+        }
+        9 => {
+            app.config.settings.visual_bell = app.config.settings.visual_bell.cycle(delta);
+        }
+        12 => {
+            let position = if delta < 0 {
+                SidebarPosition::Left
+            } else {
+                SidebarPosition::Right
+            };
+            app.set_sidebar_position(position);
+        }
+        15 => {
+            app.config.settings.sidebar_density = app.config.settings.sidebar_density.cycle(delta);
+        }
         _ => return,
     }
     if let Err(e) = app.config.save() {
@@ -6,27 +6,55 @@ use crate::app::App;
 pub(super) fn handle_context_input_keys(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Esc => {
-            app.context_input = None;
+            app.close_context_input();
+        }
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(ref mut state) = app.context_input {
+                state.preview = !state.preview;
+            }
+        }
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(ref mut state) = app.context_input {
+                state.restore_draft();
+            }
         }
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            // Submit: send text as a system message to the active OpenCode session
+            // Submit: send text as a system message to the selected target session
             if let Some(state) = app.context_input.take() {
+                if let Some(ref sid) = state.session_id {
+                    app.clear_context_draft(sid);
+                }
                 let text = state.to_string();
                 if !text.trim().is_empty() {
                     if let Some(project) = app.projects.get(app.active_project) {
-                        if let Some(ref session_id) = project.active_session {
+                        if let Some(session_id) = state
+                            .target_session_id()
+                            .or(project.active_session.as_deref())
+                        {
                             let proj_dir = project.path.to_string_lossy().to_string();
-                            let sid = session_id.clone();
+                            let sid = session_id.to_string();
                             let base_url = crate::app::base_url().to_string();
+                            let raw = state.raw;
+                            let model = project.selected_model.clone();
                             tracing::info!(
                                 session_id = sid,
                                 "Sending context input as system message"
                             );
                             tokio::spawn(async move {
                                 let client = crate::api::ApiClient::new();
-                                let msg = format!("[SYSTEM CONTEXT from user] {text}");
+                                let msg = if raw {
+                                    text
+                                } else {
+                                    format!("[SYSTEM CONTEXT from user] {text}")
+                                };
                                 match client
-                                    .send_system_message_async(&base_url, &proj_dir, &sid, &msg)
+                                    .send_system_message_async(
+                                        &base_url,
+                                        &proj_dir,
+                                        &sid,
+                                        &msg,
+                                        model.as_deref(),
+                                    )
                                     .await
                                 {
                                     Ok(()) => {
@@ -47,6 +75,70 @@ pub(super) fn handle_context_input_keys(app: &mut App, key: KeyEvent) -> Result<
                 state.insert_newline();
             }
         }
+        KeyCode::Tab => {
+            if let Some(ref mut state) = app.context_input {
+                state.cycle_target(true);
+            }
+        }
+        KeyCode::BackTab => {
+            if let Some(ref mut state) = app.context_input {
+                state.cycle_target(false);
+            }
+        }
+        // ── Word-wise motion / deletion ─────────────────────────────
+        KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(ref mut state) = app.context_input {
+                state.delete_word_left();
+            }
+        }
+        KeyCode::Backspace
+            if key
+                .modifiers
+                .intersects(KeyModifiers::ALT | KeyModifiers::CONTROL) =>
+        {
+            if let Some(ref mut state) = app.context_input {
+                state.delete_word_left();
+            }
+        }
+        KeyCode::Delete if key.modifiers.contains(KeyModifiers::ALT) => {
+            if let Some(ref mut state) = app.context_input {
+                state.delete_word_right();
+            }
+        }
+        KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::ALT) => {
+            if let Some(ref mut state) = app.context_input {
+                state.delete_word_right();
+            }
+        }
+        KeyCode::Left
+            if key
+                .modifiers
+                .intersects(KeyModifiers::ALT | KeyModifiers::CONTROL) =>
+        {
+            if let Some(ref mut state) = app.context_input {
+                state.cursor_word_left();
+            }
+        }
+        KeyCode::Right
+            if key
+                .modifiers
+                .intersects(KeyModifiers::ALT | KeyModifiers::CONTROL) =>
+        {
+            if let Some(ref mut state) = app.context_input {
+                state.cursor_word_right();
+            }
+        }
+        // macOS terminals send Alt+b / Alt+f for Option+Left / Option+Right.
+        KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::ALT) => {
+            if let Some(ref mut state) = app.context_input {
+                state.cursor_word_left();
+            }
+        }
+        KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::ALT) => {
+            if let Some(ref mut state) = app.context_input {
+                state.cursor_word_right();
+            }
+        }
         KeyCode::Backspace => {
             if let Some(ref mut state) = app.context_input {
                 state.backspace();
@@ -72,6 +164,13 @@ pub(super) fn handle_context_input_keys(app: &mut App, key: KeyEvent) -> Result<
                 state.cursor_down();
             }
         }
+        KeyCode::Char('@') => {
+            // Insert the trigger, then let the user pick a file to reference.
+            if let Some(ref mut state) = app.context_input {
+                state.insert_char('@');
+            }
+            app.open_context_file_picker();
+        }
         KeyCode::Char(c) => {
             if let Some(ref mut state) = app.context_input {
                 state.insert_char(c);
@@ -0,0 +1,138 @@
+//! Vim-style jumps shared by overlay lists: `gg`/`G` for top/bottom and
+//! Ctrl+d/Ctrl+u for half a page. Lists with a text query use Home/End
+//! instead of `gg`/`G`, since letters go to the query.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Rows moved by Ctrl+d/Ctrl+u. Overlay states don't know their rendered
+/// height, so this is a fixed step that suits typical popup sizes.
+pub(super) const HALF_PAGE: usize = 10;
+
+/// A jump within a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ListMotion {
+    Top,
+    Bottom,
+    HalfPageDown,
+    HalfPageUp,
+}
+
+/// Translate a key into a list motion. `pending_g` tracks the first `g`
+/// of `gg`; pass `None` for lists whose letters are typed into a query.
+/// Any key other than a lone `g` clears it.
+pub(super) fn list_motion(key: &KeyEvent, pending_g: Option<&mut bool>) -> Option<ListMotion> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let first_g = pending_g.as_deref().copied().unwrap_or(false);
+    let mut next_g = false;
+    let motion = match key.code {
+        KeyCode::Char('d') if ctrl => Some(ListMotion::HalfPageDown),
+        KeyCode::Char('u') if ctrl => Some(ListMotion::HalfPageUp),
+        KeyCode::Home => Some(ListMotion::Top),
+        KeyCode::End => Some(ListMotion::Bottom),
+        KeyCode::Char('g') if pending_g.is_some() && !ctrl => {
+            next_g = !first_g;
+            first_g.then_some(ListMotion::Top)
+        }
+        KeyCode::Char('G') if pending_g.is_some() && !ctrl => Some(ListMotion::Bottom),
+        _ => None,
+    };
+    if let Some(pending) = pending_g {
+        *pending = next_g;
+    }
+    motion
+}
+
+/// Apply `motion` to a list of `len` rows, returning the new
+/// `(selected, scroll_offset)`. Half-page jumps move the scroll offset
+/// along with the selection, like vim; renderers still clamp the offset
+/// to keep the selection visible.
+pub(super) fn apply_list_motion(
+    motion: ListMotion,
+    len: usize,
+    selected: usize,
+    scroll_offset: usize,
+) -> (usize, usize) {
+    let last = len.saturating_sub(1);
+    match motion {
+        ListMotion::Top => (0, 0),
+        ListMotion::Bottom => (last, scroll_offset),
+        ListMotion::HalfPageDown => (
+            (selected + HALF_PAGE).min(last),
+            (scroll_offset + HALF_PAGE).min(last),
+        ),
+        ListMotion::HalfPageUp => (
+            selected.saturating_sub(HALF_PAGE),
+            scroll_offset.saturating_sub(HALF_PAGE),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    #[test]
+    fn test_gg_needs_two_presses() {
+        let mut pending = false;
+        assert_eq!(
+            list_motion(&key(KeyCode::Char('g')), Some(&mut pending)),
+            None
+        );
+        assert!(pending);
+        assert_eq!(
+            list_motion(&key(KeyCode::Char('g')), Some(&mut pending)),
+            Some(ListMotion::Top)
+        );
+        assert!(!pending);
+
+        // Another key in between cancels the pending g.
+        list_motion(&key(KeyCode::Char('g')), Some(&mut pending));
+        assert_eq!(
+            list_motion(&key(KeyCode::Char('j')), Some(&mut pending)),
+            None
+        );
+        assert_eq!(
+            list_motion(&key(KeyCode::Char('g')), Some(&mut pending)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_lists_leave_letters_alone() {
+        assert_eq!(list_motion(&key(KeyCode::Char('g')), None), None);
+        assert_eq!(list_motion(&key(KeyCode::Char('G')), None), None);
+        assert_eq!(
+            list_motion(&key(KeyCode::End), None),
+            Some(ListMotion::Bottom)
+        );
+        assert_eq!(
+            list_motion(&ctrl('d'), None),
+            Some(ListMotion::HalfPageDown)
+        );
+        assert_eq!(list_motion(&key(KeyCode::Char('d')), None), None);
+    }
+
+    #[test]
+    fn test_apply_list_motion_clamps() {
+        assert_eq!(apply_list_motion(ListMotion::Top, 30, 12, 8), (0, 0));
+        assert_eq!(apply_list_motion(ListMotion::Bottom, 30, 12, 8), (29, 8));
+        assert_eq!(
+            apply_list_motion(ListMotion::HalfPageDown, 30, 12, 8),
+            (22, 18)
+        );
+        assert_eq!(
+            apply_list_motion(ListMotion::HalfPageDown, 30, 25, 20),
+            (29, 29)
+        );
+        assert_eq!(apply_list_motion(ListMotion::HalfPageUp, 30, 12, 8), (2, 0));
+        assert_eq!(apply_list_motion(ListMotion::HalfPageDown, 0, 0, 0), (0, 0));
+    }
+}
@@ -1,10 +1,13 @@
+mod agent_prompt;
 mod command_action;
 mod command_action_ext;
 mod command_action_slack;
 mod config;
 mod context;
+mod list_nav;
 mod mouse;
 mod overlays;
+mod paste;
 mod popout;
 mod pty_keys;
 mod routine;
@@ -22,6 +25,7 @@ use crate::vim_mode::VimMode;
 use crate::which_key::{lookup_binding, BindingMatch};
 
 pub use mouse::mouse_event_to_bytes;
+pub use paste::handle_paste;
 
 pub fn resize_ptys(app: &mut App) {
     app.resize_all_ptys();
@@ -36,6 +40,19 @@ pub fn resize_ptys(app: &mut App) {
 /// - When `Focus::TerminalPane`: Forward all keys to the active PTY.
 /// - When `Focus::IntegratedTerminal`: Forward all keys to the shell PTY.
 pub fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Presentation mode reads only its exit key and agent prompts, so a
+    // pending approval or permission request can still be answered.
+    if app.presentation_mode {
+        if agent_prompt::handle_agent_prompt_keys(app, key) {
+            return Ok(());
+        }
+        let exit = crate::config::parse_key_combo(&app.config.keybindings.presentation_exit);
+        if exit.is_ok_and(|combo| combo.matches(&key)) {
+            popout::toggle_presentation(app);
+        }
+        return Ok(());
+    }
+
     // Command mode: route to command palette
     if app.vim_mode == VimMode::Command {
         return command_action::handle_command_palette_keys(app, key);
@@ -66,6 +83,13 @@ pub fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<()> {
         }
     }
 
+    // An agent command waiting for approval, or a permission request,
+    // reads keys only once focused with `review_agent_prompt`; until then
+    // keys go where they would.
+    if agent_prompt::handle_agent_prompt_keys(app, key) {
+        return Ok(());
+    }
+
     // Overlay handlers — these consume all keys when active
     if app.input_mode == InputMode::FuzzyPicker {
         return overlays::handle_fuzzy_picker_keys(app, key);
@@ -87,10 +111,46 @@ pub fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<()> {
         return config::handle_slack_log_keys(app, key);
     }
 
+    if app.process_overview.is_some() {
+        return config::handle_process_overview_keys(app, key);
+    }
+
+    if app.activity_log.is_some() {
+        return config::handle_activity_log_keys(app, key);
+    }
+
+    if app.log_viewer.is_some() {
+        return config::handle_log_viewer_keys(app, key);
+    }
+
     if app.session_selector.is_some() {
         return overlays::handle_session_selector_keys(app, &key);
     }
 
+    if app.model_picker.is_some() {
+        return overlays::handle_model_picker_keys(app, &key);
+    }
+
+    if app.live_grep.is_some() {
+        return overlays::handle_live_grep_keys(app, &key);
+    }
+
+    if app.command_runner.is_some() {
+        return overlays::handle_command_runner_keys(app, &key);
+    }
+
+    if app.snippet_picker.is_some() {
+        return overlays::handle_snippet_picker_keys(app, &key);
+    }
+
+    if app.history_picker.is_some() {
+        return overlays::handle_history_picker_keys(app, &key);
+    }
+
+    if app.terminal_command.is_some() {
+        return overlays::handle_terminal_command_keys(app, &key);
+    }
+
     if app.watcher_modal.is_some() {
         return watcher::handle_watcher_modal_keys(app, key);
     }
@@ -119,6 +179,22 @@ pub fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<()> {
         return Ok(());
     }
 
+    if let Some(idx) = app.confirm_respawn.take() {
+        if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            app.respawn_project_ptys(idx);
+        }
+        return Ok(());
+    }
+
+    if let Some(command) = app.failed_command_prompt.take() {
+        match key.code {
+            KeyCode::Char('r') => app.rerun_failed_command(&command),
+            KeyCode::Char('y') => app.copy_failed_command(&command),
+            _ => {}
+        }
+        return Ok(());
+    }
+
     if app.show_cheatsheet {
         match key.code {
             KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => {
@@ -131,9 +207,25 @@ pub fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<()> {
         }
     }
 
-    if app.vim_mode == VimMode::Resize && key.code == KeyCode::Esc {
-        app.vim_mode = VimMode::Normal;
-        return Ok(());
+    if app.vim_mode == VimMode::Resize {
+        match key.code {
+            KeyCode::Esc => {
+                app.vim_mode = VimMode::Normal;
+                app.resize_count.clear();
+                return Ok(());
+            }
+            KeyCode::Char(c @ '0'..='9')
+                if key.modifiers.is_empty() && app.resize_count.len() < 4 =>
+            {
+                app.resize_count.push(c);
+                return Ok(());
+            }
+            KeyCode::Backspace if !app.resize_count.is_empty() => {
+                app.resize_count.pop();
+                return Ok(());
+            }
+            _ => {}
+        }
     }
 
     // Registry-based keybind lookup
@@ -193,112 +285,3 @@ pub fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<()> {
         PanelId::GitPanel => pty_keys::handle_git_panel_keys(app, key),
     }
 }
-
-pub fn handle_paste(app: &mut App, text: &str) {
-    // Route paste to overlay panels first — these consume all input when active
-
-    // Todo panel: only accepts paste when actively editing a todo
-    if let Some(ref mut state) = app.todo_panel {
-        if let Some(ref mut editing) = state.editing {
-            editing.buffer.insert_str(editing.cursor_pos, text);
-            editing.cursor_pos += text.len();
-            return;
-        }
-        // Todo panel is open but not editing — swallow paste (don't send to PTY)
-        return;
-    }
-
-    // Context input overlay: insert pasted text (may contain newlines)
-    if let Some(ref mut state) = app.context_input {
-        for c in text.chars() {
-            if c == '\n' || c == '\r' {
-                state.insert_newline();
-            } else {
-                state.insert_char(c);
-            }
-        }
-        return;
-    }
-
-    // Session selector: insert pasted text into search query
-    if let Some(ref mut state) = app.session_selector {
-        for c in text.chars() {
-            if c != '\n' && c != '\r' {
-                state.insert_char(c);
-            }
-        }
-        return;
-    }
-
-    // Session search mode: insert pasted text into search buffer
-    if app.session_search_mode {
-        for c in text.chars() {
-            if c != '\n' && c != '\r' {
-                app.session_search_buffer
-                    .insert(app.session_search_cursor, c);
-                app.session_search_cursor += 1;
-            }
-        }
-        app.update_session_search();
-        return;
-    }
-
-    // Add project input: insert pasted text into input buffer
-    if app.input_mode == InputMode::AddProject {
-        for c in text.chars() {
-            if c != '\n' && c != '\r' {
-                app.input_buffer.insert(app.input_cursor, c);
-                app.input_cursor += 1;
-            }
-        }
-        app.update_completions();
-        return;
-    }
-
-    // Fuzzy picker: insert pasted text into search query
-    if app.input_mode == InputMode::FuzzyPicker {
-        if let Some(ref mut state) = app.fuzzy_picker {
-            for c in text.chars() {
-                if c != '\n' && c != '\r' {
-                    state.insert_char(c);
-                }
-            }
-            state.tick();
-        }
-        return;
-    }
-
-    // No overlay active — forward paste to the focused PTY panel
-    let focused = app.layout.focused;
-    let Some(project) = app.active_project_mut() else {
-        return;
-    };
-    let bracketed = format!("\x1b[200~{}\x1b[201~", text);
-    let bytes = bracketed.as_bytes();
-    match focused {
-        PanelId::TerminalPane => {
-            if let Some(pty) = project.active_pty_mut() {
-                let _ = pty.write(bytes);
-            }
-        }
-        PanelId::NeovimPane => {
-            if let Some(pty) = project
-                .active_resources_mut()
-                .and_then(|r| r.neovim_pty.as_mut())
-            {
-                let _ = pty.write(bytes);
-            }
-        }
-        PanelId::IntegratedTerminal => {
-            if let Some(pty) = project.active_shell_pty_mut() {
-                let _ = pty.write(bytes);
-            }
-        }
-        PanelId::GitPanel => {
-            if let Some(pty) = project.gitui_pty.as_mut() {
-                let _ = pty.write(bytes);
-            }
-        }
-        _ => {}
-    }
-}
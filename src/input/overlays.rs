@@ -1,11 +1,19 @@
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+use super::list_nav::{apply_list_motion, list_motion};
 use crate::app::App;
+use crate::ui::fuzzy_picker::PickerTarget;
 use crate::ui::layout_manager::PanelId;
 use crate::vim_mode::VimMode;
 
 pub(super) fn handle_session_search_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    if let Some(motion) = list_motion(&key, None) {
+        let len = app.session_search_results.len();
+        (app.session_search_selected, _) =
+            apply_list_motion(motion, len, app.session_search_selected, 0);
+        return Ok(());
+    }
     match key.code {
         KeyCode::Esc => {
             app.close_session_search();
@@ -46,6 +54,29 @@ pub(super) fn handle_session_search_keys(app: &mut App, key: KeyEvent) -> Result
 }
 
 pub(super) fn handle_session_selector_keys(app: &mut App, key: &KeyEvent) -> Result<()> {
+    if app
+        .session_selector
+        .as_ref()
+        .is_some_and(|state| state.confirm_delete)
+    {
+        if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+            app.bulk_delete_sessions();
+        } else if let Some(ref mut state) = app.session_selector {
+            state.confirm_delete = false;
+        }
+        return Ok(());
+    }
+    if let Some(motion) = list_motion(key, None) {
+        if let Some(ref mut state) = app.session_selector {
+            (state.selected, state.scroll_offset) = apply_list_motion(
+                motion,
+                state.filtered.len(),
+                state.selected,
+                state.scroll_offset,
+            );
+        }
+        return Ok(());
+    }
     match key.code {
         KeyCode::Esc => {
             app.session_selector = None;
@@ -79,6 +110,17 @@ pub(super) fn handle_session_selector_keys(app: &mut App, key: &KeyEvent) -> Res
                 state.move_down();
             }
         }
+        KeyCode::Tab => {
+            if let Some(ref mut state) = app.session_selector {
+                state.toggle_mark();
+            }
+        }
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.bulk_pin_sessions();
+        }
+        KeyCode::Char('x') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.request_bulk_delete_sessions();
+        }
         KeyCode::Up => {
             if let Some(ref mut state) = app.session_selector {
                 state.move_up();
@@ -114,6 +156,152 @@ pub(super) fn handle_session_selector_keys(app: &mut App, key: &KeyEvent) -> Res
     Ok(())
 }
 
+pub(super) fn handle_model_picker_keys(app: &mut App, key: &KeyEvent) -> Result<()> {
+    let Some(ref mut state) = app.model_picker else {
+        return Ok(());
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.model_picker = None;
+            app.vim_mode = VimMode::Normal;
+        }
+        KeyCode::Enter => app.confirm_model_picker(),
+        KeyCode::Up => state.move_up(),
+        KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => state.move_up(),
+        KeyCode::Down => state.move_down(),
+        KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => state.move_down(),
+        KeyCode::Backspace => state.backspace(),
+        KeyCode::Left => state.cursor_left(),
+        KeyCode::Right => state.cursor_right(),
+        KeyCode::Char(c) => state.insert_char(c),
+        _ => {}
+    }
+    Ok(())
+}
+
+pub(super) fn handle_live_grep_keys(app: &mut App, key: &KeyEvent) -> Result<()> {
+    let Some(ref mut state) = app.live_grep else {
+        return Ok(());
+    };
+    match key.code {
+        KeyCode::Esc => {
+            app.close_live_grep();
+            app.vim_mode = VimMode::Normal;
+        }
+        KeyCode::Enter => app.confirm_live_grep(),
+        KeyCode::Up => state.move_up(),
+        KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => state.move_up(),
+        KeyCode::Down => state.move_down(),
+        KeyCode::Char('j') if key.modifiers.contains(KeyModifiers::CONTROL) => state.move_down(),
+        KeyCode::Left => state.cursor_left(),
+        KeyCode::Right => state.cursor_right(),
+        KeyCode::Backspace => {
+            if state.backspace() {
+                app.search_live_grep();
+            }
+        }
+        KeyCode::Char(c) => {
+            state.insert_char(c);
+            app.search_live_grep();
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+pub(super) fn handle_command_runner_keys(app: &mut App, key: &KeyEvent) -> Result<()> {
+    let Some(ref mut state) = app.command_runner else {
+        return Ok(());
+    };
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => {
+            app.command_runner = None;
+            app.vim_mode = VimMode::Normal;
+        }
+        KeyCode::Enter => app.run_command_runner(),
+        KeyCode::Tab => app.cycle_command_runner_tab(),
+        KeyCode::Up => app.command_runner_browse(-1),
+        KeyCode::Down => app.command_runner_browse(1),
+        KeyCode::Char('s') if ctrl => app.toggle_command_runner_snippet(),
+        KeyCode::Backspace => state.backspace(),
+        KeyCode::Left => state.cursor_left(),
+        KeyCode::Right => state.cursor_right(),
+        KeyCode::Char(c) if !ctrl => state.insert_char(c),
+        _ => {}
+    }
+    Ok(())
+}
+
+pub(super) fn handle_snippet_picker_keys(app: &mut App, key: &KeyEvent) -> Result<()> {
+    let Some(ref mut state) = app.snippet_picker else {
+        return Ok(());
+    };
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => {
+            app.snippet_picker = None;
+            app.vim_mode = VimMode::Normal;
+        }
+        KeyCode::Enter => app.insert_selected_snippet(),
+        KeyCode::Up => state.move_up(),
+        KeyCode::Char('k') if ctrl => state.move_up(),
+        KeyCode::Down => state.move_down(),
+        KeyCode::Char('j') if ctrl => state.move_down(),
+        KeyCode::Char('d') if ctrl => app.delete_selected_snippet(),
+        KeyCode::Backspace => state.backspace(),
+        KeyCode::Left => state.cursor_left(),
+        KeyCode::Right => state.cursor_right(),
+        KeyCode::Char(c) if !ctrl => state.insert_char(c),
+        _ => {}
+    }
+    Ok(())
+}
+
+pub(super) fn handle_history_picker_keys(app: &mut App, key: &KeyEvent) -> Result<()> {
+    let Some(ref mut state) = app.history_picker else {
+        return Ok(());
+    };
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => {
+            app.history_picker = None;
+            app.vim_mode = VimMode::Normal;
+        }
+        KeyCode::Enter => app.rerun_selected_history(),
+        KeyCode::Up => state.move_up(),
+        KeyCode::Char('k') if ctrl => state.move_up(),
+        KeyCode::Down => state.move_down(),
+        KeyCode::Char('j') if ctrl => state.move_down(),
+        KeyCode::Backspace => state.backspace(),
+        KeyCode::Left => state.cursor_left(),
+        KeyCode::Right => state.cursor_right(),
+        KeyCode::Char(c) if !ctrl => state.insert_char(c),
+        _ => {}
+    }
+    Ok(())
+}
+
+pub(super) fn handle_terminal_command_keys(app: &mut App, key: &KeyEvent) -> Result<()> {
+    let Some(ref mut state) = app.terminal_command else {
+        return Ok(());
+    };
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => {
+            app.terminal_command = None;
+            app.vim_mode = VimMode::Normal;
+        }
+        KeyCode::Enter => app.submit_terminal_command_prompt(),
+        KeyCode::Backspace => state.backspace(),
+        KeyCode::Left => state.cursor_left(),
+        KeyCode::Right => state.cursor_right(),
+        KeyCode::Char(c) if !ctrl => state.insert_char(c),
+        _ => {}
+    }
+    Ok(())
+}
+
 pub(super) fn handle_add_project_keys(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
         KeyCode::Esc => {
@@ -185,10 +373,16 @@ pub(super) fn handle_fuzzy_picker_keys(app: &mut App, key: KeyEvent) -> Result<(
             app.cancel_fuzzy_picker();
         }
         KeyCode::Enter => {
-            if let Some(ref _state) = app.fuzzy_picker {
-                if _state.selected_path().is_some() {
-                    app.confirm_fuzzy_add_project()?;
-                }
+            let target = app
+                .fuzzy_picker
+                .as_ref()
+                .filter(|state| state.selected_path().is_some())
+                .map(|state| state.target);
+            match target {
+                Some(PickerTarget::AddProject) => app.confirm_fuzzy_add_project()?,
+                Some(PickerTarget::ContextInputFile) => app.confirm_fuzzy_context_file(),
+                Some(PickerTarget::NeovimFile) => app.confirm_fuzzy_neovim_file(),
+                None => {}
             }
         }
         KeyCode::Up | KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => {
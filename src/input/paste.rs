@@ -0,0 +1,469 @@
+use crate::app::{App, InputMode, WatcherField};
+use crate::ui::layout_manager::PanelId;
+use crate::vim_mode::VimMode;
+
+/// Where a bracketed paste is delivered.
+///
+/// Resolved with the same precedence as key routing, so a paste always
+/// lands wherever the next keystroke would. Overlays without a text field
+/// resolve to `Ignore` rather than falling through to a PTY underneath.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PasteTarget {
+    TerminalSearch,
+    CommandPalette,
+    FuzzyPicker,
+    AddProject,
+    SessionSearch,
+    SessionSelector,
+    ModelPicker,
+    LiveGrep,
+    CommandRunner,
+    SnippetPicker,
+    HistoryPicker,
+    TerminalCommand,
+    Watcher,
+    Todo,
+    RoutineEditor,
+    ContextInput,
+    Pty(PanelId),
+    Ignore,
+}
+
+pub(super) fn paste_target(app: &App) -> PasteTarget {
+    if app.presentation_mode {
+        return PasteTarget::Ignore;
+    }
+    if app.terminal_search.is_some() {
+        return PasteTarget::TerminalSearch;
+    }
+    match app.vim_mode {
+        VimMode::Command => return PasteTarget::CommandPalette,
+        VimMode::WhichKey => return PasteTarget::Ignore,
+        _ => {}
+    }
+    if app.agent_prompt_focused {
+        return PasteTarget::Ignore;
+    }
+    match app.input_mode {
+        InputMode::FuzzyPicker => return PasteTarget::FuzzyPicker,
+        InputMode::AddProject => return PasteTarget::AddProject,
+        InputMode::Normal => {}
+    }
+    if app.session_search_mode {
+        return PasteTarget::SessionSearch;
+    }
+    if app.show_config_panel
+        || app.show_slack_log
+        || app.process_overview.is_some()
+        || app.activity_log.is_some()
+        || app.log_viewer.is_some()
+    {
+        return PasteTarget::Ignore;
+    }
+    if app.session_selector.is_some() {
+        return PasteTarget::SessionSelector;
+    }
+    if app.model_picker.is_some() {
+        return PasteTarget::ModelPicker;
+    }
+    if app.live_grep.is_some() {
+        return PasteTarget::LiveGrep;
+    }
+    if app.command_runner.is_some() {
+        return PasteTarget::CommandRunner;
+    }
+    if app.snippet_picker.is_some() {
+        return PasteTarget::SnippetPicker;
+    }
+    if app.history_picker.is_some() {
+        return PasteTarget::HistoryPicker;
+    }
+    if app.terminal_command.is_some() {
+        return PasteTarget::TerminalCommand;
+    }
+    if let Some(ref m) = app.watcher_modal {
+        return match m.active_field {
+            WatcherField::Message
+            | WatcherField::HangMessage
+            | WatcherField::TimeoutInput
+            | WatcherField::HangTimeoutInput => PasteTarget::Watcher,
+            _ => PasteTarget::Ignore,
+        };
+    }
+    if let Some(ref state) = app.todo_panel {
+        return if state.editing.is_some() {
+            PasteTarget::Todo
+        } else {
+            PasteTarget::Ignore
+        };
+    }
+    if let Some(ref state) = app.routine_panel {
+        return match state.editing {
+            Some(ref edit) if matches!(edit.focused_field, 0 | 2 | 4) => {
+                PasteTarget::RoutineEditor
+            }
+            _ => PasteTarget::Ignore,
+        };
+    }
+    if app.context_input.is_some() {
+        return PasteTarget::ContextInput;
+    }
+    if app.confirm_delete.is_some()
+        || app.confirm_respawn.is_some()
+        || app.failed_command_prompt.is_some()
+        || app.show_cheatsheet
+    {
+        return PasteTarget::Ignore;
+    }
+    match app.layout.focused {
+        PanelId::Sidebar => PasteTarget::Ignore,
+        panel => PasteTarget::Pty(panel),
+    }
+}
+
+/// Single-line fields drop line breaks from pasted text.
+fn single_line(text: &str) -> impl Iterator<Item = char> + '_ {
+    text.chars().filter(|c| *c != '\n' && *c != '\r')
+}
+
+pub fn handle_paste(app: &mut App, text: &str) {
+    match paste_target(app) {
+        PasteTarget::TerminalSearch => {
+            if let Some(ref mut search) = app.terminal_search {
+                search.query.extend(single_line(text));
+                search.cursor = search.query.len();
+            }
+            crate::mouse_handler::update_terminal_search_matches(app);
+        }
+        PasteTarget::CommandPalette => {
+            for c in single_line(text) {
+                app.command_palette.insert_char(c);
+            }
+            app.command_palette.tick();
+        }
+        PasteTarget::FuzzyPicker => {
+            if let Some(ref mut state) = app.fuzzy_picker {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+                state.tick();
+            }
+        }
+        PasteTarget::AddProject => {
+            for c in single_line(text) {
+                app.input_buffer.insert(app.input_cursor, c);
+                app.input_cursor += 1;
+            }
+            app.update_completions();
+        }
+        PasteTarget::SessionSearch => {
+            for c in single_line(text) {
+                app.session_search_buffer
+                    .insert(app.session_search_cursor, c);
+                app.session_search_cursor += 1;
+            }
+            app.update_session_search();
+        }
+        PasteTarget::SessionSelector => {
+            if let Some(ref mut state) = app.session_selector {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+            }
+        }
+        PasteTarget::ModelPicker => {
+            if let Some(ref mut state) = app.model_picker {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+            }
+        }
+        PasteTarget::LiveGrep => {
+            if let Some(ref mut state) = app.live_grep {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+            }
+            app.search_live_grep();
+        }
+        PasteTarget::CommandRunner => {
+            if let Some(ref mut state) = app.command_runner {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+            }
+        }
+        PasteTarget::SnippetPicker => {
+            if let Some(ref mut state) = app.snippet_picker {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+            }
+        }
+        PasteTarget::HistoryPicker => {
+            if let Some(ref mut state) = app.history_picker {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+            }
+        }
+        PasteTarget::TerminalCommand => {
+            if let Some(ref mut state) = app.terminal_command {
+                for c in single_line(text) {
+                    state.insert_char(c);
+                }
+            }
+        }
+        PasteTarget::Watcher => {
+            if let Some(ref mut m) = app.watcher_modal {
+                paste_into_watcher(m, text);
+            }
+        }
+        PasteTarget::Todo => {
+            if let Some(editing) = app.todo_panel.as_mut().and_then(|s| s.editing.as_mut()) {
+                editing.buffer.insert_str(editing.cursor_pos, text);
+                editing.cursor_pos += text.len();
+            }
+        }
+        PasteTarget::RoutineEditor => {
+            if let Some(edit) = app.routine_panel.as_mut().and_then(|s| s.editing.as_mut()) {
+                match edit.focused_field {
+                    0 => edit.name.extend(single_line(text)),
+                    2 => edit.prompt.push_str(text),
+                    4 => edit.cron_expr.extend(single_line(text)),
+                    _ => {}
+                }
+            }
+        }
+        PasteTarget::ContextInput => {
+            if let Some(ref mut state) = app.context_input {
+                for c in text.chars() {
+                    if c == '\n' || c == '\r' {
+                        state.insert_newline();
+                    } else {
+                        state.insert_char(c);
+                    }
+                }
+            }
+        }
+        PasteTarget::Pty(panel) => paste_into_pty(app, panel, text),
+        PasteTarget::Ignore => {}
+    }
+}
+
+fn paste_into_watcher(m: &mut crate::app::WatcherModalState, text: &str) {
+    match m.active_field {
+        WatcherField::Message | WatcherField::HangMessage => {
+            let hang = m.active_field == WatcherField::HangMessage;
+            for c in text.chars() {
+                match (c, hang) {
+                    ('\r', _) => {}
+                    ('\n', false) => m.insert_newline(),
+                    ('\n', true) => m.hang_insert_newline(),
+                    (c, false) => m.insert_char(c),
+                    (c, true) => m.hang_insert_char(c),
+                }
+            }
+        }
+        WatcherField::TimeoutInput => {
+            m.timeout_input
+                .extend(text.chars().filter(|c| c.is_ascii_digit()));
+            if let Ok(val) = m.timeout_input.parse::<u64>() {
+                m.idle_timeout_secs = val;
+            }
+        }
+        WatcherField::HangTimeoutInput => {
+            m.hang_timeout_input
+                .extend(text.chars().filter(|c| c.is_ascii_digit()));
+            if let Ok(val) = m.hang_timeout_input.parse::<u64>() {
+                m.hang_timeout_secs = val;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn paste_into_pty(app: &mut App, panel: PanelId, text: &str) {
+    let rpc_paste = app.config.settings.neovim_rpc_paste;
+    let Some(project) = app.active_project_mut() else {
+        return;
+    };
+    if panel == PanelId::NeovimPane && rpc_paste {
+        let addr = project
+            .active_resources()
+            .and_then(|r| r.neovim_pty.as_ref())
+            .and_then(|p| p.nvim_listen_addr.clone());
+        if let Some(addr) = addr {
+            match crate::nvim_rpc::nvim_put_text(&addr, text) {
+                Ok(()) => return,
+                // Fall back to bracketed paste below.
+                Err(e) => tracing::warn!("RPC paste into neovim failed: {e}"),
+            }
+        }
+    }
+    let bracketed = format!("\x1b[200~{}\x1b[201~", text);
+    let bytes = bracketed.as_bytes();
+    let pty = match panel {
+        PanelId::TerminalPane => project.active_pty_mut(),
+        PanelId::NeovimPane => project
+            .active_resources_mut()
+            .and_then(|r| r.neovim_pty.as_mut()),
+        PanelId::IntegratedTerminal => project.active_shell_pty_mut(),
+        PanelId::GitPanel => project.gitui_pty.as_mut(),
+        PanelId::Sidebar => None,
+    };
+    if let Some(pty) = pty {
+        let _ = pty.write(bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{RoutineEditState, RoutinePanelState};
+    use crate::config::Config;
+
+    fn app() -> App {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(Config::default(), tx);
+        app.layout.focused = PanelId::TerminalPane;
+        app
+    }
+
+    #[test]
+    fn test_no_overlay_goes_to_focused_pty() {
+        let mut app = app();
+        assert_eq!(paste_target(&app), PasteTarget::Pty(PanelId::TerminalPane));
+        app.layout.focused = PanelId::Sidebar;
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+    }
+
+    #[test]
+    fn test_read_only_overlays_ignore_paste() {
+        let mut app = app();
+        app.show_config_panel = true;
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.show_slack_log = true;
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.process_overview = Some(Default::default());
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.activity_log = Some(crate::app::ActivityLogState {
+            session_id: "s1".into(),
+            scroll: 0,
+        });
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.log_viewer = Some(crate::app::LogViewerState {
+            path: "/tmp/opman.log".into(),
+            lines: Vec::new(),
+            scroll: 0,
+            warnings_only: false,
+        });
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.presentation_mode = true;
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.show_cheatsheet = true;
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.confirm_delete = Some(0);
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.confirm_respawn = Some(0);
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut app = self::app();
+        app.vim_mode = VimMode::WhichKey;
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+    }
+
+    #[test]
+    fn test_todo_panel_only_accepts_paste_while_editing() {
+        let mut app = app();
+        app.todo_panel = Some(crate::app::TodoPanelState {
+            todos: Vec::new(),
+            selected: 0,
+            scroll_offset: 0,
+            session_id: "s".into(),
+            editing: None,
+            dirty: false,
+        });
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+    }
+
+    fn routine_panel(editing: Option<RoutineEditState>) -> RoutinePanelState {
+        RoutinePanelState {
+            routines: Vec::new(),
+            selected: 0,
+            scroll_offset: 0,
+            running: None,
+            loading: false,
+            show_detail: false,
+            editing,
+            confirm_delete: None,
+        }
+    }
+
+    #[test]
+    fn test_routine_panel_accepts_paste_in_text_fields_only() {
+        let mut app = app();
+        app.routine_panel = Some(routine_panel(None));
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut edit = RoutineEditState::new_create();
+        edit.focused_field = 1;
+        app.routine_panel = Some(routine_panel(Some(edit)));
+        assert_eq!(paste_target(&app), PasteTarget::Ignore);
+
+        let mut edit = RoutineEditState::new_create();
+        edit.focused_field = 2;
+        app.routine_panel = Some(routine_panel(Some(edit)));
+        handle_paste(&mut app, "run the tests\nthen report");
+        assert_eq!(
+            app.routine_panel.unwrap().editing.unwrap().prompt,
+            "run the tests\nthen report"
+        );
+    }
+
+    #[test]
+    fn test_text_overlays_take_paste() {
+        let mut app = app();
+        app.vim_mode = VimMode::Command;
+        handle_paste(&mut app, "quit\n");
+        assert_eq!(app.command_palette.query, "quit");
+
+        let mut app = self::app();
+        app.input_mode = InputMode::AddProject;
+        handle_paste(&mut app, "/tmp/x");
+        assert_eq!(app.input_buffer, "/tmp/x");
+
+        let mut app = self::app();
+        app.session_search_mode = true;
+        assert_eq!(paste_target(&app), PasteTarget::SessionSearch);
+
+        let mut app = self::app();
+        app.open_context_input();
+        handle_paste(&mut app, "a\nb");
+        assert_eq!(app.context_input.unwrap().lines, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_terminal_search_wins_over_focused_pty() {
+        let mut app = app();
+        app.terminal_search = Some(crate::app::TerminalSearchState::default());
+        handle_paste(&mut app, "needle\n");
+        assert_eq!(app.terminal_search.unwrap().query, "needle");
+    }
+}
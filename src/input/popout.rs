@@ -1,5 +1,6 @@
 use crate::app::App;
 use crate::ui::layout_manager::PanelId;
+use crate::util::shell_escape;
 
 use super::resize_ptys;
 
@@ -31,6 +32,36 @@ pub(super) fn zen_panel(app: &mut App, target: PanelId) {
     resize_ptys(app);
 }
 
+/// Enter or leave presentation mode: zen on the focused terminal pane with
+/// the status bar and overlays hidden, and all input ignored except
+/// `presentation_exit` and agent prompts. Leaving restores the layout from
+/// before, zen included.
+pub(super) fn toggle_presentation(app: &mut App) {
+    if app.presentation_mode {
+        app.presentation_mode = false;
+        if app.zen_mode {
+            let focused = app.layout.focused;
+            zen_panel(app, focused);
+        }
+        if let Some(panel) = app.pre_presentation_zen.take() {
+            zen_panel(app, panel);
+        }
+        return;
+    }
+    let target = match app.layout.focused {
+        PanelId::Sidebar => PanelId::TerminalPane,
+        focused => focused,
+    };
+    app.pre_presentation_zen = None;
+    if app.zen_mode {
+        app.pre_presentation_zen = Some(app.layout.focused);
+        zen_panel(app, target);
+    }
+    zen_panel(app, target);
+    app.terminal_search = None;
+    app.presentation_mode = true;
+}
+
 pub(super) fn popout_panels(app: &mut App) {
     if app.popout_mode {
         for child in app.popout_windows.drain(..) {
@@ -44,7 +75,7 @@ pub(super) fn popout_panels(app: &mut App) {
             app.layout.set_visible(PanelId::TerminalPane, true);
         }
         app.popout_mode = false;
-        app.toast_message = Some(("Panels restored".into(), std::time::Instant::now()));
+        app.toasts.success("Panels restored");
     } else {
         let project = match app.projects.get(app.active_project) {
             Some(p) => p,
@@ -53,6 +84,7 @@ pub(super) fn popout_panels(app: &mut App) {
         let project_dir = project.path.clone();
         let theme_envs = app.theme.pty_env_vars();
         let td = crate::theme_gen::theme_dir();
+        let opencode_command = app.opencode_command(app.active_project);
 
         let focused = app.layout.focused;
         app.pre_popout_state = Some((app.layout.panel_visible, focused));
@@ -70,10 +102,7 @@ pub(super) fn popout_panels(app: &mut App) {
 
         if panels_to_popout.is_empty() {
             app.pre_popout_state = None;
-            app.toast_message = Some((
-                "No panels visible to pop out".into(),
-                std::time::Instant::now(),
-            ));
+            app.toasts.warning("No panels visible to pop out");
             return;
         }
 
@@ -89,7 +118,19 @@ pub(super) fn popout_panels(app: &mut App) {
                         .as_ref()
                         .map(|sid| format!(" --session {}", sid))
                         .unwrap_or_default();
-                    format!("opencode attach {} --dir {}{}", base_url, dir, session_part)
+                    let (program, extra_args) =
+                        match crate::pty::attach_command(opencode_command.as_deref()) {
+                            Ok(cmd) => cmd,
+                            Err(e) => {
+                                app.toasts.error(format!("{e:#}"));
+                                continue;
+                            }
+                        };
+                    let extra: String = extra_args.iter().map(|a| format!(" {a}")).collect();
+                    format!(
+                        "{} attach {} --dir {}{}{}",
+                        program, base_url, dir, session_part, extra
+                    )
                 }
                 PanelId::NeovimPane => {
                     let colorscheme_path = td.join("nvim/colors/opencode.lua");
@@ -132,10 +173,7 @@ pub(super) fn popout_panels(app: &mut App) {
 
         if spawned.is_empty() {
             app.pre_popout_state = None;
-            app.toast_message = Some((
-                "Failed to spawn external windows".into(),
-                std::time::Instant::now(),
-            ));
+            app.toasts.error("Failed to spawn external windows");
             return;
         }
 
@@ -151,18 +189,93 @@ pub(super) fn popout_panels(app: &mut App) {
         app.popout_windows = spawned;
         app.popout_mode = true;
         let count = panels_to_popout.len();
-        app.toast_message = Some((
-            format!(
-                "{} panel{} popped out — Space+w+w to restore",
-                count,
-                if count == 1 { "" } else { "s" }
-            ),
-            std::time::Instant::now(),
+        app.toasts.success(format!(
+            "{} panel{} popped out — Space+w+w to restore",
+            count,
+            if count == 1 { "" } else { "s" }
         ));
     }
     resize_ptys(app);
 }
 
+/// Open the `path[:line]` under the cursor of the focused terminal, opencode
+/// or git pane in `settings.external_editor`, in a new terminal window.
+/// The cursor's row is tried first, then the rest of the screen from the
+/// bottom up; only paths that exist (absolute or under the project) count.
+pub(super) fn open_in_external_editor(app: &mut App) {
+    let Some(editor) = app
+        .config
+        .settings
+        .external_editor
+        .clone()
+        .filter(|e| !e.trim().is_empty())
+    else {
+        app.toasts
+            .warning("Set external_editor in config.toml first");
+        return;
+    };
+    let Some(project) = app.active_project() else {
+        return;
+    };
+    let pty = match app.layout.focused {
+        PanelId::TerminalPane => project.active_pty(),
+        PanelId::IntegratedTerminal => project
+            .active_resources()
+            .and_then(|r| r.shell_ptys.get(r.active_shell_tab)),
+        PanelId::GitPanel => project.gitui_pty.as_ref(),
+        _ => None,
+    };
+    let Some((file, line)) = pty.and_then(|pty| file_ref_on_screen(pty, &project.path)) else {
+        app.toasts.warning("No file reference in the focused pane");
+        return;
+    };
+
+    let cmd = editor_command(&editor, &file, line);
+    let project_dir = project.path.clone();
+    let theme_envs = app.theme.pty_env_vars();
+    match spawn_external_terminal(&project_dir, &cmd, "Editor", &theme_envs) {
+        // The editor outlives opman; reap the launcher without blocking.
+        Some(mut child) => {
+            std::thread::spawn(move || child.wait());
+        }
+        None => {
+            app.toasts.error("Failed to open an external terminal");
+        }
+    }
+}
+
+/// First existing file referenced on `pty`'s screen, searching the cursor
+/// row and then the remaining rows bottom-up.
+fn file_ref_on_screen(
+    pty: &crate::pty::PtyInstance,
+    root: &std::path::Path,
+) -> Option<(std::path::PathBuf, Option<i64>)> {
+    let parser = pty.parser.lock().ok()?;
+    let screen = parser.screen();
+    let rows: Vec<String> = screen.rows(0, screen.size().1).collect();
+    let cursor_row = (screen.cursor_position().0 as usize).min(rows.len().saturating_sub(1));
+    let order =
+        std::iter::once(cursor_row).chain((0..rows.len()).rev().filter(|&r| r != cursor_row));
+    order.filter_map(|r| rows.get(r)).find_map(|row| {
+        crate::app::helpers::file_refs(row)
+            .map(|(path, line)| (root.join(path), line))
+            .find(|(path, _)| path.is_file())
+    })
+}
+
+/// Shell command opening `file` at `line` with `editor`: `{file}` and
+/// `{line}` are substituted, or the file is appended if there's no `{file}`.
+fn editor_command(editor: &str, file: &std::path::Path, line: Option<i64>) -> String {
+    let file = shell_escape(file);
+    if editor.contains("{file}") {
+        editor
+            .replace("{file}", &file)
+            .replace("{line}", &line.unwrap_or(1).to_string())
+    } else {
+        format!("{editor} {file}")
+    }
+}
+
 fn spawn_external_terminal(
     cwd: &std::path::Path,
     command: &str,
@@ -185,34 +298,15 @@ fn spawn_external_terminal(
 
     if term_program.contains("iTerm") {
         spawn_iterm2(cwd, &shell_cmd, title)
-    } else if term_program.contains("Alacritty") || which_exists("alacritty") {
+    } else if term_program.contains("Alacritty") || crate::util::which("alacritty").is_some() {
         spawn_alacritty(cwd, &shell_cmd, title)
-    } else if term_program.contains("WezTerm") || which_exists("wezterm") {
+    } else if term_program.contains("WezTerm") || crate::util::which("wezterm").is_some() {
         spawn_wezterm(cwd, &shell_cmd, title)
     } else {
         spawn_macos_terminal(cwd, &shell_cmd, title)
     }
 }
 
-fn shell_escape(path: &std::path::Path) -> String {
-    let s = path.to_string_lossy();
-    if s.contains(' ') || s.contains('\'') || s.contains('"') || s.contains('\\') {
-        format!("'{}'", s.replace('\'', "'\\''"))
-    } else {
-        s.to_string()
-    }
-}
-
-fn which_exists(name: &str) -> bool {
-    std::process::Command::new("which")
-        .arg(name)
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
-}
-
 fn spawn_macos_terminal(
     _cwd: &std::path::Path,
     shell_cmd: &str,
@@ -297,3 +391,47 @@ pub(super) fn kill_process_tree(mut child: std::process::Child) {
     let _ = child.kill();
     let _ = child.wait();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_command() {
+        let file = std::path::Path::new("/repo/src/main.rs");
+        assert_eq!(
+            editor_command("hx {file}:{line}", file, Some(12)),
+            "hx /repo/src/main.rs:12"
+        );
+        assert_eq!(
+            editor_command("emacs +{line} {file}", file, None),
+            "emacs +1 /repo/src/main.rs"
+        );
+        assert_eq!(
+            editor_command("code -g", std::path::Path::new("/my repo/a.rs"), Some(3)),
+            "code -g '/my repo/a.rs'"
+        );
+    }
+
+    #[test]
+    fn test_presentation_restores_zen() {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut app = App::new_for_test(crate::config::Config::default(), tx);
+        zen_panel(&mut app, PanelId::GitPanel);
+
+        toggle_presentation(&mut app);
+        assert!(app.presentation_mode);
+        toggle_presentation(&mut app);
+        assert!(!app.presentation_mode);
+        assert!(app.zen_mode);
+        assert!(app.layout.is_visible(PanelId::GitPanel));
+        assert!(!app.layout.is_visible(PanelId::Sidebar));
+
+        // Without zen before, leaving turns it off.
+        zen_panel(&mut app, PanelId::GitPanel);
+        toggle_presentation(&mut app);
+        toggle_presentation(&mut app);
+        assert!(!app.zen_mode);
+        assert!(app.layout.is_visible(PanelId::Sidebar));
+    }
+}
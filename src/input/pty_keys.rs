@@ -261,6 +261,8 @@ pub(super) fn handle_git_panel_keys(app: &mut App, key: KeyEvent) -> Result<()>
             if !bytes.is_empty() {
                 gitui_pty.write(&bytes)?;
             }
+            // Staging, committing or discarding in gitui changes the diff.
+            project.diff_stat_stale = true;
             return Ok(());
         }
     }
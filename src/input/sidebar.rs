@@ -41,11 +41,7 @@ pub(super) fn handle_sidebar_keys(app: &mut App, key: KeyEvent) -> Result<()> {
                     if app.active_project != idx {
                         app.switch_project(idx);
                     }
-                    if app.sessions_expanded_for == Some(idx) {
-                        app.sessions_expanded_for = None;
-                    } else {
-                        app.sessions_expanded_for = Some(idx);
-                    }
+                    app.toggle_sessions_expanded(idx);
                 }
                 Some(SidebarItem::NewSession(proj_idx)) => {
                     if app.active_project != proj_idx {
@@ -129,11 +125,7 @@ pub(super) fn handle_sidebar_keys(app: &mut App, key: KeyEvent) -> Result<()> {
                 app.sidebar_item_at(app.sidebar_cursor)
             {
                 let sid = session_id.clone();
-                if app.subagents_expanded_for.as_deref() == Some(&sid) {
-                    app.subagents_expanded_for = None;
-                } else {
-                    app.subagents_expanded_for = Some(sid);
-                }
+                app.toggle_subagents(sid);
             }
         }
         crossterm::event::KeyCode::Char('r') => {
@@ -165,6 +157,12 @@ pub(super) fn handle_sidebar_keys(app: &mut App, key: KeyEvent) -> Result<()> {
                 _ => {}
             }
         }
+        crossterm::event::KeyCode::Char('p') => {
+            app.sidebar_pending_g = false;
+            if let Some(SidebarItem::Project(idx)) = app.sidebar_item_at(app.sidebar_cursor) {
+                app.toggle_project_pin(idx)?;
+            }
+        }
         crossterm::event::KeyCode::Char('d') => {
             app.sidebar_pending_g = false;
             if let Some(SidebarItem::Project(idx)) = app.sidebar_item_at(app.sidebar_cursor) {
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use crossterm::event::{KeyCode, KeyEvent};
 
+use super::list_nav::{apply_list_motion, list_motion};
 use crate::app::App;
 
 pub(super) fn handle_todo_panel_keys(app: &mut App, key: KeyEvent) -> Result<()> {
@@ -14,6 +15,18 @@ pub(super) fn handle_todo_panel_keys(app: &mut App, key: KeyEvent) -> Result<()>
         return handle_todo_edit_keys(app, key);
     }
 
+    if let Some(motion) = list_motion(&key, Some(&mut app.list_pending_g)) {
+        if let Some(ref mut state) = app.todo_panel {
+            (state.selected, state.scroll_offset) = apply_list_motion(
+                motion,
+                state.todos.len(),
+                state.selected,
+                state.scroll_offset,
+            );
+        }
+        return Ok(());
+    }
+
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.close_todo_panel();
@@ -92,12 +105,7 @@ pub(super) fn handle_todo_panel_keys(app: &mut App, key: KeyEvent) -> Result<()>
         KeyCode::Char('p') => {
             if let Some(ref mut state) = app.todo_panel {
                 if let Some(todo) = state.todos.get_mut(state.selected) {
-                    todo.priority = match todo.priority.as_str() {
-                        "low" => "medium",
-                        "medium" => "high",
-                        _ => "low",
-                    }
-                    .to_string();
+                    todo.priority = crate::app::next_priority(&todo.priority).to_string();
                     state.dirty = true;
                     let session_id = state.session_id.clone();
                     let todos = state.todos.clone();
@@ -141,19 +149,15 @@ pub(super) fn handle_todo_panel_keys(app: &mut App, key: KeyEvent) -> Result<()>
                 }
             }
         }
+        KeyCode::Char('o') => {
+            app.reveal_selected_todo();
+        }
         KeyCode::Char('y') => {
             if let Some(ref state) = app.todo_panel {
                 if let Some(todo) = state.todos.get(state.selected) {
-                    let _ = std::process::Command::new("pbcopy")
-                        .stdin(std::process::Stdio::piped())
-                        .spawn()
-                        .and_then(|mut child| {
-                            use std::io::Write;
-                            if let Some(ref mut stdin) = child.stdin {
-                                stdin.write_all(todo.content.as_bytes())?;
-                            }
-                            child.wait()
-                        });
+                    if let Err(e) = crate::clipboard::copy_to_clipboard(&todo.content) {
+                        app.toasts.error(format!("Copy failed: {e:#}"));
+                    }
                 }
             }
         }
@@ -210,6 +214,14 @@ pub(super) fn handle_todo_edit_keys(app: &mut App, key: KeyEvent) -> Result<()>
                 state.editing = None;
             }
         }
+        KeyCode::Tab => {
+            if let Some(ref mut state) = app.todo_panel {
+                if let Some(ref mut editing) = state.editing {
+                    // Saved with the content on Enter; Esc drops it.
+                    editing.priority = crate::app::next_priority(&editing.priority).to_string();
+                }
+            }
+        }
         KeyCode::Char(c) => {
             if let Some(ref mut state) = app.todo_panel {
                 if let Some(ref mut editing) = state.editing {
@@ -36,7 +36,10 @@ pub(super) fn open_watcher_modal(app: &mut App) {
             continue;
         }
         // Find the project/session info
-        let project_idx = app.session_ownership.get(sid).copied();
+        let project_idx = app
+            .session_ownership
+            .get(sid)
+            .and_then(|path| app.project_index(path));
         if let Some(pidx) = project_idx {
             if let Some(project) = app.projects.get(pidx) {
                 if let Some(s) = project.sessions.iter().find(|s| s.id == *sid) {
@@ -245,10 +248,8 @@ pub(super) fn submit_watcher(app: &mut App) {
 
     let msg = modal.message_text();
     if msg.trim().is_empty() {
-        app.toast_message = Some((
-            "Watcher not added: continuation message is empty".into(),
-            std::time::Instant::now(),
-        ));
+        app.toasts
+            .warning("Watcher not added: continuation message is empty");
         return;
     }
 
@@ -277,9 +278,9 @@ pub(super) fn submit_watcher(app: &mut App) {
     };
 
     app.session_watchers.insert(session_id.clone(), config);
-    app.toast_message = Some((
-        format!("Watcher added for session ({}s timeout)", timeout_secs),
-        std::time::Instant::now(),
+    app.toasts.success(format!(
+        "Watcher added for session ({}s timeout)",
+        timeout_secs
     ));
 
     // If the session is NOT currently active (already idle), trigger the watcher
@@ -45,8 +45,7 @@ pub(super) fn handle_watcher_session_list_keys(app: &mut App, key: KeyEvent) ->
                                 entry.has_watcher = false;
                             }
                         }
-                        app.toast_message =
-                            Some(("Watcher removed".into(), std::time::Instant::now()));
+                        app.toasts.success("Watcher removed");
                     }
                 }
             }
@@ -3,6 +3,7 @@ mod app;
 mod background_tasks;
 mod blockkit;
 mod cli;
+mod clipboard;
 mod command_palette;
 mod config;
 mod event_input;
@@ -24,6 +25,7 @@ mod server;
 use integrations::slack;
 mod setup;
 mod sse;
+mod status_server;
 mod theme;
 mod theme_gen;
 mod todo_db;
@@ -34,6 +36,8 @@ mod web;
 mod which_key;
 
 use std::io;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::{Context, Result};
 use clap::Parser;
@@ -44,6 +48,7 @@ use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use crossterm::ExecutableCommand;
+use futures::FutureExt;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use tokio::sync::mpsc;
@@ -101,14 +106,46 @@ async fn handle_skills(subcommand: SkillsCommands) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Put the host terminal back the way opman found it: raw mode off, main
+/// screen, mouse capture and bracketed paste off, default cursor. Safe to
+/// call more than once.
+fn restore_terminal() {
+    disable_raw_mode().ok();
+    let mut stdout = io::stdout();
+    stdout.execute(DisableBracketedPaste).ok();
+    stdout.execute(DisableMouseCapture).ok();
+    stdout.execute(LeaveAlternateScreen).ok();
+    stdout
+        .execute(crossterm::cursor::SetCursorStyle::DefaultUserShape)
+        .ok();
+    stdout.execute(crossterm::cursor::Show).ok();
+}
+
+/// Set by the panic hook; the event loop stops on it so the usual
+/// shutdown (PTYs, MCP sockets, opencode.json) still runs.
+pub(crate) static PANICKED: AtomicBool = AtomicBool::new(false);
+
+/// On a panic in any thread, restore the terminal and stop the opencode
+/// server before the panic message is printed, then have the event loop
+/// quit: the screen is no longer the TUI's once the terminal is restored.
+fn install_panic_hook(server_handle: server::ServerHandle) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        PANICKED.store(true, Ordering::Release);
+        restore_terminal();
+        server::kill_server(&server_handle);
+        tracing::error!("opman panicked: {}", info);
+        default_hook(info);
+    }));
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Always log to file: <config_dir>/opman/opman.log
-    let log_dir = dirs::config_dir()
-        .expect("Could not determine config directory")
-        .join("opman");
-    std::fs::create_dir_all(&log_dir).expect("Failed to create log directory");
-    let log_path = log_dir.join("opman.log");
+    let log_path = Config::log_path().expect("Could not determine config directory");
+    if let Some(log_dir) = log_path.parent() {
+        std::fs::create_dir_all(log_dir).expect("Failed to create log directory");
+    }
     let log_file = std::fs::OpenOptions::new()
         .create(true)
         .append(true)
@@ -174,6 +211,7 @@ async fn main() -> Result<()> {
     let web_user = cli.web_user.unwrap_or_default();
     let web_pass = cli.web_pass.unwrap_or_default();
     let web_only = cli.web_only;
+    let status_port = cli.status_port;
 
     // Derive instance name from --tunnel-hostname for the web UI page title.
     // e.g. "myapp.example.com" → "Myapp", "example.com" → "Example"
@@ -317,10 +355,19 @@ async fn main() -> Result<()> {
         println!("Press Ctrl+C to stop.");
         tokio::signal::ctrl_c().await.ok();
 
-        // Clean up MCP socket files
+        // Clean up MCP socket files and opman's opencode.json entries
         if enable_any_mcp {
             for project in &app.projects {
                 mcp::cleanup_socket(&project.path);
+                if let Err(e) =
+                    mcp::write_opencode_json(&project.path, false, false, false, false, None, false)
+                {
+                    tracing::warn!(
+                        "Failed to clean opencode.json for {}: {}",
+                        project.path.display(),
+                        e
+                    );
+                }
             }
         }
 
@@ -329,15 +376,23 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Start the local status endpoint (if requested)
+    setup::setup_status_endpoint(status_port, &server_handle, &mut app).await;
+
+    // A panic must not leave the user's terminal broken
+    install_panic_hook(server_handle.clone());
+
     // Setup terminal — TUI renders IMMEDIATELY after this
     enable_raw_mode().context("Failed to enable raw mode")?;
     let mut stdout = io::stdout();
     stdout
         .execute(EnterAlternateScreen)
         .context("Failed to enter alternate screen")?;
-    stdout
-        .execute(EnableMouseCapture)
-        .context("Failed to enable mouse capture")?;
+    if app.config.settings.mouse_capture {
+        stdout
+            .execute(EnableMouseCapture)
+            .context("Failed to enable mouse capture")?;
+    }
     stdout
         .execute(EnableBracketedPaste)
         .context("Failed to enable bracketed paste")?;
@@ -361,17 +416,27 @@ async fn main() -> Result<()> {
     // Start Slack integration if enabled
     setup::setup_slack(&mut app);
 
-    // Main event loop — TUI renders on first iteration (instant startup!)
-    let result =
-        event_loop::run_event_loop(&mut terminal, &mut app, watcher_rx, bg_rx, web_state_handle)
-            .await;
+    // Main event loop — TUI renders on first iteration (instant startup!).
+    // A panic in it is caught so the cleanup below still runs.
+    let result = AssertUnwindSafe(event_loop::run_event_loop(
+        &mut terminal,
+        &mut app,
+        watcher_rx,
+        bg_rx,
+        web_state_handle,
+    ))
+    .catch_unwind()
+    .await
+    .unwrap_or_else(|_| Err(anyhow::anyhow!("opman panicked")));
+
+    // Remember panel layout for the next launch, unless a panic may have
+    // left it half-updated
+    if !PANICKED.load(Ordering::Acquire) {
+        app.save_ui_state();
+    }
 
     // Cleanup (always runs, even if event loop errored)
-    disable_raw_mode().ok();
-    terminal.backend_mut().execute(DisableBracketedPaste).ok();
-    terminal.backend_mut().execute(DisableMouseCapture).ok();
-    terminal.backend_mut().execute(LeaveAlternateScreen).ok();
-    terminal.show_cursor().ok();
+    restore_terminal();
 
     server::shutdown_all_ptys(&mut app.projects);
     server::kill_server(&server_handle);
@@ -382,14 +447,26 @@ async fn main() -> Result<()> {
         let _ = child.wait();
     }
 
-    // Clean up MCP socket files
+    // Clean up MCP socket files and opman's opencode.json entries
     if enable_any_mcp {
         for project in &app.projects {
             mcp::cleanup_socket(&project.path);
+            if let Err(e) =
+                mcp::write_opencode_json(&project.path, false, false, false, false, None, false)
+            {
+                tracing::warn!(
+                    "Failed to clean opencode.json for {}: {}",
+                    project.path.display(),
+                    e
+                );
+            }
         }
     }
 
     info!("opman shut down");
 
+    if result.is_ok() && PANICKED.load(Ordering::Acquire) {
+        anyhow::bail!("opman panicked");
+    }
     result
 }
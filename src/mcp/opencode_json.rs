@@ -1,25 +1,81 @@
 use std::path::{Path, PathBuf};
 
-use tracing::info;
+use anyhow::Context;
+use tracing::{info, warn};
 
 // ─── opencode.json auto-generation ──────────────────────────────────────────
 
+/// Subcommands opman's `mcp` entries run.
+const MCP_SUBCOMMANDS: [&str; 4] = ["mcp", "mcp-nvim", "mcp-time", "mcp-ui"];
+
 /// Write (or update) the opencode.json file for a project to include the MCP server configs.
+///
+/// An existing file is merged into rather than replaced: unrelated keys are
+/// kept, and a disabled server's entry is only removed if opman wrote it.
+/// A user-defined server under one of opman's names is left alone. The file
+/// is not created just to record that every server is off.
+///
+/// Tools that need an op outside `allowed_ops` are switched off in the
+/// `tools` map so the agent doesn't see them at all; the socket server
+/// rejects those ops regardless.
+///
+/// `permission` entries opman adds are recorded outside the file, so that
+/// removing them takes exactly those and no user setting. With
+/// `keep_denials` (MCP paused, not exiting) they stay for servers that are
+/// off, so the agent doesn't regain its native tools.
 pub fn write_opencode_json(
     project_path: &Path,
     enable_terminal: bool,
     enable_neovim: bool,
     enable_time: bool,
     enable_ui: bool,
+    allowed_ops: Option<&[String]>,
+    keep_denials: bool,
+) -> anyhow::Result<()> {
+    let record_path = crate::config::Config::opencode_permissions_path(project_path)?;
+    merge_opencode_json(
+        project_path,
+        &record_path,
+        enable_terminal,
+        enable_neovim,
+        enable_time,
+        enable_ui,
+        allowed_ops,
+        keep_denials,
+    )
+}
+
+/// `write_opencode_json` with the permission record at `record_path`.
+#[allow(clippy::too_many_arguments)]
+fn merge_opencode_json(
+    project_path: &Path,
+    record_path: &Path,
+    enable_terminal: bool,
+    enable_neovim: bool,
+    enable_time: bool,
+    enable_ui: bool,
+    allowed_ops: Option<&[String]>,
+    keep_denials: bool,
 ) -> anyhow::Result<()> {
     let json_path = project_path.join("opencode.json");
+    let any_enabled = enable_terminal || enable_neovim || enable_time || enable_ui;
 
-    // Read existing config or start fresh
-    let mut config: serde_json::Value = if json_path.exists() {
-        let content = std::fs::read_to_string(&json_path)?;
-        serde_json::from_str(&content).unwrap_or(serde_json::json!({}))
+    // Read existing config or start fresh. A file we can't parse is the
+    // user's to fix; overwriting it would lose their config.
+    let original = if json_path.exists() {
+        Some(std::fs::read_to_string(&json_path)?)
+    } else if any_enabled {
+        None
     } else {
-        serde_json::json!({})
+        return Ok(());
+    };
+    let mut config: serde_json::Value = match &original {
+        Some(content) => serde_json::from_str(content)
+            .with_context(|| format!("{} is not valid JSON", json_path.display()))?,
+        None => serde_json::json!({}),
+    };
+    let Some(root) = config.as_object_mut() else {
+        anyhow::bail!("{} is not a JSON object", json_path.display());
     };
 
     // Get the current executable path for the MCP command
@@ -29,83 +85,138 @@ pub fn write_opencode_json(
     let project_path_str = project_path.to_string_lossy().to_string();
 
     // Set mcp.* configs based on enabled flags
-    let mcp = config
-        .as_object_mut()
-        .map(|obj| obj.entry("mcp").or_insert(serde_json::json!({})))
-        .unwrap();
-
+    let servers = [
+        (
+            "terminal",
+            enable_terminal,
+            vec![exe_str.as_str(), "mcp", project_path_str.as_str()],
+        ),
+        (
+            "neovim",
+            enable_neovim,
+            vec![exe_str.as_str(), "mcp-nvim", project_path_str.as_str()],
+        ),
+        ("time", enable_time, vec![exe_str.as_str(), "mcp-time"]),
+        ("ui", enable_ui, vec![exe_str.as_str(), "mcp-ui"]),
+    ];
+    let mcp = root.entry("mcp").or_insert(serde_json::json!({}));
     if let Some(mcp_obj) = mcp.as_object_mut() {
-        if enable_terminal {
-            mcp_obj.insert(
-                "terminal".to_string(),
-                serde_json::json!({
-                    "type": "local",
-                    "command": [&exe_str, "mcp", &project_path_str]
-                }),
-            );
-        } else {
-            mcp_obj.remove("terminal");
+        for (name, enabled, command) in servers {
+            if let Some(existing) = mcp_obj.get(name) {
+                if !is_opman_entry(existing, &exe_str) {
+                    if enabled {
+                        warn!(
+                            "Keeping user-defined MCP server {name:?} in {}",
+                            json_path.display()
+                        );
+                    }
+                    continue;
+                }
+            }
+            if enabled {
+                mcp_obj.insert(
+                    name.to_string(),
+                    serde_json::json!({ "type": "local", "command": command }),
+                );
+            } else {
+                mcp_obj.remove(name);
+            }
         }
-        if enable_neovim {
-            mcp_obj.insert(
-                "neovim".to_string(),
-                serde_json::json!({
-                    "type": "local",
-                    "command": [&exe_str, "mcp-nvim", &project_path_str]
-                }),
-            );
-        } else {
-            mcp_obj.remove("neovim");
+    }
+    if root
+        .get("mcp")
+        .and_then(|m| m.as_object())
+        .is_some_and(|m| m.is_empty())
+    {
+        root.remove("mcp");
+    }
+
+    // Hide tools the project's allowlist denies. opencode names MCP tools
+    // `<server>_<tool>`; entries from a previous run are cleared first.
+    let mut denied = Vec::new();
+    if enable_terminal {
+        let defs = super::tool_defs::mcp_tool_definitions();
+        for tool in super::permissions::denied_tools(allowed_ops, &defs) {
+            denied.push(format!("terminal_{tool}"));
         }
-        if enable_time {
-            mcp_obj.insert(
-                "time".to_string(),
-                serde_json::json!({
-                    "type": "local",
-                    "command": [&exe_str, "mcp-time"]
-                }),
-            );
-        } else {
-            mcp_obj.remove("time");
+    }
+    if enable_neovim {
+        let defs = crate::mcp_neovim::tool_definitions();
+        for tool in super::permissions::denied_tools(allowed_ops, &defs) {
+            denied.push(format!("neovim_{tool}"));
         }
-        if enable_ui {
-            mcp_obj.insert(
-                "ui".to_string(),
-                serde_json::json!({
-                    "type": "local",
-                    "command": [&exe_str, "mcp-ui"]
-                }),
-            );
-        } else {
-            mcp_obj.remove("ui");
+    }
+    let tools = root.entry("tools").or_insert(serde_json::json!({}));
+    if let Some(tools_obj) = tools.as_object_mut() {
+        tools_obj.retain(|k, _| {
+            !k.starts_with("terminal_terminal_") && !k.starts_with("neovim_neovim_")
+        });
+        for key in denied {
+            tools_obj.insert(key, serde_json::json!(false));
         }
     }
+    if root
+        .get("tools")
+        .and_then(|t| t.as_object())
+        .is_some_and(|t| t.is_empty())
+    {
+        root.remove("tools");
+    }
 
-    // Disable opencode's native bash tool so it uses the manager's terminal instead
-    if enable_terminal {
-        let permission = config
-            .as_object_mut()
-            .map(|obj| obj.entry("permission").or_insert(serde_json::json!({})))
-            .unwrap();
-        if let Some(perm_obj) = permission.as_object_mut() {
-            perm_obj.insert("bash".to_string(), serde_json::json!("deny"));
+    // Deny opencode's native bash tool so it uses the manager's terminal
+    // instead, and with neovim MCP its edit tool, since the AI edits files
+    // through neovim directly. A value the user set is kept. A denial opman
+    // added is dropped once its server is off (unless `keep_denials`), and
+    // never if the user changed it.
+    let deny = serde_json::json!("deny");
+    let wanted: Vec<&str> = [("bash", enable_terminal), ("edit", enable_neovim)]
+        .into_iter()
+        .filter_map(|(key, enabled)| enabled.then_some(key))
+        .collect();
+    let mut added = read_permission_record(record_path);
+    if !wanted.is_empty() {
+        root.entry("permission").or_insert(serde_json::json!({}));
+    }
+    match root.get_mut("permission").and_then(|p| p.as_object_mut()) {
+        Some(perm_obj) => {
+            added.retain(|key| {
+                let ours = perm_obj.get(key) == Some(&deny);
+                if ours && !keep_denials && !wanted.contains(&key.as_str()) {
+                    perm_obj.remove(key);
+                    return false;
+                }
+                ours
+            });
+            for key in wanted {
+                if !perm_obj.contains_key(key) {
+                    perm_obj.insert(key.to_string(), deny.clone());
+                    added.push(key.to_string());
+                }
+            }
         }
+        None => added.clear(),
+    }
+    if root
+        .get("permission")
+        .and_then(|p| p.as_object())
+        .is_some_and(|p| p.is_empty())
+    {
+        root.remove("permission");
     }
 
-    // When neovim MCP is enabled, disable opencode's native edit/write tools
-    // since the AI edits files through neovim directly.
-    if enable_neovim {
-        let permission = config
-            .as_object_mut()
-            .map(|obj| obj.entry("permission").or_insert(serde_json::json!({})))
-            .unwrap();
-        if let Some(perm_obj) = permission.as_object_mut() {
-            perm_obj.insert("edit".to_string(), serde_json::json!("deny"));
-        }
+    // Leave the file untouched (formatting included) when nothing changed.
+    let unchanged = original
+        .as_deref()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(content).ok())
+        .is_some_and(|before| before == config);
+    if unchanged {
+        write_permission_record(record_path, &added);
+        return Ok(());
     }
 
     let formatted = serde_json::to_string_pretty(&config)?;
     std::fs::write(&json_path, formatted)?;
+    write_permission_record(record_path, &added);
     info!(
         ?json_path,
         enable_terminal,
@@ -117,3 +228,138 @@ pub fn write_opencode_json(
 
     Ok(())
 }
+
+/// `permission` keys opman added to a project's opencode.json.
+fn read_permission_record(record_path: &Path) -> Vec<String> {
+    std::fs::read_to_string(record_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Save the `permission` keys opman added, or delete the record when there
+/// are none. A failure is only logged: the worst case is a denial left in
+/// opencode.json.
+fn write_permission_record(record_path: &Path, added: &[String]) {
+    let result = if added.is_empty() {
+        match std::fs::remove_file(record_path) {
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            other => other,
+        }
+    } else {
+        record_path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(record_path, serde_json::json!(added).to_string()))
+    };
+    if let Err(e) = result {
+        warn!(path = %record_path.display(), "Failed to save opencode.json permission record: {e}");
+    }
+}
+
+/// Whether an `mcp` entry is one opman wrote: a local server whose command
+/// runs this executable (or an `opman` on PATH) with one of opman's MCP
+/// subcommands.
+fn is_opman_entry(entry: &serde_json::Value, exe_str: &str) -> bool {
+    let Some(command) = entry.get("command").and_then(|c| c.as_array()) else {
+        return false;
+    };
+    let program = command.first().and_then(|p| p.as_str()).unwrap_or_default();
+    let subcommand = command.get(1).and_then(|s| s.as_str()).unwrap_or_default();
+    let is_opman =
+        program == exe_str || Path::new(program).file_name() == Some(std::ffi::OsStr::new("opman"));
+    is_opman && MCP_SUBCOMMANDS.contains(&subcommand)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(path: &Path) -> serde_json::Value {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_merge_preserves_user_config() {
+        let dir = std::env::temp_dir().join(format!("opman-opencode-json-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let json_path = dir.join("opencode.json");
+        let record = dir.join("record.json");
+        let user = serde_json::json!({
+            "$schema": "https://opencode.ai/config.json",
+            "model": "anthropic/some-model",
+            "mcp": {
+                "github": { "type": "remote", "url": "https://example.com/mcp" },
+                "time": { "type": "local", "command": ["my-time-server"] }
+            },
+            "tools": { "webfetch": false }
+        });
+        std::fs::write(&json_path, serde_json::to_string_pretty(&user).unwrap()).unwrap();
+
+        merge_opencode_json(&dir, &record, true, false, true, true, None, false).unwrap();
+        let merged = read(&json_path);
+        assert_eq!(merged["model"], user["model"]);
+        assert_eq!(merged["$schema"], user["$schema"]);
+        assert_eq!(merged["mcp"]["github"], user["mcp"]["github"]);
+        assert_eq!(merged["mcp"]["time"], user["mcp"]["time"]);
+        assert_eq!(merged["tools"], user["tools"]);
+        assert_eq!(merged["mcp"]["terminal"]["command"][1], "mcp");
+        assert_eq!(merged["mcp"]["ui"]["command"][1], "mcp-ui");
+        assert_eq!(merged["permission"]["bash"], "deny");
+
+        // Pausing MCP drops opman's servers but keeps the denial.
+        merge_opencode_json(&dir, &record, false, false, false, false, None, true).unwrap();
+        let paused = read(&json_path);
+        assert_eq!(paused["mcp"], user["mcp"]);
+        assert_eq!(paused["permission"]["bash"], "deny");
+
+        merge_opencode_json(&dir, &record, false, false, false, false, None, false).unwrap();
+        let cleaned = read(&json_path);
+        assert_eq!(cleaned["mcp"], user["mcp"]);
+        assert_eq!(cleaned["model"], user["model"]);
+        assert_eq!(cleaned["tools"], user["tools"]);
+        assert!(cleaned.get("permission").is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_json_is_left_alone() {
+        let dir = std::env::temp_dir().join(format!("opman-opencode-bad-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let json_path = dir.join("opencode.json");
+        std::fs::write(&json_path, "{ // comment\n}").unwrap();
+
+        let record = dir.join("record.json");
+        assert!(
+            merge_opencode_json(&dir, &record, true, false, false, false, None, false).is_err()
+        );
+        assert_eq!(
+            std::fs::read_to_string(&json_path).unwrap(),
+            "{ // comment\n}"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_user_permissions_are_kept() {
+        let dir = std::env::temp_dir().join(format!("opman-opencode-perm-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let json_path = dir.join("opencode.json");
+        let record = dir.join("record.json");
+        let user = serde_json::json!({ "permission": { "bash": "ask", "webfetch": "deny" } });
+        std::fs::write(&json_path, user.to_string()).unwrap();
+
+        merge_opencode_json(&dir, &record, true, true, false, false, None, false).unwrap();
+        let merged = read(&json_path);
+        assert_eq!(merged["permission"]["bash"], "ask");
+        assert_eq!(merged["permission"]["edit"], "deny");
+
+        merge_opencode_json(&dir, &record, false, false, false, false, None, false).unwrap();
+        assert_eq!(read(&json_path), user);
+        assert!(!record.exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,124 @@
+// ─── Per-project op allowlist ───────────────────────────────────────────────
+
+/// Bookkeeping ops issued by the bridge itself rather than on behalf of a
+/// tool. They touch no terminal or buffer state, so they are never denied.
+const INTERNAL_OPS: &[&str] = &["ephemeral_lock", "ephemeral_unlock"];
+
+/// Ops that come with an allowed op because its tools issue them
+/// internally: a `run` that waits polls `status` and reads the output back.
+const IMPLIED_OPS: &[(&str, &[&str])] = &[("run", &["status", "read"])];
+
+/// Whether `op` is permitted by a project's `mcp_allowed_ops`.
+///
+/// `None` allows everything. Entries match exactly, or by prefix when they
+/// end in `*` (`"nvim_*"`). An op implied by an allowed one (see
+/// [`IMPLIED_OPS`]) is allowed too.
+pub fn op_permitted(allowed: Option<&[String]>, op: &str) -> bool {
+    let Some(allowed) = allowed else {
+        return true;
+    };
+    if INTERNAL_OPS.contains(&op) || listed(allowed, op) {
+        return true;
+    }
+    IMPLIED_OPS
+        .iter()
+        .any(|(by, implied)| implied.contains(&op) && listed(allowed, by))
+}
+
+fn listed(allowed: &[String], op: &str) -> bool {
+    allowed.iter().any(|pat| match pat.strip_suffix('*') {
+        Some(prefix) => op.starts_with(prefix),
+        None => pat == op,
+    })
+}
+
+/// Socket ops a bridge tool issues. `terminal_X` and `project_X` map to
+/// op `X`, `neovim_X` to `nvim_X`; the run tools also issue the ops `run`
+/// implies.
+fn tool_ops(tool: &str) -> Vec<String> {
+    match tool {
+        "terminal_run" => return ["run", "status", "read"].map(String::from).to_vec(),
+        "terminal_ephemeral_run" => {
+            return ["new", "run", "status", "read", "close"]
+                .map(String::from)
+                .to_vec()
+        }
+        _ => {}
+    }
+    if let Some(op) = tool
+        .strip_prefix("terminal_")
+        .or_else(|| tool.strip_prefix("project_"))
+    {
+        return vec![op.to_string()];
+    }
+    if let Some(op) = tool.strip_prefix("neovim_") {
+        return vec![format!("nvim_{op}")];
+    }
+    Vec::new()
+}
+
+/// Names of the tools in `defs` (a `tools/list` array) that need an op the
+/// allowlist denies.
+pub(crate) fn denied_tools(allowed: Option<&[String]>, defs: &serde_json::Value) -> Vec<String> {
+    defs.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|d| d.get("name").and_then(|n| n.as_str()))
+        .filter(|name| {
+            tool_ops(name)
+                .iter()
+                .any(|op| !op_permitted(allowed, op))
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_op_permitted_patterns() {
+        let allowed = vec!["read".to_string(), "list".to_string(), "nvim_*".to_string()];
+        let allowed = Some(allowed.as_slice());
+        assert!(op_permitted(allowed, "read"));
+        assert!(op_permitted(allowed, "nvim_hover"));
+        assert!(op_permitted(allowed, "ephemeral_lock"));
+        assert!(!op_permitted(allowed, "run"));
+        assert!(!op_permitted(allowed, "status"));
+        assert!(op_permitted(None, "run"));
+    }
+
+    #[test]
+    fn test_terminal_run_with_run_only_permissions() {
+        let run_only = vec!["run".to_string()];
+        let run_only = Some(run_only.as_slice());
+        // Every op a waiting terminal_run sends, its output read included.
+        for op in tool_ops("terminal_run") {
+            assert!(op_permitted(run_only, &op), "{op} denied");
+        }
+        let defs = serde_json::json!([{"name": "terminal_run"}, {"name": "terminal_read"}]);
+        assert!(denied_tools(run_only, &defs).is_empty());
+        assert!(!op_permitted(run_only, "new"));
+    }
+
+    #[test]
+    fn test_denied_tools() {
+        let defs = serde_json::json!([
+            {"name": "terminal_read"},
+            {"name": "terminal_run"},
+            {"name": "terminal_ephemeral_run"},
+            {"name": "neovim_open"},
+            {"name": "project_files"},
+        ]);
+        let allowed = vec!["read".to_string(), "run".to_string(), "nvim_open".to_string()];
+        assert_eq!(
+            denied_tools(Some(allowed.as_slice()), &defs),
+            vec![
+                "terminal_ephemeral_run".to_string(),
+                "project_files".to_string()
+            ]
+        );
+        assert!(denied_tools(None, &defs).is_empty());
+    }
+}
@@ -10,8 +10,9 @@ use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
 use super::nvim_handler::handle_nvim_op_blocking;
+use super::shutdown::SocketServerControl;
 use super::types::{
-    NvimSocketRegistry, PendingSocketRequest, SocketRequest, SocketResponse,
+    NvimSocketRegistry, PendingSocketRequest, SocketFileState, SocketRequest, SocketResponse,
 };
 
 /// Update MCP activity timestamp.
@@ -26,19 +27,33 @@ fn update_activity(ts: &AtomicU64) {
 /// Spawn the Unix domain socket server for a single project.
 /// Handles concurrency controls (ephemeral dedup, per-file nvim locks,
 /// per-tab terminal locks) and direct nvim dispatch when possible.
+/// Stops accepting connections once `control` signals shutdown.
+/// Ops outside `allowed_ops` (the project's `mcp_allowed_ops`) are rejected
+/// before the direct nvim fast path; the main loop checks them again.
 pub fn spawn_socket_server(
     project_path: &Path,
     request_tx: mpsc::UnboundedSender<crate::app::BackgroundEvent>,
-    project_idx: usize,
     nvim_registry: NvimSocketRegistry,
     last_mcp_activity_ms: Arc<AtomicU64>,
+    control: SocketServerControl,
+    allowed_ops: Option<Vec<String>>,
 ) -> PathBuf {
     let sock_path = super::types::socket_path_for_project(project_path);
 
-    // Remove stale socket file if it exists
-    let _ = std::fs::remove_file(&sock_path);
+    // A crashed opman leaves its socket file behind; binding fails until
+    // it is removed. A live one belongs to another opman and is kept.
+    match super::types::remove_stale_socket(&sock_path) {
+        SocketFileState::Absent => {}
+        SocketFileState::Removed => {
+            info!(?sock_path, "Removed stale MCP socket from an earlier run");
+        }
+        SocketFileState::Live => {
+            warn!(?sock_path, "MCP socket is in use by another process; not replacing it");
+        }
+    }
 
     let sock = sock_path.clone();
+    let project_path = project_path.to_path_buf();
     tokio::spawn(async move {
         let listener = match UnixListener::bind(&sock) {
             Ok(l) => {
@@ -62,8 +77,18 @@ pub fn spawn_socket_server(
         let term_locks: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>> =
             Arc::new(Mutex::new(HashMap::new()));
 
+        let allowed_ops: Option<Arc<[String]>> = allowed_ops.map(Into::into);
+        let mut shutdown_rx = control.subscribe();
+
         loop {
-            let (stream, _) = match listener.accept().await {
+            let accepted = tokio::select! {
+                res = listener.accept() => res,
+                _ = shutdown_rx.wait_for(|stopping| *stopping) => {
+                    info!(?sock, "MCP socket server stopped accepting connections");
+                    break;
+                }
+            };
+            let (stream, _) = match accepted {
                 Ok(conn) => conn,
                 Err(e) => {
                     warn!("MCP socket accept error: {}", e);
@@ -71,16 +96,32 @@ pub fn spawn_socket_server(
                 }
             };
 
+            // Counted until the handler returns so shutdown can drain it.
+            let in_flight = control.track();
+
             let tx = request_tx.clone();
-            let pidx = project_idx;
+            let project = project_path.clone();
             let eph = busy_ephemeral.clone();
             let nvim = nvim_locks.clone();
             let term = term_locks.clone();
             let registry = nvim_registry.clone();
             let activity_ms = last_mcp_activity_ms.clone();
+            let allowed = allowed_ops.clone();
 
             tokio::spawn(async move {
-                handle_connection(stream, tx, pidx, eph, nvim, term, registry, activity_ms).await;
+                handle_connection(
+                    stream,
+                    tx,
+                    project,
+                    eph,
+                    nvim,
+                    term,
+                    registry,
+                    activity_ms,
+                    allowed,
+                )
+                .await;
+                drop(in_flight);
             });
         }
     });
@@ -88,15 +129,17 @@ pub fn spawn_socket_server(
     sock_path
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn handle_connection(
     stream: tokio::net::UnixStream,
     tx: mpsc::UnboundedSender<crate::app::BackgroundEvent>,
-    pidx: usize,
+    project: PathBuf,
     eph: Arc<Mutex<HashSet<String>>>,
     nvim: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>,
     term: Arc<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>>,
     registry: NvimSocketRegistry,
     activity_ms: Arc<AtomicU64>,
+    allowed_ops: Option<Arc<[String]>>,
 ) {
     let (reader, mut writer) = stream.into_split();
     let mut buf_reader = BufReader::new(reader);
@@ -127,6 +170,15 @@ async fn handle_connection(
     // Mark MCP activity on request arrival.
     update_activity(&activity_ms);
 
+    if !super::permissions::op_permitted(allowed_ops.as_deref(), &request.op) {
+        let resp = SocketResponse::err("operation not permitted".into());
+        let _ = writer
+            .write_all(serde_json::to_string(&resp).unwrap().as_bytes())
+            .await;
+        let _ = writer.write_all(b"\n").await;
+        return;
+    }
+
     // Handle ephemeral_lock / ephemeral_unlock directly (no main-loop round-trip)
     match request.op.as_str() {
         "ephemeral_lock" => {
@@ -244,7 +296,7 @@ async fn handle_connection(
         let session_id = request.session_id.clone().unwrap_or_default();
         let nvim_socket = {
             let reg = registry.read().await;
-            reg.get(&(pidx, session_id.clone())).cloned()
+            reg.get(&(project.clone(), session_id.clone())).cloned()
         };
 
         if let Some(nvim_socket) = nvim_socket {
@@ -271,7 +323,7 @@ async fn handle_connection(
     let session_id = request.session_id.clone().unwrap_or_default();
     let pending = PendingSocketRequest { request, reply_tx };
     let _ = tx.send(crate::app::BackgroundEvent::McpSocketRequest {
-        project_idx: pidx,
+        project_path: project,
         session_id,
         pending,
     });
@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// Upper bound on how long shutdown waits for in-flight MCP requests.
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(3);
+
+// ─── Shutdown coordination ──────────────────────────────────────────────────
+
+/// Shared shutdown state for every per-project MCP socket server.
+///
+/// On quit the main loop calls [`begin_shutdown`](Self::begin_shutdown) so the
+/// accept loops stop taking new connections, then keeps servicing
+/// `McpSocketRequest`s until [`in_flight`](Self::in_flight) drops to zero (or
+/// [`DRAIN_TIMEOUT`] elapses) before PTYs are killed.
+#[derive(Clone)]
+pub struct SocketServerControl {
+    shutdown_tx: Arc<watch::Sender<bool>>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl SocketServerControl {
+    pub fn new() -> Self {
+        let (shutdown_tx, _) = watch::channel(false);
+        Self {
+            shutdown_tx: Arc::new(shutdown_tx),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Signal all socket servers to stop accepting new connections.
+    pub fn begin_shutdown(&self) {
+        self.shutdown_tx.send_replace(true);
+    }
+
+    /// Subscribe to the shutdown signal (used by accept loops).
+    pub(super) fn subscribe(&self) -> watch::Receiver<bool> {
+        self.shutdown_tx.subscribe()
+    }
+
+    /// Number of accepted connections that have not yet written a reply.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    /// Mark a connection as in flight until the returned guard is dropped.
+    pub(super) fn track(&self) -> InFlightGuard {
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+        InFlightGuard {
+            counter: self.in_flight.clone(),
+        }
+    }
+}
+
+impl Default for SocketServerControl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Decrements the in-flight counter when a connection handler finishes.
+pub(super) struct InFlightGuard {
+    counter: Arc<AtomicUsize>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.counter.fetch_sub(1, Ordering::AcqRel);
+    }
+}
@@ -117,6 +117,32 @@ pub fn mcp_tool_definitions() -> serde_json::Value {
                 },
                 "required": ["command", "name"]
             }
+        },
+        {
+            "name": "project_files",
+            "description": "List the project's files: git-tracked files (`git ls-files`) in a git repository, otherwise a walk that skips hidden and ignored paths. Paths are relative to the project root. Faster than running `ls`/`find` through terminal_run when orienting in a repository.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "glob": {
+                        "type": "string",
+                        "description": "Optional gitignore-style filter, e.g. \"*.rs\" (any depth) or \"src/**/*.ts\"."
+                    }
+                }
+            }
+        },
+        {
+            "name": "project_gitdiff",
+            "description": "Summarise the project's uncommitted changes: `git diff --stat` for staged and unstaged changes plus untracked files. Set full=true to also get the patches (output is capped at 64 KiB). Use before committing to review what changed.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "full": {
+                        "type": "boolean",
+                        "description": "Include the full diff, not just the --stat summary (default: false)."
+                    }
+                }
+            }
         }
     ])
 }
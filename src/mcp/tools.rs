@@ -94,6 +94,19 @@ pub async fn handle_tool_call(
                 ..Default::default()
             }
         }
+        "project_files" => SocketRequest {
+            op: "files".into(),
+            glob: arguments
+                .get("glob")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            ..Default::default()
+        },
+        "project_gitdiff" => SocketRequest {
+            op: "gitdiff".into(),
+            full: arguments.get("full").and_then(|v| v.as_bool()),
+            ..Default::default()
+        },
         other => {
             return Ok(serde_json::json!([{
                 "type": "text",
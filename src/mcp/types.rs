@@ -8,11 +8,12 @@ use serde::{Deserialize, Serialize};
 
 // ─── Shared neovim socket registry ──────────────────────────────────────────
 
-/// Shared registry of neovim socket paths, keyed by (project_idx, session_id).
+/// Shared registry of neovim socket paths, keyed by (project path, session_id)
+/// so entries stay right as projects are removed and indices shift.
 /// Updated by the main loop when neovim PTYs are spawned; read by socket server
 /// tasks to handle nvim operations directly without round-tripping through the
 /// main event loop.
-pub type NvimSocketRegistry = Arc<tokio::sync::RwLock<HashMap<(usize, String), PathBuf>>>;
+pub type NvimSocketRegistry = Arc<tokio::sync::RwLock<HashMap<(PathBuf, String), PathBuf>>>;
 
 /// Create a new empty neovim socket registry.
 pub fn new_nvim_socket_registry() -> NvimSocketRegistry {
@@ -33,12 +34,13 @@ pub struct EditOp {
 /// Request sent over Unix socket from MCP bridge → manager.
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct SocketRequest {
-    pub op: String, // "read" | "run" | "list" | "new" | "close" | "rename"
+    pub op: String, // "read" | "run" | "list" | "new" | "close" | "rename" | "files" | "gitdiff"
     // + neovim ops: "nvim_open" | "nvim_read" | "nvim_command" | "nvim_buffers" | "nvim_info"
     //   "nvim_diagnostics" | "nvim_definition" | "nvim_references"
     //   "nvim_hover" | "nvim_symbols" | "nvim_code_actions"
     //   "nvim_eval" | "nvim_grep" | "nvim_diff" | "nvim_write"
     //   "nvim_edit_and_save" | "nvim_undo" | "nvim_rename" | "nvim_format" | "nvim_signature"
+    //   "nvim_selection" | "nvim_definition_context"
     /// Session ID for routing to the correct per-session resources.
     /// Set by MCP bridges from OPENCODE_SESSION_ID env var.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -71,13 +73,17 @@ pub struct SocketRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>, // for "nvim_write": write all buffers
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub glob: Option<String>, // for "nvim_grep": file glob pattern
+    pub glob: Option<String>, // for "nvim_grep" and "files": file glob pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full: Option<bool>, // for "gitdiff": include the patches, not just --stat
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub new_text: Option<String>, // for "nvim_edit_and_save": replacement text
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub count: Option<i64>, // for "nvim_undo": undo count (negative = redo)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub new_name: Option<String>, // for "nvim_rename": new symbol name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_lines: Option<usize>, // for "nvim_definition_context": lines either side
     // ── Multi-edit batch ────────────────────────────────────────────────
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub edits: Option<Vec<EditOp>>, // for "nvim_edit_and_save": batch of edits
@@ -175,6 +181,33 @@ pub struct PendingSocketRequest {
     pub reply_tx: tokio::sync::oneshot::Sender<SocketResponse>,
 }
 
+// ─── Enabled MCP servers ────────────────────────────────────────────────────
+
+/// Which MCP servers opman was started with (`--mcp`, `--terminal-mcp`, …).
+///
+/// Kept on `App` so the servers can be stopped and restarted at runtime
+/// with the same selection.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct McpFlags {
+    pub terminal: bool,
+    pub neovim: bool,
+    pub time: bool,
+    pub ui: bool,
+}
+
+impl McpFlags {
+    /// True if at least one MCP server is configured.
+    pub fn any(&self) -> bool {
+        self.terminal || self.neovim || self.time || self.ui
+    }
+
+    /// True if a per-project socket server is needed (terminal/neovim tools
+    /// talk to the manager; time/ui are self-contained).
+    pub fn needs_socket(&self) -> bool {
+        self.terminal || self.neovim
+    }
+}
+
 // ─── Socket path helper ─────────────────────────────────────────────────────
 
 /// Compute the Unix socket path for a given project path.
@@ -192,3 +225,49 @@ pub fn cleanup_socket(project_path: &Path) {
     let sock = socket_path_for_project(project_path);
     let _ = std::fs::remove_file(&sock);
 }
+
+/// What [`remove_stale_socket`] found at a socket path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SocketFileState {
+    /// No file there.
+    Absent,
+    /// Nothing was listening; the file was left by an unclean exit and
+    /// has been removed.
+    Removed,
+    /// Something accepted a connection (another opman); left alone.
+    Live,
+}
+
+/// Remove a socket file left behind by a crashed opman so it can be bound
+/// again. A socket that still accepts connections is left in place.
+pub fn remove_stale_socket(sock: &Path) -> SocketFileState {
+    if std::fs::symlink_metadata(sock).is_err() {
+        return SocketFileState::Absent;
+    }
+    if std::os::unix::net::UnixStream::connect(sock).is_ok() {
+        return SocketFileState::Live;
+    }
+    let _ = std::fs::remove_file(sock);
+    SocketFileState::Removed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_stale_socket() {
+        let sock = std::env::temp_dir().join(format!("opman-test-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&sock);
+        assert_eq!(remove_stale_socket(&sock), SocketFileState::Absent);
+
+        let listener = std::os::unix::net::UnixListener::bind(&sock).unwrap();
+        assert_eq!(remove_stale_socket(&sock), SocketFileState::Live);
+        assert!(sock.exists());
+
+        // Dropping the listener leaves the file behind, as after a crash.
+        drop(listener);
+        assert_eq!(remove_stale_socket(&sock), SocketFileState::Removed);
+        assert!(!sock.exists());
+    }
+}
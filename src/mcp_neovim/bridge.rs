@@ -10,10 +10,12 @@
 ///   - `neovim_info`       — current buffer name, cursor position, line count
 ///   - `neovim_write`      — save current buffer or all buffers
 ///   - `neovim_diff`       — unsaved changes as a unified diff
+///   - `neovim_selection`  — text the user visually selected
 ///
 /// **LSP:**
 ///   - `neovim_diagnostics` — LSP errors/warnings (buffer or project-wide)
 ///   - `neovim_definition`  — go-to-definition of symbol at position
+///   - `neovim_definition_context` — go-to-definition plus surrounding lines
 ///   - `neovim_references`  — find all references to symbol at position
 ///   - `neovim_hover`       — type/hover info for symbol at position
 ///   - `neovim_symbols`     — document or workspace symbol search
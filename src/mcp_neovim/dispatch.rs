@@ -96,6 +96,10 @@ fn dispatch_tool(
             all: arguments.get("all").and_then(|v| v.as_bool()),
             ..Default::default()
         },
+        "neovim_selection" => SocketRequest {
+            op: "nvim_selection".into(),
+            ..Default::default()
+        },
         "neovim_diff" => SocketRequest {
             op: "nvim_diff".into(),
             file_path: arguments
@@ -124,6 +128,20 @@ fn dispatch_tool(
             col: arguments.get("col").and_then(|v| v.as_i64()),
             ..Default::default()
         },
+        "neovim_definition_context" => SocketRequest {
+            op: "nvim_definition_context".into(),
+            file_path: arguments
+                .get("file_path")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+            line: arguments.get("line").and_then(|v| v.as_i64()),
+            col: arguments.get("col").and_then(|v| v.as_i64()),
+            context_lines: arguments
+                .get("context_lines")
+                .and_then(|v| v.as_u64())
+                .map(|n| n as usize),
+            ..Default::default()
+        },
         "neovim_references" => SocketRequest {
             op: "nvim_references".into(),
             file_path: arguments
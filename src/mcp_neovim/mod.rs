@@ -8,3 +8,4 @@ mod tools_defs;
 
 pub use bridge::run_mcp_neovim_bridge;
 pub use format::ext_to_lang;
+pub(crate) use tools::tool_definitions;
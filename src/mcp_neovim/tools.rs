@@ -183,7 +183,7 @@ fn refactoring_tool_defs() -> Vec<serde_json::Value> {
 }
 
 /// Assemble all tool definitions into the complete list.
-pub(super) fn tool_definitions() -> serde_json::Value {
+pub(crate) fn tool_definitions() -> serde_json::Value {
     let mut tools = Vec::new();
     tools.extend(file_buffer_tool_defs());
     tools.extend(lsp_tool_defs());
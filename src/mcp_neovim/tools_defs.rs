@@ -89,6 +89,14 @@ pub(super) fn file_buffer_tool_defs() -> Vec<serde_json::Value> {
                 }
             }
         }),
+        serde_json::json!({
+            "name": "neovim_selection",
+            "description": "Get the text the user has visually selected in the Neovim pane, with its file and line/column range. Returns the live selection while the user is in visual mode, otherwise the last selection. Use this when the user refers to \"the selected code\" or \"what I highlighted\".",
+            "inputSchema": {
+                "type": "object",
+                "properties": {}
+            }
+        }),
         serde_json::json!({
             "name": "neovim_diff",
             "description": "Show unsaved changes in a Neovim buffer as a unified diff. Compares the buffer content against the file on disk. If file_path is provided, diffs that file's buffer; otherwise diffs the current buffer.",
@@ -146,6 +154,31 @@ pub(super) fn lsp_tool_defs() -> Vec<serde_json::Value> {
                 }
             }
         }),
+        serde_json::json!({
+            "name": "neovim_definition_context",
+            "description": "Jump to the definition of the symbol at the specified position using the LSP and return the definition's location together with the surrounding source lines, in one call. Prefer this over neovim_definition followed by neovim_read. Requires an LSP server.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "file_path": {
+                        "type": "string",
+                        "description": "Absolute path of the file containing the symbol. If omitted, uses the current buffer."
+                    },
+                    "line": {
+                        "type": "number",
+                        "description": "Line number (1-indexed) of the symbol. Defaults to current cursor line."
+                    },
+                    "col": {
+                        "type": "number",
+                        "description": "Column number (0-indexed) of the symbol. Defaults to current cursor column."
+                    },
+                    "context_lines": {
+                        "type": "number",
+                        "description": "Lines of context to return above and below the definition. Defaults to 10."
+                    }
+                }
+            }
+        }),
         serde_json::json!({
             "name": "neovim_references",
             "description": "Find all references to the symbol at the specified position using the LSP. Returns file paths, line numbers, and context for each reference. Requires an LSP server.",
@@ -1,9 +1,21 @@
+use std::sync::atomic::Ordering;
+
 use anyhow::Result;
+use ratatui::layout::Rect;
+use regex::{Regex, RegexBuilder};
 
 use crate::app;
+use crate::clipboard;
 use crate::pty;
 use crate::ui::layout_manager::PanelId;
 
+/// Open `url` with the system handler, reporting a failure as a toast.
+fn open_url(url: &str, toasts: &mut app::ToastQueue) {
+    if let Err(e) = clipboard::open_url(url) {
+        toasts.error(format!("{e:#}"));
+    }
+}
+
 /// Forward mouse events to a PTY.
 /// Mouse mode active (vim, less, htop, opencode): forward as SGR bytes.
 /// Mouse mode off (plain shell): scroll adjusts scrollback offset for viewing history.
@@ -14,7 +26,8 @@ pub(crate) fn forward_mouse_to_pty(
     panel_y: u16,
     panel_id: PanelId,
     terminal_selection: &mut Option<app::TerminalSelection>,
-    toast_message: &mut Option<(String, std::time::Instant)>,
+    toasts: &mut app::ToastQueue,
+    trim_copied: bool,
 ) {
     use crossterm::event::{MouseButton, MouseEventKind};
 
@@ -63,12 +76,29 @@ pub(crate) fn forward_mouse_to_pty(
                     .contains(crossterm::event::KeyModifiers::CONTROL)
                 {
                     // Clone screen and drop lock before doing string work
-                    let row_text = if let Ok(parser) = pty.parser.lock() {
+                    let (row_text, link) = if let Ok(parser) = pty.parser.lock() {
                         let screen = parser.screen();
-                        screen.contents_between(rel_row, 0, rel_row, screen.size().1 - 1)
+                        // Link spans track the live screen, not scrollback.
+                        let link = pty
+                            .hyperlinks
+                            .lock()
+                            .ok()
+                            .filter(|_| screen.scrollback() == 0)
+                            .and_then(|mut links| {
+                                links.relocate(screen);
+                                links.url_at(rel_row, rel_col).map(str::to_string)
+                            });
+                        let text =
+                            screen.contents_between(rel_row, 0, rel_row, screen.size().1 - 1);
+                        (text, link)
                     } else {
                         return;
                     };
+                    // OSC 8 hyperlink under the click wins over URL-looking text.
+                    if let Some(url) = link {
+                        open_url(&url, toasts);
+                        return;
+                    }
                     // Lock released — do URL scanning without holding it
                     let prefixes = ["https://", "http://", "ftp://"];
                     let end_chars: &[char] = &[' ', '\t', '"', '\'', '>', '<', ')', ']', '}', '|'];
@@ -83,7 +113,7 @@ pub(crate) fn forward_mouse_to_pty(
                             let col = rel_col as usize;
                             if col >= abs_start && col < url_end {
                                 let url = &row_text[abs_start..url_end];
-                                let _ = std::process::Command::new("open").arg(url).spawn();
+                                open_url(url, toasts);
                                 return;
                             }
                             search_from = url_end;
@@ -124,21 +154,16 @@ pub(crate) fn forward_mouse_to_pty(
                                     (sel.end_row, sel.end_col, sel.start_row, sel.start_col)
                                 };
 
-                            let text = screen.contents_between(sr, sc, er, ec);
+                            let mut text = screen.contents_between(sr, sc, er, ec);
+                            if trim_copied {
+                                text = trim_line_ends(&text);
+                            }
 
-                            use std::io::Write;
-                            use std::process::{Command, Stdio};
                             if !text.trim().is_empty() {
-                                if let Ok(mut child) =
-                                    Command::new("pbcopy").stdin(Stdio::piped()).spawn()
-                                {
-                                    if let Some(stdin) = child.stdin.as_mut() {
-                                        let _ = stdin.write_all(text.as_bytes());
-                                    }
-                                    let _ = child.wait();
+                                match clipboard::copy_to_clipboard(&text) {
+                                    Ok(()) => toasts.success("Copied!"),
+                                    Err(e) => toasts.error(format!("Copy failed: {e:#}")),
                                 }
-                                *toast_message =
-                                    Some(("Copied!".to_string(), std::time::Instant::now()));
                             }
                         }
                         *terminal_selection = None;
@@ -162,47 +187,156 @@ pub(crate) fn forward_mouse_to_pty(
     }
 }
 
+/// `text` with trailing whitespace cut from every line. A final newline
+/// is kept.
+fn trim_line_ends(text: &str) -> String {
+    text.split('\n')
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Update terminal search matches based on current query.
-/// Searches the full terminal buffer (scrollback + visible) for the query string.
+/// Searches the full terminal buffer (scrollback + visible) for the query,
+/// as a case-insensitive substring unless the search's case and regex
+/// toggles say otherwise; the current match becomes the first one at or below the top of
+/// the view, so searching while scrolled back starts where the user is.
+/// The buffer's lines are read once and reused until the PTY prints
+/// again, so typing a query doesn't re-read a long scrollback per key.
 pub(crate) fn update_terminal_search_matches(app: &mut app::App) {
-    let query = if let Some(ref search) = app.terminal_search {
-        if search.query.is_empty() {
-            app.terminal_search.as_mut().unwrap().matches.clear();
-            app.terminal_search.as_mut().unwrap().current_match = 0;
-            return;
+    let Some(search) = app.terminal_search.as_mut() else {
+        return;
+    };
+    if search.query.is_empty() {
+        search.matches.clear();
+        search.current_match = 0;
+        return;
+    }
+    let (pattern, invalid_regex) =
+        search_pattern(&search.query, search.case_sensitive, search.regex);
+    search.invalid_regex = invalid_regex;
+    let Some(pattern) = pattern else {
+        search.matches.clear();
+        search.current_match = 0;
+        return;
+    };
+
+    let pty = app
+        .projects
+        .get(app.active_project)
+        .and_then(|p| p.active_shell_pty());
+    let view_top = pty.and_then(|pty| {
+        let mut parser = pty.parser.lock().ok()?;
+        let saved = parser.screen().scrollback();
+        parser.set_scrollback(usize::MAX);
+        let scrollback_len = parser.screen().scrollback();
+        let stamp = (
+            pty.last_output_at.load(Ordering::Acquire),
+            scrollback_len,
+            parser.screen().size(),
+        );
+        if search.lines_stamp != Some(stamp) {
+            search.lines = pty::read_buffer_lines(&mut parser, scrollback_len);
+            search.lines_stamp = Some(stamp);
         }
-        search.query.to_lowercase()
-    } else {
+        parser.set_scrollback(saved);
+        search.scrollback_len = scrollback_len;
+        Some(scrollback_len - saved)
+    });
+    let Some(view_top) = view_top else {
+        search.matches.clear();
+        search.current_match = 0;
         return;
     };
 
+    search.matches = find_matches(&search.lines, &pattern);
+    search.current_match = search
+        .matches
+        .iter()
+        .position(|&(line, _, _)| line >= view_top)
+        .unwrap_or(0);
+    scroll_to_current_match(app);
+}
+
+/// The regex a search query matches with, and whether the query was
+/// meant as a regex but didn't compile. Plain queries, and regex queries
+/// that don't compile, match literally; a query too large to compile even
+/// then gets no regex and matches nothing.
+fn search_pattern(query: &str, case_sensitive: bool, regex: bool) -> (Option<Regex>, bool) {
+    let build = |pattern: &str| {
+        RegexBuilder::new(pattern)
+            .case_insensitive(!case_sensitive)
+            .build()
+    };
+    let literal = || build(&regex::escape(query)).ok();
+    if !regex {
+        return (literal(), false);
+    }
+    match build(query) {
+        Ok(pattern) => (Some(pattern), false),
+        Err(_) => (literal(), true),
+    }
+}
+
+/// Find `pattern` in `lines`, as (line, col, length) matches. Empty
+/// matches (from patterns like `a*`) are skipped as there's nothing to
+/// highlight.
+fn find_matches(lines: &[String], pattern: &Regex) -> Vec<(usize, usize, usize)> {
     let mut matches = Vec::new();
-    if let Some(project) = app.projects.get(app.active_project) {
-        if let Some(pty) = project.active_shell_pty() {
-            if let Ok(parser) = pty.parser.lock() {
-                // Only iterates visible rows (typically 24-50), so fast under lock.
-                let screen = parser.screen();
-                let rows = screen.size().0;
-                for row_idx in 0..rows {
-                    let row_text = screen
-                        .contents_between(row_idx, 0, row_idx + 1, 0)
-                        .to_lowercase();
-                    let mut search_from = 0;
-                    while let Some(col) = row_text[search_from..].find(&query) {
-                        let actual_col = search_from + col;
-                        matches.push((row_idx as usize, actual_col, query.len()));
-                        search_from = actual_col + 1;
-                    }
-                }
+    for (line, text) in lines.iter().enumerate() {
+        for m in pattern.find_iter(text) {
+            if !m.is_empty() {
+                matches.push((line, m.start(), m.len()));
             }
         }
     }
+    matches
+}
 
+/// Move to the next (or previous) terminal search match and scroll to it.
+pub(crate) fn step_terminal_search(app: &mut app::App, forward: bool) {
     if let Some(ref mut search) = app.terminal_search {
-        search.matches = matches;
-        if search.current_match >= search.matches.len() {
-            search.current_match = 0;
+        if search.matches.is_empty() {
+            return;
         }
+        let len = search.matches.len();
+        search.current_match = if forward {
+            (search.current_match + 1) % len
+        } else {
+            (search.current_match + len - 1) % len
+        };
+    }
+    scroll_to_current_match(app);
+}
+
+/// Scroll the active shell tab so the current search match is in view.
+/// The scroll position is kept when the match is already visible, so
+/// stepping through nearby matches doesn't jump the view around.
+fn scroll_to_current_match(app: &mut app::App) {
+    let Some(search) = app.terminal_search.as_ref() else {
+        return;
+    };
+    let Some(&(line, _, _)) = search.matches.get(search.current_match) else {
+        return;
+    };
+    let scrollback_len = search.scrollback_len;
+    let Some(pty) = app
+        .projects
+        .get_mut(app.active_project)
+        .and_then(|p| p.active_shell_pty_mut())
+    else {
+        return;
+    };
+    if let Ok(mut parser) = pty.parser.lock() {
+        let rows = parser.screen().size().0 as usize;
+        let view_top = scrollback_len.saturating_sub(parser.screen().scrollback());
+        if line >= view_top && line < view_top + rows {
+            return;
+        }
+        // Otherwise bring the match to the middle of the view.
+        let top = line.saturating_sub(rows / 2).min(scrollback_len);
+        parser.set_scrollback(scrollback_len - top);
+        pty.scroll_offset = parser.screen().scrollback();
     }
 }
 
@@ -251,24 +385,102 @@ pub(crate) fn handle_integrated_terminal_mouse(
                     }
                     if let Some(tab) = clicked_tab {
                         if let Some(resources) = project.active_resources_mut() {
-                            resources.active_shell_tab = tab;
+                            resources.select_shell_tab(tab);
+                            // Hidden tabs keep a stale size until shown.
+                            app.layout.layout_dirty = true;
                         }
                     }
                 }
-            } else if let Some(pty) = project.active_shell_pty_mut() {
+            } else if let Some(resources) = project.active_resources_mut() {
                 // Account for tab bar offset only when tab bar is visible
                 let content_offset_y = if has_tab_bar { rect.y + 1 } else { rect.y };
-                forward_mouse_to_pty(
-                    pty,
-                    &mouse_event,
-                    rect.x,
-                    content_offset_y,
-                    PanelId::IntegratedTerminal,
-                    &mut app.terminal_selection,
-                    &mut app.toast_message,
-                );
+                let content = Rect {
+                    y: content_offset_y,
+                    height: rect.bottom().saturating_sub(content_offset_y),
+                    ..rect
+                };
+                // With a split, the pane under the mouse gets the event;
+                // clicking the inactive pane focuses it.
+                let pane = resources
+                    .shell_panes(content)
+                    .into_iter()
+                    .find(|(_, p)| mouse_event.column >= p.x && mouse_event.column < p.right());
+                if let Some((tab, pane)) = pane {
+                    if matches!(mouse_event.kind, crossterm::event::MouseEventKind::Down(_))
+                        && resources.active_shell_tab != tab
+                    {
+                        resources.select_shell_tab(tab);
+                        app.layout.layout_dirty = true;
+                    }
+                    if let Some(pty) = resources.shell_ptys.get_mut(tab) {
+                        forward_mouse_to_pty(
+                            pty,
+                            &mouse_event,
+                            pane.x,
+                            content_offset_y,
+                            PanelId::IntegratedTerminal,
+                            &mut app.terminal_selection,
+                            &mut app.toasts,
+                            app.config.settings.trim_copied_selection,
+                        );
+                    }
+                }
             }
         }
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_covers_scrollback() {
+        let mut parser = vt100::Parser::new(3, 20, 100);
+        for i in 0..10 {
+            parser.process(format!("Line {i}\r\n").as_bytes());
+        }
+        // Lines 0-7 are in scrollback, "line 8" and "line 9" on screen.
+        parser.set_scrollback(usize::MAX);
+        assert_eq!(parser.screen().scrollback(), 8);
+
+        let lines = pty::read_buffer_lines(&mut parser, 8);
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "Line 0");
+        let find = |query| {
+            let pattern = search_pattern(query, false, false).0.unwrap();
+            find_matches(&lines, &pattern)
+        };
+        assert_eq!(find("line 1"), [(1, 0, 6)]);
+        assert_eq!(find("line 9"), [(9, 0, 6)]);
+        assert_eq!(find("e 8"), [(8, 3, 3)]);
+    }
+
+    #[test]
+    fn test_search_pattern_toggles() {
+        let lines = vec!["Error: x".to_string(), "error[E0425]".to_string()];
+        let find = |query, case_sensitive, regex| {
+            let (pattern, invalid) = search_pattern(query, case_sensitive, regex);
+            (find_matches(&lines, &pattern.unwrap()), invalid)
+        };
+        assert_eq!(
+            find("error", false, false),
+            (vec![(0, 0, 5), (1, 0, 5)], false)
+        );
+        assert_eq!(find("error", true, false), (vec![(1, 0, 5)], false));
+        assert_eq!(find("E\\d+", false, true), (vec![(1, 6, 5)], false));
+        assert_eq!(find("E\\d+", false, false), (vec![], false));
+        // An unfinished pattern matches literally and is flagged.
+        assert_eq!(find("error[", false, true), (vec![(1, 0, 6)], true));
+    }
+
+    #[test]
+    fn test_trim_line_ends() {
+        assert_eq!(
+            trim_line_ends("ls -la   \nfoo\t \n  bar  "),
+            "ls -la\nfoo\n  bar"
+        );
+        assert_eq!(trim_line_ends("a  \nb \n"), "a\nb\n");
+    }
+}
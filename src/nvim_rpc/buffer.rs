@@ -83,6 +83,99 @@ pub fn nvim_cursor_pos(socket_path: &Path) -> Result<(i64, i64)> {
     Ok((line, col))
 }
 
+/// A visual-mode selection in the current buffer.
+#[derive(Debug, serde::Deserialize)]
+pub struct VisualSelection {
+    pub file: String,
+    /// `"v"` (charwise), `"V"` (linewise) or `"\x16"` (blockwise).
+    pub mode: String,
+    /// `true` while neovim is still in visual mode; `false` when this is
+    /// the last selection recorded by the `'<` / `'>` marks.
+    pub active: bool,
+    /// 1-indexed line and column of the first selected character.
+    pub start_line: i64,
+    pub start_col: i64,
+    /// 1-indexed line and column of the last selected character.
+    pub end_line: i64,
+    pub end_col: i64,
+    pub text: String,
+}
+
+impl VisualSelection {
+    /// Render as a short header plus a fenced code block.
+    pub fn to_markdown(&self) -> String {
+        let mode = match self.mode.as_str() {
+            "V" => "linewise",
+            "\x16" => "blockwise",
+            _ => "charwise",
+        };
+        let state = if self.active { "active" } else { "last" };
+        let lang = crate::mcp_neovim::ext_to_lang(&self.file);
+        format!(
+            "File: {}\nSelection ({}, {}): {}:{} – {}:{}\n```{}\n{}\n```",
+            if self.file.is_empty() { "(unnamed)" } else { &self.file },
+            state,
+            mode,
+            self.start_line,
+            self.start_col,
+            self.end_line,
+            self.end_col,
+            lang,
+            self.text
+        )
+    }
+}
+
+/// Get the text visually selected in the current window.
+///
+/// While neovim is in visual mode the live selection (`v` to cursor) is
+/// returned; otherwise the last selection from the `'<` / `'>` marks.
+/// Returns `None` when the buffer has never had a visual selection.
+pub fn nvim_visual_selection(socket_path: &Path) -> Result<Option<VisualSelection>> {
+    let lua = r#"
+        local mode = vim.api.nvim_get_mode().mode:sub(1, 1)
+        local active = mode == 'v' or mode == 'V' or mode == '\22'
+        local s, e
+        if active then
+            s, e = vim.fn.getpos('v'), vim.fn.getpos('.')
+        else
+            s, e = vim.fn.getpos("'<"), vim.fn.getpos("'>")
+            mode = vim.fn.visualmode()
+        end
+        if s[2] == 0 or e[2] == 0 or mode == '' then
+            return vim.json.encode(vim.NIL)
+        end
+        if s[2] > e[2] or (s[2] == e[2] and s[3] > e[3]) then
+            s, e = e, s
+        end
+        local buf = vim.api.nvim_get_current_buf()
+        local lines
+        if vim.fn.exists('*getregion') == 1 then
+            lines = vim.fn.getregion(s, e, { type = mode })
+        elseif mode == 'v' then
+            local last = vim.api.nvim_buf_get_lines(buf, e[2] - 1, e[2], false)[1] or ''
+            lines = vim.api.nvim_buf_get_text(buf, s[2] - 1, s[3] - 1, e[2] - 1, math.min(e[3], #last), {})
+        else
+            lines = vim.api.nvim_buf_get_lines(buf, s[2] - 1, e[2], false)
+        end
+        local last = vim.api.nvim_buf_get_lines(buf, e[2] - 1, e[2], false)[1] or ''
+        return vim.json.encode({
+            file = vim.api.nvim_buf_get_name(buf),
+            mode = mode,
+            active = active,
+            start_line = s[2],
+            start_col = s[3],
+            end_line = e[2],
+            end_col = math.min(e[3], math.max(#last, 1)),
+            text = table.concat(lines, '\n'),
+        })
+    "#;
+
+    let result = nvim_exec_lua(socket_path, lua, vec![])?;
+    let json = value_to_string(&result);
+    serde_json::from_str(&json).context("Failed to parse visual selection")
+}
+
 /// List all loaded buffers with their names.
 pub fn nvim_list_bufs(socket_path: &Path) -> Result<Vec<(i64, String)>> {
     let result = nvim_call(socket_path, "nvim_list_bufs", vec![])?;
@@ -104,6 +197,13 @@ pub fn nvim_list_bufs(socket_path: &Path) -> Result<Vec<(i64, String)>> {
     Ok(out)
 }
 
+/// Whether any buffer has unsaved changes.
+pub fn nvim_has_modified_buffers(socket_path: &Path) -> Result<bool> {
+    let lua = "return #vim.fn.getbufinfo({ bufmodified = 1 }) > 0";
+    let result = nvim_exec_lua(socket_path, lua, vec![])?;
+    result.as_bool().context("Expected a boolean")
+}
+
 /// Open a file in neovim and optionally jump to a line.
 pub fn nvim_open_file(socket_path: &Path, file_path: &str, line: Option<i64>) -> Result<()> {
     // Use fnameescape to safely handle special characters
@@ -8,6 +8,46 @@ use rmpv::Value;
 use super::buffer::nvim_write;
 use super::transport::{nvim_call, nvim_command, nvim_exec_lua};
 
+/// Paste `text` at the cursor with `nvim_put`, bypassing insert-mode
+/// auto-indent. Text ending in a newline is put linewise below the cursor
+/// line (like `p`); anything else is put characterwise at the cursor.
+/// The text is also stored in the unnamed register.
+pub fn nvim_put_text(socket_path: &Path, text: &str) -> Result<()> {
+    let text = text.replace("\r\n", "\n").replace('\r', "\n");
+    let linewise = text.ends_with('\n');
+    let body = if linewise {
+        &text[..text.len() - 1]
+    } else {
+        text.as_str()
+    };
+    let lines: Vec<Value> = body.split('\n').map(Value::from).collect();
+    let regtype = if linewise { "l" } else { "c" };
+
+    nvim_call(
+        socket_path,
+        "nvim_call_function",
+        vec![
+            Value::from("setreg"),
+            Value::Array(vec![
+                Value::from("\""),
+                Value::Array(lines.clone()),
+                Value::from(regtype),
+            ]),
+        ],
+    )?;
+    nvim_call(
+        socket_path,
+        "nvim_put",
+        vec![
+            Value::Array(lines),
+            Value::from(regtype),
+            Value::from(linewise), // after: below the cursor line for linewise
+            Value::from(true),     // follow: move cursor to end of pasted text
+        ],
+    )?;
+    Ok(())
+}
+
 /// Replace lines in a buffer.
 ///
 /// `start_line` and `end_line` are 1-indexed, inclusive.
@@ -1,8 +1,9 @@
 /// LSP operations: diagnostics, definition, references, hover, symbols, code actions.
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
+use super::buffer::{nvim_buf_get_lines, nvim_buf_line_count};
 use super::transport::{nvim_exec_lua, value_to_string};
 
 /// Get LSP diagnostics for a specific buffer or all buffers.
@@ -121,6 +122,130 @@ pub fn nvim_lsp_definition(
     Ok(value_to_string(&result))
 }
 
+/// Jump to the definition of the symbol at the given position and return its
+/// location together with `context` lines on either side, in one call.
+///
+/// Chains [`nvim_lsp_definition`] (which leaves the definition's buffer
+/// current) with [`nvim_buf_get_lines`]. Pass `buf = 0` for the current buffer.
+pub fn nvim_lsp_definition_context(
+    socket_path: &Path,
+    buf: i64,
+    line: Option<i64>,
+    col: Option<i64>,
+    context: i64,
+) -> Result<String> {
+    let raw = nvim_lsp_definition(socket_path, buf, line, col)?;
+    let parsed: serde_json::Value =
+        serde_json::from_str(&raw).context("Failed to parse definition result")?;
+    if let Some(err) = parsed.get("error").and_then(|e| e.as_str()) {
+        anyhow::bail!("{}", err);
+    }
+    let locations = parsed
+        .get("locations")
+        .and_then(|l| l.as_array())
+        .filter(|l| !l.is_empty())
+        .context("No definition found")?;
+
+    let first = &locations[0];
+    let file = first.get("file").and_then(|v| v.as_str()).unwrap_or("");
+    let lnum = first.get("lnum").and_then(|v| v.as_i64()).unwrap_or(1);
+    let col = first.get("col").and_then(|v| v.as_i64()).unwrap_or(1);
+
+    // The jump made the definition's buffer current, so read from buf 0.
+    let line_count = nvim_buf_line_count(socket_path, 0)?;
+    let start = (lnum - 1 - context).max(0);
+    let end = (lnum + context).min(line_count);
+    let lines = nvim_buf_get_lines(socket_path, 0, start, end)?;
+    let body: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            let n = start + 1 + i as i64;
+            let marker = if n == lnum { ">" } else { " " };
+            format!("{}{}: {}", marker, n, l)
+        })
+        .collect();
+
+    let mut out = format!(
+        "Definition: {}:{}:{}\n```{}\n{}\n```",
+        file,
+        lnum,
+        col,
+        crate::mcp_neovim::ext_to_lang(file),
+        body.join("\n")
+    );
+    if locations.len() > 1 {
+        out.push_str("\nOther locations:");
+        for loc in &locations[1..] {
+            out.push_str(&format!(
+                "\n  {}:{}:{}",
+                loc.get("file").and_then(|v| v.as_str()).unwrap_or(""),
+                loc.get("lnum").and_then(|v| v.as_i64()).unwrap_or(0),
+                loc.get("col").and_then(|v| v.as_i64()).unwrap_or(0),
+            ));
+        }
+    }
+    Ok(out)
+}
+
+/// Summarise in-flight LSP work (`$/progress`) across all attached clients,
+/// e.g. `"rust-analyzer: indexing (42%)"`. Returns `None` when idle.
+///
+/// The client's progress ring buffer is left alone: reading it would pop
+/// messages that statusline plugins are waiting for. Instead the first
+/// poll installs an `LspProgress` autocmd (neovim 0.10+) that keeps the
+/// latest state per token in a Lua global, dropped once its `end`
+/// notification arrives or its client stops. Work already in progress at
+/// that first poll shows up with its next update.
+pub fn nvim_lsp_progress(socket_path: &Path) -> Result<Option<String>> {
+    let lua = r#"
+        if not _G.__opman_lsp_progress then
+            _G.__opman_lsp_progress = {}
+            local state = _G.__opman_lsp_progress
+            pcall(vim.api.nvim_create_autocmd, 'LspProgress', {
+                group = vim.api.nvim_create_augroup('opman_lsp_progress', {}),
+                callback = function(ev)
+                    local data = ev.data or {}
+                    local params = data.params or {}
+                    local client = vim.lsp.get_client_by_id(data.client_id)
+                    if not client then return end
+                    local key = client.id .. ':' .. tostring(params.token)
+                    local v = params.value or {}
+                    if v.kind == 'end' then
+                        state[key] = nil
+                    else
+                        local prev = state[key] or {}
+                        state[key] = {
+                            client_id = client.id,
+                            name = client.name,
+                            title = v.title or prev.title or '',
+                            message = v.message or prev.message or '',
+                            percentage = v.percentage or prev.percentage,
+                        }
+                    end
+                end,
+            })
+        end
+        local state = _G.__opman_lsp_progress
+        local parts = {}
+        for key, p in pairs(state) do
+            if vim.lsp.get_client_by_id(p.client_id) then
+                local text = p.name .. ': ' .. (p.title ~= '' and p.title or p.message)
+                if p.percentage then text = text .. ' (' .. p.percentage .. '%)' end
+                table.insert(parts, text)
+            else
+                state[key] = nil
+            end
+        end
+        table.sort(parts)
+        return table.concat(parts, ', ')
+    "#;
+
+    let result = nvim_exec_lua(socket_path, lua, vec![])?;
+    let text = value_to_string(&result);
+    Ok(if text.is_empty() { None } else { Some(text) })
+}
+
 /// Find all references to the symbol at the given position.
 /// Pass `buf = 0` for the current buffer.
 pub fn nvim_lsp_references(
@@ -23,18 +23,19 @@ pub use transport::nvim_command;
 // buffer operations
 pub use buffer::{
     nvim_buf_diff, nvim_buf_get_lines, nvim_buf_get_name, nvim_buf_line_count, nvim_cursor_pos,
-    nvim_find_or_load_buffer, nvim_list_bufs, nvim_open_file, nvim_undo, nvim_write,
+    nvim_find_or_load_buffer, nvim_has_modified_buffers, nvim_list_bufs, nvim_open_file, nvim_undo,
+    nvim_visual_selection, nvim_write,
 };
 
 // editing
 pub use edit::{
-    nvim_buf_multi_edit_and_save, nvim_buf_set_text_and_save, ResolvedEdit,
+    nvim_buf_multi_edit_and_save, nvim_buf_set_text_and_save, nvim_put_text, ResolvedEdit,
 };
 
 // LSP navigation & diagnostics
 pub use lsp::{
-    nvim_lsp_code_actions, nvim_lsp_definition, nvim_lsp_diagnostics, nvim_lsp_hover,
-    nvim_lsp_references,
+    nvim_lsp_code_actions, nvim_lsp_definition, nvim_lsp_definition_context,
+    nvim_lsp_diagnostics, nvim_lsp_hover, nvim_lsp_progress, nvim_lsp_references,
 };
 
 // LSP symbols
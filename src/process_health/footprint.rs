@@ -0,0 +1,60 @@
+//! Per-process memory readings for the process overview overlay.
+//!
+//! Linux reads `/proc/<pid>/status`; other platforms shell out to `ps`.
+
+/// Resident set size of `pid` in bytes, or `None` if it can't be read
+/// (process gone, permission denied, unsupported platform).
+pub fn process_rss_bytes(pid: u32) -> Option<u64> {
+    if let Ok(status) = std::fs::read_to_string(format!("/proc/{}/status", pid)) {
+        return parse_vm_rss(&status);
+    }
+    let output = std::process::Command::new("ps")
+        .args(["-o", "rss=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let kb: u64 = String::from_utf8_lossy(&output.stdout).trim().parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Extract `VmRSS` (reported in kB) from a `/proc/<pid>/status` dump.
+fn parse_vm_rss(status: &str) -> Option<u64> {
+    let rest = status.lines().find_map(|l| l.strip_prefix("VmRSS:"))?;
+    let trimmed = rest.trim();
+    let kb: u64 = trimmed.strip_suffix("kB").unwrap_or(trimmed).trim().parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Human-readable byte count (`512K`, `38.2M`, `1.1G`).
+pub fn format_bytes(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    let b = bytes as f64;
+    if b < KB * KB {
+        format!("{:.0}K", b / KB)
+    } else if b < KB * KB * KB {
+        format!("{:.1}M", b / (KB * KB))
+    } else {
+        format!("{:.1}G", b / (KB * KB * KB))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vm_rss() {
+        let status = "Name:\tnvim\nVmPeak:\t  9000 kB\nVmRSS:\t   2048 kB\nThreads:\t1\n";
+        assert_eq!(parse_vm_rss(status), Some(2048 * 1024));
+        assert_eq!(parse_vm_rss("Name:\tzombie\n"), None);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512 * 1024), "512K");
+        assert_eq!(format_bytes(38 * 1024 * 1024 + 200 * 1024), "38.2M");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024 / 2), "1.5G");
+    }
+}
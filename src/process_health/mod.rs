@@ -6,6 +6,7 @@
 //!
 //! **Excluded by design:** process restart (user-specified constraint).
 
+pub mod footprint;
 pub mod orphan_cleanup;
 pub mod port_cleanup;
 pub mod temp_cleanup;
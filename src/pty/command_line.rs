@@ -0,0 +1,95 @@
+/// Follows what is typed into a shell PTY so the last submitted command line
+/// can be recovered (e.g. to save it as a snippet).
+#[derive(Debug, Default)]
+pub struct CommandLineTracker {
+    /// Text typed since the last Enter.
+    typed: String,
+    /// Editing keys we can't follow (arrows, history, tab completion) were
+    /// used, so `typed` may not match what the shell will run.
+    edited: bool,
+}
+
+impl CommandLineTracker {
+    /// Feed bytes written to the PTY. Returns the last non-empty line
+    /// submitted in `data`: the typed text, or the cursor's screen line
+    /// (from `screen_line`) without its prompt when the line was edited.
+    pub fn feed(
+        &mut self,
+        data: &[u8],
+        screen_line: impl Fn() -> Option<String>,
+    ) -> Option<String> {
+        let mut submitted = None;
+        for c in String::from_utf8_lossy(data).chars() {
+            match c {
+                '\r' | '\n' => {
+                    let line = if self.edited {
+                        screen_line().map(|l| strip_prompt(&l).to_string())
+                    } else {
+                        Some(self.typed.trim().to_string())
+                    };
+                    self.typed.clear();
+                    self.edited = false;
+                    if let Some(line) = line.filter(|l| !l.is_empty()) {
+                        submitted = Some(line);
+                    }
+                }
+                // Backspace / DEL
+                '\x7f' | '\x08' => {
+                    self.typed.pop();
+                }
+                // Ctrl+C / Ctrl+U discard the line
+                '\x03' | '\x15' => {
+                    self.typed.clear();
+                    self.edited = false;
+                }
+                c if c.is_control() => self.edited = true,
+                c => self.typed.push(c),
+            }
+        }
+        submitted
+    }
+}
+
+/// Drop a shell prompt from the start of a screen line: everything up to
+/// the first common prompt terminator.
+fn strip_prompt(line: &str) -> &str {
+    const MARKERS: [&str; 5] = ["$ ", "% ", "# ", "> ", "❯ "];
+    MARKERS
+        .iter()
+        .filter_map(|m| line.find(m).map(|i| i + m.len()))
+        .min()
+        .map_or(line, |i| &line[i..])
+        .trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_line_is_submitted() {
+        let mut tracker = CommandLineTracker::default();
+        assert_eq!(tracker.feed(b"cargo tset", || None), None);
+        assert_eq!(tracker.feed(b"\x7f\x7f\x7fest", || None), None);
+        assert_eq!(tracker.feed(b"\r", || None).as_deref(), Some("cargo test"));
+        assert_eq!(tracker.feed(b"\r", || None), None);
+        assert_eq!(
+            tracker.feed(b"make build\n", || None).as_deref(),
+            Some("make build")
+        );
+    }
+
+    #[test]
+    fn test_edited_line_falls_back_to_screen() {
+        let mut tracker = CommandLineTracker::default();
+        tracker.feed(b"\x1b[A", || None);
+        let screen = || Some("user@host:~/p$ cargo run --release   ".to_string());
+        assert_eq!(
+            tracker.feed(b"\r", screen).as_deref(),
+            Some("cargo run --release")
+        );
+
+        tracker.feed(b"junk\x03ls", || None);
+        assert_eq!(tracker.feed(b"\r", || None).as_deref(), Some("ls"));
+    }
+}
@@ -0,0 +1,215 @@
+//! direnv support for shell PTYs: the environment a project's `.envrc`
+//! exports, read with `direnv export json`, so shell tabs match the user's
+//! normal terminal even when their shell has no direnv hook.
+//!
+//! Spawning never runs direnv: [`with_direnv`] only reads a cache that
+//! [`resolve_direnv`] fills from a blocking task. Exports are cached per
+//! directory until the `.envrc` changes; failures are cached too, for
+//! [`FAILURE_RETRY`], so a later `direnv allow` is picked up.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+
+/// A variable to set (`Some`) or unset (`None`) in a spawned shell.
+pub type EnvVar = (String, Option<String>);
+
+/// How long direnv may run before it is killed.
+const DIRENV_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a failed export is reused before direnv is asked again.
+const FAILURE_RETRY: Duration = Duration::from_secs(60);
+
+/// The export for a directory, valid while the `.envrc` it came from
+/// keeps its modification time.
+struct CachedExport {
+    envrc_mtime: Option<SystemTime>,
+    env: Vec<EnvVar>,
+    /// When direnv failed, leaving `env` empty.
+    failed_at: Option<Instant>,
+}
+
+fn cache() -> &'static Mutex<HashMap<PathBuf, CachedExport>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, CachedExport>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// `base` followed, when `enabled`, by the variables direnv sets for a
+/// shell started in `dir`. `None` when those haven't been resolved yet;
+/// call [`resolve_direnv`] off the event loop, then try again.
+pub fn with_direnv(base: &[(String, String)], dir: &Path, enabled: bool) -> Option<Vec<EnvVar>> {
+    let mut env: Vec<EnvVar> = base
+        .iter()
+        .map(|(key, value)| (key.clone(), Some(value.clone())))
+        .collect();
+    if enabled {
+        env.extend(cached_env(dir)?);
+    }
+    Some(env)
+}
+
+/// The `.envrc` that applies to `dir` and its modification time.
+fn find_envrc(dir: &Path) -> Option<(PathBuf, Option<SystemTime>)> {
+    let envrc = dir
+        .ancestors()
+        .map(|d| d.join(".envrc"))
+        .find(|f| f.is_file())?;
+    let mtime = std::fs::metadata(&envrc).and_then(|m| m.modified()).ok();
+    Some((envrc, mtime))
+}
+
+/// Cached variables for `dir`: empty when no `.envrc` applies, `None`
+/// when the current `.envrc` hasn't been exported yet.
+fn cached_env(dir: &Path) -> Option<Vec<EnvVar>> {
+    let Some((_, envrc_mtime)) = find_envrc(dir) else {
+        return Some(Vec::new());
+    };
+    let cache = cache().lock().ok()?;
+    cache
+        .get(dir)
+        .filter(|c| c.envrc_mtime == envrc_mtime)
+        .filter(|c| c.failed_at.map_or(true, |t| t.elapsed() < FAILURE_RETRY))
+        .map(|c| c.env.clone())
+}
+
+/// Run direnv for `dir` and cache what it exports. Empty (and cached as
+/// a failure) when direnv isn't installed, the `.envrc` is blocked (not yet
+/// `direnv allow`ed), or direnv fails or times out. Blocks for as long as
+/// direnv runs.
+pub fn resolve_direnv(dir: &Path) {
+    let Some((_, envrc_mtime)) = find_envrc(dir) else {
+        return;
+    };
+    let env = run_direnv(dir);
+    if let Ok(mut cache) = cache().lock() {
+        let export = CachedExport {
+            envrc_mtime,
+            failed_at: env.is_none().then(Instant::now),
+            env: env.unwrap_or_default(),
+        };
+        cache.insert(dir.to_path_buf(), export);
+    }
+}
+
+/// Run `direnv export json` in `dir`, killing it after [`DIRENV_TIMEOUT`].
+/// `None` if it couldn't run or failed.
+fn run_direnv(dir: &Path) -> Option<Vec<EnvVar>> {
+    let mut child = match Command::new("direnv")
+        .args(["export", "json"])
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            tracing::debug!("direnv not available: {e}");
+            return None;
+        }
+    };
+    // Read output while waiting so a large export can't fill the pipe.
+    let mut stdout = child.stdout.take()?;
+    let reader = std::thread::spawn(move || {
+        let mut out = String::new();
+        let _ = stdout.read_to_string(&mut out);
+        out
+    });
+    let deadline = Instant::now() + DIRENV_TIMEOUT;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => std::thread::sleep(Duration::from_millis(20)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                tracing::warn!(dir = %dir.display(), "direnv export timed out");
+                return None;
+            }
+        }
+    };
+    let stdout = reader.join().unwrap_or_default();
+    if !status.success() {
+        let mut stderr = String::new();
+        if let Some(mut pipe) = child.stderr.take() {
+            let _ = pipe.read_to_string(&mut stderr);
+        }
+        tracing::warn!(
+            dir = %dir.display(),
+            "direnv export failed: {}",
+            stderr.trim()
+        );
+        return None;
+    }
+    Some(parse_export(&stdout))
+}
+
+/// Parse `direnv export json` output: an object of variable names to new
+/// values, with `null` for variables to unset. Empty output means no
+/// change.
+fn parse_export(json: &str) -> Vec<EnvVar> {
+    if json.trim().is_empty() {
+        return Vec::new();
+    }
+    let Ok(serde_json::Value::Object(vars)) = serde_json::from_str(json) else {
+        tracing::warn!("Unexpected direnv export output");
+        return Vec::new();
+    };
+    let mut env: Vec<EnvVar> = vars
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            serde_json::Value::String(value) => Some((key, Some(value))),
+            serde_json::Value::Null => Some((key, None)),
+            _ => None,
+        })
+        .collect();
+    env.sort();
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_export() {
+        let json =
+            r#"{"DATABASE_URL":"postgres://localhost/app","OLD_VAR":null,"DIRENV_DIR":"-/app"}"#;
+        assert_eq!(
+            parse_export(json),
+            [
+                (
+                    "DATABASE_URL".to_string(),
+                    Some("postgres://localhost/app".to_string())
+                ),
+                ("DIRENV_DIR".to_string(), Some("-/app".to_string())),
+                ("OLD_VAR".to_string(), None),
+            ]
+        );
+        assert!(parse_export("").is_empty());
+        assert!(parse_export("not json").is_empty());
+    }
+
+    #[test]
+    fn test_with_direnv_waits_for_resolve() {
+        let dir = std::env::temp_dir().join(format!("opman-direnv-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(".envrc"), "export OPMAN_TEST=1\n").unwrap();
+        let base = [("TERM".to_string(), "xterm".to_string())];
+
+        assert_eq!(
+            with_direnv(&base, &dir, false),
+            Some(vec![("TERM".to_string(), Some("xterm".to_string()))])
+        );
+        assert_eq!(with_direnv(&base, &dir, true), None);
+        // Whatever direnv made of it (a fresh `.envrc` is blocked), the
+        // answer is cached.
+        resolve_direnv(&dir);
+        assert!(with_direnv(&base, &dir, true).is_some());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
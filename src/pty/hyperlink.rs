@@ -0,0 +1,187 @@
+//! OSC 8 hyperlinks (`ESC ] 8 ; params ; URI ST`), which vt100 drops.
+//!
+//! The reader thread feeds output to the parser in pieces split at each
+//! OSC 8 sequence and notes the cursor there, so every link becomes one
+//! span per screen row with its URI. Spans are in screen coordinates and
+//! go stale when output scrolls; each remembers the text it covered so
+//! [`Hyperlinks::relocate`] can follow it up the screen.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Oldest spans are dropped beyond this many.
+const MAX_SPANS: usize = 256;
+
+/// One row's worth of a hyperlink: columns `start_col..end_col` of `row`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HyperlinkSpan {
+    pub row: u16,
+    pub start_col: u16,
+    pub end_col: u16,
+    pub url: String,
+    text: String,
+}
+
+/// Hyperlinks seen in one PTY's output.
+#[derive(Debug, Default)]
+pub struct Hyperlinks {
+    /// Newest last.
+    spans: VecDeque<HyperlinkSpan>,
+    /// Start position and URI of a link whose closing sequence is pending.
+    open: Option<(u16, u16, String)>,
+}
+
+impl Hyperlinks {
+    /// Record an OSC 8 sequence seen with the cursor at its current position:
+    /// a non-empty `uri` starts a link, an empty one ends it.
+    fn mark(&mut self, screen: &vt100::Screen, uri: &[u8]) {
+        let (row, col) = screen.cursor_position();
+        if let Some((start_row, start_col, url)) = self.open.take() {
+            self.close(screen, (start_row, start_col), (row, col), url);
+        }
+        if !uri.is_empty() {
+            self.open = Some((row, col, String::from_utf8_lossy(uri).into_owned()));
+        }
+    }
+
+    fn close(&mut self, screen: &vt100::Screen, start: (u16, u16), end: (u16, u16), url: String) {
+        // Output scrolled while the link was open; its start is lost.
+        if end <= start {
+            return;
+        }
+        let cols = screen.size().1;
+        for row in start.0..=end.0 {
+            let start_col = if row == start.0 { start.1 } else { 0 };
+            let end_col = if row == end.0 { end.1 } else { cols };
+            let text = screen.contents_between(row, start_col, row, end_col);
+            if start_col >= end_col || text.trim().is_empty() {
+                continue;
+            }
+            self.spans.push_back(HyperlinkSpan {
+                row,
+                start_col,
+                end_col,
+                url: url.clone(),
+                text,
+            });
+        }
+        while self.spans.len() > MAX_SPANS {
+            self.spans.pop_front();
+        }
+    }
+
+    /// Move each span to where its text now is, searching upward from its
+    /// recorded row, and forget spans whose text has left the screen.
+    pub fn relocate(&mut self, screen: &vt100::Screen) {
+        self.spans.retain_mut(|span| {
+            let found = (0..=span.row).rev().find(|&row| {
+                screen.contents_between(row, span.start_col, row, span.end_col) == span.text
+            });
+            match found {
+                Some(row) => {
+                    span.row = row;
+                    true
+                }
+                None => false,
+            }
+        });
+    }
+
+    /// Spans, newest first. Call [`Hyperlinks::relocate`] beforehand.
+    pub fn spans(&self) -> impl Iterator<Item = &HyperlinkSpan> {
+        self.spans.iter().rev()
+    }
+
+    /// URI of the newest link covering `(row, col)`.
+    pub fn url_at(&self, row: u16, col: u16) -> Option<&str> {
+        self.spans()
+            .find(|s| s.row == row && (s.start_col..s.end_col).contains(&col))
+            .map(|s| s.url.as_str())
+    }
+}
+
+/// Feed `data` to the parser, recording each OSC 8 sequence in `links`.
+/// A sequence split across two reads is passed through unrecorded.
+pub(super) fn process(parser: &mut vt100::Parser, data: &[u8], links: &Mutex<Hyperlinks>) {
+    let mut rest = data;
+    while let Some((end, uri)) = next_osc8(rest) {
+        parser.process(&rest[..end]);
+        if let Ok(mut links) = links.lock() {
+            links.mark(parser.screen(), uri);
+        }
+        rest = &rest[end..];
+    }
+    parser.process(rest);
+}
+
+/// Find the first complete OSC 8 sequence in `data`, returning the index just
+/// past its terminator (BEL or ST) and its URI.
+fn next_osc8(data: &[u8]) -> Option<(usize, &[u8])> {
+    let start = data.windows(4).position(|w| w == b"\x1b]8;")? + 4;
+    let body_len = data[start..].iter().position(|&b| b == 0x07 || b == 0x1b)?;
+    let body = &data[start..start + body_len];
+    let end = match data[start + body_len] {
+        0x07 => start + body_len + 1,
+        _ if data.get(start + body_len + 1) == Some(&b'\\') => start + body_len + 2,
+        _ => return None,
+    };
+    // Body is `params;URI`; params are ignored.
+    let uri = body
+        .iter()
+        .position(|&b| b == b';')
+        .map_or(&body[..0], |semi| &body[semi + 1..]);
+    Some((end, uri))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_span_and_url() {
+        let mut parser = vt100::Parser::new(5, 40, 0);
+        let links = Mutex::new(Hyperlinks::default());
+        process(
+            &mut parser,
+            b"see \x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\ here",
+            &links,
+        );
+        let links = links.lock().unwrap();
+        assert_eq!(links.url_at(0, 4), Some("https://example.com"));
+        assert_eq!(links.url_at(0, 7), Some("https://example.com"));
+        assert_eq!(links.url_at(0, 8), None);
+        assert_eq!(links.url_at(0, 3), None);
+    }
+
+    #[test]
+    fn test_bel_terminator_and_params() {
+        let mut parser = vt100::Parser::new(5, 40, 0);
+        let links = Mutex::new(Hyperlinks::default());
+        process(
+            &mut parser,
+            b"\x1b]8;id=1;file:///tmp/a\x07a\x1b]8;;\x07",
+            &links,
+        );
+        assert_eq!(links.lock().unwrap().url_at(0, 0), Some("file:///tmp/a"));
+    }
+
+    #[test]
+    fn test_relocate_after_scroll() {
+        let mut parser = vt100::Parser::new(3, 20, 0);
+        let links = Mutex::new(Hyperlinks::default());
+        process(
+            &mut parser,
+            b"\r\n\r\n\x1b]8;;u\x1b\\link\x1b]8;;\x1b\\",
+            &links,
+        );
+        process(&mut parser, b"\r\nmore", &links);
+        let mut links = links.lock().unwrap();
+        links.relocate(parser.screen());
+        assert_eq!(links.url_at(1, 0), Some("u"));
+        assert_eq!(links.url_at(2, 0), None);
+
+        parser.process(b"\x1b[2J");
+        links.relocate(parser.screen());
+        assert_eq!(links.spans().count(), 0);
+    }
+}
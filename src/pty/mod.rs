@@ -1,11 +1,20 @@
+mod command_line;
+mod direnv;
+pub mod hyperlink;
+mod output_log;
 mod reader;
 mod spawn_gitui;
 mod spawn_neovim;
 mod spawn_opencode;
 mod spawn_shell;
 
+pub use direnv::{resolve_direnv, with_direnv, EnvVar};
+pub use output_log::OutputLog;
+pub(crate) use reader::IDLE_BACKOFF;
+pub use spawn_opencode::attach_command;
+
 use std::io::Write;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
 use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, Result};
@@ -34,6 +43,9 @@ pub struct PtyInstance {
     pub scroll_offset: usize,
     pub name: String,
     pub command_state: Arc<Mutex<CommandState>>,
+    /// OSC 133 `D` (command finished) reports seen so far. Shell
+    /// integration also sends one with the first prompt.
+    pub commands_finished: Arc<AtomicU64>,
     /// Path to the neovim `--listen` socket (only set for neovim PTYs).
     pub nvim_listen_addr: Option<std::path::PathBuf>,
     /// Set to `true` by the reader thread when new PTY output arrives.
@@ -44,6 +56,32 @@ pub struct PtyInstance {
     /// thread whenever bytes arrive.  Read by hang detection to determine if
     /// a long-running tool call is still producing output.
     pub last_output_at: Arc<AtomicU64>,
+    /// Last command an agent sent here through the MCP `run` op. Shown in
+    /// the terminal tab bar so agent actions stay visible.
+    pub last_mcp_command: Option<String>,
+    /// Converted cells from the last draw (see `term_render::RenderCache`).
+    pub render_cache: std::cell::RefCell<crate::ui::term_render::RenderCache>,
+    /// Last DECSCUSR cursor style requested by the program (0 = default).
+    pub cursor_style: Arc<AtomicU8>,
+    /// OSC 8 hyperlinks in the output (see [`hyperlink`]).
+    pub hyperlinks: Arc<Mutex<hyperlink::Hyperlinks>>,
+    /// vt100's BEL count when last checked by [`PtyInstance::take_bell`].
+    bells_seen: usize,
+    /// A bell rang while this tab was in the background; cleared when the
+    /// tab is shown.
+    pub bell: bool,
+    command_line: command_line::CommandLineTracker,
+    /// Last command line submitted at this PTY's shell prompt.
+    pub last_command: Option<String>,
+    /// Command lines submitted since the app last collected them for the
+    /// project's command history.
+    pub submitted_commands: Vec<String>,
+    /// File this shell tab's output is mirrored to, if any. It stays with
+    /// the tab as other tabs open and close, and across restarts.
+    pub output_log_path: Option<std::path::PathBuf>,
+    /// The git root toggle last sent this shell tab to the git root rather
+    /// than the project root.
+    pub at_git_root: bool,
 }
 
 impl std::fmt::Debug for PtyInstance {
@@ -72,21 +110,94 @@ impl PtyInstance {
         self.dirty.swap(false, Ordering::AcqRel)
     }
 
+    /// Returns `true` if the program rang the bell (BEL) since the last call.
+    pub fn take_bell(&mut self) -> bool {
+        let count = match self.parser.lock() {
+            Ok(p) => p.screen().audible_bell_count(),
+            Err(_) => return false,
+        };
+        let rang = count != self.bells_seen;
+        self.bells_seen = count;
+        rang
+    }
+
+    /// Every line of the buffer, oldest scrollback line first. The
+    /// parser's scroll position is restored.
+    pub fn buffer_lines(&self) -> Vec<String> {
+        let Ok(mut parser) = self.parser.lock() else {
+            return Vec::new();
+        };
+        let saved = parser.screen().scrollback();
+        parser.set_scrollback(usize::MAX);
+        let scrollback_len = parser.screen().scrollback();
+        let lines = read_buffer_lines(&mut parser, scrollback_len);
+        parser.set_scrollback(saved);
+        lines
+    }
+
     /// Write input bytes (e.g. keystrokes) to the PTY child process.
+    ///
+    /// Command lines are followed only while the shell is at its prompt
+    /// (see [`Self::at_prompt`]); input to a running command, such as a
+    /// `sudo` password, is never recorded.
     pub fn write(&mut self, data: &[u8]) -> Result<()> {
-        if let Some(ref mut writer) = self.writer {
-            // Detect Enter key → mark command as Running
-            if data.contains(&b'\r') || data.contains(&b'\n') {
-                if let Ok(mut state) = self.command_state.lock() {
-                    *state = CommandState::Running;
-                }
+        if self.writer.is_none() {
+            return Ok(());
+        }
+        if self.at_prompt() {
+            let parser = &self.parser;
+            if let Some(command) = self.command_line.feed(data, || cursor_line(parser)) {
+                self.submitted_commands.push(command.clone());
+                self.last_command = Some(command);
             }
+        } else {
+            self.command_line = Default::default();
+        }
+        // Detect Enter key → mark command as Running
+        if data.contains(&b'\r') || data.contains(&b'\n') {
+            if let Ok(mut state) = self.command_state.lock() {
+                *state = CommandState::Running;
+            }
+        }
+        if let Some(ref mut writer) = self.writer {
             writer.write_all(data).context("Failed to write to PTY")?;
             writer.flush().context("Failed to flush PTY writer")?;
         }
         Ok(())
     }
 
+    /// Whether input goes to the shell's own prompt: no command is running
+    /// (Enter or OSC 133 `B` started one that hasn't finished), no
+    /// full-screen program is up, and echo is on (it is off while
+    /// programs read passwords).
+    fn at_prompt(&self) -> bool {
+        let running = self
+            .command_state
+            .lock()
+            .map_or(true, |s| *s == CommandState::Running);
+        let alternate = self
+            .parser
+            .lock()
+            .map_or(true, |p| p.screen().alternate_screen());
+        !running && !alternate && self.echo_enabled()
+    }
+
+    /// Whether the terminal echoes input, per the PTY's termios. `true`
+    /// when it can't be read.
+    fn echo_enabled(&self) -> bool {
+        #[cfg(unix)]
+        if let Some(fd) = self.master.as_ref().and_then(|m| m.as_raw_fd()) {
+            let mut termios = std::mem::MaybeUninit::<libc::termios>::uninit();
+            // SAFETY: `fd` is the open PTY master; `termios` is only read
+            // after `tcgetattr` reports it filled in.
+            if unsafe { libc::tcgetattr(fd, termios.as_mut_ptr()) } == 0 {
+                let termios = unsafe { termios.assume_init() };
+                return termios.c_lflag & libc::ECHO != 0;
+            }
+        }
+        true
+    }
+
     /// Resize the PTY (and inform the parser).
     pub fn resize(&mut self, rows: u16, cols: u16) -> Result<()> {
         if self.rows == rows && self.cols == cols {
@@ -122,6 +233,25 @@ impl PtyInstance {
         }
     }
 
+    /// OS process ID of the child, if it was spawned and is still tracked.
+    pub fn process_id(&self) -> Option<u32> {
+        self.child.as_ref().and_then(|c| c.process_id())
+    }
+
+    /// The program in the PTY's foreground: the shell itself at a prompt,
+    /// or whatever it is running. Falls back to the spawned child.
+    pub fn foreground_program(&self) -> Option<String> {
+        #[cfg(unix)]
+        let leader = self
+            .master
+            .as_ref()
+            .and_then(|m| m.process_group_leader())
+            .and_then(|pid| u32::try_from(pid).ok());
+        #[cfg(not(unix))]
+        let leader = None;
+        program_of(leader.or_else(|| self.process_id())?)
+    }
+
     /// Kill the child process.
     pub fn kill(&mut self) -> Result<()> {
         if let Some(ref mut child) = self.child {
@@ -130,3 +260,102 @@ impl PtyInstance {
         Ok(())
     }
 }
+
+/// Executable of `pid`: `/proc/<pid>/exe` on Linux, `ps` elsewhere.
+fn program_of(pid: u32) -> Option<String> {
+    if let Ok(path) = std::fs::read_link(format!("/proc/{pid}/exe")) {
+        return Some(path.to_string_lossy().into_owned());
+    }
+    let output = std::process::Command::new("ps")
+        .args(["-o", "comm=", "-p", &pid.to_string()])
+        .output()
+        .ok()?;
+    // Login shells report themselves as `-zsh`.
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let name = stdout.trim().trim_start_matches('-').to_string();
+    (output.status.success() && !name.is_empty()).then_some(name)
+}
+
+/// Every line of the parser's buffer, oldest scrollback line
+/// first. `scrollback_len` is the buffer's scrollback length; the parser
+/// is left scrolled to an arbitrary position.
+pub fn read_buffer_lines(parser: &mut vt100::Parser, scrollback_len: usize) -> Vec<String> {
+    let rows = parser.screen().size().0 as usize;
+    let total = scrollback_len + rows;
+    let mut lines = Vec::with_capacity(total);
+
+    // Read the buffer a screenful at a time; `first` is the line shown on
+    // the view's top row at each scroll position.
+    let mut top = 0;
+    while top < total && rows > 0 {
+        let offset = scrollback_len.saturating_sub(top);
+        parser.set_scrollback(offset);
+        let first = scrollback_len - offset;
+        let screen = parser.screen();
+        for line in top..(top + rows).min(total) {
+            let row = (line - first) as u16;
+            lines.push(screen.contents_between(row, 0, row + 1, 0));
+        }
+        top += rows;
+    }
+    lines
+}
+
+/// Text of the row the cursor is on.
+fn cursor_line(parser: &Mutex<vt100::Parser>) -> Option<String> {
+    let parser = parser.lock().ok()?;
+    let screen = parser.screen();
+    let (row, _) = screen.cursor_position();
+    Some(screen.contents_between(row, 0, row, screen.size().1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A PTY with no child process; its input goes to a buffer.
+    fn detached_pty() -> PtyInstance {
+        PtyInstance {
+            parser: Arc::new(Mutex::new(vt100::Parser::new(24, 80, 0))),
+            writer: Some(Box::new(Vec::new())),
+            child: None,
+            rows: 24,
+            cols: 80,
+            master: None,
+            scroll_offset: 0,
+            name: String::new(),
+            command_state: Arc::new(Mutex::new(CommandState::Idle)),
+            commands_finished: Arc::new(AtomicU64::new(0)),
+            nvim_listen_addr: None,
+            dirty: Arc::new(AtomicBool::new(false)),
+            last_output_at: Arc::new(AtomicU64::new(0)),
+            last_mcp_command: None,
+            render_cache: Default::default(),
+            cursor_style: Arc::new(AtomicU8::new(0)),
+            hyperlinks: Default::default(),
+            bells_seen: 0,
+            bell: false,
+            command_line: Default::default(),
+            last_command: None,
+            submitted_commands: Vec::new(),
+            output_log_path: None,
+            at_git_root: false,
+        }
+    }
+
+    #[test]
+    fn test_input_while_running_is_not_recorded() {
+        let mut pty = detached_pty();
+        pty.write(b"sudo make install\r").unwrap();
+        assert_eq!(*pty.command_state.lock().unwrap(), CommandState::Running);
+
+        // The command is still running, so this is its password prompt.
+        pty.write(b"hunter2\r").unwrap();
+        assert_eq!(pty.last_command.as_deref(), Some("sudo make install"));
+
+        // OSC 133 `D` reported the command finished: back at the prompt.
+        *pty.command_state.lock().unwrap() = CommandState::Success;
+        pty.write(b"ls\r").unwrap();
+        assert_eq!(pty.submitted_commands, ["sudo make install", "ls"]);
+    }
+}
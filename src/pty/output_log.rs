@@ -0,0 +1,139 @@
+//! On-disk mirror of a shell PTY's raw output, so recent terminal output
+//! survives a crash or restart. The file is capped: once it grows past
+//! its limit, it is cut back to the newest half, keeping it a rolling
+//! window of the most recent output.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+/// Raw output of one PTY appended to a capped file. Bytes are stored as
+/// received, escape sequences included, so they can be replayed into a
+/// terminal or read as text with the escapes stripped.
+pub struct OutputLog {
+    path: PathBuf,
+    file: File,
+    len: u64,
+    max_bytes: u64,
+}
+
+impl OutputLog {
+    /// Open (or create) the log at `path`, appending to what an earlier
+    /// run left there. It is readable by the user only: terminal output can
+    /// hold secrets. `None`, with a warning logged, if it can't be opened.
+    pub fn open(path: &Path, max_bytes: u64) -> Option<Self> {
+        let opened = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| {
+                let mut options = OpenOptions::new();
+                options.create(true).read(true).append(true);
+                private_mode(&mut options);
+                let file = options.open(path)?;
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    // Logs written before the mode was set.
+                    file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+                }
+                Ok(file)
+            })
+            .and_then(|file| Ok((file.metadata()?.len(), file)));
+        match opened {
+            Ok((len, file)) => Some(Self {
+                path: path.to_path_buf(),
+                file,
+                len,
+                max_bytes: max_bytes.max(2),
+            }),
+            Err(e) => {
+                tracing::warn!(path = %path.display(), "Failed to open terminal output log: {e}");
+                None
+            }
+        }
+    }
+
+    /// The file the output goes to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append `data`, cutting the file back to its newest half first if
+    /// it would grow past the cap.
+    pub fn write(&mut self, data: &[u8]) -> std::io::Result<()> {
+        // A single read larger than the cap keeps only its end.
+        let data = &data[data.len().saturating_sub(self.max_bytes as usize)..];
+        let incoming = data.len() as u64;
+        if self.len + incoming > self.max_bytes {
+            self.keep_tail((self.max_bytes / 2).min(self.max_bytes - incoming))?;
+        }
+        self.file.write_all(data)?;
+        self.len += incoming;
+        Ok(())
+    }
+
+    /// Rewrite the file to hold only its last `keep` bytes. The tail goes
+    /// to a temporary file renamed over the log, so a crash mid-rewrite
+    /// leaves the old log rather than a truncated one.
+    fn keep_tail(&mut self, keep: u64) -> std::io::Result<()> {
+        let keep = keep.min(self.len);
+        let mut tail = Vec::with_capacity(keep as usize);
+        self.file.seek(SeekFrom::Start(self.len - keep))?;
+        (&self.file).take(keep).read_to_end(&mut tail)?;
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        private_mode(&mut options);
+        let mut tmp = options.open(&tmp_path)?;
+        tmp.write_all(&tail)?;
+        drop(tmp);
+        std::fs::rename(&tmp_path, &self.path)?;
+        self.file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(&self.path)?;
+        self.len = tail.len() as u64;
+        Ok(())
+    }
+}
+
+/// Create files readable and writable by the user only.
+fn private_mode(options: &mut OpenOptions) {
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(options, 0o600);
+    #[cfg(not(unix))]
+    let _ = options;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_log_keeps_newest_bytes() {
+        let path = std::env::temp_dir()
+            .join(format!("opman-output-log-{}", std::process::id()))
+            .join("tab.log");
+        let mut log = OutputLog::open(&path, 8).unwrap();
+        log.write(b"abcdef").unwrap();
+        log.write(b"ghi").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"cdefghi");
+        log.write(b"0123456789").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"23456789");
+        assert!(!path.with_extension("log.tmp").exists());
+
+        // Reopening appends to the previous run's output.
+        drop(log);
+        let mut log = OutputLog::open(&path, 8).unwrap();
+        log.write(b"x").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"6789x");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -1,40 +1,66 @@
 use std::io::Read;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering};
 use std::sync::{Arc, Mutex};
 
-use super::CommandState;
+use super::hyperlink::{self, Hyperlinks};
+use super::{CommandState, OutputLog};
+
+/// Sleep between reads when the PTY reports no data instead of blocking.
+pub(crate) const IDLE_BACKOFF: std::time::Duration = std::time::Duration::from_millis(10);
 
 /// Background reader loop: reads bytes from the PTY and feeds them to the parser.
 ///
-/// Also scans for OSC 133 shell integration sequences to track command state:
+/// Also scans for OSC 133 shell integration sequences to track command state
+/// (every `D` also bumps `commands_finished`):
 /// - `\x1b]133;B` → command started (Running)
 /// - `\x1b]133;D;0` → command succeeded (Success)
 /// - `\x1b]133;D;N` where N≠0 → command failed (Failure)
 /// - `\x1b]133;A` → prompt shown (Idle, resets after Success/Failure display)
+///
+/// and for DECSCUSR (`CSI Ps SP q`), which vt100 ignores, storing the last
+/// requested cursor style in `cursor_style`. OSC 8 hyperlinks are recorded
+/// in `hyperlinks` (see [`hyperlink::process`]). Raw output is appended
+/// to `output_log` when given; it is dropped after a write error.
+///
+/// A read interrupted by a signal is retried rather than ending the loop;
+/// `WouldBlock` (a non-blocking fd) backs off with [`IDLE_BACKOFF`].
+#[allow(clippy::too_many_arguments)]
 pub(super) fn read_pty_output(
     mut reader: Box<dyn Read + Send>,
     parser: Arc<Mutex<vt100::Parser>>,
     command_state: Arc<Mutex<CommandState>>,
+    commands_finished: Arc<AtomicU64>,
     dirty: Arc<AtomicBool>,
     last_output_at: Arc<AtomicU64>,
+    cursor_style: Arc<AtomicU8>,
+    hyperlinks: Arc<Mutex<Hyperlinks>>,
+    mut output_log: Option<OutputLog>,
 ) {
     let mut buf = [0u8; 4096];
-    let mut leftover: Vec<u8> = Vec::new();
+    let mut osc133_carry: Vec<u8> = Vec::new();
+    let mut decscusr_carry: Vec<u8> = Vec::new();
     loop {
         match reader.read(&mut buf) {
             Ok(0) => break,
             Ok(n) => {
-                let data = if leftover.is_empty() {
-                    &buf[..n]
-                } else {
-                    leftover.extend_from_slice(&buf[..n]);
-                    leftover.as_slice()
-                };
-
-                scan_osc133(data, &command_state);
+                scan_osc133(
+                    &mut osc133_carry,
+                    &buf[..n],
+                    &command_state,
+                    &commands_finished,
+                );
+                if let Some(style) = scan_decscusr(&mut decscusr_carry, &buf[..n]) {
+                    cursor_style.store(style, Ordering::Release);
+                }
 
                 if let Ok(mut p) = parser.lock() {
-                    p.process(&buf[..n]);
+                    hyperlink::process(&mut p, &buf[..n], &hyperlinks);
+                }
+                if let Some(log) = output_log.as_mut() {
+                    if let Err(e) = log.write(&buf[..n]) {
+                        tracing::warn!("Stopped writing terminal output log: {e}");
+                        output_log = None;
+                    }
                 }
                 dirty.store(true, Ordering::Release);
                 let now = std::time::SystemTime::now()
@@ -42,23 +68,121 @@ pub(super) fn read_pty_output(
                     .unwrap_or_default()
                     .as_millis() as u64;
                 last_output_at.store(now, Ordering::Release);
-
-                // Keep trailing bytes for OSC sequences split across reads
-                let keep = data.len().min(32);
-                let tail = data[data.len() - keep..].to_vec();
-                leftover.clear();
-                leftover = tail;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(IDLE_BACKOFF);
             }
             Err(_) => break,
         }
     }
 }
 
+/// Return the `Ps` of the last DECSCUSR sequence (`ESC [ Ps SP q`) in
+/// `carry` followed by `data`, if any. An omitted `Ps` means 0 (terminal
+/// default). `carry` holds a sequence left unfinished by the previous read
+/// and is replaced by the one `data` ends in, if any.
+pub(super) fn scan_decscusr(carry: &mut Vec<u8>, data: &[u8]) -> Option<u8> {
+    let joined;
+    let data = if carry.is_empty() {
+        data
+    } else {
+        carry.extend_from_slice(data);
+        joined = std::mem::take(carry);
+        joined.as_slice()
+    };
+    *carry = unfinished_decscusr(data).to_vec();
+
+    let mut last = None;
+    let mut pos = 0;
+    while let Some(offset) = data[pos..].windows(2).position(|w| w == b"\x1b[") {
+        let start = pos + offset + 2;
+        let digits = data[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        let end = start + digits;
+        if data.get(end..end + 2) == Some(b" q") && digits <= 1 {
+            last = Some(if digits == 0 { 0 } else { data[start] - b'0' });
+        }
+        pos = end.max(start);
+    }
+    last.filter(|ps| *ps <= 6)
+}
+
+/// The end of `data` if it is the start of a DECSCUSR sequence (at most
+/// `ESC [ Ps SP`), else nothing.
+fn unfinished_decscusr(data: &[u8]) -> &[u8] {
+    let from = data.len().saturating_sub(4);
+    let Some(esc) = data[from..].iter().rposition(|&b| b == 0x1b) else {
+        return &[];
+    };
+    let tail = &data[from + esc..];
+    let unfinished = match &tail[1..] {
+        [] | [b'['] | [b'[', b' '] => true,
+        [b'[', ps] | [b'[', ps, b' '] => ps.is_ascii_digit(),
+        _ => false,
+    };
+    if unfinished {
+        tail
+    } else {
+        &[]
+    }
+}
+
+/// The end of `data` if it is an OSC 133 sequence (or the start of its
+/// prefix) without its BEL or ST terminator yet, else nothing.
+fn unfinished_osc133(data: &[u8]) -> &[u8] {
+    const PREFIX: &[u8] = b"\x1b]133;";
+    let from = data.len().saturating_sub(32);
+    let Some(esc) = data[from..].iter().rposition(|&b| b == 0x1b) else {
+        return &[];
+    };
+    let tail = &data[from + esc..];
+    let unfinished = if tail.len() <= PREFIX.len() {
+        PREFIX.starts_with(tail)
+    } else {
+        tail.starts_with(PREFIX) && !tail.contains(&0x07)
+    };
+    if unfinished {
+        tail
+    } else {
+        &[]
+    }
+}
+
+/// Scan `carry` followed by `data` for OSC 133 shell integration sequences
+/// (see [`scan_complete_osc133`]). A sequence `data` ends in before its
+/// terminator is left in `carry` for the next read, so it is scanned once
+/// complete and a `D` is never counted twice.
+pub(super) fn scan_osc133(
+    carry: &mut Vec<u8>,
+    data: &[u8],
+    command_state: &Arc<Mutex<CommandState>>,
+    commands_finished: &AtomicU64,
+) {
+    let joined;
+    let data = if carry.is_empty() {
+        data
+    } else {
+        carry.extend_from_slice(data);
+        joined = std::mem::take(carry);
+        joined.as_slice()
+    };
+    let complete = data.len() - unfinished_osc133(data).len();
+    scan_complete_osc133(&data[..complete], command_state, commands_finished);
+    *carry = data[complete..].to_vec();
+}
+
 /// Scan a byte slice for OSC 133 shell integration sequences.
 ///
 /// Looks for patterns like `ESC ] 133 ; <cmd> BEL` or `ESC ] 133 ; <cmd> ST`
 /// where BEL = 0x07 and ST = ESC \.
-pub(super) fn scan_osc133(data: &[u8], command_state: &Arc<Mutex<CommandState>>) {
+fn scan_complete_osc133(
+    data: &[u8],
+    command_state: &Arc<Mutex<CommandState>>,
+    commands_finished: &AtomicU64,
+) {
     // OSC 133 prefix: ESC ] 1 3 3 ;
     const PREFIX: &[u8] = b"\x1b]133;";
 
@@ -91,6 +215,7 @@ pub(super) fn scan_osc133(data: &[u8], command_state: &Arc<Mutex<CommandState>>)
                 // 'C' = command output start (ignore, already Running)
                 b'C' => {}
                 b'D' => {
+                    commands_finished.fetch_add(1, Ordering::Release);
                     if seq_start + 1 < data.len() && data[seq_start + 1] == b';' {
                         let code_start = seq_start + 2;
                         let mut code_end = code_start;
@@ -135,3 +260,55 @@ pub(super) fn scan_osc133(data: &[u8], command_state: &Arc<Mutex<CommandState>>)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc133_split_across_reads_counts_once() {
+        let state = Arc::new(Mutex::new(CommandState::Idle));
+        let finished = AtomicU64::new(0);
+        let mut carry: Vec<u8> = Vec::new();
+        for read in [&b"out\x1b]13"[..], b"3;D;1", b"\x07\x1b]133;A\x07more"] {
+            scan_osc133(&mut carry, read, &state, &finished);
+        }
+        assert_eq!(finished.load(Ordering::Acquire), 1);
+        assert_eq!(*state.lock().unwrap(), CommandState::Failure);
+        assert!(carry.is_empty());
+    }
+
+    #[test]
+    fn test_scan_decscusr() {
+        let mut carry = Vec::new();
+        assert_eq!(scan_decscusr(&mut carry, b"\x1b[5 q"), Some(5));
+        assert_eq!(scan_decscusr(&mut carry, b"a\x1b[2 qb\x1b[ q"), Some(0));
+        // Out of range, too many digits, or not DECSCUSR.
+        assert_eq!(scan_decscusr(&mut carry, b"\x1b[7 q\x1b[12 q\x1b[5q"), None);
+        assert!(carry.is_empty());
+    }
+
+    #[test]
+    fn test_scan_decscusr_split_across_reads() {
+        let seq = b"\x1b[3 q";
+        for split in 1..seq.len() {
+            let mut carry = Vec::new();
+            let (first, second) = seq.split_at(split);
+            let mut read = b"output".to_vec();
+            read.extend_from_slice(first);
+            assert_eq!(scan_decscusr(&mut carry, &read), None, "split at {split}");
+            assert_eq!(carry, first, "split at {split}");
+            assert_eq!(
+                scan_decscusr(&mut carry, second),
+                Some(3),
+                "split at {split}"
+            );
+            assert!(carry.is_empty());
+        }
+
+        // A finished sequence isn't reported again by the next read.
+        let mut carry = Vec::new();
+        assert_eq!(scan_decscusr(&mut carry, b"\x1b[4 q"), Some(4));
+        assert_eq!(scan_decscusr(&mut carry, b"more"), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8};
 use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, Result};
@@ -14,6 +14,7 @@ impl PtyInstance {
     pub fn spawn_gitui(
         rows: u16,
         cols: u16,
+        scrollback: usize,
         working_dir: &std::path::Path,
         theme_path: Option<&std::path::Path>,
     ) -> Result<Self> {
@@ -57,25 +58,35 @@ impl PtyInstance {
         let safe_rows = rows.max(2);
         let safe_cols = cols.max(2);
         let parser = Arc::new(Mutex::new(vt100::Parser::new(
-            safe_rows, safe_cols, 1_000_000,
+            safe_rows, safe_cols, scrollback,
         )));
 
         let command_state = Arc::new(Mutex::new(CommandState::Idle));
+        let commands_finished = Arc::new(AtomicU64::new(0));
 
         let dirty = Arc::new(AtomicBool::new(true));
         let last_output_at = Arc::new(AtomicU64::new(0));
+        let cursor_style = Arc::new(AtomicU8::new(0));
+        let hyperlinks = Arc::new(Mutex::new(Default::default()));
 
         let parser_clone = Arc::clone(&parser);
         let cmd_state_clone = Arc::clone(&command_state);
+        let finished_clone = Arc::clone(&commands_finished);
         let dirty_clone = Arc::clone(&dirty);
         let output_at_clone = Arc::clone(&last_output_at);
+        let cursor_style_clone = Arc::clone(&cursor_style);
+        let hyperlinks_clone = Arc::clone(&hyperlinks);
         std::thread::spawn(move || {
             reader::read_pty_output(
                 reader_handle,
                 parser_clone,
                 cmd_state_clone,
+                finished_clone,
                 dirty_clone,
                 output_at_clone,
+                cursor_style_clone,
+                hyperlinks_clone,
+                None,
             );
         });
         Ok(Self {
@@ -88,9 +99,21 @@ impl PtyInstance {
             scroll_offset: 0,
             name: String::new(),
             command_state,
+            commands_finished,
             nvim_listen_addr: None,
             dirty,
             last_output_at,
+            last_mcp_command: None,
+            render_cache: Default::default(),
+            cursor_style,
+            hyperlinks,
+            bells_seen: 0,
+            bell: false,
+            command_line: Default::default(),
+            last_command: None,
+            submitted_commands: Vec::new(),
+            output_log_path: None,
+            at_git_root: false,
         })
     }
 }
@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8};
 use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, Result};
@@ -11,15 +11,21 @@ use super::{CommandState, PtyInstance};
 impl PtyInstance {
     /// Spawn a new PTY running `opencode attach <url>`.
     ///
-    /// The PTY will capture the opencode TUI output via a VT100 parser.
+    /// `command` replaces `opencode` and may carry extra flags (see
+    /// [`attach_command`]). The PTY will capture the opencode TUI output
+    /// via a VT100 parser.
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn(
         url: &str,
         rows: u16,
         cols: u16,
+        scrollback: usize,
         working_dir: &std::path::Path,
         session_id: Option<&str>,
         theme_envs: &[(String, String)],
+        command: Option<&str>,
     ) -> Result<Self> {
+        let (program, extra_args) = attach_command(command)?;
         let pty_system = native_pty_system();
 
         let pair = pty_system
@@ -31,7 +37,7 @@ impl PtyInstance {
             })
             .context("Failed to open PTY pair")?;
 
-        let mut cmd = CommandBuilder::new("opencode");
+        let mut cmd = CommandBuilder::new(&program);
         cmd.arg("attach");
         cmd.arg(url);
         cmd.arg("--dir");
@@ -46,6 +52,7 @@ impl PtyInstance {
             // terminal/neovim tool calls route to the correct session.
             cmd.env("OPENCODE_SESSION_ID", sid);
         }
+        cmd.args(&extra_args);
         cmd.cwd(working_dir);
         cmd.env("TERM", "xterm-256color");
         cmd.env("COLORTERM", "truecolor");
@@ -56,7 +63,7 @@ impl PtyInstance {
         let child = pair
             .slave
             .spawn_command(cmd)
-            .context("Failed to spawn opencode attach in PTY")?;
+            .with_context(|| format!("Failed to spawn {program} attach in PTY"))?;
 
         let reader_handle = pair
             .master
@@ -71,25 +78,35 @@ impl PtyInstance {
         let safe_rows = rows.max(2);
         let safe_cols = cols.max(2);
         let parser = Arc::new(Mutex::new(vt100::Parser::new(
-            safe_rows, safe_cols, 1_000_000,
+            safe_rows, safe_cols, scrollback,
         )));
 
         let command_state = Arc::new(Mutex::new(CommandState::Idle));
+        let commands_finished = Arc::new(AtomicU64::new(0));
 
         let dirty = Arc::new(AtomicBool::new(true));
         let last_output_at = Arc::new(AtomicU64::new(0));
+        let cursor_style = Arc::new(AtomicU8::new(0));
+        let hyperlinks = Arc::new(Mutex::new(Default::default()));
 
         let parser_clone = Arc::clone(&parser);
         let cmd_state_clone = Arc::clone(&command_state);
+        let finished_clone = Arc::clone(&commands_finished);
         let dirty_clone = Arc::clone(&dirty);
         let output_at_clone = Arc::clone(&last_output_at);
+        let cursor_style_clone = Arc::clone(&cursor_style);
+        let hyperlinks_clone = Arc::clone(&hyperlinks);
         std::thread::spawn(move || {
             reader::read_pty_output(
                 reader_handle,
                 parser_clone,
                 cmd_state_clone,
+                finished_clone,
                 dirty_clone,
                 output_at_clone,
+                cursor_style_clone,
+                hyperlinks_clone,
+                None,
             );
         });
 
@@ -105,9 +122,65 @@ impl PtyInstance {
             scroll_offset: 0,
             name: String::new(),
             command_state,
+            commands_finished,
             nvim_listen_addr: None,
             dirty,
             last_output_at,
+            last_mcp_command: None,
+            render_cache: Default::default(),
+            cursor_style,
+            hyperlinks,
+            bells_seen: 0,
+            bell: false,
+            command_line: Default::default(),
+            last_command: None,
+            submitted_commands: Vec::new(),
+            output_log_path: None,
+            at_git_root: false,
         })
     }
 }
+
+/// Split a configured opencode command into its program and extra flags,
+/// with POSIX shell quoting (`--prompt 'two words'`).
+///
+/// `None` yields plain `opencode`. A configured program must exist (as a
+/// path, or on `$PATH`) so a typo fails loudly instead of leaving a blank
+/// pane.
+pub fn attach_command(command: Option<&str>) -> Result<(String, Vec<String>)> {
+    let Some(command) = command else {
+        return Ok(("opencode".to_string(), Vec::new()));
+    };
+    let parts = shell_words::split(command)
+        .with_context(|| format!("Invalid opencode command `{command}`"))?;
+    let mut parts = parts.into_iter();
+    let program = parts.next().context("opencode command is empty")?;
+    if crate::util::which(&program).is_none() {
+        anyhow::bail!("opencode command `{program}` not found");
+    }
+    Ok((program, parts.collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attach_command() {
+        let (program, args) = attach_command(None).unwrap();
+        assert_eq!(program, "opencode");
+        assert!(args.is_empty());
+
+        let (program, args) = attach_command(Some("/bin/sh --model a/b")).unwrap();
+        assert_eq!(program, "/bin/sh");
+        assert_eq!(args, ["--model", "a/b"]);
+
+        let (_, args) =
+            attach_command(Some(r#"/bin/sh --prompt 'two words' "it's" a\ b"#)).unwrap();
+        assert_eq!(args, ["--prompt", "two words", "it's", "a b"]);
+        assert!(attach_command(Some("/bin/sh --prompt 'unclosed")).is_err());
+
+        assert!(attach_command(Some("   ")).is_err());
+        assert!(attach_command(Some("opencdoe-typo-not-installed")).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicU8};
 use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, Result};
@@ -6,20 +6,26 @@ use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use tracing::debug;
 
 use super::reader;
-use super::{CommandState, PtyInstance};
+use super::{CommandState, EnvVar, OutputLog, PtyInstance};
 
 impl PtyInstance {
     /// Spawn a new PTY running the user's default shell.
     ///
-    /// Uses $SHELL or falls back to /bin/bash.
+    /// Uses $SHELL or falls back to /bin/bash. `envs` are set (or, when
+    /// `None`, unset) in the shell's environment. `scrollback` is the number
+    /// of lines kept above the screen. Output is also mirrored to
+    /// `output_log` when given.
+    #[allow(clippy::too_many_arguments)]
     pub fn spawn_shell(
         rows: u16,
         cols: u16,
+        scrollback: usize,
         working_dir: &std::path::Path,
-        theme_envs: &[(String, String)],
+        envs: &[EnvVar],
         theme_dir: Option<&std::path::Path>,
         command: Option<&str>,
         name: Option<String>,
+        output_log: Option<OutputLog>,
     ) -> Result<Self> {
         let shell = command
             .map(|s| s.to_string())
@@ -41,8 +47,11 @@ impl PtyInstance {
         cmd.cwd(working_dir);
         cmd.env("TERM", "xterm-256color");
         cmd.env("COLORTERM", "truecolor");
-        for (key, val) in theme_envs {
-            cmd.env(key, val);
+        for (key, val) in envs {
+            match val {
+                Some(val) => cmd.env(key, val),
+                None => cmd.env_remove(key),
+            }
         }
         if let Some(dir) = theme_dir {
             let gitui_theme = dir.join("gitui/opencode.ron");
@@ -81,26 +90,39 @@ impl PtyInstance {
 
         let safe_rows = rows.max(2);
         let safe_cols = cols.max(2);
+        // vt100 keeps the scrollback capacity across `set_size`, so resizes
+        // don't shrink it back to a default.
         let parser = Arc::new(Mutex::new(vt100::Parser::new(
-            safe_rows, safe_cols, 1_000_000,
+            safe_rows, safe_cols, scrollback,
         )));
 
         let command_state = Arc::new(Mutex::new(CommandState::Idle));
+        let commands_finished = Arc::new(AtomicU64::new(0));
 
         let dirty = Arc::new(AtomicBool::new(true));
         let last_output_at = Arc::new(AtomicU64::new(0));
+        let cursor_style = Arc::new(AtomicU8::new(0));
+        let hyperlinks = Arc::new(Mutex::new(Default::default()));
 
+        let output_log_path = output_log.as_ref().map(|log| log.path().to_path_buf());
         let parser_clone = Arc::clone(&parser);
         let cmd_state_clone = Arc::clone(&command_state);
+        let finished_clone = Arc::clone(&commands_finished);
         let dirty_clone = Arc::clone(&dirty);
         let output_at_clone = Arc::clone(&last_output_at);
+        let cursor_style_clone = Arc::clone(&cursor_style);
+        let hyperlinks_clone = Arc::clone(&hyperlinks);
         std::thread::spawn(move || {
             reader::read_pty_output(
                 reader_handle,
                 parser_clone,
                 cmd_state_clone,
+                finished_clone,
                 dirty_clone,
                 output_at_clone,
+                cursor_style_clone,
+                hyperlinks_clone,
+                output_log,
             );
         });
 
@@ -116,9 +138,21 @@ impl PtyInstance {
             scroll_offset: 0,
             name: name.unwrap_or_else(|| String::new()),
             command_state,
+            commands_finished,
             nvim_listen_addr: None,
             dirty,
             last_output_at,
+            last_mcp_command: None,
+            render_cache: Default::default(),
+            cursor_style,
+            hyperlinks,
+            bells_seen: 0,
+            bell: false,
+            command_line: Default::default(),
+            last_command: None,
+            submitted_commands: Vec::new(),
+            output_log_path,
+            at_git_root: false,
         };
         Ok(pty)
     }
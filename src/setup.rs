@@ -15,32 +15,7 @@ use crate::web;
 /// Handle the `slack-manifest` subcommand.
 pub(crate) fn handle_slack_manifest() -> Result<()> {
     const MANIFEST: &str = include_str!("../slack-app-manifest.yaml");
-    // Try to copy to clipboard (macOS: pbcopy, Linux: xclip/xsel)
-    let copied = std::process::Command::new("pbcopy")
-        .stdin(std::process::Stdio::piped())
-        .spawn()
-        .and_then(|mut child| {
-            use std::io::Write;
-            if let Some(ref mut stdin) = child.stdin {
-                stdin.write_all(MANIFEST.as_bytes())?;
-            }
-            child.wait()
-        })
-        .map(|s| s.success())
-        .unwrap_or(false)
-        || std::process::Command::new("xclip")
-            .args(["-selection", "clipboard"])
-            .stdin(std::process::Stdio::piped())
-            .spawn()
-            .and_then(|mut child| {
-                use std::io::Write;
-                if let Some(ref mut stdin) = child.stdin {
-                    stdin.write_all(MANIFEST.as_bytes())?;
-                }
-                child.wait()
-            })
-            .map(|s| s.success())
-            .unwrap_or(false);
+    let copied = crate::clipboard::copy_to_clipboard(MANIFEST).is_ok();
 
     println!("{}", MANIFEST);
     if copied {
@@ -88,6 +63,28 @@ pub(crate) async fn setup_web_server(
     }
 }
 
+/// Start the `--status-port` endpoint and point the app at its snapshot.
+pub(crate) async fn setup_status_endpoint(
+    status_port: Option<u16>,
+    server_handle: &crate::server::ServerHandle,
+    app: &mut App,
+) {
+    let Some(port) = status_port else {
+        return;
+    };
+    let status = crate::app::StatusHandle::default();
+    match crate::status_server::start_status_server(port, status.clone(), server_handle.clone())
+        .await
+    {
+        Ok(actual_port) => {
+            info!("Status endpoint at http://127.0.0.1:{}/status", actual_port);
+            app.status_handle = Some(status);
+            app.publish_status();
+        }
+        Err(e) => tracing::warn!("Failed to start status endpoint: {:#}", e),
+    }
+}
+
 /// Setup the KV file watcher for theme reloading.
 pub(crate) fn setup_kv_watcher() -> Result<(std::sync::mpsc::Receiver<notify::Event>, PollWatcher)>
 {
@@ -158,38 +155,16 @@ pub(crate) fn setup_initial_projects(
         sse::spawn_provider_fetcher(&app.bg_tx, i, dir);
     }
 
-    // Spawn MCP socket servers and write opencode.json for each project
+    // Spawn MCP socket servers and write opencode.json for each project.
+    // This also sets `neovim_mcp_enabled` (disables follow-edits).
     if enable_any_mcp {
-        for i in 0..app.projects.len() {
-            let project_path = app.projects[i].path.clone();
-            if enable_terminal_mcp || enable_neovim_mcp {
-                mcp::spawn_socket_server(
-                    &project_path,
-                    app.bg_tx.clone(),
-                    i,
-                    app.nvim_registry.clone(),
-                    app.last_mcp_activity_ms.clone(),
-                );
-            }
-            if let Err(e) = mcp::write_opencode_json(
-                &project_path,
-                enable_terminal_mcp,
-                enable_neovim_mcp,
-                enable_time_mcp,
-                enable_ui_mcp,
-            ) {
-                tracing::warn!(
-                    "Failed to write opencode.json for {}: {}",
-                    project_path.display(),
-                    e
-                );
-            }
-        }
-    }
-
-    // When neovim MCP is enabled, store flag on App (disables follow-edits)
-    if enable_neovim_mcp {
-        app.neovim_mcp_enabled = true;
+        app.mcp_flags = mcp::McpFlags {
+            terminal: enable_terminal_mcp,
+            neovim: enable_neovim_mcp,
+            time: enable_time_mcp,
+            ui: enable_ui_mcp,
+        };
+        app.start_mcp_servers();
     }
 
     // TUI-only setup: PTY spawning and neovim auto-start
@@ -197,8 +172,25 @@ pub(crate) fn setup_initial_projects(
         return;
     }
 
-    // Auto-activate project 0 by spawning PTY directly
-    let (cols, rows) = crossterm::terminal::size().unwrap_or((80, 24));
+    let startup = app.config.settings.startup.clone();
+
+    // Auto-start neovim for every project (by default only when neovim MCP
+    // is enabled) once its session is attached, whether that's the startup
+    // project below or one picked later.
+    if startup.prespawn_neovim.unwrap_or(enable_neovim_mcp) {
+        app.prespawn_neovim_for = app.projects.iter().map(|p| p.path.clone()).collect();
+    }
+
+    // Auto-activate the startup project by spawning its PTY directly
+    let Some(idx) = startup.project_index(app.projects.iter().map(|p| p.name.as_str())) else {
+        info!("Startup project activation disabled");
+        return;
+    };
+    app.active_project = idx;
+    if startup.prespawn_shell {
+        app.prespawn_shell_for = Some(app.projects[idx].path.clone());
+    }
+    let (cols, rows) = app.terminal_size();
     let content_area = ratatui::layout::Rect::new(0, 0, cols, rows.saturating_sub(1));
     app.layout.compute_rects(content_area);
     let (inner_cols, inner_rows) = app
@@ -206,18 +198,28 @@ pub(crate) fn setup_initial_projects(
         .panel_rect(crate::ui::layout_manager::PanelId::TerminalPane)
         .map(|r| (r.width, r.height))
         .unwrap_or((cols.saturating_sub(32), rows.saturating_sub(2)));
-    let path = app.projects[0].path.clone();
+    let path = app.projects[idx].path.clone();
     let theme_envs = app.theme.pty_env_vars();
-    spawn_activate_project(&app.bg_tx, 0, path, inner_rows, inner_cols, theme_envs);
+    let opencode_command = app.opencode_command(idx);
+    spawn_activate_project(
+        &app.bg_tx,
+        idx,
+        path,
+        inner_rows,
+        inner_cols,
+        app.config.settings.scrollback_lines,
+        theme_envs,
+        opencode_command,
+    );
 
-    // Auto-start neovim PTY for all projects when neovim MCP is enabled
-    if enable_neovim_mcp {
-        let saved = app.active_project;
-        for i in 0..app.projects.len() {
-            app.active_project = i;
-            app.ensure_neovim_pty();
-        }
-        app.active_project = saved;
+    // Panels restored visible from the last session need their PTYs.
+    // Session-scoped PTYs (neovim, shell) are also picked up by the draw
+    // loop once the active session has been attached.
+    if app.layout.is_visible(crate::ui::layout_manager::PanelId::GitPanel) {
+        app.ensure_gitui_pty();
+    }
+    if app.layout.is_visible(crate::ui::layout_manager::PanelId::NeovimPane) {
+        app.ensure_neovim_pty();
     }
 }
 
@@ -135,6 +135,19 @@ pub(super) fn handle_sse_data(
                 }
             }
         }
+        "permission.replied" => {
+            let request_id = event
+                .properties
+                .get("requestID")
+                .or_else(|| event.properties.get("id"))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            if !request_id.is_empty() {
+                debug!(project_idx, request_id = %request_id, "SSE: permission.replied");
+                let _ = bg_tx.send(BackgroundEvent::SsePermissionReplied { request_id });
+            }
+        }
         "question.asked" => {
             match serde_json::from_value::<crate::app::QuestionRequest>(event.properties) {
                 Ok(req) => {
@@ -155,6 +168,49 @@ pub(super) fn handle_sse_data(
                 }
             }
         }
+        "message.part.updated" => {
+            let Some(part) = event.properties.get("part") else {
+                return Ok(());
+            };
+            if part.get("type").and_then(|v| v.as_str()) != Some("tool") {
+                return Ok(());
+            }
+            let str_field = |key: &str| {
+                part.get(key)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let session_id = str_field("sessionID");
+            let call_id = str_field("callID");
+            let tool = str_field("tool");
+            let state = part.get("state");
+            let status = state
+                .and_then(|s| s.get("status"))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            match status {
+                "pending" | "running" => {
+                    let _ = bg_tx.send(BackgroundEvent::SseToolStarted {
+                        project_idx,
+                        session_id,
+                        call_id,
+                        tool,
+                        input: state
+                            .and_then(|s| s.get("input"))
+                            .cloned()
+                            .unwrap_or_default(),
+                    });
+                }
+                "completed" | "error" => {
+                    let _ = bg_tx.send(BackgroundEvent::SseToolFinished {
+                        session_id,
+                        call_id,
+                    });
+                }
+                _ => {}
+            }
+        }
         "session.error" => {
             let session_id = event
                 .properties
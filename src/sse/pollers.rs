@@ -74,7 +74,8 @@ pub fn spawn_session_poller(
 }
 
 /// Fetch provider model limits once at startup for a project.
-/// Sends ModelLimitsFetched with the max context window found across all models.
+/// Sends ModelLimitsFetched with the max context window found across all
+/// models and the default `provider/model`, if the server reports one.
 pub fn spawn_provider_fetcher(
     bg_tx: &mpsc::UnboundedSender<BackgroundEvent>,
     project_idx: usize,
@@ -105,9 +106,14 @@ pub fn spawn_provider_fetcher(
                 _ => continue,
             };
 
+            // Providers are either the body itself or its `all` field.
+            let providers = body
+                .as_array()
+                .or_else(|| body.get("all").and_then(|a| a.as_array()));
+
             // Find the largest context window across all providers/models
             let mut max_context: u64 = 0;
-            if let Some(providers) = body.as_array() {
+            if let Some(providers) = providers {
                 for provider in providers {
                     if let Some(models) = provider.get("models").and_then(|m| m.as_object()) {
                         for (_model_id, model) in models {
@@ -126,11 +132,18 @@ pub fn spawn_provider_fetcher(
             }
 
             if max_context > 0 {
+                let model = default_model(&body);
                 let _ = tx.send(BackgroundEvent::ModelLimitsFetched {
                     project_idx,
                     context_window: max_context,
+                    model: model.clone(),
                 });
-                debug!(project_idx, max_context, "Provider model limits fetched");
+                debug!(
+                    project_idx,
+                    max_context,
+                    ?model,
+                    "Provider model limits fetched"
+                );
                 return;
             }
         }
@@ -139,7 +152,24 @@ pub fn spawn_provider_fetcher(
         let _ = tx.send(BackgroundEvent::ModelLimitsFetched {
             project_idx,
             context_window: 200_000,
+            model: None,
         });
         debug!(project_idx, "Using default context window (200k)");
     });
 }
+
+/// The default `provider/model` from a `/provider` response: the default
+/// of the first connected provider that has one, else any default.
+fn default_model(body: &serde_json::Value) -> Option<String> {
+    let defaults = body.get("default")?.as_object()?;
+    let connected = body
+        .get("connected")
+        .and_then(|c| c.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|p| p.as_str());
+    connected
+        .filter_map(|p| Some((p, defaults.get(p)?)))
+        .chain(defaults.iter().map(|(p, m)| (p.as_str(), m)))
+        .find_map(|(provider, model)| Some(format!("{provider}/{}", model.as_str()?)))
+}
@@ -0,0 +1,78 @@
+//! Local read-only status endpoint (`--status-port`): `GET /status` returns
+//! opman's live state as JSON for tmux status lines, menubar apps and
+//! scripts. Binds to 127.0.0.1 only.
+
+use anyhow::{Context, Result};
+use axum::extract::State;
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Serialize;
+use tracing::{error, info};
+
+use crate::app::{StatusHandle, StatusSnapshot};
+use crate::server::ServerHandle;
+
+#[derive(Clone)]
+struct StatusState {
+    status: StatusHandle,
+    server: ServerHandle,
+}
+
+#[derive(Serialize)]
+struct StatusResponse {
+    #[serde(flatten)]
+    snapshot: StatusSnapshot,
+    server: ServerStatus,
+}
+
+/// The managed `opencode serve` process.
+#[derive(Serialize)]
+struct ServerStatus {
+    url: &'static str,
+    running: bool,
+}
+
+/// Serve `status` on `127.0.0.1:port` (0 = random free port) and return
+/// the port bound.
+pub(crate) async fn start_status_server(
+    port: u16,
+    status: StatusHandle,
+    server: ServerHandle,
+) -> Result<u16> {
+    let listener = tokio::net::TcpListener::bind(("127.0.0.1", port))
+        .await
+        .with_context(|| format!("Failed to bind status endpoint to 127.0.0.1:{port}"))?;
+    let actual_port = listener.local_addr()?.port();
+    let router = Router::new()
+        .route("/status", get(get_status))
+        .with_state(StatusState { status, server });
+
+    tokio::spawn(async move {
+        info!("Status endpoint listening on 127.0.0.1:{}", actual_port);
+        if let Err(e) = axum::serve(listener, router).await {
+            error!("Status endpoint error: {}", e);
+        }
+    });
+    Ok(actual_port)
+}
+
+async fn get_status(State(state): State<StatusState>) -> Json<StatusResponse> {
+    let snapshot = state.status.read().map(|s| s.clone()).unwrap_or_default();
+    Json(StatusResponse {
+        snapshot,
+        server: ServerStatus {
+            url: crate::app::base_url(),
+            running: server_running(&state.server),
+        },
+    })
+}
+
+/// Whether the opencode server process is still alive.
+fn server_running(server: &ServerHandle) -> bool {
+    let Ok(mut child) = server.lock() else {
+        return false;
+    };
+    child
+        .as_mut()
+        .is_some_and(|c| matches!(c.try_wait(), Ok(None)))
+}
@@ -0,0 +1,143 @@
+use std::collections::VecDeque;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Clear, Widget};
+
+use crate::app::{ActivityEntry, ActivityKind};
+use crate::theme::ThemeColors;
+
+/// Overlay panel showing a session's activity timeline, newest at the bottom.
+pub struct ActivityLogPanel<'a> {
+    theme: &'a ThemeColors,
+    /// Session title (or ID) shown in the title row.
+    title: String,
+    entries: Option<&'a VecDeque<ActivityEntry>>,
+    scroll: usize,
+}
+
+impl<'a> ActivityLogPanel<'a> {
+    pub fn new(
+        theme: &'a ThemeColors,
+        title: String,
+        entries: Option<&'a VecDeque<ActivityEntry>>,
+        scroll: usize,
+    ) -> Self {
+        Self {
+            theme,
+            title,
+            entries,
+            scroll,
+        }
+    }
+
+    fn kind_color(&self, kind: ActivityKind) -> Color {
+        match kind {
+            ActivityKind::Session => self.theme.primary,
+            ActivityKind::Tool => self.theme.accent,
+            ActivityKind::Edit => self.theme.info,
+            ActivityKind::Cost => self.theme.secondary,
+            ActivityKind::Permission => self.theme.warning,
+            ActivityKind::Error => self.theme.error,
+        }
+    }
+
+    pub fn render_popup(&self, area: Rect, buf: &mut Buffer) {
+        // 80% width, 70% height, centered
+        let popup_width = (area.width * 80 / 100)
+            .max(60)
+            .min(area.width.saturating_sub(2));
+        let popup_height = (area.height * 70 / 100)
+            .max(14)
+            .min(area.height.saturating_sub(2));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        super::render_overlay_dim(area, buf);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default().style(Style::default().bg(self.theme.background_panel));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 6 || inner.width < 20 {
+            return;
+        }
+
+        let cx = inner.x + 1;
+        let cw = inner.width.saturating_sub(2);
+
+        // ── Title row ──────────────────────────────────────────────────
+        let title_y = inner.y;
+        let esc_hint = "esc";
+        let title = format!("Activity · {}", self.title);
+        let title_width = cw.saturating_sub(esc_hint.len() as u16 + 1) as usize;
+        buf.set_string(
+            cx,
+            title_y,
+            crate::util::truncate_str(&title, title_width),
+            Style::default()
+                .fg(self.theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+        let esc_x = cx + cw.saturating_sub(esc_hint.len() as u16);
+        buf.set_string(
+            esc_x,
+            title_y,
+            esc_hint,
+            Style::default().fg(self.theme.text_muted),
+        );
+
+        // ── Separator ──────────────────────────────────────────────────
+        let sep_y = title_y + 1;
+        let sep = "─".repeat(cw as usize);
+        buf.set_string(
+            cx,
+            sep_y,
+            &sep,
+            Style::default().fg(self.theme.border_subtle),
+        );
+
+        // ── Entries (newest at the bottom) ─────────────────────────────
+        let list_y = sep_y + 1;
+        let max_rows = (inner.y + inner.height).saturating_sub(list_y + 1) as usize;
+
+        match self.entries.filter(|e| !e.is_empty()) {
+            None => {
+                buf.set_string(
+                    cx,
+                    list_y,
+                    "(no activity recorded yet)",
+                    Style::default().fg(self.theme.text_muted),
+                );
+            }
+            Some(entries) => {
+                let scroll = self.scroll.min(entries.len().saturating_sub(max_rows));
+                let end = entries.len() - scroll;
+                let start = end.saturating_sub(max_rows);
+                for (i, entry) in entries.range(start..end).enumerate() {
+                    let cy = list_y + i as u16;
+                    let time = entry.at.format("%H:%M:%S").to_string();
+                    buf.set_string(cx, cy, &time, Style::default().fg(self.theme.text_muted));
+
+                    let text_x = cx + time.len() as u16 + 2;
+                    let text_width = cw.saturating_sub(time.len() as u16 + 2) as usize;
+                    buf.set_string(
+                        text_x,
+                        cy,
+                        crate::util::truncate_str(&entry.text, text_width),
+                        Style::default().fg(self.kind_color(entry.kind)),
+                    );
+                }
+            }
+        }
+
+        // ── Bottom hint ────────────────────────────────────────────────
+        let hint = "↑↓ scroll · G newest · Esc close";
+        let hint_y = popup_area.y + popup_area.height - 1;
+        buf.set_string(cx, hint_y, hint, Style::default().fg(self.theme.text_muted));
+    }
+}
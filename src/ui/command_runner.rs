@@ -0,0 +1,170 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+
+use crate::app::App;
+
+/// Render the command runner overlay: the command line, the target tab
+/// and the project's command snippets.
+pub fn render_command_runner(app: &App, area: Rect, buf: &mut Buffer) {
+    let Some(state) = &app.command_runner else {
+        return;
+    };
+    let theme = &app.theme;
+    let snippets = app.command_snippets(state.project_idx);
+    let tabs = app.shell_tab_names();
+
+    // title + input + target + separator + snippets + hints
+    let list_rows = snippets.len().clamp(1, 10) as u16;
+    let popup_width = 70u16.min(area.width.saturating_sub(2));
+    let popup_height = (list_rows + 6).min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    super::render_overlay_dim(area, buf);
+    Clear.render(popup_area, buf);
+
+    let block = Block::default().style(Style::default().bg(theme.background_panel));
+    let panel_inner = block.inner(popup_area);
+    block.render(popup_area, buf);
+
+    let inner = Rect {
+        x: panel_inner.x + 2,
+        y: panel_inner.y + 1,
+        width: panel_inner.width.saturating_sub(4),
+        height: panel_inner.height.saturating_sub(1),
+    };
+    if inner.height < 5 {
+        return;
+    }
+
+    let title_line = Line::from(vec![
+        Span::styled(
+            "Run Command",
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" ".repeat((inner.width as usize).saturating_sub(11 + 3))),
+        Span::styled("esc", Style::default().fg(theme.text_muted)),
+    ]);
+    buf.set_line(inner.x, inner.y, &title_line, inner.width);
+
+    // ── Command line with block cursor ─────────────────────────────────
+    let input_y = inner.y + 1;
+    let prompt = "$ ";
+    buf.set_string(
+        inner.x,
+        input_y,
+        prompt,
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD),
+    );
+    let query_x = inner.x + prompt.len() as u16;
+    buf.set_string(
+        query_x,
+        input_y,
+        &state.input,
+        Style::default().fg(theme.text),
+    );
+    let cursor_x = query_x + state.input[..state.cursor_pos].chars().count() as u16;
+    if cursor_x < inner.x + inner.width {
+        let cursor_char = state.input[state.cursor_pos..]
+            .chars()
+            .next()
+            .unwrap_or(' ');
+        buf.set_string(
+            cursor_x,
+            input_y,
+            cursor_char.to_string(),
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+
+    // ── Target tab ─────────────────────────────────────────────────────
+    let target = match state.tab.and_then(|i| tabs.get(i)) {
+        Some(name) => name.clone(),
+        None => "new tab".to_string(),
+    };
+    let target_line = Line::from(vec![
+        Span::styled("in ", Style::default().fg(theme.text_muted)),
+        Span::styled(target, Style::default().fg(theme.accent)),
+    ]);
+    buf.set_line(inner.x, input_y + 1, &target_line, inner.width);
+
+    let separator_y = input_y + 2;
+    buf.set_string(
+        inner.x,
+        separator_y,
+        "─".repeat(inner.width as usize),
+        Style::default().fg(theme.border_subtle),
+    );
+
+    // ── Snippets ───────────────────────────────────────────────────────
+    let hint_y = inner.y + inner.height.saturating_sub(1);
+    let list_y = separator_y + 1;
+    let visible = hint_y.saturating_sub(list_y) as usize;
+    if snippets.is_empty() {
+        buf.set_string(
+            inner.x + 2,
+            list_y,
+            "No snippets yet — ^s saves the typed command",
+            Style::default().fg(theme.text_muted),
+        );
+    } else {
+        let selected = state.snippet.unwrap_or(0);
+        let start = (selected + 1).saturating_sub(visible);
+        for (row, (i, command)) in snippets
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible)
+            .enumerate()
+        {
+            let style = if state.snippet == Some(i) {
+                Style::default()
+                    .bg(theme.primary)
+                    .fg(theme.background)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            let y = list_y + row as u16;
+            buf.set_string(inner.x, y, " ".repeat(inner.width as usize), style);
+            buf.set_string(
+                inner.x + 1,
+                y,
+                crate::util::truncate_str(command, inner.width.saturating_sub(2) as usize),
+                style,
+            );
+        }
+    }
+
+    let hints = Line::from(vec![
+        Span::styled("  ⏎", Style::default().fg(theme.accent)),
+        Span::styled(" run  ", Style::default().fg(theme.text_muted)),
+        Span::styled("↑↓", Style::default().fg(theme.accent)),
+        Span::styled(" snippets  ", Style::default().fg(theme.text_muted)),
+        Span::styled("tab", Style::default().fg(theme.accent)),
+        Span::styled(" target  ", Style::default().fg(theme.text_muted)),
+        Span::styled("^s", Style::default().fg(theme.accent)),
+        Span::styled(" save/remove", Style::default().fg(theme.text_muted)),
+    ]);
+    Paragraph::new(hints).render(
+        Rect {
+            x: inner.x,
+            y: hint_y,
+            width: inner.width,
+            height: 1,
+        },
+        buf,
+    );
+}
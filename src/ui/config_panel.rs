@@ -3,13 +3,16 @@ use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::widgets::{Block, Clear, Widget};
 
+use crate::config::Settings;
 use crate::theme::ThemeColors;
 
-/// A single setting entry – either a boolean toggle, a numeric percentage, or seconds.
+/// A single setting entry – a boolean toggle, a numeric percentage, seconds,
+/// or one of a fixed set of named choices.
 pub enum SettingValue {
     Bool(bool),
     Percent(u8),
     Seconds(u64),
+    Choice(&'static str),
 }
 
 pub struct ConfigPanel<'a> {
@@ -19,27 +22,64 @@ pub struct ConfigPanel<'a> {
 }
 
 impl<'a> ConfigPanel<'a> {
-    pub fn new(
-        theme: &'a ThemeColors,
-        selected: usize,
-        follow_edits_in_neovim: bool,
-        unfocused_dim_percent: u8,
-        slack_enabled: bool,
-        relay_buffer_secs: u64,
-    ) -> Self {
+    pub fn new(theme: &'a ThemeColors, selected: usize, s: &Settings) -> Self {
         let settings = vec![
             (
                 "Follow edits in neovim",
-                SettingValue::Bool(follow_edits_in_neovim),
+                SettingValue::Bool(s.follow_edits_in_neovim),
             ),
             (
                 "Unfocused panel dimming",
-                SettingValue::Percent(unfocused_dim_percent),
+                SettingValue::Percent(s.unfocused_dim_percent),
             ),
-            ("Slack integration", SettingValue::Bool(slack_enabled)),
+            ("Slack integration", SettingValue::Bool(s.slack.enabled)),
             (
                 "Slack relay buffer (sec)",
-                SettingValue::Seconds(relay_buffer_secs),
+                SettingValue::Seconds(s.slack.relay_buffer_secs),
+            ),
+            (
+                "Auto-expand new subagents",
+                SettingValue::Bool(s.auto_expand_subagents),
+            ),
+            (
+                "Paste into neovim via RPC",
+                SettingValue::Bool(s.neovim_rpc_paste),
+            ),
+            (
+                "Approve agent commands (dry-run)",
+                SettingValue::Bool(s.mcp_run_dry_run),
+            ),
+            (
+                "Terminal cursor shape",
+                SettingValue::Choice(s.cursor_shape.label()),
+            ),
+            ("Terminal cursor blink", SettingValue::Bool(s.cursor_blink)),
+            ("Terminal bell", SettingValue::Choice(s.visual_bell.label())),
+            (
+                "Edit todo continuation prompt",
+                SettingValue::Bool(s.edit_todo_continuation),
+            ),
+            ("Mouse capture", SettingValue::Bool(s.mouse_capture)),
+            (
+                "Sidebar position",
+                SettingValue::Choice(s.sidebar_position.label()),
+            ),
+            (
+                "Focus follows mouse",
+                SettingValue::Bool(s.focus_follows_mouse),
+            ),
+            (
+                "Trim copied selections",
+                SettingValue::Bool(s.trim_copied_selection),
+            ),
+            (
+                "Sidebar density",
+                SettingValue::Choice(s.sidebar_density.label()),
+            ),
+            ("Load .envrc via direnv", SettingValue::Bool(s.direnv)),
+            (
+                "Log terminal output to disk",
+                SettingValue::Bool(s.terminal_output_log),
             ),
         ];
         Self {
@@ -157,6 +197,16 @@ impl<'a> ConfigPanel<'a> {
                         label_style,
                     );
                 }
+                SettingValue::Choice(name) => {
+                    let control = format!("◀ {:^9} ▶  ", name);
+                    buf.set_string(inner_x, cy, &control, control_style);
+                    buf.set_string(
+                        inner_x + control.chars().count() as u16,
+                        cy,
+                        label,
+                        label_style,
+                    );
+                }
             }
 
             cy += 1;
@@ -2,7 +2,7 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Clear, Paragraph, Widget};
+use ratatui::widgets::{Clear, Paragraph, Widget, Wrap};
 
 use crate::app::App;
 
@@ -48,17 +48,45 @@ impl<'a> ContextInput<'a> {
         if inner.height < 3 {
             return;
         }
-        let title_line = Line::from(vec![
+        let title = if state.raw {
+            "Todo Continuation"
+        } else {
+            "Context Input"
+        };
+        let mut title_spans = vec![
             Span::styled(
-                "Context Input",
+                title,
                 Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
             ),
             Span::raw("  "),
-            Span::styled(
-                "insert context for OpenCode",
+        ];
+        if state.saved_draft.is_some() {
+            title_spans.push(Span::styled(
+                "draft saved — ",
+                Style::default().fg(theme.warning),
+            ));
+            title_spans.push(Span::styled(
+                "Ctrl+R",
+                Style::default()
+                    .fg(theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            title_spans.push(Span::styled(
+                " to restore",
+                Style::default().fg(theme.warning),
+            ));
+        } else {
+            let subtitle = if state.raw {
+                "edit the message, then send it"
+            } else {
+                "insert context for OpenCode"
+            };
+            title_spans.push(Span::styled(
+                subtitle,
                 Style::default().fg(theme.text_muted),
-            ),
-        ]);
+            ));
+        }
+        let title_line = Line::from(title_spans);
         let esc_hint = Span::styled("esc", Style::default().fg(theme.text_muted));
         buf.set_line(content_x, inner.y + 1, &title_line, content_width);
         let esc_w = 3u16;
@@ -80,7 +108,14 @@ impl<'a> ContextInput<'a> {
 
         // Text area with word wrapping
         let text_y = inner.y + 3;
-        let text_height = inner.height.saturating_sub(5) as usize; // title + sep + hint
+        let area_height = inner.height.saturating_sub(5) as usize; // title + sep + hint
+        // With the preview on, the raw input takes the top half and the
+        // rendered Markdown the bottom half (below a one-row separator).
+        let text_height = if state.preview && area_height >= 4 {
+            area_height / 2
+        } else {
+            area_height
+        };
         let cw = content_width as usize;
 
         // Build visual rows from logical lines, wrapping at content_width.
@@ -192,9 +227,46 @@ impl<'a> ContextInput<'a> {
             }
         }
 
+        if text_height < area_height {
+            let sep_y = text_y + text_height as u16;
+            let label = " Preview ";
+            let rule = "─".repeat((content_width as usize).saturating_sub(label.len() + 2));
+            buf.set_string(
+                content_x,
+                sep_y,
+                format!("──{label}{rule}"),
+                Style::default().fg(theme.border_subtle),
+            );
+            let preview_height = (area_height - text_height - 1) as u16;
+            let preview_area = Rect::new(content_x, sep_y + 1, content_width, preview_height);
+            let lines = super::markdown_preview::markdown_lines(&state.lines, theme);
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .render(preview_area, buf);
+        }
+
         // Hint bar at bottom
         let hint_y = inner.y + inner.height.saturating_sub(1);
-        let hint = Line::from(vec![
+        let mut hint_spans = Vec::new();
+        if state.targets.len() > 1 {
+            let label = state
+                .targets
+                .get(state.target_idx)
+                .map(|(_, l)| l.as_str())
+                .unwrap_or("");
+            hint_spans.push(Span::styled(
+                "Tab",
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            hint_spans.push(Span::styled(" to: ", Style::default().fg(theme.text_muted)));
+            hint_spans.push(Span::styled(
+                format!("{label}  "),
+                Style::default().fg(theme.accent),
+            ));
+        }
+        hint_spans.extend([
             Span::styled(
                 "Enter",
                 Style::default()
@@ -209,6 +281,13 @@ impl<'a> ContextInput<'a> {
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" submit  ", Style::default().fg(theme.text_muted)),
+            Span::styled(
+                "Ctrl+P",
+                Style::default()
+                    .fg(theme.secondary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" preview  ", Style::default().fg(theme.text_muted)),
             Span::styled(
                 "Esc",
                 Style::default()
@@ -217,6 +296,7 @@ impl<'a> ContextInput<'a> {
             ),
             Span::styled(" cancel", Style::default().fg(theme.text_muted)),
         ]);
+        let hint = Line::from(hint_spans);
         Paragraph::new(hint).render(Rect::new(content_x, hint_y, content_width, 1), buf);
     }
 }
@@ -11,6 +11,17 @@ use std::sync::Arc;
 use nucleo::pattern::{CaseMatching, Normalization};
 use nucleo::{Config, Nucleo};
 
+/// What confirming a selection in the picker does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickerTarget {
+    /// Add (or switch to) the selected directory as a project.
+    AddProject,
+    /// Insert the selected project-relative file path into the context input.
+    ContextInputFile,
+    /// Open the selected project file in the session's neovim.
+    NeovimFile,
+}
+
 /// State for the fuzzy directory picker (lives in App).
 pub struct FuzzyPickerState {
     pub matcher: Nucleo<String>,
@@ -20,9 +31,15 @@ pub struct FuzzyPickerState {
     pub selected: u32,
     pub scroll_offset: u32,
     walk_complete_flag: Arc<AtomicBool>,
+    /// Walker completion as of the last `tick`, to redraw once it finishes.
+    walk_complete_seen: bool,
+    /// Set on drop to stop the walker thread.
+    walk_cancel_flag: Arc<AtomicBool>,
     /// Sidebar projects shown by default when query is empty.
     /// Each entry is (display_name, raw_path).
     pub existing_projects: Vec<(String, String)>,
+    /// What the picker was opened for.
+    pub target: PickerTarget,
 }
 
 impl std::fmt::Debug for FuzzyPickerState {
@@ -35,15 +52,24 @@ impl std::fmt::Debug for FuzzyPickerState {
     }
 }
 
+/// Closing the picker (Esc, or confirming a selection) stops a scan that
+/// is still running instead of leaving it to walk the whole tree.
+impl Drop for FuzzyPickerState {
+    fn drop(&mut self) {
+        self.walk_cancel_flag.store(true, Ordering::Relaxed);
+    }
+}
+
 impl FuzzyPickerState {
     /// Create a new fuzzy picker and start scanning directories under `root`.
     #[allow(dead_code)]
     pub fn new(root: PathBuf) -> Self {
-        Self::new_with_existing(root, Vec::new())
+        Self::new_with_existing(vec![root], Vec::new())
     }
 
-    /// Create a fuzzy picker that includes existing project paths in results.
-    pub fn new_with_existing(root: PathBuf, existing_projects: Vec<String>) -> Self {
+    /// Create a fuzzy picker over the directories under each of `roots`
+    /// that includes existing project paths in results.
+    pub fn new_with_existing(roots: Vec<PathBuf>, existing_projects: Vec<String>) -> Self {
         let matcher = Nucleo::new(
             Config::DEFAULT.match_paths(),
             Arc::new(|| {}),
@@ -69,8 +95,10 @@ impl FuzzyPickerState {
             })
             .collect();
 
+        let walk_cancel = Arc::new(AtomicBool::new(false));
+        let walk_cancel_clone = Arc::clone(&walk_cancel);
         std::thread::spawn(move || {
-            walker::walk_directories(root, injector, existing_projects);
+            walker::walk_directories(roots, injector, existing_projects, &walk_cancel_clone);
             walk_done_clone.store(true, Ordering::Release);
         });
 
@@ -82,7 +110,85 @@ impl FuzzyPickerState {
             selected: 0,
             scroll_offset: 0,
             walk_complete_flag: walk_done,
+            walk_complete_seen: false,
+            walk_cancel_flag: walk_cancel,
             existing_projects: sidebar_projects,
+            target: PickerTarget::AddProject,
+        }
+    }
+
+    /// Create a fuzzy picker over the files under `root` (gitignore-aware).
+    /// Items are paths relative to `root`.
+    pub fn new_files(root: PathBuf, target: PickerTarget) -> Self {
+        let matcher = Nucleo::new(
+            Config::DEFAULT.match_paths(),
+            Arc::new(|| {}),
+            None, // use default thread count
+            1,    // single match column
+        );
+
+        let injector = matcher.injector();
+        let walk_done = Arc::new(AtomicBool::new(false));
+        let walk_done_clone = Arc::clone(&walk_done);
+
+        let walk_cancel = Arc::new(AtomicBool::new(false));
+        let walk_cancel_clone = Arc::clone(&walk_cancel);
+        std::thread::spawn(move || {
+            walker::walk_files(root, injector, &walk_cancel_clone);
+            walk_done_clone.store(true, Ordering::Release);
+        });
+
+        Self {
+            matcher,
+            query: String::new(),
+            prev_query: String::new(),
+            cursor_pos: 0,
+            selected: 0,
+            scroll_offset: 0,
+            walk_complete_flag: walk_done,
+            walk_complete_seen: false,
+            walk_cancel_flag: walk_cancel,
+            existing_projects: Vec::new(),
+            target,
+        }
+    }
+
+    /// Title shown at the top of the picker popup.
+    pub fn title(&self) -> &'static str {
+        match self.target {
+            PickerTarget::AddProject => "Search",
+            PickerTarget::ContextInputFile => "Insert file",
+            PickerTarget::NeovimFile => "Open file",
+        }
+    }
+
+    /// Placeholder shown when the query matches nothing.
+    pub fn empty_message(&self) -> &'static str {
+        match self.target {
+            PickerTarget::AddProject => "No matching directories",
+            PickerTarget::ContextInputFile | PickerTarget::NeovimFile => "No matching files",
+        }
+    }
+
+    /// Header status: how many entries the walker has found while it
+    /// runs, then the match count (or the total, with no query).
+    pub fn progress_label(&self) -> String {
+        let noun = match self.target {
+            PickerTarget::AddProject => "dirs",
+            PickerTarget::ContextInputFile | PickerTarget::NeovimFile => "files",
+        };
+        let total = crate::util::group_thousands(self.total_count() as u64);
+        if !self.walk_complete() {
+            format!("scanning… ({total} {noun})")
+        } else if self.query.is_empty() {
+            format!("{total} {noun}")
+        } else {
+            let matched = self.matched_count();
+            let plural = if matched == 1 { "" } else { "es" };
+            format!(
+                "{} match{plural}",
+                crate::util::group_thousands(matched as u64)
+            )
         }
     }
 
@@ -91,7 +197,7 @@ impl FuzzyPickerState {
     }
 
     /// Tick the matcher and update pattern if query changed.
-    /// Returns true if results changed.
+    /// Returns true if results or walker progress changed.
     pub fn tick(&mut self) -> bool {
         // Update pattern if query changed
         if self.query != self.prev_query {
@@ -106,8 +212,12 @@ impl FuzzyPickerState {
             self.prev_query = self.query.clone();
         }
 
+        let scanned = self.total_count();
         let status = self.matcher.tick(10);
-        status.changed
+        let complete = self.walk_complete();
+        let finished = complete && !self.walk_complete_seen;
+        self.walk_complete_seen = complete;
+        status.changed || finished || self.total_count() != scanned
     }
 
     /// Get the total number of matched items.
@@ -58,14 +58,19 @@ impl<'a> FuzzyPicker<'a> {
             return;
         }
 
+        let title = state.title();
         let title_span = Span::styled(
-            "Search",
+            title,
             Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         );
+        let progress = format!("  {}", state.progress_label());
+        let progress_span = Span::styled(progress, Style::default().fg(theme.text_muted));
         let esc_span = Span::styled("esc", Style::default().fg(theme.text_muted));
+        let used = title.len() + progress_span.width() + 3;
         let title_line = Line::from(vec![
             title_span,
-            Span::raw(" ".repeat((inner.width as usize).saturating_sub(6 + 3))),
+            progress_span,
+            Span::raw(" ".repeat((inner.width as usize).saturating_sub(used))),
             esc_span,
         ]);
         buf.set_line(inner.x, inner.y, &title_line, inner.width);
@@ -148,29 +153,16 @@ fn render_hint_line(
     state: &FuzzyPickerState,
     theme: &ThemeColors,
 ) {
-    let (count_text, status_indicator) =
-        if state.query.is_empty() && !state.existing_projects.is_empty() {
-            let n = state.existing_projects.len();
-            (
-                format!("  {} project{}", n, if n == 1 { "" } else { "s" }),
-                "",
-            )
-        } else {
-            let matched = state.matched_count();
-            let total = state.total_count();
-            let indicator = if state.walk_complete() {
-                ""
-            } else {
-                " (scanning...)"
-            };
-            (format!("  {}/{}", matched, total), indicator)
-        };
+    // Walker progress is shown in the header.
+    let count_text = if state.query.is_empty() && !state.existing_projects.is_empty() {
+        let n = state.existing_projects.len();
+        format!("  {} project{}", n, if n == 1 { "" } else { "s" })
+    } else {
+        format!("  {}/{}", state.matched_count(), state.total_count())
+    };
 
     let spans = vec![
-        Span::styled(
-            format!("{}{}", count_text, status_indicator),
-            Style::default().fg(theme.text_muted),
-        ),
+        Span::styled(count_text, Style::default().fg(theme.text_muted)),
         Span::raw("  "),
         Span::styled("enter", Style::default().fg(theme.accent)),
         Span::styled(": select  ", Style::default().fg(theme.text_muted)),
@@ -26,7 +26,7 @@ pub(super) fn render_results(
             buf.set_string(
                 area.x + 2,
                 area.y + area.height / 2,
-                "No matching directories",
+                state.empty_message(),
                 Style::default().fg(theme.text_muted),
             );
         }
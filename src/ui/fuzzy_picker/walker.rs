@@ -1,12 +1,20 @@
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use nucleo::{Injector, Utf32String};
 
+/// Inject every directory under `roots` (the roots themselves excluded),
+/// after the existing projects. Directories reachable from more than one
+/// root are only injected once. Stops early once `cancel` is set.
 pub(super) fn walk_directories(
-    root: PathBuf,
+    roots: Vec<PathBuf>,
     injector: Injector<String>,
     existing_projects: Vec<String>,
+    cancel: &AtomicBool,
 ) {
+    let Some((first, rest)) = roots.split_first() else {
+        return;
+    };
     let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/"));
     let home_str = home.to_string_lossy().to_string();
 
@@ -26,7 +34,11 @@ pub(super) fn walk_directories(
         });
     }
 
-    let walker = ignore::WalkBuilder::new(&root)
+    let mut builder = ignore::WalkBuilder::new(first);
+    for root in rest {
+        builder.add(root);
+    }
+    let walker = builder
         .standard_filters(false)
         .follow_links(true)
         .max_depth(Some(5))
@@ -44,6 +56,9 @@ pub(super) fn walk_directories(
         .build();
 
     for entry in walker {
+        if cancel.load(Ordering::Relaxed) {
+            return;
+        }
         let entry = match entry {
             Ok(e) => e,
             Err(_) => continue,
@@ -59,8 +74,8 @@ pub(super) fn walk_directories(
 
         let path = entry.path().to_string_lossy().to_string();
 
-        // Skip paths already injected as existing projects
-        if seen.contains(&path) {
+        // Skip existing projects and directories seen under another root
+        if !seen.insert(path.clone()) {
             continue;
         }
 
@@ -75,3 +90,58 @@ pub(super) fn walk_directories(
         });
     }
 }
+
+/// Inject every file under `root` as a root-relative path, honouring
+/// `.gitignore` and skipping hidden entries. Stops early once `cancel` is
+/// set.
+pub(super) fn walk_files(root: PathBuf, injector: Injector<String>, cancel: &AtomicBool) {
+    let walker = ignore::WalkBuilder::new(&root)
+        .hidden(true)
+        .git_ignore(true)
+        .build();
+
+    for entry in walker {
+        if cancel.load(Ordering::Relaxed) {
+            return;
+        }
+        let entry = match entry {
+            Ok(e) => e,
+            Err(_) => continue,
+        };
+
+        if !entry.file_type().map(|ft| ft.is_file()).unwrap_or(false) {
+            continue;
+        }
+
+        let rel = match entry.path().strip_prefix(&root) {
+            Ok(r) => r.to_string_lossy().to_string(),
+            Err(_) => continue,
+        };
+
+        let display = rel.clone();
+        let _ = injector.push(rel, |_, cols| {
+            cols[0] = Utf32String::from(display.as_str());
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nucleo::{Config, Nucleo};
+    use std::sync::Arc;
+
+    fn walked_file_count(cancel: bool) -> u32 {
+        let mut matcher: Nucleo<String> = Nucleo::new(Config::DEFAULT, Arc::new(|| {}), None, 1);
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+        walk_files(root, matcher.injector(), &AtomicBool::new(cancel));
+        matcher.tick(10);
+        matcher.snapshot().item_count()
+    }
+
+    #[test]
+    fn test_cancelled_walk_stops() {
+        assert!(walked_file_count(false) > 0);
+        assert_eq!(walked_file_count(true), 0);
+    }
+}
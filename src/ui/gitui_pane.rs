@@ -1,12 +1,29 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Style;
+use ratatui::text::{Line, Span};
 use ratatui::widgets::Widget;
 
-use crate::app::App;
+use crate::app::{App, DiffStat};
 use crate::theme::ansi_palette_from_theme;
 use crate::ui::term_render;
 
+/// Rows above gitui taken by the diff-stat header.
+pub const GIT_HEADER_HEIGHT: u16 = 1;
+
+/// The part of the git panel gitui draws in: everything below the header,
+/// or the whole panel when it is too short to spare a row.
+pub fn gitui_area(panel: Rect) -> Rect {
+    if panel.height <= GIT_HEADER_HEIGHT + 1 {
+        return panel;
+    }
+    Rect {
+        y: panel.y + GIT_HEADER_HEIGHT,
+        height: panel.height - GIT_HEADER_HEIGHT,
+        ..panel
+    }
+}
+
 pub struct GituiPane<'a> {
     pub app: &'a App,
 }
@@ -31,6 +48,12 @@ impl Widget for GituiPane<'_> {
             }
         };
 
+        let header = area;
+        let area = gitui_area(area);
+        if area != header {
+            render_diff_stat(buf, header, project.diff_stat, &self.app.theme);
+        }
+
         match &project.gitui_pty {
             Some(pty) => {
                 {
@@ -40,7 +63,23 @@ impl Widget for GituiPane<'_> {
                     };
                     let palette = ansi_palette_from_theme(&self.app.theme);
                     let screen = parser.screen();
-                    term_render::render_screen(screen, area, buf, &palette, &self.app.theme);
+                    term_render::render_screen_cached(
+                        screen,
+                        area,
+                        buf,
+                        &palette,
+                        &self.app.theme,
+                        &mut pty.render_cache.borrow_mut(),
+                    );
+                    term_render::render_hyperlinks(pty, screen, area, buf);
+                    term_render::render_pane_cursor(
+                        self.app,
+                        crate::ui::layout_manager::PanelId::GitPanel,
+                        pty,
+                        screen,
+                        area,
+                        buf,
+                    );
                 }
 
                 if let Some(ref sel) = self.app.terminal_selection {
@@ -81,6 +120,41 @@ impl Widget for GituiPane<'_> {
     }
 }
 
+/// One-line summary of uncommitted changes: files, then +added/-deleted
+/// in the theme's success/error colors.
+fn render_diff_stat(
+    buf: &mut Buffer,
+    area: Rect,
+    stat: Option<DiffStat>,
+    theme: &crate::theme::ThemeColors,
+) {
+    let muted = Style::default().fg(theme.text_muted);
+    let spans = match stat {
+        None => vec![Span::styled(" diff: —", muted)],
+        Some(stat) if stat.is_clean() => vec![Span::styled(" ✓ no changes", muted)],
+        Some(stat) => vec![
+            Span::styled(
+                format!(
+                    " {} file{} ",
+                    stat.files,
+                    if stat.files == 1 { "" } else { "s" }
+                ),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled(
+                format!("+{}", stat.insertions),
+                Style::default().fg(theme.success),
+            ),
+            Span::raw(" "),
+            Span::styled(
+                format!("-{}", stat.deletions),
+                Style::default().fg(theme.error),
+            ),
+        ],
+    };
+    buf.set_line(area.x, area.y, &Line::from(spans), area.width);
+}
+
 fn render_no_gitui(buf: &mut Buffer, area: Rect, theme: &crate::theme::ThemeColors) {
     let msg = "No gitui (install: cargo install gitui)";
     let x = area.x + area.width.saturating_sub(msg.len() as u16) / 2;
@@ -6,7 +6,7 @@ use ratatui::layout::Rect;
 use ratatui::style::Style;
 use ratatui::widgets::{Clear, Widget};
 
-use crate::app::App;
+use crate::app::{App, SessionResources};
 use crate::theme::ansi_palette_from_theme;
 use crate::ui::layout_manager::PanelId;
 use crate::ui::sidebar::lerp_color;
@@ -61,6 +61,9 @@ impl<'a> IntegratedTerminal<'a> {
                     .bg(theme.background)
                     .fg(theme.accent)
                     .add_modifier(ratatui::style::Modifier::BOLD)
+            } else if resources.shell_split() == Some(i) {
+                // Visible in the other half of a split.
+                Style::default().bg(theme.background).fg(theme.text)
             } else {
                 Style::default().bg(theme.background).fg(theme.text_muted)
             };
@@ -87,6 +90,89 @@ impl<'a> IntegratedTerminal<'a> {
                 buf.set_string(x_offset, area.y, "●", dot_style);
                 x_offset += 1;
             }
+            // Bell rang while this tab was in the background.
+            if pty.bell && x_offset < area.x + area.width {
+                let bell_style = Style::default().fg(theme.warning).bg(theme.background);
+                buf.set_string(x_offset, area.y, "!", bell_style);
+                x_offset += 1;
+            }
+        }
+
+        // Last agent-run command of the active tab, right-aligned.
+        let last_cmd = resources
+            .shell_ptys
+            .get(resources.active_shell_tab)
+            .and_then(|pty| pty.last_mcp_command.as_deref())
+            .and_then(|cmd| cmd.lines().next());
+        if let Some(cmd) = last_cmd {
+            let free = (area.x + area.width).saturating_sub(x_offset + 2) as usize;
+            if free > 8 {
+                let text = format!(" $ {}", crate::util::truncate_str(cmd, free - 4));
+                let cmd_x = area.x + area.width - text.chars().count() as u16 - 1;
+                buf.set_string(
+                    cmd_x,
+                    area.y,
+                    &text,
+                    Style::default().bg(theme.background).fg(theme.text_muted),
+                );
+            }
+        }
+    }
+
+    /// Render the visible shell tab(s) below the tab bar. Only the active
+    /// tab gets the cursor, search and selection; a split tab is drawn
+    /// beside it, separated by a divider.
+    fn render_shells(&self, resources: &SessionResources, content_area: Rect, buf: &mut Buffer) {
+        let panes = resources.shell_panes(content_area);
+        let palette = ansi_palette_from_theme(&self.app.theme);
+        for &(tab, pane) in &panes {
+            let Some(shell_pty) = resources.shell_ptys.get(tab) else {
+                continue;
+            };
+            let is_active = tab == resources.active_shell_tab;
+            let scrollback;
+            {
+                let parser = match shell_pty.parser.lock() {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+                let screen = parser.screen();
+                scrollback = screen.scrollback();
+                term_render::render_screen_cached(
+                    screen,
+                    pane,
+                    buf,
+                    &palette,
+                    &self.app.theme,
+                    &mut shell_pty.render_cache.borrow_mut(),
+                );
+                term_render::render_hyperlinks(shell_pty, screen, pane, buf);
+                if is_active {
+                    term_render::render_pane_cursor(
+                        self.app,
+                        PanelId::IntegratedTerminal,
+                        shell_pty,
+                        screen,
+                        pane,
+                        buf,
+                    );
+                }
+                render_url_underlines(buf, pane, screen);
+            }
+            // Lock released — search/selection only touch the ratatui buffer.
+            if is_active {
+                render_search_highlights(self.app, scrollback, pane, buf);
+                render_selection(self.app, pane, buf);
+                render_search_bar(self.app, pane, buf);
+            }
+        }
+        if let [(_, left), _] = panes[..] {
+            let style = Style::default()
+                .fg(self.app.theme.border_subtle)
+                .bg(self.app.theme.background);
+            for y in left.y..left.bottom() {
+                buf.set_string(left.right(), y, "│", style);
+            }
         }
     }
 
@@ -106,7 +192,7 @@ impl<'a> IntegratedTerminal<'a> {
         }
 
         if let Some(project) = self.app.active_project() {
-            if let Some(shell_pty) = project.active_shell_pty() {
+            if project.active_shell_pty().is_some() {
                 let tab_area = Rect {
                     x: area.x,
                     y: area.y,
@@ -120,27 +206,9 @@ impl<'a> IntegratedTerminal<'a> {
                     height: area.height.saturating_sub(1),
                 };
                 self.render_tab_bar(project, tab_area, buf);
-
-                {
-                    let parser = match shell_pty.parser.lock() {
-                        Ok(p) => p,
-                        Err(_) => return,
-                    };
-                    let palette = ansi_palette_from_theme(&self.app.theme);
-                    let screen = parser.screen();
-                    term_render::render_screen(
-                        screen,
-                        content_area,
-                        buf,
-                        &palette,
-                        &self.app.theme,
-                    );
-                    render_url_underlines(buf, content_area, screen);
+                if let Some(resources) = project.active_resources() {
+                    self.render_shells(resources, content_area, buf);
                 }
-                // Lock released — search/selection only touch the ratatui buffer.
-                render_search_highlights(self.app, content_area, buf);
-                render_selection(self.app, content_area, buf);
-                render_search_bar(self.app, content_area, buf);
             } else {
                 render_no_shell(area, buf, &self.app.theme);
             }
@@ -161,7 +229,7 @@ impl<'a> Widget for IntegratedTerminal<'a> {
         }
 
         if let Some(project) = self.app.active_project() {
-            if let Some(shell_pty) = project.active_shell_pty() {
+            if project.active_shell_pty().is_some() {
                 let tab_area = Rect {
                     x: area.x,
                     y: area.y,
@@ -175,27 +243,9 @@ impl<'a> Widget for IntegratedTerminal<'a> {
                     height: area.height.saturating_sub(1),
                 };
                 self.render_tab_bar(project, tab_area, buf);
-
-                {
-                    let parser = match shell_pty.parser.lock() {
-                        Ok(p) => p,
-                        Err(_) => return,
-                    };
-                    let palette = ansi_palette_from_theme(&self.app.theme);
-                    let screen = parser.screen();
-                    term_render::render_screen(
-                        screen,
-                        content_area,
-                        buf,
-                        &palette,
-                        &self.app.theme,
-                    );
-                    render_url_underlines(buf, content_area, screen);
+                if let Some(resources) = project.active_resources() {
+                    self.render_shells(resources, content_area, buf);
                 }
-                // Lock released — search/selection only touch the ratatui buffer.
-                render_search_highlights(self.app, content_area, buf);
-                render_selection(self.app, content_area, buf);
-                render_search_bar(self.app, content_area, buf);
             } else {
                 render_no_shell(area, buf, theme);
             }
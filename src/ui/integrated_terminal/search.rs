@@ -4,17 +4,35 @@ use ratatui::style::{Color, Modifier, Style};
 
 use crate::app::App;
 
-pub(super) fn render_search_highlights(app: &App, content_area: Rect, buf: &mut Buffer) {
+/// Width of the case and regex toggle indicators in the search bar.
+const TOGGLES_WIDTH: u16 = 6;
+
+/// Highlight the search matches in view; `scrollback` is how far the pane
+/// is scrolled back.
+pub(super) fn render_search_highlights(
+    app: &App,
+    scrollback: usize,
+    content_area: Rect,
+    buf: &mut Buffer,
+) {
     if let Some(ref search) = app.terminal_search {
         if search.query.is_empty() {
             return;
         }
+        // Buffer line shown on the pane's top row.
+        let view_top = search.scrollback_len.saturating_sub(scrollback);
 
         let match_bg = Color::Rgb(100, 100, 40);
         let current_match_bg = Color::Rgb(200, 150, 0);
         let match_fg = Color::Rgb(255, 255, 255);
 
-        for (i, &(row, col, len)) in search.matches.iter().enumerate() {
+        for (i, &(line, col, len)) in search.matches.iter().enumerate() {
+            let Some(row) = line
+                .checked_sub(view_top)
+                .filter(|&row| row < content_area.height as usize)
+            else {
+                continue;
+            };
             let is_current = i == search.current_match;
             let bg = if is_current {
                 current_match_bg
@@ -69,7 +87,7 @@ pub(super) fn render_search_bar(app: &App, content_area: Rect, buf: &mut Buffer)
         let max_query_len = (content_area
             .right()
             .saturating_sub(query_x)
-            .saturating_sub(15)) as usize;
+            .saturating_sub(15 + TOGGLES_WIDTH)) as usize;
         let display_query = if search.query.len() > max_query_len {
             &search.query[search.query.len() - max_query_len..]
         } else {
@@ -86,7 +104,7 @@ pub(super) fn render_search_bar(app: &App, content_area: Rect, buf: &mut Buffer)
         );
 
         let cursor_x = query_x + display_query.len() as u16;
-        if cursor_x < content_area.right().saturating_sub(15) {
+        if cursor_x < content_area.right().saturating_sub(15 + TOGGLES_WIDTH) {
             buf.set_string(
                 cursor_x,
                 bar_y,
@@ -95,7 +113,9 @@ pub(super) fn render_search_bar(app: &App, content_area: Rect, buf: &mut Buffer)
             );
         }
 
-        let match_info = if search.matches.is_empty() {
+        let match_info = if search.invalid_regex {
+            " invalid regex ".to_string()
+        } else if search.matches.is_empty() {
             if search.query.is_empty() {
                 String::new()
             } else {
@@ -105,6 +125,24 @@ pub(super) fn render_search_bar(app: &App, content_area: Rect, buf: &mut Buffer)
             format!(" {}/{} ", search.current_match + 1, search.matches.len())
         };
 
+        // Case (Alt+C) and regex (Ctrl+R) toggles, lit up when on.
+        let toggles_x = content_area
+            .right()
+            .saturating_sub(15 + TOGGLES_WIDTH)
+            .max(query_x);
+        let toggle_style = |on: bool| {
+            if on {
+                Style::default()
+                    .fg(Color::Rgb(255, 200, 0))
+                    .bg(bar_bg)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Rgb(110, 110, 110)).bg(bar_bg)
+            }
+        };
+        buf.set_string(toggles_x, bar_y, " Aa", toggle_style(search.case_sensitive));
+        buf.set_string(toggles_x + 3, bar_y, " .*", toggle_style(search.regex));
+
         if !match_info.is_empty() {
             let info_x = content_area.right().saturating_sub(match_info.len() as u16);
             buf.set_string(
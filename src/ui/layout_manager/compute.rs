@@ -1,17 +1,29 @@
 use ratatui::layout::Rect;
 
 use super::types::{LayoutNode, PanelId, PanelRect, SeparatorRect, SplitDirection};
-use super::{LayoutManager, MIN_PANEL_SIZE, SEPARATOR_SIZE};
+use super::{LayoutManager, SEPARATOR_SIZE};
 
 impl LayoutManager {
     pub fn compute_rects(&mut self, area: Rect) {
         self.last_area = area;
         self.panel_rects.clear();
         self.separator_rects.clear();
-        self.compute_node_rects(&self.root.clone(), area, 0);
+        let min = (self.min_width, self.min_height);
+        self.compute_node_rects(&self.root.clone(), area, 0, min);
     }
 
-    fn compute_node_rects(&mut self, node: &LayoutNode, area: Rect, depth: usize) {
+    /// Panel sizes straight from the split ratios, without the minimum
+    /// size clamp, for checking a resize before it is applied.
+    pub(crate) fn unclamped_panel_rects(&mut self, area: Rect) -> Vec<PanelRect> {
+        self.panel_rects.clear();
+        self.separator_rects.clear();
+        self.compute_node_rects(&self.root.clone(), area, 0, (0, 0));
+        std::mem::take(&mut self.panel_rects)
+    }
+
+    /// Lay out `node` in `area`; children of a split get at least
+    /// `min` (columns, rows) each where the area allows.
+    fn compute_node_rects(&mut self, node: &LayoutNode, area: Rect, depth: usize, min: (u16, u16)) {
         match node {
             LayoutNode::Leaf(panel) => {
                 self.panel_rects.push(PanelRect {
@@ -34,18 +46,28 @@ impl LayoutManager {
                     SplitDirection::Horizontal => area.x,
                     SplitDirection::Vertical => area.y,
                 };
+                let end = match direction {
+                    SplitDirection::Horizontal => area.x + area.width,
+                    SplitDirection::Vertical => area.y + area.height,
+                };
+                let min_size = match direction {
+                    SplitDirection::Horizontal => min.0,
+                    SplitDirection::Vertical => min.1,
+                };
 
                 for (i, (ratio, child)) in children.iter().enumerate() {
                     let normalized = ratio / total_ratio;
                     let size = if i == children.len() - 1 {
-                        match direction {
-                            SplitDirection::Horizontal => (area.x + area.width).saturating_sub(pos),
-                            SplitDirection::Vertical => (area.y + area.height).saturating_sub(pos),
-                        }
+                        end.saturating_sub(pos)
                     } else {
-                        (available as f64 * normalized)
-                            .round()
-                            .max(MIN_PANEL_SIZE as f64) as u16
+                        // Leave the panels after this one their minimum too.
+                        let after = (children.len() - 1 - i) as u16;
+                        let reserved =
+                            after.saturating_mul(min_size.saturating_add(SEPARATOR_SIZE));
+                        let room = end.saturating_sub(pos).saturating_sub(reserved);
+                        ((available as f64 * normalized).round() as u16)
+                            .max(min_size)
+                            .min(room)
                     };
 
                     let child_rect = match direction {
@@ -53,7 +75,7 @@ impl LayoutManager {
                         SplitDirection::Vertical => Rect::new(area.x, pos, area.width, size),
                     };
 
-                    self.compute_node_rects(child, child_rect, depth + 1);
+                    self.compute_node_rects(child, child_rect, depth + 1, min);
                     pos += size;
 
                     if i < children.len() - 1 {
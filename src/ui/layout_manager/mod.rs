@@ -10,7 +10,6 @@ use ratatui::layout::Rect;
 use types::panel_index;
 pub use types::{DragState, LayoutNode, PanelId, PanelRect, SeparatorRect, SplitDirection};
 
-const MIN_PANEL_SIZE: u16 = 6;
 const SEPARATOR_SIZE: u16 = 1;
 
 pub struct LayoutManager {
@@ -24,6 +23,11 @@ pub struct LayoutManager {
     /// Set to `true` whenever layout structure changes (visibility, resize, tree rebuild).
     /// The draw loop checks this + area change to decide whether to recompute rects.
     pub layout_dirty: bool,
+    /// Build layouts mirrored, with the sidebar column on the right.
+    pub sidebar_right: bool,
+    /// Columns and rows a panel keeps when splits are computed or dragged.
+    pub min_width: u16,
+    pub min_height: u16,
 }
 
 impl LayoutManager {
@@ -37,6 +41,18 @@ impl LayoutManager {
             panel_visible: [true, true, false, false, false],
             last_area: Rect::default(),
             layout_dirty: true,
+            sidebar_right: false,
+            min_width: 10,
+            min_height: 3,
+        }
+    }
+
+    /// Put the sidebar on the right (or back on the left), rebuilding the
+    /// current layout mirrored.
+    pub fn set_sidebar_right(&mut self, right: bool) {
+        if self.sidebar_right != right {
+            self.sidebar_right = right;
+            self.rebuild_tree();
         }
     }
 
@@ -63,4 +79,141 @@ impl LayoutManager {
             self.focused = panel;
         }
     }
+
+    /// Like [`restore_visibility`](Self::restore_visibility), then adopt the
+    /// saved split tree (and with it the panel sizes) if it holds exactly
+    /// the visible panels.
+    pub fn restore_snapshot(
+        &mut self,
+        visible: [bool; 5],
+        focused: PanelId,
+        root: Option<&LayoutNode>,
+    ) {
+        self.restore_visibility(visible, focused);
+        let Some(root) = root else {
+            return;
+        };
+        let mut panels = Vec::new();
+        root.collect_panels(&mut panels);
+        let matches = panels.len() == self.panel_visible.iter().filter(|v| **v).count()
+            && panels.iter().all(|p| self.is_visible(*p))
+            && root.sidebar_on_right() != Some(!self.sidebar_right);
+        if matches {
+            self.root = root.clone();
+            self.layout_dirty = true;
+        }
+    }
+
+    /// Show exactly `panels`, focusing the first one that isn't the
+    /// sidebar. An empty list changes nothing.
+    pub fn show_only(&mut self, panels: &[PanelId]) {
+        let mut visible = [false; 5];
+        for panel in panels {
+            visible[panel_index(*panel)] = true;
+        }
+        let focused = panels
+            .iter()
+            .copied()
+            .find(|p| *p != PanelId::Sidebar)
+            .unwrap_or(PanelId::Sidebar);
+        self.restore_visibility(visible, focused);
+    }
+
+    /// Apply a saved visibility array and focus in one step.
+    /// Falls back to the terminal pane if the saved focus is hidden, and
+    /// never restores a layout with no visible panels.
+    pub fn restore_visibility(&mut self, visible: [bool; 5], focused: PanelId) {
+        if !visible.iter().any(|v| *v) {
+            return;
+        }
+        self.panel_visible = visible;
+        self.rebuild_tree();
+        self.focused = if self.is_visible(focused) {
+            focused
+        } else {
+            PanelId::TerminalPane
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sidebar_right_mirrors_layout() {
+        let mut layout = LayoutManager::new();
+        layout.set_visible(PanelId::NeovimPane, true);
+        layout.set_visible(PanelId::IntegratedTerminal, true);
+        let left_root = layout.root.clone();
+        layout.set_sidebar_right(true);
+        layout.compute_rects(Rect::new(0, 0, 120, 40));
+
+        let sidebar = layout.panel_rect(PanelId::Sidebar).unwrap();
+        assert_eq!(sidebar.x + sidebar.width, 120);
+        assert_eq!(layout.panel_rect(PanelId::NeovimPane).unwrap().x, 0);
+        assert_eq!(layout.panel_at(119, 0), Some(PanelId::Sidebar));
+
+        // A saved left-handed tree is not adopted; the mirrored one stays.
+        layout.restore_snapshot(layout.panel_visible, PanelId::Sidebar, Some(&left_root));
+        assert_eq!(layout.root.sidebar_on_right(), Some(true));
+    }
+
+    #[test]
+    fn test_huge_min_panel_size_does_not_overflow() {
+        let mut layout = LayoutManager::new();
+        layout.min_width = u16::MAX;
+        layout.min_height = u16::MAX;
+        layout.compute_rects(Rect::new(0, 0, 100, 30));
+        let sidebar = layout.panel_rect(PanelId::Sidebar).unwrap();
+        assert!(sidebar.right() <= 100);
+    }
+
+    #[test]
+    fn test_drag_stops_at_min_panel_size() {
+        let mut layout = LayoutManager::new();
+        layout.compute_rects(Rect::new(0, 0, 100, 30));
+        for _ in 0..50 {
+            layout.apply_resize_delta(0, 0, -0.02);
+        }
+        let sidebar = layout.panel_rect(PanelId::Sidebar).unwrap();
+        assert!(sidebar.width >= layout.min_width);
+        assert!(sidebar.width < 12);
+
+        for _ in 0..100 {
+            layout.apply_resize_delta(0, 0, 0.02);
+        }
+        let terminal = layout.panel_rect(PanelId::TerminalPane).unwrap();
+        assert!(terminal.width >= layout.min_width);
+    }
+
+    #[test]
+    fn test_show_only_sets_visibility_and_focus() {
+        let mut layout = LayoutManager::new();
+        layout.show_only(&[PanelId::Sidebar, PanelId::NeovimPane]);
+        assert!(layout.is_visible(PanelId::Sidebar));
+        assert!(layout.is_visible(PanelId::NeovimPane));
+        assert!(!layout.is_visible(PanelId::TerminalPane));
+        assert_eq!(layout.focused, PanelId::NeovimPane);
+
+        layout.show_only(&[PanelId::Sidebar]);
+        assert_eq!(layout.focused, PanelId::Sidebar);
+    }
+
+    #[test]
+    fn test_resize_focused_to_exact_size() {
+        let mut layout = LayoutManager::new();
+        layout.set_visible(PanelId::NeovimPane, true);
+        layout.compute_rects(Rect::new(0, 0, 200, 50));
+        layout.focused = PanelId::NeovimPane;
+
+        layout.resize_focused_to(1, 0, 80);
+        assert_eq!(layout.panel_rect(PanelId::NeovimPane).unwrap().width, 80);
+        layout.resize_focused_to(-1, 0, 60);
+        assert_eq!(layout.panel_rect(PanelId::NeovimPane).unwrap().width, 60);
+
+        // Too large to leave the other panels their minimum: unchanged.
+        layout.resize_focused_to(1, 0, 199);
+        assert_eq!(layout.panel_rect(PanelId::NeovimPane).unwrap().width, 60);
+    }
 }
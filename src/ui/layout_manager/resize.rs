@@ -1,6 +1,6 @@
 use ratatui::layout::Rect;
 
-use super::types::{DragState, LayoutNode, SplitDirection};
+use super::types::{DragState, LayoutNode, SeparatorRect, SplitDirection};
 use super::LayoutManager;
 
 impl LayoutManager {
@@ -47,13 +47,30 @@ impl LayoutManager {
 
     /// Delta-based resize: adjusts ratios by delta rather than absolute position,
     /// preventing jerk when the click doesn't exactly land on the separator center.
+    /// A resize that would shrink any panel below `min_width`/`min_height`
+    /// is dropped.
     pub(crate) fn apply_resize_delta(
         &mut self,
         target_depth: usize,
         parent_idx: usize,
         ratio_delta: f64,
     ) {
+        let area = self.last_area;
+        let before_root = self.root.clone();
+        let before_rects = self.unclamped_panel_rects(area);
         Self::apply_delta_recursive(&mut self.root, target_depth, 0, parent_idx, ratio_delta);
+        let too_small = self.unclamped_panel_rects(area).iter().any(|after| {
+            let before = before_rects
+                .iter()
+                .find(|b| b.panel == after.panel)
+                .map_or(after.rect, |b| b.rect);
+            (after.rect.width < self.min_width && after.rect.width < before.width)
+                || (after.rect.height < self.min_height && after.rect.height < before.height)
+        });
+        if too_small {
+            self.root = before_root;
+        }
+        self.compute_rects(area);
         self.layout_dirty = true;
     }
 
@@ -99,65 +116,74 @@ impl LayoutManager {
 
     pub fn resize_focused(&mut self, dx: i16, dy: i16) {
         let step: f64 = 0.02;
-        let focused_rect = match self.panel_rect(self.focused) {
-            Some(r) => r,
-            None => return,
+        if let Some((sep, _)) = self.focused_separator(dx, dy) {
+            let delta = if dx > 0 || dy > 0 { step } else { -step };
+            self.apply_resize_delta(sep.depth, sep.parent_index, delta);
+        }
+    }
+
+    /// Set the focused panel's width (`dx`) or height (`dy`) to `size`
+    /// cells by moving the separator `resize_focused` would move in that
+    /// direction. Nothing changes if that would push a panel below its
+    /// minimum size.
+    pub fn resize_focused_to(&mut self, dx: i16, dy: i16, size: u16) {
+        let Some((sep, after)) = self.focused_separator(dx, dy) else {
+            return;
         };
+        let extent = |r: Rect| if dx != 0 { r.width } else { r.height };
+        // Cells per unit of ratio: the whole area at first, then corrected
+        // by how far each step really moved the edge (nested splits).
+        let mut scale = extent(self.last_area) as f64;
+        for _ in 0..4 {
+            let Some(rect) = self.panel_rect(self.focused) else {
+                return;
+            };
+            let diff = size as f64 - extent(rect) as f64;
+            if diff == 0.0 || scale <= 0.0 {
+                return;
+            }
+            let delta = if after { diff / scale } else { -diff / scale };
+            self.apply_resize_delta(sep.depth, sep.parent_index, delta);
+            let moved =
+                self.panel_rect(self.focused).map_or(0, extent) as f64 - extent(rect) as f64;
+            if moved == 0.0 {
+                return;
+            }
+            scale *= moved / diff;
+        }
+    }
 
-        for sep in &self.separator_rects.clone() {
-            match sep.direction {
+    /// The separator on the focused panel's edge that resizing towards
+    /// (`dx`, `dy`) moves, and whether it is the right/bottom edge.
+    fn focused_separator(&self, dx: i16, dy: i16) -> Option<(SeparatorRect, bool)> {
+        let focused_rect = self.panel_rect(self.focused)?;
+        self.separator_rects.iter().find_map(|sep| {
+            let (before, after) = match sep.direction {
                 SplitDirection::Horizontal if dx != 0 => {
-                    let sep_x = sep.rect.x;
                     let y_overlap = sep.rect.y < focused_rect.y + focused_rect.height
                         && sep.rect.y + sep.rect.height > focused_rect.y;
                     if !y_overlap {
-                        continue;
-                    }
-
-                    if dx > 0 && sep_x == focused_rect.x + focused_rect.width {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, step);
-                        return;
-                    }
-                    if dx < 0 && sep_x + sep.rect.width == focused_rect.x {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, -step);
-                        return;
-                    }
-                    if dx > 0 && sep_x + sep.rect.width == focused_rect.x {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, step);
-                        return;
-                    }
-                    if dx < 0 && sep_x == focused_rect.x + focused_rect.width {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, -step);
-                        return;
+                        return None;
                     }
+                    (
+                        sep.rect.x + sep.rect.width == focused_rect.x,
+                        sep.rect.x == focused_rect.x + focused_rect.width,
+                    )
                 }
                 SplitDirection::Vertical if dy != 0 => {
-                    let sep_y = sep.rect.y;
                     let x_overlap = sep.rect.x < focused_rect.x + focused_rect.width
                         && sep.rect.x + sep.rect.width > focused_rect.x;
                     if !x_overlap {
-                        continue;
-                    }
-
-                    if dy > 0 && sep_y == focused_rect.y + focused_rect.height {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, step);
-                        return;
-                    }
-                    if dy < 0 && sep_y + sep.rect.height == focused_rect.y {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, -step);
-                        return;
-                    }
-                    if dy > 0 && sep_y + sep.rect.height == focused_rect.y {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, step);
-                        return;
-                    }
-                    if dy < 0 && sep_y == focused_rect.y + focused_rect.height {
-                        self.apply_resize_delta(sep.depth, sep.parent_index, -step);
-                        return;
+                        return None;
                     }
+                    (
+                        sep.rect.y + sep.rect.height == focused_rect.y,
+                        sep.rect.y == focused_rect.y + focused_rect.height,
+                    )
                 }
-                _ => {}
-            }
-        }
+                _ => return None,
+            };
+            (before || after).then_some((*sep, after))
+        })
     }
 }
@@ -33,6 +33,14 @@ impl LayoutManager {
     }
 
     pub(crate) fn rebuild_tree(&mut self) {
+        self.build_tree();
+        if self.sidebar_right {
+            self.root.mirror();
+        }
+    }
+
+    /// Build the tree for the visible panels, sidebar on the left.
+    fn build_tree(&mut self) {
         self.layout_dirty = true;
         let sidebar = self.panel_visible[0];
         let terminal = self.panel_visible[1];
@@ -1,6 +1,6 @@
 use ratatui::layout::Rect;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum PanelId {
     Sidebar,
     TerminalPane,
@@ -9,13 +9,13 @@ pub enum PanelId {
     GitPanel,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum SplitDirection {
     Horizontal,
     Vertical,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum LayoutNode {
     Leaf(PanelId),
     Split {
@@ -47,6 +47,60 @@ pub enum DragState {
     },
 }
 
+impl LayoutNode {
+    /// Append every panel in this subtree to `out`.
+    pub(crate) fn collect_panels(&self, out: &mut Vec<PanelId>) {
+        match self {
+            LayoutNode::Leaf(panel) => out.push(*panel),
+            LayoutNode::Split { children, .. } => {
+                for (_, child) in children {
+                    child.collect_panels(out);
+                }
+            }
+        }
+    }
+
+    /// Reverse the children of every horizontal split, flipping the
+    /// layout left to right.
+    pub(crate) fn mirror(&mut self) {
+        if let LayoutNode::Split {
+            direction,
+            children,
+        } = self
+        {
+            if *direction == SplitDirection::Horizontal {
+                children.reverse();
+            }
+            for (_, child) in children {
+                child.mirror();
+            }
+        }
+    }
+
+    /// Whether the sidebar is the last child of its row (`Some(true)`),
+    /// the first (`Some(false)`), or not found in a row at all.
+    pub(crate) fn sidebar_on_right(&self) -> Option<bool> {
+        let LayoutNode::Split {
+            direction,
+            children,
+        } = self
+        else {
+            return None;
+        };
+        if *direction == SplitDirection::Horizontal {
+            let is_sidebar =
+                |(_, c): &(f64, LayoutNode)| matches!(c, LayoutNode::Leaf(PanelId::Sidebar));
+            if children.first().is_some_and(is_sidebar) {
+                return Some(false);
+            }
+            if children.last().is_some_and(is_sidebar) {
+                return Some(true);
+            }
+        }
+        children.iter().find_map(|(_, c)| c.sidebar_on_right())
+    }
+}
+
 pub(crate) struct LayoutRatios {
     pub sidebar: f64,
     pub terminal: f64,
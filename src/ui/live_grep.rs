@@ -0,0 +1,229 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+
+use crate::app::{App, GrepMatch, LiveGrepState, MAX_LIVE_GREP_RESULTS};
+use crate::theme::ThemeColors;
+
+/// Render the live grep overlay: a query line above `file:line` matches
+/// with the matched spans highlighted.
+pub fn render_live_grep(app: &App, area: Rect, buf: &mut Buffer) {
+    let Some(state) = &app.live_grep else {
+        return;
+    };
+    let theme = &app.theme;
+
+    let popup_width = (area.width * 80 / 100)
+        .max(60)
+        .min(area.width.saturating_sub(2));
+    let popup_height = (area.height * 70 / 100)
+        .max(10)
+        .min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    super::render_overlay_dim(area, buf);
+    Clear.render(popup_area, buf);
+
+    let block = Block::default().style(Style::default().bg(theme.background_panel));
+    let panel_inner = block.inner(popup_area);
+    block.render(popup_area, buf);
+
+    let inner = Rect {
+        x: panel_inner.x + 2,
+        y: panel_inner.y + 1,
+        width: panel_inner.width.saturating_sub(4),
+        height: panel_inner.height.saturating_sub(1),
+    };
+    if inner.height < 4 {
+        return;
+    }
+
+    // ── Title row: name, match count, esc ──────────────────────────────
+    let count = if state.searching {
+        format!("{} matches…", state.results.len())
+    } else if state.results.len() >= MAX_LIVE_GREP_RESULTS {
+        format!("first {MAX_LIVE_GREP_RESULTS} matches")
+    } else if state.query.is_empty() {
+        String::new()
+    } else {
+        format!("{} matches", state.results.len())
+    };
+    let right = format!("{count}  esc");
+    let fill = " ".repeat((inner.width as usize).saturating_sub(9 + right.chars().count()));
+    let title_line = Line::from(vec![
+        Span::styled(
+            "Live Grep",
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(fill),
+        Span::styled(right, Style::default().fg(theme.text_muted)),
+    ]);
+    buf.set_line(inner.x, inner.y, &title_line, inner.width);
+
+    let input_y = inner.y + 1;
+    let separator_y = input_y + 1;
+    let hint_y = inner.y + inner.height.saturating_sub(1);
+    let results_y = separator_y + 1;
+
+    render_input_line(buf, inner.x, input_y, inner.width, state, theme);
+    buf.set_string(
+        inner.x,
+        separator_y,
+        "─".repeat(inner.width as usize),
+        Style::default().fg(theme.border_subtle),
+    );
+
+    let results_area = Rect {
+        x: inner.x,
+        y: results_y,
+        width: inner.width,
+        height: hint_y.saturating_sub(results_y),
+    };
+    if results_area.height > 0 {
+        render_results(buf, results_area, state, theme);
+    }
+
+    let hints = Line::from(vec![
+        Span::styled("  ↑↓", Style::default().fg(theme.accent)),
+        Span::styled(" navigate  ", Style::default().fg(theme.text_muted)),
+        Span::styled("⏎", Style::default().fg(theme.accent)),
+        Span::styled(" open in neovim  ", Style::default().fg(theme.text_muted)),
+        Span::styled("esc", Style::default().fg(theme.accent)),
+        Span::styled(" close", Style::default().fg(theme.text_muted)),
+    ]);
+    Paragraph::new(hints).render(
+        Rect {
+            x: inner.x,
+            y: hint_y,
+            width: inner.width,
+            height: 1,
+        },
+        buf,
+    );
+}
+
+fn render_input_line(
+    buf: &mut Buffer,
+    x: u16,
+    y: u16,
+    width: u16,
+    state: &LiveGrepState,
+    theme: &ThemeColors,
+) {
+    let prompt = "> ";
+    buf.set_string(
+        x,
+        y,
+        prompt,
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let query_x = x + prompt.len() as u16;
+    buf.set_string(query_x, y, &state.query, Style::default().fg(theme.text));
+
+    // Block cursor
+    let cursor_x = query_x + state.query[..state.cursor_pos].chars().count() as u16;
+    if cursor_x < x + width {
+        let cursor_char = state.query[state.cursor_pos..]
+            .chars()
+            .next()
+            .unwrap_or(' ');
+        buf.set_string(
+            cursor_x,
+            y,
+            cursor_char.to_string(),
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+}
+
+fn render_results(buf: &mut Buffer, area: Rect, state: &LiveGrepState, theme: &ThemeColors) {
+    let message = match &state.error {
+        Some(error) => error.as_str(),
+        None if state.query.is_empty() => "Type to search the project",
+        None if state.results.is_empty() && state.searching => "Searching…",
+        None if state.results.is_empty() => "No matches",
+        None => "",
+    };
+    if !message.is_empty() {
+        let style = if state.error.is_some() {
+            Style::default().fg(theme.error)
+        } else {
+            Style::default().fg(theme.text_muted)
+        };
+        buf.set_string(
+            area.x + 2,
+            area.y + area.height / 2,
+            crate::util::truncate_str(message, area.width.saturating_sub(2) as usize),
+            style,
+        );
+        return;
+    }
+
+    let visible_count = area.height as usize;
+    let selected = state.selected.min(state.results.len().saturating_sub(1));
+    let scroll_offset = if selected >= state.scroll_offset + visible_count {
+        selected - visible_count + 1
+    } else if selected < state.scroll_offset {
+        selected
+    } else {
+        state.scroll_offset
+    };
+    let end = state.results.len().min(scroll_offset + visible_count);
+
+    for (row_idx, i) in (scroll_offset..end).enumerate() {
+        let row = area.y + row_idx as u16;
+        let m = &state.results[i];
+        let is_selected = i == selected;
+
+        let base = if is_selected {
+            Style::default().bg(theme.background_element).fg(theme.text)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        buf.set_string(area.x, row, " ".repeat(area.width as usize), base);
+
+        let mut spans = vec![Span::styled(
+            format!("{}:{} ", m.path, m.line),
+            base.fg(theme.info),
+        )];
+        spans.extend(match_spans(
+            m,
+            base,
+            base.fg(theme.warning).add_modifier(Modifier::BOLD),
+        ));
+        buf.set_line(area.x, row, &Line::from(spans), area.width);
+    }
+}
+
+/// Split a match's text into plain and highlighted spans.
+fn match_spans(m: &GrepMatch, plain: Style, highlight: Style) -> Vec<Span<'_>> {
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    for &(start, end) in &m.spans {
+        if start < pos {
+            continue;
+        }
+        if start > pos {
+            spans.push(Span::styled(&m.text[pos..start], plain));
+        }
+        spans.push(Span::styled(&m.text[start..end], highlight));
+        pos = end;
+    }
+    if pos < m.text.len() {
+        spans.push(Span::styled(&m.text[pos..], plain));
+    }
+    spans
+}
@@ -0,0 +1,129 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Clear, Widget};
+use tracing::Level;
+
+use crate::app::{log_line_level, LogViewerState};
+use crate::theme::ThemeColors;
+
+/// Overlay panel showing the tail of opman's log, newest at the bottom.
+pub struct LogViewerPanel<'a> {
+    theme: &'a ThemeColors,
+    state: &'a LogViewerState,
+}
+
+impl<'a> LogViewerPanel<'a> {
+    pub fn new(theme: &'a ThemeColors, state: &'a LogViewerState) -> Self {
+        Self { theme, state }
+    }
+
+    fn level_color(&self, line: &str) -> Color {
+        match log_line_level(line) {
+            Some(Level::ERROR) => self.theme.error,
+            Some(Level::WARN) => self.theme.warning,
+            Some(Level::INFO) => self.theme.text,
+            _ => self.theme.text_muted,
+        }
+    }
+
+    pub fn render_popup(&self, area: Rect, buf: &mut Buffer) {
+        // 90% width, 80% height, centered
+        let popup_width = (area.width * 90 / 100)
+            .max(60)
+            .min(area.width.saturating_sub(2));
+        let popup_height = (area.height * 80 / 100)
+            .max(14)
+            .min(area.height.saturating_sub(2));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        super::render_overlay_dim(area, buf);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default().style(Style::default().bg(self.theme.background_panel));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 6 || inner.width < 20 {
+            return;
+        }
+
+        let cx = inner.x + 1;
+        let cw = inner.width.saturating_sub(2);
+
+        // ── Title row ──────────────────────────────────────────────────
+        let title_y = inner.y;
+        let esc_hint = "esc";
+        let filter = if self.state.warnings_only {
+            " · warnings only"
+        } else {
+            ""
+        };
+        let title = format!("Log · {}{filter}", self.state.path.display());
+        let title_width = cw.saturating_sub(esc_hint.len() as u16 + 1) as usize;
+        buf.set_string(
+            cx,
+            title_y,
+            crate::util::truncate_str(&title, title_width),
+            Style::default()
+                .fg(self.theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+        let esc_x = cx + cw.saturating_sub(esc_hint.len() as u16);
+        buf.set_string(
+            esc_x,
+            title_y,
+            esc_hint,
+            Style::default().fg(self.theme.text_muted),
+        );
+
+        // ── Separator ──────────────────────────────────────────────────
+        let sep_y = title_y + 1;
+        let sep = "─".repeat(cw as usize);
+        buf.set_string(
+            cx,
+            sep_y,
+            &sep,
+            Style::default().fg(self.theme.border_subtle),
+        );
+
+        // ── Lines (newest at the bottom) ───────────────────────────────
+        let list_y = sep_y + 1;
+        let max_rows = (inner.y + inner.height).saturating_sub(list_y + 1) as usize;
+        let lines = self.state.visible_lines();
+
+        if lines.is_empty() {
+            buf.set_string(
+                cx,
+                list_y,
+                "(log is empty)",
+                Style::default().fg(self.theme.text_muted),
+            );
+        } else {
+            let scroll = self.state.scroll.min(lines.len().saturating_sub(max_rows));
+            let end = lines.len() - scroll;
+            let start = end.saturating_sub(max_rows);
+            for (i, line) in lines[start..end].iter().enumerate() {
+                buf.set_string(
+                    cx,
+                    list_y + i as u16,
+                    crate::util::truncate_str(line, cw as usize),
+                    Style::default().fg(self.level_color(line)),
+                );
+            }
+        }
+
+        // ── Bottom hint ────────────────────────────────────────────────
+        let hint = "↑↓ scroll · G newest · r refresh · w warnings only · Esc close";
+        let hint_y = popup_area.y + popup_area.height - 1;
+        buf.set_string(
+            cx,
+            hint_y,
+            crate::util::truncate_str(hint, cw as usize),
+            Style::default().fg(self.theme.text_muted),
+        );
+    }
+}
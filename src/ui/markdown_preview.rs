@@ -0,0 +1,180 @@
+//! Minimal Markdown renderer for overlay previews.
+//!
+//! Handles headings, bullet/numbered lists, block quotes, fenced code blocks,
+//! and inline `**bold**`, `*italic*` and `` `code` `` spans. Anything else is
+//! rendered as plain text — this is a preview, not a full CommonMark parser.
+
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::theme::ThemeColors;
+
+/// Style class of an inline run of text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InlineKind {
+    Plain,
+    Bold,
+    Italic,
+    Code,
+}
+
+/// Render buffer lines as styled preview lines.
+pub fn markdown_lines<'a>(lines: &'a [String], theme: &ThemeColors) -> Vec<Line<'a>> {
+    let mut out = Vec::with_capacity(lines.len());
+    let mut in_code = false;
+
+    for line in lines {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            out.push(Line::from(Span::styled(
+                "─".repeat(24),
+                Style::default().fg(theme.border_subtle),
+            )));
+            continue;
+        }
+
+        if in_code {
+            out.push(Line::from(Span::styled(
+                line.as_str(),
+                Style::default()
+                    .fg(theme.info)
+                    .bg(theme.background_element),
+            )));
+            continue;
+        }
+
+        let hashes = trimmed.chars().take_while(|c| *c == '#').count();
+        if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+            out.push(Line::from(Span::styled(
+                trimmed[hashes + 1..].trim(),
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            continue;
+        }
+
+        let indent = &line[..line.len() - trimmed.len()];
+        let mut spans = vec![Span::raw(indent)];
+        let body = if let Some(rest) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+            .or_else(|| trimmed.strip_prefix("+ "))
+        {
+            spans.push(Span::styled("• ", Style::default().fg(theme.accent)));
+            rest
+        } else if let Some((marker, rest)) = numbered_marker(trimmed) {
+            spans.push(Span::styled(marker, Style::default().fg(theme.accent)));
+            rest
+        } else if let Some(rest) = trimmed.strip_prefix("> ") {
+            spans.push(Span::styled("│ ", Style::default().fg(theme.border)));
+            rest
+        } else {
+            trimmed
+        };
+
+        for (kind, text) in inline_spans(body) {
+            let style = match kind {
+                InlineKind::Plain => Style::default().fg(theme.text),
+                InlineKind::Bold => Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+                InlineKind::Italic => Style::default()
+                    .fg(theme.text)
+                    .add_modifier(Modifier::ITALIC),
+                InlineKind::Code => Style::default()
+                    .fg(theme.info)
+                    .bg(theme.background_element),
+            };
+            spans.push(Span::styled(text, style));
+        }
+        out.push(Line::from(spans));
+    }
+
+    out
+}
+
+/// Split `"12. rest"` into `("12. ", "rest")`.
+fn numbered_marker(s: &str) -> Option<(&str, &str)> {
+    let digits = s.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 || !s[digits..].starts_with(". ") {
+        return None;
+    }
+    Some(s.split_at(digits + 2))
+}
+
+/// Tokenize inline emphasis and code spans. Unterminated markers are kept
+/// as plain text.
+pub(crate) fn inline_spans(s: &str) -> Vec<(InlineKind, &str)> {
+    let mut out = Vec::new();
+    let mut plain_start = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let rest = &s[i..];
+        let (marker, kind) = if rest.starts_with("**") {
+            ("**", InlineKind::Bold)
+        } else if rest.starts_with('`') {
+            ("`", InlineKind::Code)
+        } else if rest.starts_with('*') {
+            ("*", InlineKind::Italic)
+        } else {
+            i += rest.chars().next().map(|c| c.len_utf8()).unwrap_or(1);
+            continue;
+        };
+
+        let body_start = i + marker.len();
+        match s[body_start..].find(marker) {
+            Some(len) if len > 0 => {
+                if plain_start < i {
+                    out.push((InlineKind::Plain, &s[plain_start..i]));
+                }
+                out.push((kind, &s[body_start..body_start + len]));
+                i = body_start + len + marker.len();
+                plain_start = i;
+            }
+            _ => i += marker.len(),
+        }
+    }
+
+    if plain_start < s.len() {
+        out.push((InlineKind::Plain, &s[plain_start..]));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_bold_code_italic() {
+        let spans = inline_spans("a **b** `c` *d* e");
+        assert_eq!(
+            spans,
+            vec![
+                (InlineKind::Plain, "a "),
+                (InlineKind::Bold, "b"),
+                (InlineKind::Plain, " "),
+                (InlineKind::Code, "c"),
+                (InlineKind::Plain, " "),
+                (InlineKind::Italic, "d"),
+                (InlineKind::Plain, " e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_unterminated_is_plain() {
+        assert_eq!(
+            inline_spans("2 * 3 and `x"),
+            vec![(InlineKind::Plain, "2 * 3 and `x")]
+        );
+    }
+
+    #[test]
+    fn test_numbered_marker() {
+        assert_eq!(numbered_marker("12. item"), Some(("12. ", "item")));
+        assert_eq!(numbered_marker("12 item"), None);
+    }
+}
@@ -1,6 +1,8 @@
+pub mod activity_log;
 pub mod blame_view;
 pub mod branch_popup;
 pub mod cheatsheet;
+pub mod command_runner;
 pub mod commit_popup;
 pub mod config_panel;
 pub mod context_input;
@@ -12,18 +14,27 @@ pub mod gitui_pane;
 pub mod input_dialog;
 pub mod integrated_terminal;
 pub mod layout_manager;
+pub mod live_grep;
+pub mod log_viewer;
+pub mod markdown_preview;
+pub mod model_picker;
 pub mod neovim_pane;
+pub mod permission_prompt;
+pub mod process_overview;
 pub mod remote_popup;
 mod render_helpers;
 mod render_overlays;
 pub mod routine_panel;
+pub mod run_approval;
 pub mod session_selector;
 pub mod sidebar;
 pub mod slack_log_panel;
+pub mod snippet_picker;
 pub mod status_bar;
 pub mod submodule_popup;
 pub mod tag_popup;
 pub mod term_render;
+pub mod terminal_command;
 pub mod terminal_pane;
 pub mod todo_panel;
 pub mod watcher_modal;
@@ -50,6 +61,8 @@ use self::terminal_pane::TerminalPane;
 
 pub fn draw(frame: &mut Frame, app: &mut App) {
     let size = frame.area();
+    app.last_terminal_size = (size.width, size.height);
+    app.host_cursor.set(None);
 
     if app.layout.is_visible(PanelId::IntegratedTerminal) {
         app.ensure_shell_pty();
@@ -69,8 +82,12 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         .constraints([Constraint::Min(1), Constraint::Length(1)])
         .split(size);
 
-    let content_area = status_chunks[0];
-    let status_area = status_chunks[1];
+    // Presentation mode gives the whole screen to the focused pane.
+    let (content_area, status_area) = if app.presentation_mode {
+        (size, Rect::default())
+    } else {
+        (status_chunks[0], status_chunks[1])
+    };
 
     // Only recompute layout rects + resize PTYs when the terminal size
     // changed or the layout structure was modified (panels toggled, resized).
@@ -207,8 +224,31 @@ pub fn draw(frame: &mut Frame, app: &mut App) {
         render_helpers::render_separator_junctions(frame, app, &seps);
     }
 
-    render_status_bar(frame, app, status_area);
-    render_overlays::render_overlays(frame, app, size);
+    if !app.presentation_mode {
+        render_status_bar(frame, app, status_area);
+        if app.bell_flash_until.is_some() {
+            let flash = Style::default().add_modifier(Modifier::REVERSED);
+            frame.buffer_mut().set_style(status_area, flash);
+        }
+    }
+
+    // Show the host cursor for the focused pane unless an overlay covers it.
+    let cursor_cell = app
+        .host_cursor
+        .get()
+        .map(|(x, y, _)| frame.buffer_mut()[(x, y)].clone());
+    if app.presentation_mode {
+        render_overlays::render_agent_prompts(frame, app, size);
+    } else {
+        render_overlays::render_overlays(frame, app, size);
+    }
+    if let (Some((x, y, _)), Some(cell)) = (app.host_cursor.get(), cursor_cell) {
+        if frame.buffer_mut()[(x, y)] == cell {
+            frame.set_cursor_position((x, y));
+        } else {
+            app.host_cursor.set(None);
+        }
+    }
 }
 
 fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
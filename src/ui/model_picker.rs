@@ -0,0 +1,210 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+
+use crate::app::{App, ModelPickerState};
+use crate::theme::ThemeColors;
+
+/// Render the model picker overlay: a query line above the fuzzy-filtered
+/// models, with the project's current model marked.
+pub fn render_model_picker(app: &App, area: Rect, buf: &mut Buffer) {
+    let Some(state) = &app.model_picker else {
+        return;
+    };
+    let theme = &app.theme;
+    let current = app
+        .projects
+        .get(state.project_idx)
+        .and_then(|p| p.model.as_deref());
+
+    let popup_width = 70u16.min(area.width.saturating_sub(2));
+    let list_max = (area.height / 2).saturating_sub(6);
+    let popup_height = (list_max + 6).max(8).min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    super::render_overlay_dim(area, buf);
+    Clear.render(popup_area, buf);
+
+    let block = Block::default().style(Style::default().bg(theme.background_panel));
+    let panel_inner = block.inner(popup_area);
+    block.render(popup_area, buf);
+
+    let inner = Rect {
+        x: panel_inner.x + 2,
+        y: panel_inner.y + 1,
+        width: panel_inner.width.saturating_sub(4),
+        height: panel_inner.height.saturating_sub(1),
+    };
+    if inner.height < 4 {
+        return;
+    }
+
+    let title_span = Span::styled(
+        "Switch Model",
+        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+    );
+    let esc_span = Span::styled("esc", Style::default().fg(theme.text_muted));
+    let fill = " ".repeat((inner.width as usize).saturating_sub(12 + 3));
+    let title_line = Line::from(vec![title_span, Span::raw(fill), esc_span]);
+    buf.set_line(inner.x, inner.y, &title_line, inner.width);
+
+    let input_y = inner.y + 1;
+    let separator_y = input_y + 1;
+    let hint_y = inner.y + inner.height.saturating_sub(1);
+    let results_y = separator_y + 1;
+
+    render_input_line(buf, inner.x, input_y, inner.width, state, theme);
+    buf.set_string(
+        inner.x,
+        separator_y,
+        "─".repeat(inner.width as usize),
+        Style::default().fg(theme.border_subtle),
+    );
+
+    let results_area = Rect {
+        x: inner.x,
+        y: results_y,
+        width: inner.width,
+        height: hint_y.saturating_sub(results_y),
+    };
+    if results_area.height > 0 {
+        render_results(buf, results_area, state, current, theme);
+    }
+
+    let hints = Line::from(vec![
+        Span::styled("  ↑↓", Style::default().fg(theme.accent)),
+        Span::styled(" navigate  ", Style::default().fg(theme.text_muted)),
+        Span::styled("⏎", Style::default().fg(theme.accent)),
+        Span::styled(" switch  ", Style::default().fg(theme.text_muted)),
+        Span::styled("esc", Style::default().fg(theme.accent)),
+        Span::styled(" close", Style::default().fg(theme.text_muted)),
+    ]);
+    Paragraph::new(hints).render(
+        Rect {
+            x: inner.x,
+            y: hint_y,
+            width: inner.width,
+            height: 1,
+        },
+        buf,
+    );
+}
+
+fn render_input_line(
+    buf: &mut Buffer,
+    x: u16,
+    y: u16,
+    width: u16,
+    state: &ModelPickerState,
+    theme: &ThemeColors,
+) {
+    let prompt = "> ";
+    buf.set_string(
+        x,
+        y,
+        prompt,
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let query_x = x + prompt.len() as u16;
+    buf.set_string(query_x, y, &state.query, Style::default().fg(theme.text));
+
+    // Block cursor
+    let cursor_x = query_x + state.cursor_pos as u16;
+    if cursor_x < x + width {
+        let cursor_char = state.query[state.cursor_pos..]
+            .chars()
+            .next()
+            .unwrap_or(' ');
+        buf.set_string(
+            cursor_x,
+            y,
+            cursor_char.to_string(),
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+}
+
+fn render_results(
+    buf: &mut Buffer,
+    area: Rect,
+    state: &ModelPickerState,
+    current: Option<&str>,
+    theme: &ThemeColors,
+) {
+    let message = match &state.entries {
+        None => "Loading models…",
+        Some(_) if state.filtered.is_empty() && !state.query.is_empty() => "No matching models",
+        Some(_) if state.filtered.is_empty() => "No models available",
+        Some(_) => "",
+    };
+    let Some(entries) = state.entries.as_ref().filter(|_| message.is_empty()) else {
+        buf.set_string(
+            area.x + 2,
+            area.y + area.height / 2,
+            message,
+            Style::default().fg(theme.text_muted),
+        );
+        return;
+    };
+
+    let visible_count = area.height as usize;
+    let selected = state.selected.min(state.filtered.len().saturating_sub(1));
+    let scroll_offset = if selected >= state.scroll_offset + visible_count {
+        selected - visible_count + 1
+    } else if selected < state.scroll_offset {
+        selected
+    } else {
+        state.scroll_offset
+    };
+    let end = state.filtered.len().min(scroll_offset + visible_count);
+
+    for (row_idx, i) in (scroll_offset..end).enumerate() {
+        let row = area.y + row_idx as u16;
+        let entry = &entries[state.filtered[i]];
+        let is_selected = i == selected;
+        let is_current = current == Some(entry.model_ref().as_str());
+
+        let row_style = if is_selected {
+            Style::default()
+                .bg(theme.primary)
+                .fg(theme.background)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let muted = if is_selected {
+            row_style
+        } else {
+            Style::default().fg(theme.text_muted)
+        };
+        buf.set_string(area.x, row, " ".repeat(area.width as usize), row_style);
+
+        let marker = if is_current { "● " } else { "  " };
+        let line = Line::from(vec![
+            Span::styled(
+                marker,
+                if is_selected {
+                    row_style
+                } else {
+                    Style::default().fg(theme.success)
+                },
+            ),
+            Span::styled(format!("{} ", entry.name), row_style),
+            Span::styled(entry.model_ref(), muted),
+        ]);
+        buf.set_line(area.x, row, &line, area.width);
+    }
+}
@@ -43,7 +43,23 @@ impl Widget for NeovimPane<'_> {
                     };
                     let palette = ansi_palette_from_theme(&self.app.theme);
                     let screen = parser.screen();
-                    term_render::render_screen(screen, area, buf, &palette, &self.app.theme);
+                    term_render::render_screen_cached(
+                        screen,
+                        area,
+                        buf,
+                        &palette,
+                        &self.app.theme,
+                        &mut pty.render_cache.borrow_mut(),
+                    );
+                    term_render::render_hyperlinks(pty, screen, area, buf);
+                    term_render::render_pane_cursor(
+                        self.app,
+                        crate::ui::layout_manager::PanelId::NeovimPane,
+                        pty,
+                        screen,
+                        area,
+                        buf,
+                    );
                 }
 
                 if let Some(ref sel) = self.app.terminal_selection {
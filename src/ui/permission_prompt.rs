@@ -0,0 +1,143 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Clear, Widget};
+
+use crate::app::PendingPermission;
+use crate::theme::ThemeColors;
+
+/// Maximum pattern lines shown before eliding the rest.
+const MAX_PATTERN_LINES: usize = 8;
+
+/// Approval prompt for an agent permission request.
+pub struct PermissionPrompt<'a> {
+    theme: &'a ThemeColors,
+    pending: &'a PendingPermission,
+    /// `project · session` the request came from.
+    origin: String,
+    queued: usize,
+}
+
+impl<'a> PermissionPrompt<'a> {
+    pub fn new(
+        theme: &'a ThemeColors,
+        pending: &'a PendingPermission,
+        origin: String,
+        queued: usize,
+    ) -> Self {
+        Self {
+            theme,
+            pending,
+            origin,
+            queued,
+        }
+    }
+
+    /// Banner shown until the prompt is focused with `review_key`. It
+    /// names the project, which may not be the one on screen.
+    pub fn render_banner(&self, area: Rect, buf: &mut Buffer, review_key: &str) {
+        let mut text = format!(
+            "{}: agent asks permission for {} · {} to review",
+            self.origin,
+            self.pending.request.permission,
+            crate::config::format_key_display(review_key)
+        );
+        if self.queued > 1 {
+            text.push_str(&format!(" ({} waiting)", self.queued));
+        }
+        super::render_helpers::render_prompt_banner(area, buf, self.theme, &text);
+    }
+
+    pub fn render_popup(&self, area: Rect, buf: &mut Buffer) {
+        let patterns = &self.pending.request.patterns;
+        let shown = patterns.len().clamp(1, MAX_PATTERN_LINES);
+        let elided = patterns.len().saturating_sub(MAX_PATTERN_LINES);
+
+        // title + origin + blank + patterns + [elided] + blank + hint
+        let popup_height =
+            (shown as u16 + 6 + u16::from(elided > 0)).min(area.height.saturating_sub(2));
+        let popup_width = (area.width * 60 / 100)
+            .max(50)
+            .min(area.width.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        super::render_overlay_dim(area, buf);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default().style(Style::default().bg(self.theme.background_panel));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 5 || inner.width < 20 {
+            return;
+        }
+
+        let cx = inner.x + 2;
+        let cw = inner.width.saturating_sub(4) as usize;
+        let bottom = inner.y + inner.height;
+
+        // ── Title row ──────────────────────────────────────────────────
+        let title = format!("Agent asks permission: {}", self.pending.request.permission);
+        buf.set_string(
+            cx,
+            inner.y,
+            crate::util::truncate_str(&title, cw),
+            Style::default()
+                .fg(self.theme.warning)
+                .add_modifier(Modifier::BOLD),
+        );
+        if self.queued > 1 {
+            let more = format!("1 of {}", self.queued);
+            let more_x = cx + (cw as u16).saturating_sub(more.len() as u16);
+            buf.set_string(
+                more_x,
+                inner.y,
+                &more,
+                Style::default().fg(self.theme.text_muted),
+            );
+        }
+        buf.set_string(
+            cx,
+            inner.y + 1,
+            crate::util::truncate_str(&self.origin, cw),
+            Style::default().fg(self.theme.text_muted),
+        );
+
+        // ── Patterns ───────────────────────────────────────────────────
+        let mut cy = inner.y + 3;
+        let pattern_style = Style::default()
+            .fg(self.theme.info)
+            .bg(self.theme.background_element);
+        let lines: Vec<&str> = if patterns.is_empty() {
+            vec!["(no details)"]
+        } else {
+            patterns.iter().map(String::as_str).collect()
+        };
+        for line in lines.iter().take(shown) {
+            if cy >= bottom.saturating_sub(1) {
+                break;
+            }
+            buf.set_string(cx, cy, " ".repeat(cw), pattern_style);
+            buf.set_string(cx, cy, crate::util::truncate_str(line, cw), pattern_style);
+            cy += 1;
+        }
+        if elided > 0 && cy < bottom.saturating_sub(1) {
+            buf.set_string(
+                cx,
+                cy,
+                format!("… {elided} more"),
+                Style::default().fg(self.theme.text_muted),
+            );
+        }
+
+        // ── Bottom hint ────────────────────────────────────────────────
+        buf.set_string(
+            cx,
+            bottom - 1,
+            "y allow once · A allow always · n deny · Esc back",
+            Style::default().fg(self.theme.text_muted),
+        );
+    }
+}
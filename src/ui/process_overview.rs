@@ -0,0 +1,130 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Clear, Widget};
+
+use crate::app::ProcessOverviewState;
+use crate::process_health::footprint::format_bytes;
+use crate::theme::ThemeColors;
+
+/// Overlay panel listing live PTY processes and their memory use.
+pub struct ProcessOverviewPanel<'a> {
+    theme: &'a ThemeColors,
+    state: &'a ProcessOverviewState,
+}
+
+impl<'a> ProcessOverviewPanel<'a> {
+    pub fn new(theme: &'a ThemeColors, state: &'a ProcessOverviewState) -> Self {
+        Self { theme, state }
+    }
+
+    pub fn render_popup(&self, area: Rect, buf: &mut Buffer) {
+        // 80% width, 70% height, centered
+        let popup_width = (area.width * 80 / 100)
+            .max(60)
+            .min(area.width.saturating_sub(2));
+        let popup_height = (area.height * 70 / 100)
+            .max(14)
+            .min(area.height.saturating_sub(2));
+
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        super::render_overlay_dim(area, buf);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default().style(Style::default().bg(self.theme.background_panel));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 6 || inner.width < 20 {
+            return;
+        }
+
+        let cx = inner.x + 1;
+        let cw = inner.width.saturating_sub(2);
+
+        // ── Title row ──────────────────────────────────────────────────
+        let title_y = inner.y;
+        buf.set_string(
+            cx,
+            title_y,
+            "Processes",
+            Style::default()
+                .fg(self.theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+        let esc_hint = "esc";
+        let esc_x = cx + cw.saturating_sub(esc_hint.len() as u16);
+        buf.set_string(
+            esc_x,
+            title_y,
+            esc_hint,
+            Style::default().fg(self.theme.text_muted),
+        );
+
+        // ── Totals ─────────────────────────────────────────────────────
+        let rows = &self.state.rows;
+        let summary_y = title_y + 1;
+        let count = |kind: &str| rows.iter().filter(|r| r.kind == kind).count();
+        let summary = format!(
+            "{} processes · opencode:{} shell:{} neovim:{} gitui:{} · total {}",
+            rows.len(),
+            count("opencode"),
+            count("shell"),
+            count("neovim"),
+            count("gitui"),
+            format_bytes(self.state.total_rss()),
+        );
+        buf.set_string(cx, summary_y, &summary, Style::default().fg(self.theme.primary));
+
+        // ── Separator ──────────────────────────────────────────────────
+        let sep_y = summary_y + 1;
+        let sep = "─".repeat(cw as usize);
+        buf.set_string(
+            cx,
+            sep_y,
+            &sep,
+            Style::default().fg(self.theme.border_subtle),
+        );
+
+        // ── Process rows ───────────────────────────────────────────────
+        let list_y = sep_y + 1;
+        let max_rows = (inner.y + inner.height).saturating_sub(list_y + 1) as usize;
+
+        if rows.is_empty() {
+            buf.set_string(
+                cx,
+                list_y,
+                "(no PTY processes running)",
+                Style::default().fg(self.theme.text_muted),
+            );
+        } else {
+            let start = self.state.scroll.min(rows.len().saturating_sub(1));
+            for (i, row) in rows[start..].iter().take(max_rows).enumerate() {
+                let cy = list_y + i as u16;
+                let pid = row.pid.map_or_else(|| "-".to_string(), |p| p.to_string());
+                let rss = row.rss.map_or_else(|| "?".to_string(), format_bytes);
+                // "  38.2M  12345  neovim    project / session"
+                let prefix = format!("{:>7} {:>7}  {:<9}", rss, pid, row.kind);
+                buf.set_string(cx, cy, &prefix, Style::default().fg(self.theme.accent));
+
+                let label = if row.session.is_empty() {
+                    row.project.clone()
+                } else {
+                    format!("{} / {}", row.project, row.session)
+                };
+                let label_x = cx + prefix.len() as u16 + 1;
+                let label_width = cw.saturating_sub(prefix.len() as u16 + 1) as usize;
+                let label = crate::util::truncate_str(&label, label_width);
+                buf.set_string(label_x, cy, label, Style::default().fg(self.theme.text));
+            }
+        }
+
+        // ── Bottom hint ────────────────────────────────────────────────
+        let hint = "↑↓ scroll · r refresh · Esc close";
+        let hint_y = popup_area.y + popup_area.height - 1;
+        buf.set_string(cx, hint_y, hint, Style::default().fg(self.theme.text_muted));
+    }
+}
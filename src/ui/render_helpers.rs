@@ -24,6 +24,22 @@ pub fn render_overlay_dim(area: Rect, buf: &mut Buffer) {
     }
 }
 
+/// One-line notice across the top of `area` for an agent prompt that is
+/// waiting to be focused. It takes no keys, so it must say how to review.
+pub(super) fn render_prompt_banner(area: Rect, buf: &mut Buffer, theme: &ThemeColors, text: &str) {
+    if area.height == 0 || area.width < 4 {
+        return;
+    }
+    let style = Style::default()
+        .fg(theme.background)
+        .bg(theme.warning)
+        .add_modifier(Modifier::BOLD);
+    let width = area.width as usize;
+    buf.set_string(area.x, area.y, " ".repeat(width), style);
+    let text = crate::util::truncate_str(text, width - 2);
+    buf.set_string(area.x + 1, area.y, text, style);
+}
+
 /// Dim an unfocused panel by reducing the brightness of every cell.
 /// Blends each color channel toward a dark base by the given factor
 /// (0.0 = fully dark, 1.0 = no change).  Driven by the
@@ -140,6 +156,13 @@ pub(super) fn render_separator_junctions(frame: &mut Frame, app: &App, seps: &[S
                 } else if hy >= vy_start && hy < vy_end {
                     buf.set_string(vx, hy, "┼", style);
                 }
+            } else if hy >= vy_start && hy < vy_end {
+                // Row separator ending (┤) or starting (├) at a column one.
+                if vx == hx_end {
+                    buf.set_string(vx, hy, "┤", style);
+                } else if vx + 1 == hx_start {
+                    buf.set_string(vx, hy, "├", style);
+                }
             }
         }
     }
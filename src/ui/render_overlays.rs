@@ -17,7 +17,9 @@ use super::render_helpers::render_overlay_dim;
 use super::sidebar::SessionSearchPanel;
 
 pub(super) fn render_overlays(frame: &mut Frame, app: &App, size: Rect) {
-    if app.input_mode == InputMode::FuzzyPicker {
+    // A picker opened from the context input is drawn on top of it (below).
+    let picker_over_context = app.context_input.is_some();
+    if app.input_mode == InputMode::FuzzyPicker && !picker_over_context {
         if let Some(ref _picker) = app.fuzzy_picker {
             let fuzzy = FuzzyPicker::new(app);
             fuzzy.render_popup(size, frame.buffer_mut());
@@ -48,14 +50,7 @@ pub(super) fn render_overlays(frame: &mut Frame, app: &App, size: Rect) {
     }
 
     if app.show_config_panel {
-        let panel = ConfigPanel::new(
-            &app.theme,
-            app.config_panel_selected,
-            app.config.settings.follow_edits_in_neovim,
-            app.config.settings.unfocused_dim_percent,
-            app.config.settings.slack.enabled,
-            app.config.settings.slack.relay_buffer_secs,
-        );
+        let panel = ConfigPanel::new(&app.theme, app.config_panel_selected, &app.config.settings);
         panel.render_popup(size, frame.buffer_mut());
     }
 
@@ -72,10 +67,59 @@ pub(super) fn render_overlays(frame: &mut Frame, app: &App, size: Rect) {
             }
         }
     }
+    if let Some(ref state) = app.process_overview {
+        let panel = super::process_overview::ProcessOverviewPanel::new(&app.theme, state);
+        panel.render_popup(size, frame.buffer_mut());
+    }
+    if let Some(ref state) = app.activity_log {
+        let title = app
+            .projects
+            .iter()
+            .flat_map(|p| p.sessions.iter())
+            .find(|s| s.id == state.session_id && !s.title.is_empty())
+            .map(|s| s.title.clone())
+            .unwrap_or_else(|| state.session_id.clone());
+        let panel = super::activity_log::ActivityLogPanel::new(
+            &app.theme,
+            title,
+            app.session_activity_entries(&state.session_id),
+            state.scroll,
+        );
+        panel.render_popup(size, frame.buffer_mut());
+    }
+    if let Some(ref state) = app.log_viewer {
+        let panel = super::log_viewer::LogViewerPanel::new(&app.theme, state);
+        panel.render_popup(size, frame.buffer_mut());
+    }
+
     if app.session_selector.is_some() {
         super::session_selector::render_session_selector(app, size, frame.buffer_mut());
     }
 
+    if app.model_picker.is_some() {
+        super::model_picker::render_model_picker(app, size, frame.buffer_mut());
+    }
+
+    if app.live_grep.is_some() {
+        super::live_grep::render_live_grep(app, size, frame.buffer_mut());
+    }
+
+    if app.command_runner.is_some() {
+        super::command_runner::render_command_runner(app, size, frame.buffer_mut());
+    }
+
+    if app.snippet_picker.is_some() {
+        super::snippet_picker::render_snippet_picker(app, size, frame.buffer_mut());
+    }
+
+    if app.history_picker.is_some() {
+        super::snippet_picker::render_history_picker(app, size, frame.buffer_mut());
+    }
+
+    if app.terminal_command.is_some() {
+        super::terminal_command::render_terminal_command(app, size, frame.buffer_mut());
+    }
+
     if app.todo_panel.is_some() {
         super::todo_panel::render_todo_panel(app, size, frame.buffer_mut());
     }
@@ -87,12 +131,79 @@ pub(super) fn render_overlays(frame: &mut Frame, app: &App, size: Rect) {
     if app.context_input.is_some() {
         let ci = super::context_input::ContextInput::new(app);
         ci.render_popup(size, frame.buffer_mut());
+        if app.input_mode == InputMode::FuzzyPicker && app.fuzzy_picker.is_some() {
+            FuzzyPicker::new(app).render_popup(size, frame.buffer_mut());
+        }
     }
 
     if app.watcher_modal.is_some() {
         let wm = super::watcher_modal::WatcherModal::new(app);
         wm.render_popup(size, frame.buffer_mut());
     }
+
+    render_agent_prompts(frame, app, size);
+}
+
+/// Pending run approvals and permission requests: a banner, or the full
+/// prompt once focused. Drawn in presentation mode too, where the other
+/// overlays are hidden.
+pub(super) fn render_agent_prompts(frame: &mut Frame, app: &App, size: Rect) {
+    let review_key = &app.config.keybindings.review_agent_prompt;
+    // Approvals are answered first, so a permission request waits behind
+    // them.
+    let permission = app
+        .permission_prompts
+        .front()
+        .filter(|_| app.run_approvals.is_empty());
+    if let Some(pending) = permission {
+        let project = app.projects.get(pending.project_idx);
+        let session = project
+            .and_then(|p| {
+                p.sessions
+                    .iter()
+                    .find(|s| s.id == pending.request.session_id)
+            })
+            .map(|s| s.title.as_str())
+            .filter(|t| !t.is_empty())
+            .unwrap_or(&pending.request.session_id);
+        let origin = format!(
+            "{} · {}",
+            project.map(|p| p.name.as_str()).unwrap_or(""),
+            session
+        );
+        let prompt = super::permission_prompt::PermissionPrompt::new(
+            &app.theme,
+            pending,
+            origin,
+            app.permission_prompts.len(),
+        );
+        if app.agent_prompt_focused {
+            prompt.render_popup(size, frame.buffer_mut());
+        } else {
+            prompt.render_banner(size, frame.buffer_mut(), review_key);
+        }
+    }
+
+    // Drawn last: once focused it captures keys ahead of every other
+    // overlay; until then it is a banner.
+    if let Some(approval) = app.run_approvals.front() {
+        let project_name = app
+            .projects
+            .get(approval.project_idx)
+            .map(|p| p.name.as_str())
+            .unwrap_or("");
+        let prompt = super::run_approval::RunApprovalPrompt::new(
+            &app.theme,
+            approval,
+            project_name,
+            app.run_approvals.len(),
+        );
+        if app.agent_prompt_focused {
+            prompt.render_popup(size, frame.buffer_mut());
+        } else {
+            prompt.render_banner(size, frame.buffer_mut(), review_key);
+        }
+    }
 }
 
 fn render_command_palette(
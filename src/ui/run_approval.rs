@@ -0,0 +1,143 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Clear, Widget};
+
+use crate::app::PendingRunApproval;
+use crate::theme::ThemeColors;
+
+/// Maximum command lines shown before eliding the rest.
+const MAX_COMMAND_LINES: usize = 8;
+
+/// Approval prompt for an agent `run` held by dry-run mode.
+pub struct RunApprovalPrompt<'a> {
+    theme: &'a ThemeColors,
+    approval: &'a PendingRunApproval,
+    project_name: &'a str,
+    queued: usize,
+}
+
+impl<'a> RunApprovalPrompt<'a> {
+    pub fn new(
+        theme: &'a ThemeColors,
+        approval: &'a PendingRunApproval,
+        project_name: &'a str,
+        queued: usize,
+    ) -> Self {
+        Self {
+            theme,
+            approval,
+            project_name,
+            queued,
+        }
+    }
+
+    /// Banner shown until the prompt is focused with `review_key`.
+    pub fn render_banner(&self, area: Rect, buf: &mut Buffer, review_key: &str) {
+        let first_line = self.approval.command().lines().next().unwrap_or("");
+        let mut text = format!(
+            "{}: agent wants to run `{}` · {} to review",
+            self.project_name,
+            crate::util::truncate_str(first_line, 40),
+            crate::config::format_key_display(review_key)
+        );
+        if self.queued > 1 {
+            text.push_str(&format!(" ({} waiting)", self.queued));
+        }
+        super::render_helpers::render_prompt_banner(area, buf, self.theme, &text);
+    }
+
+    pub fn render_popup(&self, area: Rect, buf: &mut Buffer) {
+        let lines: Vec<&str> = self.approval.command().lines().collect();
+        let shown = lines.len().clamp(1, MAX_COMMAND_LINES);
+        let elided = lines.len().saturating_sub(MAX_COMMAND_LINES);
+
+        // title + project + blank + command + [elided] + blank + hint
+        let popup_height =
+            (shown as u16 + 6 + u16::from(elided > 0)).min(area.height.saturating_sub(2));
+        let popup_width = (area.width * 60 / 100)
+            .max(50)
+            .min(area.width.saturating_sub(2));
+        let x = area.x + (area.width.saturating_sub(popup_width)) / 2;
+        let y = area.y + (area.height.saturating_sub(popup_height)) / 2;
+        let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+        super::render_overlay_dim(area, buf);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default().style(Style::default().bg(self.theme.background_panel));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        if inner.height < 5 || inner.width < 20 {
+            return;
+        }
+
+        let cx = inner.x + 2;
+        let cw = inner.width.saturating_sub(4) as usize;
+        let bottom = inner.y + inner.height;
+
+        // ── Title row ──────────────────────────────────────────────────
+        buf.set_string(
+            cx,
+            inner.y,
+            "Agent wants to run a command",
+            Style::default()
+                .fg(self.theme.warning)
+                .add_modifier(Modifier::BOLD),
+        );
+        if self.queued > 1 {
+            let more = format!("1 of {}", self.queued);
+            let more_x = cx + (cw as u16).saturating_sub(more.len() as u16);
+            buf.set_string(
+                more_x,
+                inner.y,
+                &more,
+                Style::default().fg(self.theme.text_muted),
+            );
+        }
+        let tab = self
+            .approval
+            .pending
+            .request
+            .tab
+            .map_or_else(|| "active tab".to_string(), |t| format!("tab {t}"));
+        let origin = format!("{} · {}", self.project_name, tab);
+        buf.set_string(
+            cx,
+            inner.y + 1,
+            crate::util::truncate_str(&origin, cw),
+            Style::default().fg(self.theme.text_muted),
+        );
+
+        // ── Command ────────────────────────────────────────────────────
+        let mut cy = inner.y + 3;
+        let cmd_style = Style::default()
+            .fg(self.theme.info)
+            .bg(self.theme.background_element);
+        for line in lines.iter().take(shown) {
+            if cy >= bottom.saturating_sub(1) {
+                break;
+            }
+            buf.set_string(cx, cy, " ".repeat(cw), cmd_style);
+            buf.set_string(cx, cy, crate::util::truncate_str(line, cw), cmd_style);
+            cy += 1;
+        }
+        if elided > 0 && cy < bottom.saturating_sub(1) {
+            buf.set_string(
+                cx,
+                cy,
+                format!("… {elided} more lines"),
+                Style::default().fg(self.theme.text_muted),
+            );
+        }
+
+        // ── Bottom hint ────────────────────────────────────────────────
+        buf.set_string(
+            cx,
+            bottom - 1,
+            "y run · n reject · Esc back",
+            Style::default().fg(self.theme.text_muted),
+        );
+    }
+}
@@ -111,7 +111,7 @@ pub fn render_session_selector(app: &App, area: Rect, buf: &mut Buffer) {
     }
 
     // Hint line
-    render_hint_line(buf, inner.x, hint_y, inner.width, theme);
+    render_hint_line(buf, inner.x, hint_y, inner.width, state, theme);
 }
 
 fn render_input_line(
@@ -154,15 +154,53 @@ fn render_input_line(
     }
 }
 
-fn render_hint_line(buf: &mut Buffer, x: u16, y: u16, width: u16, theme: &ThemeColors) {
-    let spans = vec![
-        Span::styled("  ↑↓", Style::default().fg(theme.accent)),
-        Span::styled(" navigate  ", Style::default().fg(theme.text_muted)),
-        Span::styled("⏎", Style::default().fg(theme.accent)),
-        Span::styled(" select  ", Style::default().fg(theme.text_muted)),
-        Span::styled("esc", Style::default().fg(theme.accent)),
-        Span::styled(" close", Style::default().fg(theme.text_muted)),
-    ];
+fn render_hint_line(
+    buf: &mut Buffer,
+    x: u16,
+    y: u16,
+    width: u16,
+    state: &SessionSelectorState,
+    theme: &ThemeColors,
+) {
+    let spans = if state.confirm_delete {
+        let count = state.action_targets().len();
+        vec![
+            Span::styled(
+                format!("  Delete {count} session(s)? "),
+                Style::default()
+                    .fg(theme.error)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("y", Style::default().fg(theme.accent)),
+            Span::styled("/N", Style::default().fg(theme.text_muted)),
+        ]
+    } else if !state.marked.is_empty() {
+        vec![
+            Span::styled(
+                format!("  {} marked  ", state.marked.len()),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled("tab", Style::default().fg(theme.accent)),
+            Span::styled(" mark  ", Style::default().fg(theme.text_muted)),
+            Span::styled("^t", Style::default().fg(theme.accent)),
+            Span::styled(" pin  ", Style::default().fg(theme.text_muted)),
+            Span::styled("^x", Style::default().fg(theme.accent)),
+            Span::styled(" delete  ", Style::default().fg(theme.text_muted)),
+            Span::styled("esc", Style::default().fg(theme.accent)),
+            Span::styled(" close", Style::default().fg(theme.text_muted)),
+        ]
+    } else {
+        vec![
+            Span::styled("  ↑↓", Style::default().fg(theme.accent)),
+            Span::styled(" navigate  ", Style::default().fg(theme.text_muted)),
+            Span::styled("⏎", Style::default().fg(theme.accent)),
+            Span::styled(" select  ", Style::default().fg(theme.text_muted)),
+            Span::styled("tab", Style::default().fg(theme.accent)),
+            Span::styled(" mark  ", Style::default().fg(theme.text_muted)),
+            Span::styled("esc", Style::default().fg(theme.accent)),
+            Span::styled(" close", Style::default().fg(theme.text_muted)),
+        ]
+    };
 
     let line = Line::from(spans);
     let hint_area = Rect {
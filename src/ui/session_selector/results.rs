@@ -69,6 +69,18 @@ pub(super) fn render_results(
         let blank: String = " ".repeat(area.width as usize);
         buf.set_string(area.x, row, &blank, row_bg);
 
+        // Checkmark in the left margin for entries marked for a bulk action
+        if state.marked.contains(&entry_idx) {
+            let mark_style = if is_selected {
+                row_bg
+            } else {
+                Style::default()
+                    .fg(theme.success)
+                    .add_modifier(Modifier::BOLD)
+            };
+            buf.set_string(area.x, row, "✓", mark_style);
+        }
+
         // Build the line: {project_name} / {session_title}    {relative_time}
         let session_title = if entry.session.title.is_empty() {
             // Use first 8 chars of ID as fallback
@@ -2,6 +2,10 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::ListItem;
 
+use super::hit_map::{
+    ARROW_COLLAPSED, ARROW_EXPANDED, BRANCH, COMPACT_SESSION_INDENT, COMPACT_SUBAGENT_INDENT,
+    SESSION_INDENT, SUBAGENT_INDENT,
+};
 use super::lerp_color;
 use super::Sidebar;
 
@@ -19,6 +23,26 @@ enum Indicator {
     Idle,
 }
 
+/// Up to three letters standing in for a project name in the compact
+/// sidebar: the first letter of each word, or the first two letters of a
+/// single-word name.
+fn project_initials(name: &str) -> String {
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let initials: String = match words.as_slice() {
+        [] => return "?".into(),
+        [word] => word.chars().take(2).collect(),
+        words => words
+            .iter()
+            .filter_map(|w| w.chars().next())
+            .take(3)
+            .collect(),
+    };
+    initials.to_uppercase()
+}
+
 impl<'a> Sidebar<'a> {
     /// Determine the highest-priority indicator state for a session.
     fn session_indicator(&self, session_id: &str) -> Indicator {
@@ -103,7 +127,10 @@ impl<'a> Sidebar<'a> {
     /// Two independent highlights:
     ///   - **cursor** (`sidebar_cursor`): background highlight via `background_element`
     ///   - **selected** (`sidebar_selection`): bold + primary foreground (active session)
-    pub(super) fn build_items(&self) -> Vec<ListItem<'a>> {
+    ///
+    /// Compact rows keep the same order and count, so selection and mouse
+    /// hit-testing are unaffected.
+    pub(super) fn build_items(&self, width: u16, compact: bool) -> Vec<ListItem<'a>> {
         let mut items = Vec::new();
 
         if self.app.projects.is_empty() {
@@ -120,12 +147,18 @@ impl<'a> Sidebar<'a> {
 
         let mut flat_idx = 0;
 
-        for (i, project) in self.app.projects.iter().enumerate() {
+        for i in self.app.sidebar_project_order() {
+            let project = &self.app.projects[i];
             let is_active = i == self.app.active_project;
             let is_selected = flat_idx == self.app.sidebar_selection;
             let is_cursor = flat_idx == self.app.sidebar_cursor;
 
-            let marker = if is_active { "▶ " } else { "  " };
+            let marker = match (is_active, compact) {
+                (true, false) => "▶ ",
+                (false, false) => "  ",
+                (true, true) => "▶",
+                (false, true) => " ",
+            };
             let mut style = if is_selected {
                 Style::default()
                     .fg(self.app.theme.primary)
@@ -146,12 +179,16 @@ impl<'a> Sidebar<'a> {
             if !matches!(project_indicator, Indicator::Idle) {
                 spans.push(self.indicator_span(&project_indicator, is_cursor));
             }
-            spans.push(Span::styled(project.name.clone(), style));
+            if compact {
+                spans.push(Span::styled(project_initials(&project.name), style));
+            } else {
+                self.push_project_name(&mut spans, i, style, is_cursor, width);
+            }
             let project_line = Line::from(spans);
             items.push(ListItem::new(project_line));
             flat_idx += 1;
 
-            let is_expanded = self.app.sessions_expanded_for == Some(i);
+            let is_expanded = self.app.sessions_expanded(i);
             let visible = self.app.visible_sessions(i);
 
             if is_expanded {
@@ -171,17 +208,26 @@ impl<'a> Sidebar<'a> {
                 if is_cur {
                     pad_style = self.with_cursor_bg(pad_style);
                 }
+                let (indent, label) = if compact {
+                    (COMPACT_SESSION_INDENT, "+ New")
+                } else {
+                    (SESSION_INDENT, "+ New Session")
+                };
                 items.push(ListItem::new(Line::from(vec![
-                    Span::styled("    ", pad_style),
-                    Span::styled("+ New Session", ns_style),
+                    Span::styled(indent, pad_style),
+                    Span::styled(label, ns_style),
                 ])));
                 flat_idx += 1;
 
                 if visible.is_empty() {
                     let hint_style = Style::default().fg(self.app.theme.text_muted);
-                    let hint_text = "  └ no sessions yet";
+                    let hint_text = if compact {
+                        "  none"
+                    } else {
+                        "  └ no sessions yet"
+                    };
                     items.push(ListItem::new(Line::from(vec![
-                        Span::raw("    "),
+                        Span::raw(indent),
                         Span::styled(hint_text, hint_style),
                     ])));
                 }
@@ -191,7 +237,8 @@ impl<'a> Sidebar<'a> {
                 let is_sel = flat_idx == self.app.sidebar_selection;
                 let is_cur = flat_idx == self.app.sidebar_cursor;
                 let indicator = self.session_indicator(&session.id);
-                let has_subagents = !self.app.subagent_sessions(i, &session.id).is_empty();
+                let subagent_count = self.app.subagent_sessions(i, &session.id).len();
+                let has_subagents = subagent_count > 0;
                 let is_subagents_open =
                     self.app.subagents_expanded_for.as_deref() == Some(&session.id);
                 let mut s_style = if is_sel {
@@ -213,12 +260,28 @@ impl<'a> Sidebar<'a> {
                 if is_cur {
                     pad_style = self.with_cursor_bg(pad_style);
                 }
-                let mut spans = vec![Span::styled("    ", pad_style)];
-                spans.push(Span::styled("└ ", s_style));
+                let mut spans = if compact {
+                    vec![Span::styled(COMPACT_SESSION_INDENT, pad_style)]
+                } else {
+                    vec![
+                        Span::styled(SESSION_INDENT, pad_style),
+                        Span::styled(BRANCH, s_style),
+                    ]
+                };
                 spans.push(self.indicator_span(&indicator, is_cur));
                 if has_subagents {
-                    let arrow = if is_subagents_open { "▼ " } else { "▶ " };
+                    let arrow = if is_subagents_open {
+                        ARROW_EXPANDED
+                    } else {
+                        ARROW_COLLAPSED
+                    };
                     spans.push(Span::styled(arrow, s_style));
+                    // Subagent count badge, visible even while collapsed.
+                    let mut badge_style = Style::default().fg(self.app.theme.accent);
+                    if is_cur {
+                        badge_style = self.with_cursor_bg(badge_style);
+                    }
+                    spans.push(Span::styled(format!("{subagent_count} "), badge_style));
                 }
                 spans.push(Span::styled(title.to_string(), s_style));
                 items.push(ListItem::new(Line::from(spans)));
@@ -249,8 +312,14 @@ impl<'a> Sidebar<'a> {
                         if sub_cur {
                             sub_pad = self.with_cursor_bg(sub_pad);
                         }
-                        let mut sub_spans = vec![Span::styled("      ", sub_pad)];
-                        sub_spans.push(Span::styled("└ ", sub_style));
+                        let mut sub_spans = if compact {
+                            vec![Span::styled(COMPACT_SUBAGENT_INDENT, sub_pad)]
+                        } else {
+                            vec![
+                                Span::styled(SUBAGENT_INDENT, sub_pad),
+                                Span::styled(BRANCH, sub_style),
+                            ]
+                        };
                         sub_spans.push(self.indicator_span(&sub_indicator, sub_cur));
                         sub_spans.push(Span::styled(sub_title.to_string(), sub_style));
                         items.push(ListItem::new(Line::from(sub_spans)));
@@ -276,9 +345,14 @@ impl<'a> Sidebar<'a> {
                 if is_cur {
                     pad_style = self.with_cursor_bg(pad_style);
                 }
+                let (indent, label) = if compact {
+                    (COMPACT_SESSION_INDENT, "…")
+                } else {
+                    (SESSION_INDENT, "└ more...")
+                };
                 items.push(ListItem::new(Line::from(vec![
-                    Span::styled("    ", pad_style),
-                    Span::styled("└ more...", more_style),
+                    Span::styled(indent, pad_style),
+                    Span::styled(label, more_style),
                 ])));
                 flat_idx += 1;
             }
@@ -297,14 +371,63 @@ impl<'a> Sidebar<'a> {
         if is_cur {
             add_style = self.with_cursor_bg(add_style);
         }
+        let label = if compact { " [+]" } else { "  [+ Add Project]" };
         items.push(ListItem::new(Line::from(vec![Span::styled(
-            "  [+ Add Project]",
-            add_style,
+            label, add_style,
         )])));
 
         items
     }
 
+    /// Append a full-width project row's name, pin mark and right-aligned
+    /// count of parent sessions to `spans`.
+    fn push_project_name(
+        &self,
+        spans: &mut Vec<Span<'a>>,
+        project_idx: usize,
+        style: Style,
+        is_cursor: bool,
+        width: u16,
+    ) {
+        let project = &self.app.projects[project_idx];
+        spans.push(Span::styled(project.name.clone(), style));
+        let pinned = self
+            .app
+            .config
+            .projects
+            .get(project_idx)
+            .is_some_and(|e| e.pinned);
+        if pinned {
+            let mut pin_style = Style::default().fg(self.app.theme.text_muted);
+            if is_cursor {
+                pin_style = self.with_cursor_bg(pin_style);
+            }
+            spans.push(Span::styled(" ↑", pin_style));
+        }
+
+        // Right-aligned, dimmed count of parent sessions.
+        let session_count = project
+            .sessions
+            .iter()
+            .filter(|s| s.parent_id.is_empty())
+            .count();
+        if session_count > 0 {
+            let badge = format!("{session_count} ");
+            let used: usize = spans.iter().map(|s| s.width()).sum();
+            let pad = (width as usize).saturating_sub(used + badge.len());
+            if pad > 0 {
+                let mut badge_style = Style::default().fg(self.app.theme.text_muted);
+                let mut pad_style = Style::default();
+                if is_cursor {
+                    badge_style = self.with_cursor_bg(badge_style);
+                    pad_style = self.with_cursor_bg(pad_style);
+                }
+                spans.push(Span::styled(" ".repeat(pad), pad_style));
+                spans.push(Span::styled(badge, badge_style));
+            }
+        }
+    }
+
     /// Compute the highest-priority indicator across all sessions in a project.
     fn project_indicator(&self, project_idx: usize) -> Indicator {
         let Some(project) = self.app.projects.get(project_idx) else {
@@ -332,3 +455,17 @@ impl<'a> Sidebar<'a> {
         best
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::project_initials;
+
+    #[test]
+    fn test_project_initials() {
+        assert_eq!(project_initials("opman"), "OP");
+        assert_eq!(project_initials("my-web_app"), "MWA");
+        assert_eq!(project_initials("api server v2 beta"), "ASV");
+        assert_eq!(project_initials("x"), "X");
+        assert_eq!(project_initials("--"), "?");
+    }
+}
@@ -0,0 +1,111 @@
+//! Column layout of sidebar session rows, shared by the renderer and mouse
+//! hit-testing so the two can't drift apart.
+//!
+//! A parent session row reads `    └ ● ▶ 3 title`: indent, branch, status
+//! indicator, then (only with subagents) the expand arrow and count badge.
+//! Subagent rows are indented further and never have an arrow. Compact
+//! rows (`settings.sidebar_density`) drop the branch and most of the
+//! indent: ` ● ▶ 3 title`.
+
+use std::ops::Range;
+
+/// Leading padding of a parent session row.
+pub(super) const SESSION_INDENT: &str = "    ";
+/// Leading padding of a subagent row.
+pub(super) const SUBAGENT_INDENT: &str = "      ";
+/// Leading padding of a parent / subagent row in the compact sidebar.
+pub(super) const COMPACT_SESSION_INDENT: &str = " ";
+pub(super) const COMPACT_SUBAGENT_INDENT: &str = "  ";
+/// Tree branch drawn before every session.
+pub(super) const BRANCH: &str = "└ ";
+/// Subagent arrow when the list is collapsed / expanded.
+pub(super) const ARROW_COLLAPSED: &str = "▶ ";
+pub(super) const ARROW_EXPANDED: &str = "▼ ";
+
+/// Display width of the status indicator ("● " or "─ ").
+const INDICATOR_WIDTH: usize = 2;
+/// Display width of the branch and the arrow (one glyph plus a space).
+const GLYPH_WIDTH: usize = 2;
+
+/// Interactive part of a sidebar row under the mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowRegion {
+    /// The subagent arrow and count badge: toggles the subagent list.
+    SubagentToggle,
+    /// Anywhere else on the row: selects the session.
+    Body,
+}
+
+/// Columns (relative to the sidebar's left edge) covered by the subagent
+/// arrow and its count badge on a parent session row, including the
+/// badge's trailing space. `None` when the row has no arrow.
+pub fn subagent_toggle_columns(subagent_count: usize, compact: bool) -> Option<Range<usize>> {
+    if subagent_count == 0 {
+        return None;
+    }
+    let start = if compact {
+        COMPACT_SESSION_INDENT.len() + INDICATOR_WIDTH
+    } else {
+        SESSION_INDENT.len() + GLYPH_WIDTH + INDICATOR_WIDTH
+    };
+    let badge_width = subagent_count.to_string().len() + 1;
+    Some(start..start + GLYPH_WIDTH + badge_width)
+}
+
+/// The region of a parent session row at `column`. Subagent rows have no
+/// arrow and are all body.
+pub fn session_row_region(subagent_count: usize, column: usize, compact: bool) -> RowRegion {
+    match subagent_toggle_columns(subagent_count, compact) {
+        Some(cols) if cols.contains(&column) => RowRegion::SubagentToggle,
+        _ => RowRegion::Body,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toggle_covers_arrow_and_badge() {
+        // "    └ ● ▶ 3 title": arrow at 8-9, badge "3 " at 10-11.
+        assert_eq!(subagent_toggle_columns(3, false), Some(8..12));
+        assert_eq!(session_row_region(3, 7, false), RowRegion::Body);
+        assert_eq!(session_row_region(3, 8, false), RowRegion::SubagentToggle);
+        assert_eq!(session_row_region(3, 11, false), RowRegion::SubagentToggle);
+        assert_eq!(session_row_region(3, 12, false), RowRegion::Body);
+
+        // Wider badge for double-digit counts.
+        assert_eq!(subagent_toggle_columns(12, false), Some(8..13));
+        assert_eq!(session_row_region(12, 12, false), RowRegion::SubagentToggle);
+        assert_eq!(session_row_region(12, 13, false), RowRegion::Body);
+    }
+
+    #[test]
+    fn test_row_without_subagents_is_all_body() {
+        assert_eq!(subagent_toggle_columns(0, false), None);
+        for column in 0..20 {
+            assert_eq!(session_row_region(0, column, false), RowRegion::Body);
+        }
+    }
+
+    #[test]
+    fn test_layout_matches_rendered_prefix() {
+        let parent = format!("{SESSION_INDENT}{BRANCH}● ");
+        assert_eq!(parent.chars().count(), 8);
+        // A subagent's branch sits under its parent's indicator.
+        let subagent = format!("{SUBAGENT_INDENT}{BRANCH}");
+        assert_eq!(subagent.chars().count(), 8);
+        assert_eq!(ARROW_COLLAPSED.chars().count(), GLYPH_WIDTH);
+        assert_eq!(ARROW_EXPANDED.chars().count(), GLYPH_WIDTH);
+        assert_eq!(BRANCH.chars().count(), GLYPH_WIDTH);
+    }
+
+    #[test]
+    fn test_compact_toggle_follows_indicator() {
+        // " ● ▶ 3 title": arrow at 3-4, badge "3 " at 5-6.
+        assert_eq!(subagent_toggle_columns(3, true), Some(3..7));
+        assert_eq!(session_row_region(3, 2, true), RowRegion::Body);
+        assert_eq!(session_row_region(3, 3, true), RowRegion::SubagentToggle);
+        assert_eq!(session_row_region(3, 7, true), RowRegion::Body);
+    }
+}
@@ -1,6 +1,8 @@
 mod build;
+mod hit_map;
 mod search_panel;
 
+pub use hit_map::{session_row_region, RowRegion};
 pub use search_panel::SessionSearchPanel;
 
 use std::cmp::min;
@@ -50,7 +52,13 @@ impl<'a> Sidebar<'a> {
 
 impl<'a> Widget for Sidebar<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let mut items = self.build_items();
+        let compact = self
+            .app
+            .config
+            .settings
+            .sidebar_density
+            .is_compact(area.width);
+        let mut items = self.build_items(area.width, compact);
 
         if let Some(idx) = self.app.confirm_delete {
             let name = self
@@ -60,14 +68,19 @@ impl<'a> Widget for Sidebar<'a> {
                 .map(|p| p.name.as_str())
                 .unwrap_or("?");
             items.push(ListItem::new(Line::from("")));
+            let prompt = if compact {
+                " Delete? y/N".to_string()
+            } else {
+                format!("  Delete \"{}\"? (y/N)", name)
+            };
             items.push(ListItem::new(Line::from(Span::styled(
-                format!("  Delete \"{}\"? (y/N)", name),
+                prompt,
                 Style::default().fg(self.app.theme.warning),
             ))));
         } else {
             items.push(ListItem::new(Line::from("")));
             items.push(ListItem::new(Line::from(Span::styled(
-                "  ? for shortcuts",
+                if compact { " ?" } else { "  ? for shortcuts" },
                 Style::default().fg(self.app.theme.text_muted),
             ))));
         }
@@ -75,13 +88,7 @@ impl<'a> Widget for Sidebar<'a> {
         // Scrolling follows the cursor (j/k position)
         let max_visible = area.height.saturating_sub(1) as usize;
         let total_items = items.len();
-        let sidebar_cursor = self.app.sidebar_cursor;
-
-        let scroll_offset = if sidebar_cursor >= max_visible {
-            sidebar_cursor - max_visible + 1
-        } else {
-            0
-        };
+        let scroll_offset = self.app.update_sidebar_scroll(max_visible, total_items);
 
         let end = min(scroll_offset + max_visible, total_items);
         let visible_items: Vec<ListItem> = items
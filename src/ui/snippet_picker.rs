@@ -0,0 +1,212 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+
+use crate::app::{App, SnippetPickerState};
+
+/// Labels that tell the snippet and command history pickers apart.
+struct PickerText {
+    title: &'static str,
+    empty: &'static str,
+    no_match: &'static str,
+    enter: &'static str,
+    /// Whether `^d` deletes the selected entry.
+    delete: bool,
+}
+
+/// Render the snippet picker overlay: a filter line above the project's
+/// command snippets.
+pub fn render_snippet_picker(app: &App, area: Rect, buf: &mut Buffer) {
+    let Some(state) = &app.snippet_picker else {
+        return;
+    };
+    let text = PickerText {
+        title: "Snippets",
+        empty: "No snippets yet — save the terminal's last command to add one",
+        no_match: "No matching snippets",
+        enter: " insert  ",
+        delete: true,
+    };
+    render_picker(app, state, &text, area, buf);
+}
+
+/// Render the command history picker: the project's recorded shell
+/// commands, newest first.
+pub fn render_history_picker(app: &App, area: Rect, buf: &mut Buffer) {
+    let Some(state) = &app.history_picker else {
+        return;
+    };
+    let text = PickerText {
+        title: "Command History",
+        empty: "No commands recorded for this project yet",
+        no_match: "No matching commands",
+        enter: " run  ",
+        delete: false,
+    };
+    render_picker(app, state, &text, area, buf);
+}
+
+fn render_picker(
+    app: &App,
+    state: &SnippetPickerState,
+    text: &PickerText,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let theme = &app.theme;
+
+    // title + input + separator + snippets + hints
+    let list_rows = state.snippets.len().clamp(1, 12) as u16;
+    let popup_width = 70u16.min(area.width.saturating_sub(2));
+    let popup_height = (list_rows + 5).min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    super::render_overlay_dim(area, buf);
+    Clear.render(popup_area, buf);
+
+    let block = Block::default().style(Style::default().bg(theme.background_panel));
+    let panel_inner = block.inner(popup_area);
+    block.render(popup_area, buf);
+
+    let inner = Rect {
+        x: panel_inner.x + 2,
+        y: panel_inner.y + 1,
+        width: panel_inner.width.saturating_sub(4),
+        height: panel_inner.height.saturating_sub(1),
+    };
+    if inner.height < 4 {
+        return;
+    }
+
+    let title_line = Line::from(vec![
+        Span::styled(
+            text.title,
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" ".repeat((inner.width as usize).saturating_sub(text.title.len() + 3))),
+        Span::styled("esc", Style::default().fg(theme.text_muted)),
+    ]);
+    buf.set_line(inner.x, inner.y, &title_line, inner.width);
+
+    // ── Filter line with block cursor ──────────────────────────────────
+    let input_y = inner.y + 1;
+    let prompt = "> ";
+    buf.set_string(
+        inner.x,
+        input_y,
+        prompt,
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD),
+    );
+    let query_x = inner.x + prompt.len() as u16;
+    buf.set_string(
+        query_x,
+        input_y,
+        &state.query,
+        Style::default().fg(theme.text),
+    );
+    let cursor_x = query_x + state.query[..state.cursor_pos].chars().count() as u16;
+    if cursor_x < inner.x + inner.width {
+        let cursor_char = state.query[state.cursor_pos..]
+            .chars()
+            .next()
+            .unwrap_or(' ');
+        buf.set_string(
+            cursor_x,
+            input_y,
+            cursor_char.to_string(),
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+
+    let separator_y = input_y + 1;
+    buf.set_string(
+        inner.x,
+        separator_y,
+        "─".repeat(inner.width as usize),
+        Style::default().fg(theme.border_subtle),
+    );
+
+    // ── Entries ────────────────────────────────────────────────────────
+    let hint_y = inner.y + inner.height.saturating_sub(1);
+    let list_y = separator_y + 1;
+    let visible = hint_y.saturating_sub(list_y) as usize;
+    if state.filtered.is_empty() {
+        let message = if state.snippets.is_empty() {
+            text.empty
+        } else {
+            text.no_match
+        };
+        buf.set_string(
+            inner.x + 2,
+            list_y,
+            crate::util::truncate_str(message, inner.width.saturating_sub(2) as usize),
+            Style::default().fg(theme.text_muted),
+        );
+    } else {
+        let start = (state.selected + 1).saturating_sub(visible);
+        for (row, (pos, &i)) in state
+            .filtered
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(visible)
+            .enumerate()
+        {
+            let style = if pos == state.selected {
+                Style::default()
+                    .bg(theme.primary)
+                    .fg(theme.background)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.text)
+            };
+            let y = list_y + row as u16;
+            buf.set_string(inner.x, y, " ".repeat(inner.width as usize), style);
+            buf.set_string(
+                inner.x + 1,
+                y,
+                crate::util::truncate_str(
+                    &state.snippets[i],
+                    inner.width.saturating_sub(2) as usize,
+                ),
+                style,
+            );
+        }
+    }
+
+    let mut hints = vec![
+        Span::styled("  ⏎", Style::default().fg(theme.accent)),
+        Span::styled(text.enter, Style::default().fg(theme.text_muted)),
+        Span::styled("↑↓", Style::default().fg(theme.accent)),
+        Span::styled(" navigate  ", Style::default().fg(theme.text_muted)),
+    ];
+    if text.delete {
+        hints.push(Span::styled("^d", Style::default().fg(theme.accent)));
+        hints.push(Span::styled(
+            " delete",
+            Style::default().fg(theme.text_muted),
+        ));
+    }
+    let hints = Line::from(hints);
+    Paragraph::new(hints).render(
+        Rect {
+            x: inner.x,
+            y: hint_y,
+            width: inner.width,
+            height: 1,
+        },
+        buf,
+    );
+}
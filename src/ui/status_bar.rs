@@ -4,14 +4,14 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Paragraph, Widget};
 
-use crate::app::{App, ServerStatus};
+use crate::app::{App, ServerStatus, ToastSeverity};
 use crate::ui::layout_manager::PanelId;
 use crate::ui::sidebar::lerp_color;
 use crate::vim_mode::VimMode;
 
 /// Status bar widget displayed at the bottom of the screen.
 ///
-/// Shows: current project name, git branch, server status, focus mode.
+/// Shows: current project name, git branch, server status, model, focus mode.
 pub struct StatusBar<'a> {
     app: &'a App,
 }
@@ -48,10 +48,13 @@ impl<'a> Widget for StatusBar<'a> {
                 .bg(self.app.theme.warning)
                 .add_modifier(Modifier::BOLD),
         };
-        spans.push(Span::styled(
-            format!(" {} ", self.app.vim_mode.label()),
-            mode_style,
-        ));
+        let mode_label =
+            if self.app.vim_mode == VimMode::Resize && !self.app.resize_count.is_empty() {
+                format!(" {} {} ", self.app.vim_mode.label(), self.app.resize_count)
+            } else {
+                format!(" {} ", self.app.vim_mode.label())
+            };
+        spans.push(Span::styled(mode_label, mode_style));
 
         // Project name
         if let Some(project) = self.app.active_project() {
@@ -111,6 +114,17 @@ impl<'a> Widget for StatusBar<'a> {
                 }
             }
 
+            // Provider/model (the server default; a model picked in opman
+            // only goes with opman's own prompts)
+            if let Some(model) = project.model.as_deref() {
+                let shown = crate::util::truncate_str(model, 32);
+                let ellipsis = if shown.len() < model.len() { "…" } else { "" };
+                spans.push(Span::styled(
+                    format!("  {}{}", shown, ellipsis),
+                    Style::default().fg(self.app.theme.secondary),
+                ));
+            }
+
             // Token usage and context window — always shown when stats exist
             if let Some(session_id) = &project.active_session {
                 if let Some(stats) = self.app.session_stats.get(session_id) {
@@ -127,7 +141,7 @@ impl<'a> Widget for StatusBar<'a> {
                     let context_window = self
                         .app
                         .model_limits
-                        .get(&self.app.active_project)
+                        .get(&project.path)
                         .map(|ml| ml.context_window)
                         .unwrap_or(0);
 
@@ -183,6 +197,45 @@ impl<'a> Widget for StatusBar<'a> {
             ));
         }
 
+        // Tool call the active session is running
+        let activity = self
+            .app
+            .active_project()
+            .and_then(|p| p.active_session.as_ref())
+            .and_then(|sid| self.app.tool_activity.get(sid));
+        if let Some((_, label)) = activity {
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format!("⚙ {}", crate::util::truncate_str(label, 48)),
+                Style::default().fg(self.app.theme.accent),
+            ));
+        }
+
+        // LSP progress from the active session's neovim
+        if let Some((ref sid, ref progress)) = self.app.lsp_progress {
+            let is_active = self
+                .app
+                .active_project()
+                .and_then(|p| p.active_session.as_deref())
+                == Some(sid.as_str());
+            if is_active {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled(
+                    format!("⟳ {}…", crate::util::truncate_str(progress, 48)),
+                    Style::default().fg(self.app.theme.info),
+                ));
+            }
+        }
+
+        // MCP servers paused at runtime
+        if self.app.mcp_flags.any() && !self.app.mcp_enabled {
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                "MCP off",
+                Style::default().fg(self.app.theme.warning),
+            ));
+        }
+
         // Slack connection status indicator
         if let Some(ref slack_state_arc) = self.app.slack_state {
             if let Ok(state) = slack_state_arc.try_lock() {
@@ -207,14 +260,47 @@ impl<'a> Widget for StatusBar<'a> {
                 ));
             }
         }
-        if let Some((ref msg, ts)) = self.app.toast_message {
-            if ts.elapsed() < std::time::Duration::from_secs(2) {
-                spans.push(Span::raw("  "));
+        if let Some(name) = self
+            .app
+            .confirm_respawn
+            .and_then(|idx| self.app.projects.get(idx))
+            .map(|p| p.name.as_str())
+        {
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format!("Respawn all terminals for \"{name}\"? (y/N)"),
+                Style::default()
+                    .fg(self.app.theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        } else if let Some(ref command) = self.app.failed_command_prompt {
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format!(
+                    "Failed: {} (r rerun, y copy with output)",
+                    crate::util::truncate_str(command, 40)
+                ),
+                Style::default()
+                    .fg(self.app.theme.error)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        } else if let Some(toast) = self.app.toasts.current() {
+            let color = match toast.severity {
+                ToastSeverity::Info => self.app.theme.accent,
+                ToastSeverity::Success => self.app.theme.success,
+                ToastSeverity::Warning => self.app.theme.warning,
+                ToastSeverity::Error => self.app.theme.error,
+            };
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                &toast.text,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+            let pending = self.app.toasts.pending();
+            if pending > 0 {
                 spans.push(Span::styled(
-                    msg,
-                    Style::default()
-                        .fg(self.app.theme.accent)
-                        .add_modifier(Modifier::BOLD),
+                    format!(" (+{pending} more)"),
+                    Style::default().fg(self.app.theme.text_muted),
                 ));
             }
         }
@@ -14,12 +14,22 @@
 //!    `String` via `vt100::Cell::contents()`.
 //!    NOTE: vt100::Cell::contents() returns String::with_capacity(24) each
 //!    time.  For the common case of single-char cells we avoid this entirely.
+//! 5. **Per-row cache** – [`render_screen_cached`] remembers the vt100 cells
+//!    each row was converted from and reuses a previous row whose cells are
+//!    unchanged wherever it now sits, so output that scrolls the screen only
+//!    pays for converting the rows that are new.
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
 
+use std::sync::atomic::Ordering;
+
+use crate::app::App;
+use crate::config::CursorShape;
+use crate::pty::PtyInstance;
 use crate::theme::ThemeColors;
+use crate::ui::layout_manager::PanelId;
 
 /// Render a `vt100::Screen` into a ratatui `Buffer`, with theme-aware ANSI
 /// palette remapping baked into the same pass.
@@ -32,6 +42,9 @@ use crate::theme::ThemeColors;
 /// ```
 ///
 /// `palette` should be the 16-entry array from `ansi_palette_from_theme()`.
+/// Panes use [`render_screen_cached`]; this uncached form is the baseline
+/// for the benchmark below.
+#[allow(dead_code)]
 pub fn render_screen(
     screen: &vt100::Screen,
     area: Rect,
@@ -49,17 +62,22 @@ pub fn render_screen(
 
             if let Some(screen_cell) = screen.cell(row, col) {
                 let cell = &mut buf[(buf_x, buf_y)];
-                fill_cell_optimized(screen_cell, cell, palette, theme);
+                let clip = clips_wide_glyph(screen_cell, col, cols);
+                fill_cell_optimized(screen_cell, cell, palette, theme, clip);
             }
         }
     }
 
-    // Cursor rendering (matches tui-term default Cursor behaviour)
+    render_cell_cursor(screen, area, buf);
+}
+
+/// Cursor rendering (matches tui-term default Cursor behaviour).
+pub fn render_cell_cursor(screen: &vt100::Screen, area: Rect, buf: &mut Buffer) {
     if !screen.hide_cursor() {
         let (c_row, c_col) = screen.cursor_position();
         let cx = c_col + area.x;
         let cy = c_row + area.y;
-        if cy < area.y + rows && cx < area.x + cols {
+        if cy < area.y + area.height && cx < area.x + area.width {
             let c_cell = &mut buf[(cx, cy)];
             if let Some(cell) = screen.cell(c_row, c_col) {
                 if cell.has_contents() {
@@ -75,6 +93,173 @@ pub fn render_screen(
     }
 }
 
+/// Converted rows from the previous frame of one PTY, keyed by the vt100
+/// cells they were built from.
+#[derive(Default)]
+pub struct RenderCache {
+    /// Area size and colors the cached rows were rendered with.
+    key: Option<(u16, u16, [Color; 16], Color, Color)>,
+    rows: Vec<Option<CachedRow>>,
+}
+
+struct CachedRow {
+    src: Vec<vt100::Cell>,
+    out: Vec<ratatui::buffer::Cell>,
+}
+
+/// [`render_screen`] with a [`RenderCache`]: a row whose vt100 cells match a
+/// row of the last frame, at any position, is copied instead of
+/// re-converted. The search starts just past the previous row's match, so a
+/// scroll finds its rows in one step. The cache resets itself when the area
+/// size, palette or theme changes.
+///
+/// The cursor is left to [`render_pane_cursor`].
+pub fn render_screen_cached(
+    screen: &vt100::Screen,
+    area: Rect,
+    buf: &mut Buffer,
+    palette: &[Color; 16],
+    theme: &ThemeColors,
+    cache: &mut RenderCache,
+) {
+    let rows = area.height;
+    let cols = area.width;
+    let key = (rows, cols, *palette, theme.text, theme.background);
+    if cache.key != Some(key) {
+        cache.key = Some(key);
+        cache.rows.clear();
+    }
+
+    let mut previous = std::mem::take(&mut cache.rows);
+    let mut next_guess = 0;
+    for row in 0..rows {
+        let src: Vec<&vt100::Cell> = (0..cols).map_while(|col| screen.cell(row, col)).collect();
+        let found = (0..previous.len())
+            .map(|i| (next_guess + i) % previous.len())
+            .find(|&i| {
+                previous[i]
+                    .as_ref()
+                    .is_some_and(|cached| cached.src.iter().eq(src.iter().copied()))
+            });
+        if let Some(i) = found {
+            next_guess = i + 1;
+        }
+        let y = row + area.y;
+        let cached = match found.and_then(|i| previous[i].take()) {
+            Some(cached) => {
+                for (col, cell) in cached.out.iter().enumerate() {
+                    buf[(area.x + col as u16, y)] = cell.clone();
+                }
+                cached
+            }
+            None => {
+                let mut out = Vec::with_capacity(src.len());
+                for (col, screen_cell) in src.iter().enumerate() {
+                    let col = col as u16;
+                    let dst = &mut buf[(col + area.x, y)];
+                    let clip = clips_wide_glyph(screen_cell, col, cols);
+                    fill_cell_optimized(screen_cell, dst, palette, theme, clip);
+                    out.push(dst.clone());
+                }
+                CachedRow {
+                    src: src.into_iter().cloned().collect(),
+                    out,
+                }
+            }
+        };
+        cache.rows.push(Some(cached));
+    }
+}
+
+/// Draw a pane's cursor. The focused pane uses the host terminal's real
+/// cursor (recorded in `app.host_cursor`, placed by `ui::draw`) so the
+/// program's DECSCUSR shape and blink show; other panes get a cell cursor.
+pub fn render_pane_cursor(
+    app: &App,
+    panel: PanelId,
+    pty: &PtyInstance,
+    screen: &vt100::Screen,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    if app.layout.focused != panel {
+        render_cell_cursor(screen, area, buf);
+        return;
+    }
+    if screen.hide_cursor() {
+        return;
+    }
+    let (row, col) = screen.cursor_position();
+    if row >= area.height || col >= area.width {
+        return;
+    }
+    let requested = pty.cursor_style.load(Ordering::Acquire);
+    let settings = &app.config.settings;
+    let style = cursor_style(requested, settings.cursor_shape, settings.cursor_blink);
+    app.host_cursor
+        .set(Some((area.x + col, area.y + row, style)));
+}
+
+/// Underline cells covered by an OSC 8 hyperlink. Skipped while scrolled
+/// back, since link spans track the live screen.
+pub fn render_hyperlinks(
+    pty: &PtyInstance,
+    screen: &vt100::Screen,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    if screen.scrollback() > 0 {
+        return;
+    }
+    let Ok(mut links) = pty.hyperlinks.lock() else {
+        return;
+    };
+    links.relocate(screen);
+    let style = Style::default().add_modifier(Modifier::UNDERLINED);
+    for span in links.spans().filter(|s| s.row < area.height) {
+        for col in span.start_col..span.end_col.min(area.width) {
+            buf[(area.x + col, area.y + span.row)].set_style(style);
+        }
+    }
+}
+
+/// Combine the DECSCUSR `Ps` a program requested with the user's cursor
+/// settings into the `Ps` to apply (0 = the terminal's default cursor).
+pub fn cursor_style(requested: u8, shape: CursorShape, blink: bool) -> u8 {
+    if shape == CursorShape::Auto && requested == 0 && blink {
+        return 0;
+    }
+    // Blinking variants are odd: 1 block, 3 underline, 5 bar.
+    let base = match shape {
+        CursorShape::Auto => match requested {
+            3 | 4 => 3,
+            5 | 6 => 5,
+            _ => 1,
+        },
+        CursorShape::Block => 1,
+        CursorShape::Underline => 3,
+        CursorShape::Bar => 5,
+    };
+    let wants_blink = requested == 0 || requested % 2 == 1;
+    if blink && wants_blink {
+        base
+    } else {
+        base + 1
+    }
+}
+
+/// A double-width glyph in the last column would spill into whatever is
+/// drawn to the right of the pane (this happens while a resize has not yet
+/// reached the PTY), so it is drawn as a blank instead.
+///
+/// Wide glyphs elsewhere need no special casing: vt100 leaves the trailing
+/// column as an empty continuation cell, which is never given a symbol, and
+/// ratatui skips the cell after a wide symbol when flushing.
+#[inline(always)]
+fn clips_wide_glyph(cell: &vt100::Cell, col: u16, cols: u16) -> bool {
+    cell.is_wide() && col + 1 >= cols
+}
+
 /// Convert a single vt100 cell into a ratatui buffer cell, with inline
 /// ANSI palette remapping.  This is the hot inner loop.
 #[inline(always)]
@@ -83,13 +268,17 @@ fn fill_cell_optimized(
     dst: &mut ratatui::buffer::Cell,
     palette: &[Color; 16],
     theme: &ThemeColors,
+    clip_wide: bool,
 ) {
     // --- Symbol ---
     // Fast path: inspect the cell contents without allocating.
     // vt100::Cell::contents() allocates String::with_capacity(24).
     // We use has_contents() to skip empty cells entirely, and for
     // non-empty cells we must call contents() since the char array is private.
-    if src.has_contents() {
+    if clip_wide {
+        dst.set_char(' ');
+    } else if src.has_contents() {
+        // Combining marks arrive in the same cell as their base character.
         let s = src.contents();
         // Fast path: single-byte ASCII (covers ~95% of terminal content)
         let bytes = s.as_bytes();
@@ -155,3 +344,144 @@ fn convert_color_remapped(
         vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_render_matches_uncached() {
+        let theme = ThemeColors::default();
+        let palette = crate::theme::ansi_palette_from_theme(&theme);
+        let area = Rect::new(0, 0, 40, 10);
+        let mut parser = vt100::Parser::new(10, 40, 0);
+        let mut cache = RenderCache::default();
+        for i in 0..25 {
+            parser.process(format!("line {i}\r\n").as_bytes());
+            let mut plain = Buffer::empty(area);
+            let mut cached = Buffer::empty(area);
+            render_screen(parser.screen(), area, &mut plain, &palette, &theme);
+            let screen = parser.screen();
+            render_screen_cached(screen, area, &mut cached, &palette, &theme, &mut cache);
+            render_cell_cursor(screen, area, &mut cached);
+            assert_eq!(plain, cached, "frame {i}");
+        }
+    }
+
+    #[test]
+    fn test_cached_render_matches_uncached_after_redraw() {
+        let theme = ThemeColors::default();
+        let palette = crate::theme::ansi_palette_from_theme(&theme);
+        let area = Rect::new(0, 0, 20, 4);
+        let mut parser = vt100::Parser::new(4, 20, 0);
+        let mut cache = RenderCache::default();
+        // Scroll, repeat a row, then redraw the screen in a different order.
+        let steps: [&[u8]; 4] = [
+            b"a\r\nb\r\nc",
+            b"\r\n\x1b[1mb\x1b[0m\r\nb",
+            b"\x1b[2J\x1b[Hc\r\na\r\nb",
+            b"\x1b[1;1H\x1b[2Kb",
+        ];
+        for (i, step) in steps.iter().enumerate() {
+            parser.process(step);
+            let mut plain = Buffer::empty(area);
+            let mut cached = Buffer::empty(area);
+            render_screen(parser.screen(), area, &mut plain, &palette, &theme);
+            let screen = parser.screen();
+            render_screen_cached(screen, area, &mut cached, &palette, &theme, &mut cache);
+            render_cell_cursor(screen, area, &mut cached);
+            assert_eq!(plain, cached, "step {i}");
+        }
+    }
+
+    /// Render `frames` frames of a 200x60 pane, each after one more line of
+    /// output, without and with the cache.
+    fn bench_stream(line: impl Fn(usize) -> String) {
+        let theme = ThemeColors::default();
+        let palette = crate::theme::ansi_palette_from_theme(&theme);
+        let area = Rect::new(0, 0, 200, 60);
+        let frames = 2000;
+
+        let mut parser = vt100::Parser::new(60, 200, 0);
+        let start = std::time::Instant::now();
+        for i in 0..frames {
+            parser.process(line(i).as_bytes());
+            let mut buf = Buffer::empty(area);
+            render_screen(parser.screen(), area, &mut buf, &palette, &theme);
+        }
+        let uncached = start.elapsed();
+
+        let mut parser = vt100::Parser::new(60, 200, 0);
+        let mut cache = RenderCache::default();
+        let start = std::time::Instant::now();
+        for i in 0..frames {
+            parser.process(line(i).as_bytes());
+            let mut buf = Buffer::empty(area);
+            let screen = parser.screen();
+            render_screen_cached(screen, area, &mut buf, &palette, &theme, &mut cache);
+        }
+        let cached = start.elapsed();
+
+        println!("{frames} frames: uncached {uncached:?}, cached {cached:?}");
+    }
+
+    /// `cargo test --release -- --ignored --nocapture bench_yes_stream`
+    #[test]
+    #[ignore]
+    fn bench_yes_stream() {
+        bench_stream(|_| "y\r\n".into());
+    }
+
+    /// Distinct lines, so every row changes as the screen scrolls:
+    /// `cargo test --release -- --ignored --nocapture bench_seq_stream`
+    #[test]
+    #[ignore]
+    fn bench_seq_stream() {
+        bench_stream(|i| format!("{i}\r\n"));
+    }
+
+    #[test]
+    fn test_cursor_style_settings() {
+        // Program asked for a blinking bar.
+        assert_eq!(cursor_style(5, CursorShape::Auto, true), 5);
+        assert_eq!(cursor_style(5, CursorShape::Auto, false), 6);
+        assert_eq!(cursor_style(5, CursorShape::Block, true), 1);
+        // Nothing requested: terminal default unless overridden.
+        assert_eq!(cursor_style(0, CursorShape::Auto, true), 0);
+        assert_eq!(cursor_style(0, CursorShape::Underline, true), 3);
+        assert_eq!(cursor_style(2, CursorShape::Bar, true), 6);
+    }
+
+    fn render_text(text: &str, parser_cols: u16, area_cols: u16) -> Buffer {
+        let theme = ThemeColors::default();
+        let palette = crate::theme::ansi_palette_from_theme(&theme);
+        let mut parser = vt100::Parser::new(2, parser_cols, 0);
+        parser.process(text.as_bytes());
+        let area = Rect::new(0, 0, area_cols, 2);
+        let mut buf = Buffer::empty(area);
+        render_screen(parser.screen(), area, &mut buf, &palette, &theme);
+        buf
+    }
+
+    #[test]
+    fn test_wide_glyphs_keep_columns_aligned() {
+        let buf = render_text("中文ab😀c", 20, 20);
+        let symbols: Vec<&str> = (0..9).map(|x| buf[(x, 0)].symbol()).collect();
+        assert_eq!(symbols, ["中", " ", "文", " ", "a", "b", "😀", " ", "c"]);
+    }
+
+    #[test]
+    fn test_combining_marks_share_a_cell() {
+        let buf = render_text("e\u{301}x", 10, 10);
+        assert_eq!(buf[(0, 0)].symbol(), "e\u{301}");
+        assert_eq!(buf[(1, 0)].symbol(), "x");
+    }
+
+    #[test]
+    fn test_wide_glyph_in_last_column_is_clipped() {
+        // The PTY is still 6 wide but the pane already shrank to 5.
+        let buf = render_text("abcd中", 6, 5);
+        assert_eq!(buf[(3, 0)].symbol(), "d");
+        assert_eq!(buf[(4, 0)].symbol(), " ");
+    }
+}
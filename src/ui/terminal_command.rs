@@ -0,0 +1,128 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Paragraph, Widget};
+
+use crate::app::App;
+
+/// Render the terminal command prompt: the program line, what an empty
+/// line falls back to, and the busy-tab confirmation.
+pub fn render_terminal_command(app: &App, area: Rect, buf: &mut Buffer) {
+    let Some(state) = &app.terminal_command else {
+        return;
+    };
+    let theme = &app.theme;
+    let project = app
+        .projects
+        .get(state.project_idx)
+        .map_or("", |p| p.name.as_str());
+
+    // title + input + note + hints
+    let popup_width = 60u16.min(area.width.saturating_sub(2));
+    let popup_height = 6u16.min(area.height.saturating_sub(2));
+    let popup_area = Rect {
+        x: area.x + (area.width.saturating_sub(popup_width)) / 2,
+        y: area.y + (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    super::render_overlay_dim(area, buf);
+    Clear.render(popup_area, buf);
+
+    let block = Block::default().style(Style::default().bg(theme.background_panel));
+    let panel_inner = block.inner(popup_area);
+    block.render(popup_area, buf);
+
+    let inner = Rect {
+        x: panel_inner.x + 2,
+        y: panel_inner.y + 1,
+        width: panel_inner.width.saturating_sub(4),
+        height: panel_inner.height.saturating_sub(1),
+    };
+    if inner.height < 4 {
+        return;
+    }
+
+    let title = format!("Terminal Command · {project}");
+    let title = crate::util::truncate_str(&title, (inner.width as usize).saturating_sub(4));
+    let title_line = Line::from(vec![
+        Span::styled(
+            title.to_string(),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" ".repeat((inner.width as usize).saturating_sub(title.chars().count() + 3))),
+        Span::styled("esc", Style::default().fg(theme.text_muted)),
+    ]);
+    buf.set_line(inner.x, inner.y, &title_line, inner.width);
+
+    // ── Program line with block cursor ─────────────────────────────────
+    let input_y = inner.y + 1;
+    let prompt = "$ ";
+    buf.set_string(
+        inner.x,
+        input_y,
+        prompt,
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD),
+    );
+    let query_x = inner.x + prompt.len() as u16;
+    buf.set_string(
+        query_x,
+        input_y,
+        &state.input,
+        Style::default().fg(theme.text),
+    );
+    let cursor_x = query_x + state.input[..state.cursor_pos].chars().count() as u16;
+    if cursor_x < inner.x + inner.width {
+        let cursor_char = state.input[state.cursor_pos..]
+            .chars()
+            .next()
+            .unwrap_or(' ');
+        buf.set_string(
+            cursor_x,
+            input_y,
+            cursor_char.to_string(),
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+
+    // ── Note: busy confirmation or the empty-line fallback ─────────────
+    let busy = app.busy_shell_tabs(state.project_idx);
+    let note = if state.confirm_busy {
+        Span::styled(
+            format!("{busy} tab(s) still running a command — ⏎ again to restart them"),
+            Style::default().fg(theme.warning),
+        )
+    } else {
+        Span::styled(
+            "empty = default shell · existing tabs restart",
+            Style::default().fg(theme.text_muted),
+        )
+    };
+    buf.set_line(inner.x, input_y + 1, &Line::from(note), inner.width);
+
+    let hints = Line::from(vec![
+        Span::styled("  ⏎", Style::default().fg(theme.accent)),
+        Span::styled(
+            " save & restart tabs  ",
+            Style::default().fg(theme.text_muted),
+        ),
+        Span::styled("esc", Style::default().fg(theme.accent)),
+        Span::styled(" cancel", Style::default().fg(theme.text_muted)),
+    ]);
+    Paragraph::new(hints).render(
+        Rect {
+            x: inner.x,
+            y: inner.y + inner.height.saturating_sub(1),
+            width: inner.width,
+            height: 1,
+        },
+        buf,
+    );
+}
@@ -1,9 +1,9 @@
+mod splash;
 mod watcher_overlay;
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
-use ratatui::widgets::{Paragraph, Widget};
+use ratatui::widgets::Widget;
 
 use crate::app::App;
 use crate::theme::ansi_palette_from_theme;
@@ -58,7 +58,23 @@ impl<'a> Widget for TerminalPane<'a> {
                     };
                     let palette = ansi_palette_from_theme(&self.app.theme);
                     let screen = parser.screen();
-                    term_render::render_screen(screen, pty_area, buf, &palette, &self.app.theme);
+                    term_render::render_screen_cached(
+                        screen,
+                        pty_area,
+                        buf,
+                        &palette,
+                        &self.app.theme,
+                        &mut pty.render_cache.borrow_mut(),
+                    );
+                    term_render::render_hyperlinks(pty, screen, pty_area, buf);
+                    term_render::render_pane_cursor(
+                        self.app,
+                        crate::ui::layout_manager::PanelId::TerminalPane,
+                        pty,
+                        screen,
+                        pty_area,
+                        buf,
+                    );
                 }
 
                 // Render selection highlight
@@ -102,11 +118,7 @@ impl<'a> Widget for TerminalPane<'a> {
             }
         }
 
-        let placeholder =
-            Paragraph::new("No active terminal. Select a project and press Enter to connect.")
-                .style(Style::default().fg(self.app.theme.text_muted));
-
-        Widget::render(placeholder, area, buf);
+        self.render_splash(area, buf);
     }
 }
 
@@ -0,0 +1,63 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Paragraph, Widget};
+
+use crate::command_palette::leader_nested_hint;
+use crate::config::format_key_display;
+
+use super::TerminalPane;
+
+impl<'a> TerminalPane<'a> {
+    /// Version and a few starting keybinds, shown while no terminal is
+    /// attached (startup, before the first project's PTY arrives).
+    pub(super) fn render_splash(&self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.app.theme;
+        let keys = &self.app.config.keybindings;
+        let hints = [
+            (format_key_display(&keys.leader), "Leader"),
+            (
+                leader_nested_hint(keys, &keys.leader_project, &keys.project_add),
+                "Add project",
+            ),
+            (format_key_display(&keys.command_mode), "Command palette"),
+            (format_key_display(&keys.cheatsheet_alt), "Cheatsheet"),
+            (format_key_display("enter"), "Open the selected project"),
+        ];
+        let key_width = hints.iter().map(|(k, _)| k.len()).max().unwrap_or(0);
+        let desc_width = hints.iter().map(|(_, d)| d.len()).max().unwrap_or(0);
+
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("opman v{}", env!("CARGO_PKG_VERSION")),
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::default(),
+        ];
+        for (key, desc) in &hints {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{key:>key_width$}"),
+                    Style::default().fg(theme.accent),
+                ),
+                Span::styled(
+                    format!("  {desc:<desc_width$}"),
+                    Style::default().fg(theme.text_muted),
+                ),
+            ]));
+        }
+
+        let height = (lines.len() as u16).min(area.height);
+        let splash_area = Rect {
+            y: area.y + (area.height - height) / 2,
+            height,
+            ..area
+        };
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .render(splash_area, buf);
+    }
+}
@@ -75,12 +75,16 @@ pub(super) fn render_todo_list(
             Style::default().fg(status_color).bg(bg),
         );
 
-        // Priority indicator
-        let pcolor = priority_color(&todo.priority, is_selected, theme);
+        // Priority indicator (the edit's, until it is confirmed)
+        let priority = match state.editing {
+            Some(ref editing) if is_editing => &editing.priority,
+            _ => &todo.priority,
+        };
+        let pcolor = priority_color(priority, is_selected, theme);
         buf.set_string(
             x + 3,
             row,
-            priority_arrow(&todo.priority),
+            priority_arrow(priority),
             Style::default().fg(pcolor).bg(bg),
         );
 
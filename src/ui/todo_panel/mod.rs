@@ -175,7 +175,7 @@ fn render_hint_line(
     theme: &ThemeColors,
 ) {
     let hints = if state.editing.is_some() {
-        vec![("Enter", "confirm"), ("Esc", "cancel")]
+        vec![("Enter", "confirm"), ("Tab", "priority"), ("Esc", "cancel")]
     } else {
         vec![
             ("Space", "toggle"),
@@ -184,6 +184,7 @@ fn render_hint_line(
             ("d", "delete"),
             ("p", "priority"),
             ("S+K/J", "reorder"),
+            ("o", "open file"),
             ("q", "close"),
         ]
     };
@@ -244,16 +245,18 @@ pub(super) fn priority_arrow(priority: &str) -> &'static str {
     }
 }
 
-pub(super) fn priority_color(priority: &str, is_selected: bool, _theme: &ThemeColors) -> Color {
+/// High = error, medium = warning, low = muted. On the selected row, whose
+/// background is `primary`, low and unknown priorities use the background
+/// colour like the pending status icon does.
+pub(super) fn priority_color(priority: &str, is_selected: bool, theme: &ThemeColors) -> Color {
     match priority {
-        "high" => Color::Red,
-        "medium" => Color::Yellow,
-        "low" => Color::Green,
+        "high" => theme.error,
+        "medium" => theme.warning,
         _ => {
             if is_selected {
-                Color::White
+                theme.background
             } else {
-                Color::DarkGray
+                theme.text_muted
             }
         }
     }
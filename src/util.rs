@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 /// Truncate a string to at most `max_bytes` bytes, ensuring the cut falls on a
 /// UTF-8 char boundary.  Equivalent to the nightly `str::floor_char_boundary`.
 #[inline]
@@ -33,3 +35,126 @@ pub fn split_at_safe(s: &str, idx: usize) -> (&str, &str) {
     let i = floor_char_boundary(s, idx);
     (&s[..i], &s[i..])
 }
+
+/// Byte index of the char before `pos` in `s`; 0 at the start. For
+/// moving a text input's cursor left.
+pub fn prev_char_boundary(s: &str, pos: usize) -> usize {
+    s[..pos]
+        .char_indices()
+        .next_back()
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Byte index of the char after `pos` in `s`; `s.len()` at the end.
+pub fn next_char_boundary(s: &str, pos: usize) -> usize {
+    s[pos..]
+        .char_indices()
+        .nth(1)
+        .map(|(i, _)| pos + i)
+        .unwrap_or(s.len())
+}
+
+/// Delete the char before `*cursor` (backspace), moving the cursor back.
+/// Returns whether anything was deleted.
+pub fn delete_char_before(s: &mut String, cursor: &mut usize) -> bool {
+    if *cursor == 0 {
+        return false;
+    }
+    let prev = prev_char_boundary(s, *cursor);
+    s.replace_range(prev..*cursor, "");
+    *cursor = prev;
+    true
+}
+
+/// Format a count with comma thousands separators, e.g. `12,345`.
+pub fn group_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// The executable `program` resolves to, like `which`: a path is checked
+/// as is, a bare name is looked up on `PATH`.
+pub fn which(program: &str) -> Option<PathBuf> {
+    if program.contains('/') {
+        let path = PathBuf::from(program);
+        return is_executable(&path).then_some(path);
+    }
+    let paths = std::env::var_os("PATH")?;
+    std::env::split_paths(&paths)
+        .map(|dir| dir.join(program))
+        .find(|path| is_executable(path))
+}
+
+fn is_executable(path: &Path) -> bool {
+    let Ok(meta) = std::fs::metadata(path) else {
+        return false;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        meta.is_file() && meta.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        meta.is_file()
+    }
+}
+
+/// `path` as a POSIX shell word: single-quoted unless every character is
+/// one the shell takes literally.
+pub fn shell_escape(path: &Path) -> String {
+    let s = path.to_string_lossy();
+    let plain = |c: char| c.is_ascii_alphanumeric() || "/._-+,:@%=".contains(c);
+    if !s.is_empty() && s.chars().all(plain) {
+        s.to_string()
+    } else {
+        format!("'{}'", s.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_which() {
+        assert_eq!(which("/bin/sh"), Some(PathBuf::from("/bin/sh")));
+        assert!(which("sh").is_some_and(|p| p.ends_with("sh")));
+        assert_eq!(which("/bin/definitely-not-a-shell"), None);
+        assert_eq!(which("definitely-not-a-shell"), None);
+        // Directories aren't programs.
+        assert_eq!(which("/bin"), None);
+    }
+
+    #[test]
+    fn test_text_cursor_helpers() {
+        let mut s = "aé€".to_string();
+        assert_eq!(prev_char_boundary(&s, 0), 0);
+        assert_eq!(prev_char_boundary(&s, 3), 1);
+        assert_eq!(next_char_boundary(&s, 1), 3);
+        assert_eq!(next_char_boundary(&s, s.len()), s.len());
+
+        let mut cursor = s.len();
+        assert!(delete_char_before(&mut s, &mut cursor));
+        assert_eq!((s.as_str(), cursor), ("aé", 3));
+        cursor = 0;
+        assert!(!delete_char_before(&mut s, &mut cursor));
+        assert_eq!(s, "aé");
+    }
+
+    #[test]
+    fn test_shell_escape() {
+        assert_eq!(shell_escape(Path::new("/src/app")), "/src/app");
+        assert_eq!(shell_escape(Path::new("/my src")), "'/my src'");
+        assert_eq!(shell_escape(Path::new("/src/$HOME")), "'/src/$HOME'");
+        assert_eq!(shell_escape(Path::new("/src/it's")), r"'/src/it'\''s'");
+    }
+}
@@ -21,10 +21,10 @@ pub(super) async fn resolve_editor_nvim_socket(
     state: &ServerState,
     session_id: &str,
 ) -> WebResult<PathBuf> {
-    let project_idx = state.web_state.active_project_index().await;
+    let project_path = state.web_state.get_working_dir().await.unwrap_or_default();
     let registry = state.nvim_registry.read().await;
     registry
-        .get(&(project_idx, session_id.to_string()))
+        .get(&(project_path, session_id.to_string()))
         .cloned()
         .ok_or_else(|| WebError::BadRequest("No Neovim/LSP backend active for this session. Open a Neovim session first.".into()))
 }
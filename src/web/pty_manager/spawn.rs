@@ -19,6 +19,7 @@ pub(crate) struct WebPty {
 }
 
 /// Background reader that captures raw bytes into the output buffer.
+/// Interrupted and `WouldBlock` reads are handled as in the TUI reader.
 fn read_raw_pty_output(mut reader: Box<dyn Read + Send>, output: RawOutputBuffer) {
     let mut buf = [0u8; 8192];
     loop {
@@ -27,6 +28,10 @@ fn read_raw_pty_output(mut reader: Box<dyn Read + Send>, output: RawOutputBuffer
             Ok(n) => {
                 output.push(&buf[..n]);
             }
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(crate::pty::IDLE_BACKOFF);
+            }
             Err(_) => break,
         }
     }
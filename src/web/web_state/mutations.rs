@@ -92,7 +92,7 @@ impl super::WebStateHandle {
             config.projects.push(ProjectEntry {
                 name: project_name.clone(),
                 path: canonical.to_string_lossy().to_string(),
-                terminal_command: None,
+                ..Default::default()
             });
             if let Err(e) = config.save() {
                 warn!("Failed to save config after adding project: {e}");
@@ -254,7 +254,7 @@ impl super::WebStateHandle {
             }
             full_msg.push_str(&msg);
             if let Err(e) = api
-                .send_system_message_async(&base_url, &project_dir, &sid, &full_msg)
+                .send_system_message_async(&base_url, &project_dir, &sid, &full_msg, None)
                 .await
             {
                 warn!("Watcher failed to send message to {}: {}", sid, e);
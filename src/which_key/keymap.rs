@@ -207,6 +207,12 @@ pub fn build_keymap(kb: &KeyBindings) -> Vec<RuntimeKeyBinding> {
             NORMAL_MODES,
             "Sidebar",
         ),
+        rk_display(
+            KeyCombo::new(KeyModifiers::NONE, KeyCode::Char('p')),
+            "Pin / unpin project",
+            NORMAL_MODES,
+            "Sidebar",
+        ),
         rk_display(
             KeyCombo::new(KeyModifiers::NONE, KeyCode::Char('d')),
             "Delete project",
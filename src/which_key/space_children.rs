@@ -24,6 +24,18 @@ pub fn build_space_children(kb: &KeyBindings) -> Vec<RuntimeKeyBinding> {
             CommandAction::SessionSelector,
             NORMAL_MODES,
         ),
+        rk_leaf(
+            parse(&kb.project_model),
+            "Switch Model",
+            CommandAction::ModelPicker,
+            NORMAL_MODES,
+        ),
+        rk_leaf(
+            parse(&kb.project_mute),
+            "Mute Notifications",
+            CommandAction::ToggleProjectMute,
+            NORMAL_MODES,
+        ),
     ];
 
     let window_children = vec![
@@ -98,6 +110,12 @@ pub fn build_space_children(kb: &KeyBindings) -> Vec<RuntimeKeyBinding> {
                     CommandAction::NewTerminalTab,
                     NORMAL_MODES,
                 ),
+                rk_leaf(
+                    parse(&kb.terminal_split_tab),
+                    "Split",
+                    CommandAction::SplitTerminalTab,
+                    NORMAL_MODES,
+                ),
                 rk_leaf(
                     parse(&kb.terminal_next_tab),
                     "Next Tab",
@@ -122,6 +140,36 @@ pub fn build_space_children(kb: &KeyBindings) -> Vec<RuntimeKeyBinding> {
                     CommandAction::SearchTerminal,
                     NORMAL_MODES,
                 ),
+                rk_leaf(
+                    parse(&kb.terminal_snippets),
+                    "Snippets",
+                    CommandAction::CommandSnippets,
+                    NORMAL_MODES,
+                ),
+                rk_leaf(
+                    parse(&kb.terminal_save_snippet),
+                    "Save Last Command",
+                    CommandAction::SaveLastCommand,
+                    NORMAL_MODES,
+                ),
+                rk_leaf(
+                    parse(&kb.terminal_set_command),
+                    "Set Terminal Command",
+                    CommandAction::SetTerminalCommand,
+                    NORMAL_MODES,
+                ),
+                rk_leaf(
+                    parse(&kb.terminal_history),
+                    "Command History",
+                    CommandAction::CommandHistory,
+                    NORMAL_MODES,
+                ),
+                rk_leaf(
+                    parse(&kb.terminal_failed_command),
+                    "Rerun/Copy Failed",
+                    CommandAction::FailedCommand,
+                    NORMAL_MODES,
+                ),
             ];
             rk_prefix(
                 parse(&kb.leader_terminal),
@@ -174,6 +222,12 @@ pub fn build_space_children(kb: &KeyBindings) -> Vec<RuntimeKeyBinding> {
                     CommandAction::ZenGit,
                     NORMAL_MODES,
                 ),
+                rk_leaf(
+                    parse(&kb.zen_presentation),
+                    "Presentation",
+                    CommandAction::PresentationMode,
+                    NORMAL_MODES,
+                ),
             ];
             rk_prefix(parse(&kb.leader_zen), "Zen", NORMAL_MODES, zen_children)
         },
@@ -219,6 +273,48 @@ pub fn build_space_children(kb: &KeyBindings) -> Vec<RuntimeKeyBinding> {
             CommandAction::ToggleRoutinePanel,
             NORMAL_MODES,
         ),
+        rk_leaf(
+            parse(&kb.leader_mcp),
+            "Toggle MCP",
+            CommandAction::ToggleMcp,
+            NORMAL_MODES,
+        ),
+        rk_leaf(
+            parse(&kb.leader_blame),
+            "Blame Line",
+            CommandAction::BlameLine,
+            NORMAL_MODES,
+        ),
+        rk_leaf(
+            parse(&kb.leader_editor),
+            "Open in Editor",
+            CommandAction::OpenInExternalEditor,
+            NORMAL_MODES,
+        ),
+        rk_leaf(
+            parse(&kb.leader_find_file),
+            "Find File",
+            CommandAction::FindFile,
+            NORMAL_MODES,
+        ),
+        rk_leaf(
+            parse(&kb.leader_live_grep),
+            "Live Grep",
+            CommandAction::LiveGrep,
+            NORMAL_MODES,
+        ),
+        rk_leaf(
+            parse(&kb.leader_run_command),
+            "Run Command",
+            CommandAction::RunCommand,
+            NORMAL_MODES,
+        ),
+        rk_leaf(
+            parse(&kb.leader_mouse),
+            "Toggle Mouse",
+            CommandAction::ToggleMouseCapture,
+            NORMAL_MODES,
+        ),
         {
             let swap_children = vec![
                 rk_leaf(